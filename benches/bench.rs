@@ -3,6 +3,7 @@ extern crate fastmath;
 
 use rayon::prelude::*;
 use fastmath::*;
+use fastmath::stats::*;
 use criterion::{Criterion, BenchmarkGroup, measurement::WallTime};
 use criterion::{black_box, criterion_group, criterion_main};
 
@@ -41,6 +42,36 @@ fn exp_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[
     });
 }
 
+fn expm1_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_expm1()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::expm1(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_expm1()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::expm1(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn exp10_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_exp10()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::exp10(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_exp10()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::exp10(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
 fn cos_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
     group.bench_function("f64_fast", |b| {
         b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_cos()).collect::<Vec<f64>>())
@@ -111,6 +142,57 @@ fn sin_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[
     });
 }
 
+fn sincos_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sincos()).collect::<Vec<(f64, f64)>>())
+    });
+    group.bench_function("f64_lookup", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).lookup_sincos()).collect::<Vec<(f64, f64)>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).sin_cos()).collect::<Vec<(f64, f64)>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sincos()).collect::<Vec<(f32, f32)>>())
+    });
+    group.bench_function("f32_lookup", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).lookup_sincos()).collect::<Vec<(f32, f32)>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).sin_cos()).collect::<Vec<(f32, f32)>>())
+    });
+}
+
+fn asin_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_asin()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::asin(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_asin()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::asin(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn acos_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_acos()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::acos(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_acos()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::acos(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
 fn tan_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
     group.bench_function("f64_fast", |b| {
         b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_tan()).collect::<Vec<f64>>())
@@ -126,48 +208,1128 @@ fn tan_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[
     });
 }
 
-fn sigmoid_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+fn log_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
     group.bench_function("f64_fast", |b| {
-        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sigmoid()).collect::<Vec<f64>>())
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_ln()).collect::<Vec<f64>>())
     });
     group.bench_function("f64_builtin", |b| {
-        b.iter(|| x_f64.iter().map(|&x| exact::f64::sigmoid(black_box(x))).collect::<Vec<f64>>())
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::ln(black_box(x))).collect::<Vec<f64>>())
     });
     group.bench_function("f32_fast", |b| {
-        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sigmoid()).collect::<Vec<f32>>())
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_ln()).collect::<Vec<f32>>())
     });
     group.bench_function("f32_builtin", |b| {
-        b.iter(|| x_f32.iter().map(|&x| exact::f32::sigmoid(black_box(x))).collect::<Vec<f32>>())
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::ln(black_box(x))).collect::<Vec<f32>>())
     });
 }
 
-fn criterion_benchmark(c: &mut Criterion) {
-    // to ensure tests are fair, we need to instantiate the lookup tables
-    1.0f64.lookup_cos();
-    1.0f32.lookup_cos();
-    // Then, tests can begin
-    let mut group = c.benchmark_group("pow2");
-    pow2_benchmarks(&mut group, &X_F64, &X_F32);
-    group.finish();
+fn sqrt_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sqrt()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_fast_newton_0", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sqrt_newton::<0>()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_fast_newton_1", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sqrt_newton::<1>()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_fast_newton_3", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sqrt_newton::<3>()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::sqrt(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sqrt()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_fast_newton_0", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sqrt_newton::<0>()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_fast_newton_2", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sqrt_newton::<2>()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::sqrt(black_box(x))).collect::<Vec<f32>>())
+    });
+}
 
-    let mut group = c.benchmark_group("exp");
-    exp_benchmarks(&mut group, &X_F64, &X_F32);
-    group.finish();
+fn inv_sqrt_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_inv_sqrt()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::inv_sqrt(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_inv_sqrt()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::inv_sqrt(black_box(x))).collect::<Vec<f32>>())
+    });
+}
 
-    let mut group = c.benchmark_group("cos");
-    cos_benchmarks(&mut group, &X_F64, &X_F32);
-    group.finish();
+fn cbrt_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_cbrt()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::cbrt(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_cbrt()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::cbrt(black_box(x))).collect::<Vec<f32>>())
+    });
+}
 
-    let mut group = c.benchmark_group("sin");
-    sin_benchmarks(&mut group, &X_F64, &X_F32);
-    group.finish();
+fn hypot_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().zip(x_f64.iter().rev()).map(|(&x, &y)| black_box(x).fast_hypot(black_box(y))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().zip(x_f64.iter().rev()).map(|(&x, &y)| exact::f64::hypot(black_box(x), black_box(y))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().zip(x_f32.iter().rev()).map(|(&x, &y)| black_box(x).fast_hypot(black_box(y))).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().zip(x_f32.iter().rev()).map(|(&x, &y)| exact::f32::hypot(black_box(x), black_box(y))).collect::<Vec<f32>>())
+    });
+}
 
-    let mut group = c.benchmark_group("tan");
-    tan_benchmarks(&mut group, &X_F64, &X_F32);
-    group.finish();
+fn log_add_exp_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().zip(x_f64.iter().rev()).map(|(&x, &y)| black_box(x).fast_log_add_exp(black_box(y))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().zip(x_f64.iter().rev()).map(|(&x, &y)| exact::f64::log_add_exp(black_box(x), black_box(y))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().zip(x_f32.iter().rev()).map(|(&x, &y)| black_box(x).fast_log_add_exp(black_box(y))).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().zip(x_f32.iter().rev()).map(|(&x, &y)| exact::f32::log_add_exp(black_box(x), black_box(y))).collect::<Vec<f32>>())
+    });
+}
 
-    let mut group = c.benchmark_group("sigmoid");
-    sigmoid_benchmarks(&mut group, &X_F64, &X_F32);
+fn logsumexp_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| black_box(x_f64).fast_logsumexp())
+    });
+    group.bench_function("f64_fast_par", |b| {
+        b.iter(|| black_box(x_f64).par_logsumexp())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| exact::f64::logsumexp(black_box(x_f64)))
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| black_box(x_f32).fast_logsumexp())
+    });
+    group.bench_function("f32_fast_par", |b| {
+        b.iter(|| black_box(x_f32).par_logsumexp())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| exact::f32::logsumexp(black_box(x_f32)))
+    });
+}
+
+fn softmax_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.chunks(100).map(|row| black_box(row).fast_softmax()).collect::<Vec<Vec<f64>>>())
+    });
+    group.bench_function("f64_fast_par_rows", |b| {
+        b.iter(|| black_box(x_f64.to_vec()).par_softmax_rows(100))
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.chunks(100).map(|row| exact::f64::softmax(black_box(row))).collect::<Vec<Vec<f64>>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.chunks(100).map(|row| black_box(row).fast_softmax()).collect::<Vec<Vec<f32>>>())
+    });
+    group.bench_function("f32_fast_par_rows", |b| {
+        b.iter(|| black_box(x_f32.to_vec()).par_softmax_rows(100))
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.chunks(100).map(|row| exact::f32::softmax(black_box(row))).collect::<Vec<Vec<f32>>>())
+    });
+}
+
+fn entropy_benchmarks(group: &mut BenchmarkGroup<WallTime>, p_f64: &[f64], p_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| black_box(p_f64).fast_entropy())
+    });
+    group.bench_function("f64_fast_par", |b| {
+        b.iter(|| black_box(p_f64).par_entropy())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| exact::f64::entropy(black_box(p_f64)))
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| black_box(p_f32).fast_entropy())
+    });
+    group.bench_function("f32_fast_par", |b| {
+        b.iter(|| black_box(p_f32).par_entropy())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| exact::f32::entropy(black_box(p_f32)))
+    });
+}
+
+fn kl_divergence_benchmarks(group: &mut BenchmarkGroup<WallTime>, p_f64: &[f64], p_f32: &[f32]) {
+    let (q_f64, q_f32) = (p_f64.iter().rev().copied().collect::<Vec<f64>>(), p_f32.iter().rev().copied().collect::<Vec<f32>>());
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| black_box(p_f64).fast_kl_divergence(&q_f64))
+    });
+    group.bench_function("f64_fast_par", |b| {
+        b.iter(|| black_box(p_f64).par_kl_divergence(&q_f64))
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| exact::f64::kl_divergence(black_box(p_f64), &q_f64))
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| black_box(p_f32).fast_kl_divergence(&q_f32))
+    });
+    group.bench_function("f32_fast_par", |b| {
+        b.iter(|| black_box(p_f32).par_kl_divergence(&q_f32))
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| exact::f32::kl_divergence(black_box(p_f32), &q_f32))
+    });
+}
+
+fn atan_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_atan()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::atan(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_atan()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::atan(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn sinh_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sinh()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::sinh(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sinh()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::sinh(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn cosh_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_cosh()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::cosh(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_cosh()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::cosh(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn tanh_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_tanh()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::tanh(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_tanh()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::tanh(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn log1p_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_log1p()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::log1p(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_log1p()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::log1p(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn db_to_gain_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).db_to_gain()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::db_to_gain(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).db_to_gain()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::db_to_gain(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn gain_to_db_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).gain_to_db()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::gain_to_db(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).gain_to_db()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::gain_to_db(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn pow_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_pow(2.4)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::pow(black_box(x), 2.4)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_fast_powi", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_powi(7)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin_powi", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::powi(black_box(x), 7)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_pow(2.4)).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::pow(black_box(x), 2.4)).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_fast_powi", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_powi(7)).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin_powi", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::powi(black_box(x), 7)).collect::<Vec<f32>>())
+    });
+}
+
+fn erf_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_erf()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::erf(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_erf()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::erf(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn erfc_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_erfc()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::erfc(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_erfc()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::erfc(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn erfinv_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_erfinv()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::erfinv(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_erfinv()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::erfinv(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn gamma_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_gamma()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::gamma(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_gamma()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::gamma(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn lgamma_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_lgamma()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::lgamma(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_lgamma()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::lgamma(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn digamma_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_digamma()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::digamma(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_digamma()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::digamma(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn sigmoid_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sigmoid()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_fast_newton", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sigmoid_newton::<2>()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::sigmoid(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sigmoid()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_fast_newton", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sigmoid_newton::<2>()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::sigmoid(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn gelu_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_gelu()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_fast_par", |b| {
+        b.iter(|| black_box(x_f64).par_gelu())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::gelu(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_gelu()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_fast_par", |b| {
+        b.iter(|| black_box(x_f32).par_gelu())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::gelu(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn gelu_erf_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_gelu_erf()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::gelu_erf(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_gelu_erf()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::gelu_erf(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn silu_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_silu()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::silu(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_silu()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::silu(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn softplus_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_softplus()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::softplus(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_softplus()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::softplus(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn mish_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_mish()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::mish(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_mish()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::mish(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn elu_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_elu(1.0)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::elu(black_box(x), 1.0)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_elu(1.0)).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::elu(black_box(x), 1.0)).collect::<Vec<f32>>())
+    });
+}
+
+fn normal_pdf_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).normal_pdf()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::normal_pdf(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).normal_pdf()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::normal_pdf(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn normal_cdf_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).normal_cdf()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::normal_cdf(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).normal_cdf()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::normal_cdf(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn normal_probit_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).normal_probit()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::normal_probit(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).normal_probit()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::normal_probit(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn sinc_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sinc()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::sinc(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sinc()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::sinc(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn lanczos_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    let (table_f64, table_f32) = (lookup::LanczosLookupTable::<f64>::new(3.0), lookup::LanczosLookupTable::<f32>::new(3.0));
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_lanczos(3.0)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_lookup", |b| {
+        b.iter(|| x_f64.iter().map(|&x| table_f64.lookup(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::lanczos(black_box(x), 3.0)).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_lanczos(3.0)).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_lookup", |b| {
+        b.iter(|| x_f32.iter().map(|&x| table_f32.lookup(black_box(x))).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::lanczos(black_box(x), 3.0)).collect::<Vec<f32>>())
+    });
+}
+
+fn asinh_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_asinh()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::asinh(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_asinh()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::asinh(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn acosh_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_acosh()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::acosh(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_acosh()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::acosh(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn atanh_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_atanh()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::atanh(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_atanh()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::atanh(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn sec_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_sec()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::sec(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_sec()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::sec(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn csc_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_csc()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::csc(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_csc()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::csc(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn cot_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_cot()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::cot(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_cot()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::cot(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn j0_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_j0()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::j0(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_j0()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::j0(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn j1_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_j1()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::j1(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_j1()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::j1(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn y0_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_y0()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::y0(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_y0()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::y0(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn i0_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_i0()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::i0(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_i0()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::i0(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn recip_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_recip()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::recip(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_recip()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::recip(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn div_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().zip(x_f64.iter().rev()).map(|(&x, &y)| black_box(x).fast_div(black_box(y))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().zip(x_f64.iter().rev()).map(|(&x, &y)| exact::f64::div(black_box(x), black_box(y))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().zip(x_f32.iter().rev()).map(|(&x, &y)| black_box(x).fast_div(black_box(y))).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().zip(x_f32.iter().rev()).map(|(&x, &y)| exact::f32::div(black_box(x), black_box(y))).collect::<Vec<f32>>())
+    });
+}
+
+fn floor_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_floor()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::floor(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_floor()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::floor(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn round_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_round()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::round(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_round()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::round(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn fmod_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).fast_fmod(black_box(std::f64::consts::TAU))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::fmod(black_box(x), black_box(std::f64::consts::TAU))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).fast_fmod(black_box(std::f32::consts::TAU))).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::fmod(black_box(x), black_box(std::f32::consts::TAU))).collect::<Vec<f32>>())
+    });
+}
+
+fn wrap_to_tau_benchmarks(group: &mut BenchmarkGroup<WallTime>, x_f64: &[f64], x_f32: &[f32]) {
+    group.bench_function("f64_fast", |b| {
+        b.iter(|| x_f64.iter().map(|&x| black_box(x).wrap_to_tau()).collect::<Vec<f64>>())
+    });
+    group.bench_function("f64_builtin", |b| {
+        b.iter(|| x_f64.iter().map(|&x| exact::f64::wrap_to_tau(black_box(x))).collect::<Vec<f64>>())
+    });
+    group.bench_function("f32_fast", |b| {
+        b.iter(|| x_f32.iter().map(|&x| black_box(x).wrap_to_tau()).collect::<Vec<f32>>())
+    });
+    group.bench_function("f32_builtin", |b| {
+        b.iter(|| x_f32.iter().map(|&x| exact::f32::wrap_to_tau(black_box(x))).collect::<Vec<f32>>())
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    // to ensure tests are fair, we need to instantiate the lookup tables
+    1.0f64.lookup_cos();
+    1.0f32.lookup_cos();
+    // Then, tests can begin
+    let mut group = c.benchmark_group("pow2");
+    pow2_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("exp");
+    exp_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("expm1");
+    expm1_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("exp10");
+    exp10_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("cos");
+    cos_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("sin");
+    sin_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("sincos");
+    sincos_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let x_f64_unit = X_F64.iter().map(|&x| x / 10.0).collect::<Vec<f64>>();
+    let x_f32_unit = X_F32.iter().map(|&x| x / 10.0).collect::<Vec<f32>>();
+    let mut group = c.benchmark_group("asin");
+    asin_benchmarks(&mut group, &x_f64_unit, &x_f32_unit);
+    group.finish();
+
+    let mut group = c.benchmark_group("acos");
+    acos_benchmarks(&mut group, &x_f64_unit, &x_f32_unit);
+    group.finish();
+
+    let mut group = c.benchmark_group("tan");
+    tan_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("log");
+    let x_f64_pos = X_F64.iter().map(|&x| x.abs() + f64::MIN_POSITIVE).collect::<Vec<f64>>();
+    let x_f32_pos = X_F32.iter().map(|&x| x.abs() + f32::MIN_POSITIVE).collect::<Vec<f32>>();
+    log_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("log1p");
+    log1p_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("db_to_gain");
+    db_to_gain_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("gain_to_db");
+    gain_to_db_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("pow");
+    pow_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("sqrt");
+    sqrt_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("inv_sqrt");
+    inv_sqrt_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("cbrt");
+    cbrt_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("hypot");
+    hypot_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("log_add_exp");
+    log_add_exp_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("logsumexp");
+    logsumexp_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("softmax");
+    softmax_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let p_f64 = exact::f64::softmax(&X_F64);
+    let p_f32 = exact::f32::softmax(&X_F32);
+    let mut group = c.benchmark_group("entropy");
+    entropy_benchmarks(&mut group, &p_f64, &p_f32);
+    group.finish();
+
+    let mut group = c.benchmark_group("kl_divergence");
+    kl_divergence_benchmarks(&mut group, &p_f64, &p_f32);
+    group.finish();
+
+    let mut group = c.benchmark_group("atan");
+    atan_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("sinh");
+    sinh_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("cosh");
+    cosh_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("tanh");
+    tanh_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("erf");
+    erf_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("erfc");
+    erfc_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("erfinv");
+    erfinv_benchmarks(&mut group, &x_f64_unit, &x_f32_unit);
+    group.finish();
+
+    let mut group = c.benchmark_group("gamma");
+    gamma_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    // [10, 170] for f64 and [10, 35] for f32, where fast_gamma uses Stirling's series up to overflow
+    let x_f64_gamma_large = X_F64.iter().map(|&x| 90.0 + 8.0 * x).collect::<Vec<f64>>();
+    let x_f32_gamma_large = X_F32.iter().map(|&x| 22.5 + 1.25 * x).collect::<Vec<f32>>();
+    let mut group = c.benchmark_group("gamma_large");
+    gamma_benchmarks(&mut group, &x_f64_gamma_large, &x_f32_gamma_large);
+    group.finish();
+
+    let mut group = c.benchmark_group("lgamma");
+    lgamma_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("digamma");
+    digamma_benchmarks(&mut group, &x_f64_pos, &x_f32_pos);
+    group.finish();
+
+    let mut group = c.benchmark_group("sigmoid");
+    sigmoid_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("gelu");
+    gelu_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("gelu_erf");
+    gelu_erf_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("silu");
+    silu_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("softplus");
+    softplus_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("mish");
+    mish_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("elu");
+    elu_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("normal_pdf");
+    normal_pdf_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("normal_cdf");
+    normal_cdf_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("normal_probit");
+    let x_f64_prob = X_F64.iter().map(|&x| 0.5 + x / 20.001).collect::<Vec<f64>>();
+    let x_f32_prob = X_F32.iter().map(|&x| 0.5 + x / 20.001).collect::<Vec<f32>>();
+    normal_probit_benchmarks(&mut group, &x_f64_prob, &x_f32_prob);
+    group.finish();
+
+    let mut group = c.benchmark_group("sinc");
+    sinc_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("lanczos");
+    lanczos_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("asinh");
+    asinh_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("acosh");
+    let x_f64_above_1 = x_f64_pos.iter().map(|&x| 1.0 + x).collect::<Vec<f64>>();
+    let x_f32_above_1 = x_f32_pos.iter().map(|&x| 1.0 + x).collect::<Vec<f32>>();
+    acosh_benchmarks(&mut group, &x_f64_above_1, &x_f32_above_1);
+    group.finish();
+
+    let mut group = c.benchmark_group("atanh");
+    atanh_benchmarks(&mut group, &x_f64_unit, &x_f32_unit);
+    group.finish();
+
+    let mut group = c.benchmark_group("sec");
+    sec_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("csc");
+    csc_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("cot");
+    cot_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("j0");
+    j0_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("j1");
+    j1_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("y0");
+    y0_benchmarks(&mut group, &x_f64_above_1, &x_f32_above_1);
+    group.finish();
+
+    let mut group = c.benchmark_group("i0");
+    i0_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("recip");
+    recip_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("div");
+    div_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("floor");
+    floor_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    let mut group = c.benchmark_group("round");
+    round_benchmarks(&mut group, &X_F64, &X_F32);
+    group.finish();
+
+    // Angles over many periods, as the trig functions and cycling lookup tables see them
+    let x_f64_wide = X_F64.iter().map(|&x| x * 1000.0).collect::<Vec<f64>>();
+    let x_f32_wide = X_F32.iter().map(|&x| x * 1000.0).collect::<Vec<f32>>();
+    let mut group = c.benchmark_group("fmod");
+    fmod_benchmarks(&mut group, &x_f64_wide, &x_f32_wide);
+    group.finish();
+
+    let mut group = c.benchmark_group("wrap_to_tau");
+    wrap_to_tau_benchmarks(&mut group, &x_f64_wide, &x_f32_wide);
     group.finish();
 }
 
@@ -72,7 +72,7 @@ mod precalculate_test_tables {
                             .try_into().map_err(|_| "Failed to convert Vec<f64> to [f64; X_SIZE]")?;
 
             let data = format!(
-                "#[cfg(test)]\n#[allow(dead_code)]\npub const X_F32: [f32; {}] = {:?};\n#[cfg(test)]\n#[allow(dead_code)]\npub const X_F64: [f64; {}] = {:?};",
+                "#[cfg(test)]\n#[allow(dead_code, clippy::approx_constant, clippy::large_const_arrays)]\npub const X_F32: [f32; {}] = {:?};\n#[cfg(test)]\n#[allow(dead_code, clippy::approx_constant, clippy::large_const_arrays)]\npub const X_F64: [f64; {}] = {:?};",
                 X_SIZE, x_f32, X_SIZE, x_f64
             );
 
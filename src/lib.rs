@@ -3,6 +3,7 @@
 
 pub mod lookup;
 pub mod macros;
+pub mod stats;
 
 mod fastmath;
 pub use fastmath::*;
@@ -10,6 +10,8 @@ use crate::{
     impl_fbitfbit_lookup_table,
     impl_cycling_fbitfbit_lookup_table,
 };
+use crate::FastBessel;
+use crate::{FastFmod, WrapAngle};
 use crate::lookup::TABLE_SIZE;
 use crate::lookup::const_tables::*;
 
@@ -34,6 +36,34 @@ impl ToIndex for f64 {
     }
 }
 
+// Rounds up on the index side: truncates with ToIndex and steps up if that lands below the position, so
+// that the key type needs no rounding of its own. Infinities saturate and NaN gives 0, as with `as`
+#[inline]
+fn ceil_index<T: Float + ToIndex>(position: T) -> usize {
+    let index = position.to_index();
+    if T::from(index).unwrap() < position { index.saturating_add(1) } else { index }
+}
+
+// FastFmod and WrapAngle through f64, which every key type converts to losslessly, so that the cycling
+// and sin tables keep their bounds. The remainder is exact in f64, so it converts back exactly, but the
+// wrapped angle can round up to tau on the way back
+#[inline]
+fn fmod_key<T: Float>(key: T, range: T) -> T
+where
+    f64: From<T>,
+{
+    T::from(f64::from(key).fast_fmod(f64::from(range))).unwrap()
+}
+
+#[inline]
+fn wrap_key_to_tau<T: Float + FloatConst>(key: T) -> T
+where
+    f64: From<T>,
+{
+    let wrapped = T::from(f64::from(key).wrap_to_tau()).unwrap();
+    if wrapped >= T::TAU() { wrapped - T::TAU() } else { wrapped }
+}
+
 pub trait FromIndex {
     fn from_index(index: usize) -> Self;
 }
@@ -65,6 +95,7 @@ where
     f64: From<T1>,
     T2: Float + std::marker::Send + std::marker::Sync,
 {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(keys: [T1; TABLE_SIZE], values: [T2; TABLE_SIZE]) -> Self {
         FloatLookupTable {
             keys: keys,
@@ -78,7 +109,7 @@ where
         // Find value at the nearest key, using interpolative search
         // This assumes evenly distributed keys
         let max_index: T1 = T1::from(self.keys.len() - 1).unwrap();
-        let index: usize = (key * max_index / self.max_key).ceil().to_index();
+        let index: usize = ceil_index(key * max_index / self.max_key);
         // Ensure value cannot be out of bounds
         *self.values.get(index).unwrap_or_else(
             || -> &T2 {
@@ -93,7 +124,7 @@ where
 
     pub fn key_to_index(&self, key: T1) -> usize
     {
-        (key * T1::from(self.keys.len() - 1).unwrap() / self.max_key).ceil().to_index()
+        ceil_index(key * T1::from(self.keys.len() - 1).unwrap() / self.max_key)
     }
 
     pub fn lookup(&self, key: T1) -> T2 {
@@ -138,6 +169,7 @@ where
     f64: From<T1>,
     T2: Float + std::marker::Send + std::marker::Sync,
 {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(keys: [T1; TABLE_SIZE], values: [T2; TABLE_SIZE], lower_bound: T1, upper_bound: T1) -> Self {
         CyclingFloatLookupTable {
             lookup_table: FloatLookupTable::new(keys, values),
@@ -148,7 +180,7 @@ where
 
     pub fn lookup(&self, key: T1) -> T2 {
         self.lookup_table.lookup(
-            (key % self.range) + self.lower_bound
+            fmod_key(key, self.range) + self.lower_bound
         )
     }
 
@@ -196,7 +228,8 @@ where
         self.lookup_table.lookup_table.get_next(key)
     }
 
-    pub fn lookup(&self, key: T) -> T {
+    // Reduce |key| into [0, TAU) and find which quadrant it falls in
+    fn quadrant(&self, key: T) -> (T, usize) {
         let mut abs_key = key.abs();
         let comparisons: [T; 4] = [T::FRAC_PI_2(), T::PI(), T::PI() + T::FRAC_PI_2(), T::TAU()];
         let quadrant: usize =
@@ -205,13 +238,18 @@ where
                 .position(|&x: &T| -> bool { abs_key < x })
                 .unwrap_or_else(
                     || -> usize {
-                        abs_key = abs_key % T::TAU();
+                        abs_key = wrap_key_to_tau(abs_key);
                         comparisons
                             .iter()
                             .position(|&x: &T| -> bool { abs_key < x })
                             .expect("This should literally be mathematically impossible")
                     }
                 );
+        (abs_key, quadrant)
+    }
+
+    pub fn lookup(&self, key: T) -> T {
+        let (abs_key, quadrant) = self.quadrant(key);
         match (key.is_sign_negative(), quadrant) {
             (true,  0) => self.direct_lookup(abs_key).neg(),
             (true,  1) => self.direct_lookup(T::PI() - abs_key).neg(),
@@ -225,6 +263,22 @@ where
         }
     }
 
+    // Returns (sin(key), cos(key)), reducing the key only once
+    pub fn lookup_sincos(&self, key: T) -> (T, T) {
+        let (abs_key, quadrant) = self.quadrant(key);
+        let three_frac_pi_2 = T::PI() + T::FRAC_PI_2();
+        // Within each quadrant, cos is the sin table read from the other end
+        let (abs_sin, cos) = match quadrant {
+            0 => (self.direct_lookup(abs_key), self.direct_lookup(T::FRAC_PI_2() - abs_key)),
+            1 => (self.direct_lookup(T::PI() - abs_key), self.direct_lookup(abs_key - T::FRAC_PI_2()).neg()),
+            2 => (self.direct_lookup(abs_key - T::PI()).neg(), self.direct_lookup(three_frac_pi_2 - abs_key).neg()),
+            3 => (self.direct_lookup(T::TAU() - abs_key).neg(), self.direct_lookup(abs_key - three_frac_pi_2)),
+            _ => panic!("Something very strange has happened"),
+        };
+        // sin is odd and cos is even
+        if key.is_sign_negative() { (abs_sin.neg(), cos) } else { (abs_sin, cos) }
+    }
+
     pub fn map_lookups<'a, I>(&'a self, keys: I) -> impl Iterator<Item=T> + 'a
     where
         I: ToIterator<T> + 'a,
@@ -241,6 +295,7 @@ where
 }
 impl EndoSinLookupTable<f32>
 {
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         EndoSinLookupTable {
             lookup_table: CyclingFloatLookupTable::<f32, f32>::new_const(
@@ -251,6 +306,7 @@ impl EndoSinLookupTable<f32>
 }
 impl EndoSinLookupTable<f64>
 {
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         EndoSinLookupTable {
             lookup_table: CyclingFloatLookupTable::<f64, f64>::new_const(
@@ -296,6 +352,7 @@ where
 }
 impl EndoCosLookupTable<f32>
 {
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         EndoCosLookupTable {
             lookup_table: EndoSinLookupTable::<f32>{
@@ -314,6 +371,7 @@ impl EndoCosLookupTable<f32>
 }
 impl EndoCosLookupTable<f64>
 {
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         EndoCosLookupTable {
             lookup_table: EndoSinLookupTable::<f64>{
@@ -329,4 +387,145 @@ impl EndoCosLookupTable<f64>
             },
         }
     }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct LanczosLookupTable<T>
+where
+    T: Float + FloatConst + std::marker::Send + std::marker::Sync,
+    T: ToIndex,
+{
+    lookup_table: FloatLookupTable<T, T>,
+    a: T,
+}
+impl<T> LanczosLookupTable<T>
+where
+    T: Float + FloatConst + std::marker::Send + std::marker::Sync,
+    T: ToIndex,
+    f64: From<T>,
+{
+    // Tabulates the kernel over [0, a] for a fixed a. Lookups round the key up to the next entry,
+    // so each entry holds the kernel at the middle of the keys that round up to it
+    pub fn new(a: T) -> Self {
+        assert!(a > T::zero() && a.is_finite(), "the Lanczos window must be positive and finite");
+        let step = a / T::from(TABLE_SIZE - 1).unwrap();
+        let half_step = step / T::from(2).unwrap();
+        let sinc = |x: T| if x == T::zero() { T::one() } else { (T::PI() * x).sin() / (T::PI() * x) };
+
+        let mut keys = [T::zero(); TABLE_SIZE];
+        let mut values = [T::zero(); TABLE_SIZE];
+        for (i, (key, value)) in keys.iter_mut().zip(values.iter_mut()).enumerate() {
+            *key = step * T::from(i).unwrap();
+            let midpoint = (*key - half_step).max(T::zero());
+            *value = sinc(midpoint) * sinc(midpoint / a);
+        }
+        LanczosLookupTable {
+            lookup_table: FloatLookupTable::new(keys, values),
+            a,
+        }
+    }
+
+    pub fn lookup(&self, key: T) -> T {
+        // The kernel is even and vanishes outside the window
+        let abs_key = key.abs();
+        if abs_key < self.a {
+            self.lookup_table.lookup(abs_key)
+        } else if key.is_nan() {
+            key
+        } else {
+            T::zero()
+        }
+    }
+
+    pub fn map_lookups<'a, I>(&'a self, keys: I) -> impl Iterator<Item=T> + 'a
+    where
+        I: ToIterator<T> + 'a,
+    {
+        keys.into_iter().map(move |key| self.lookup(key))
+    }
+
+    pub fn par_map_lookups<'a, I>(&'a self, keys: I) -> impl ParallelIterator<Item=T> + 'a
+    where
+        I: ToParallelIterator<T> + 'a,
+    {
+        keys.into_par_iter().map(move |key| self.lookup(key))
+    }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct BesselLookupTable<T>
+where
+    T: Float + std::marker::Send + std::marker::Sync,
+    T: ToIndex,
+{
+    lookup_table: FloatLookupTable<T, T>,
+    function: fn(T) -> T,
+    odd: bool,
+}
+impl<T> BesselLookupTable<T>
+where
+    T: Float + FastBessel + std::marker::Send + std::marker::Sync,
+    T: ToIndex,
+    f64: From<T>,
+{
+    // Tabulates J0 over [0, max_key], e.g. for evaluating it on a bounded grid of radii
+    pub fn j0(max_key: T) -> Self {
+        Self::new(T::fast_j0, false, max_key)
+    }
+
+    // Tabulates J1 over [0, max_key]
+    pub fn j1(max_key: T) -> Self {
+        Self::new(T::fast_j1, true, max_key)
+    }
+
+    // Tabulates I0 over [0, max_key]. A Kaiser window with shape beta only evaluates I0 on [0, beta]
+    pub fn i0(max_key: T) -> Self {
+        Self::new(T::fast_i0, false, max_key)
+    }
+
+    // As with the Lanczos table, each entry holds the function at the middle of the keys that round up to it
+    fn new(function: fn(T) -> T, odd: bool, max_key: T) -> Self {
+        assert!(max_key > T::zero() && max_key.is_finite(), "the table range must be positive and finite");
+        let step = max_key / T::from(TABLE_SIZE - 1).unwrap();
+        let half_step = step / T::from(2).unwrap();
+
+        let mut keys = [T::zero(); TABLE_SIZE];
+        let mut values = [T::zero(); TABLE_SIZE];
+        for (i, (key, value)) in keys.iter_mut().zip(values.iter_mut()).enumerate() {
+            *key = step * T::from(i).unwrap();
+            *value = function((*key - half_step).max(T::zero()));
+        }
+        BesselLookupTable {
+            lookup_table: FloatLookupTable::new(keys, values),
+            function,
+            odd,
+        }
+    }
+
+    pub fn lookup(&self, key: T) -> T {
+        // Use the symmetry of the function to look up |key|, and fall back to computing it past the table
+        let abs_key = key.abs();
+        let value = if abs_key <= self.lookup_table.max_key {
+            self.lookup_table.lookup(abs_key)
+        } else {
+            (self.function)(abs_key)
+        };
+        if self.odd && key.is_sign_negative() { -value } else { value }
+    }
+
+    pub fn map_lookups<'a, I>(&'a self, keys: I) -> impl Iterator<Item=T> + 'a
+    where
+        I: ToIterator<T> + 'a,
+    {
+        keys.into_iter().map(move |key| self.lookup(key))
+    }
+
+    pub fn par_map_lookups<'a, I>(&'a self, keys: I) -> impl ParallelIterator<Item=T> + 'a
+    where
+        I: ToParallelIterator<T> + 'a,
+    {
+        keys.into_par_iter().map(move |key| self.lookup(key))
+    }
 }
\ No newline at end of file
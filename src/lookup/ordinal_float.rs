@@ -1,5 +1,6 @@
 use num_traits::float::Float;
 
+#[allow(clippy::derive_ord_xor_partial_ord)]
 #[derive(Default, Debug, Clone, Copy, PartialEq,  PartialOrd)]
 pub struct FloatOrd<T: Float>(pub T);
 impl<T: Float> FloatOrd<T> {
@@ -3,6 +3,7 @@ mod lookup_table {
     macro_rules! impl_fbitfbit_lookup_table {
         ($key_type:ty, $value_type:ty) => {
             impl FloatLookupTable<$key_type, $value_type> {
+                #[allow(clippy::redundant_field_names)]
                 pub const fn new_const(keys: [$key_type; TABLE_SIZE], values: [$value_type; TABLE_SIZE]) -> Self {
                     FloatLookupTable {
                         keys: keys,
@@ -18,6 +19,7 @@ mod lookup_table {
     macro_rules! impl_cycling_fbitfbit_lookup_table {
         ($key_type:ty, $value_type:ty) => {
             impl CyclingFloatLookupTable<$key_type, $value_type> {
+                #[allow(clippy::redundant_field_names)]
                 pub const fn new_const(keys: [$key_type; TABLE_SIZE], values: [$value_type; TABLE_SIZE], lower_bound: $key_type, range: $key_type) -> Self {
                     CyclingFloatLookupTable {
                         lookup_table: FloatLookupTable::<$key_type, $value_type>::new_const(keys, values),
@@ -0,0 +1,3 @@
+pub mod normal;
+
+pub use normal::*;
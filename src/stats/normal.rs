@@ -0,0 +1,232 @@
+// Standard normal distribution: density, log-density, cumulative distribution and its inverse
+#![allow(clippy::needless_arbitrary_self_type)]
+
+use std::f32::consts as f32_consts;
+use std::f64::consts as f64_consts;
+use rayon::prelude::*;
+
+use crate::fastmath::{FastErf, FastExp, FastLog, EXP_MIN_F32, EXP_MIN_F64, erfinv_giles_f32, erfinv_giles_f64};
+
+// 1 / sqrt(2 * pi) and ln(sqrt(2 * pi))
+const FRAC_1_SQRT_2PI_F32: f32 = 0.3989423;
+const FRAC_1_SQRT_2PI_F64: f64 = 0.3989422804014327;
+const LN_SQRT_2PI_F32: f32 = 0.9189385;
+const LN_SQRT_2PI_F64: f64 = 0.9189385332046728;
+
+// Beyond these values of w = -ln(4q(1 - q)) the Giles polynomials lose accuracy, so the probit
+// switches to erfinv(1 - 2q) / sqrt(w) fitted as a polynomial in 1 / sqrt(w) by minimax regression
+const PROBIT_FAR_TAIL_W_F32: f32 = 15.0;
+const PROBIT_FAR_TAIL_W_F64: f64 = 36.0;
+
+#[inline]
+fn erfcinv_far_tail_f32(w: f32) -> f32 {
+    let s = w.sqrt();
+    let u = 1.0 / s;
+    let p = 1.0010538
+        + u * (-0.053566482
+        + u * (-0.84959304
+        + u * (2.016671
+        + u * -1.5684807)));
+    s * p
+}
+#[inline]
+fn erfcinv_far_tail_f64(w: f64) -> f64 {
+    let s = w.sqrt();
+    let u = 1.0 / s;
+    let p = 1.0000570164554476
+        + u * (-0.011635303531721666
+        + u * (-1.6589419134138539
+        + u * (11.802743718609982
+        + u * (-84.58063773081412
+        + u * (499.12321046831283
+        + u * (-2005.0543231504905
+        + u * (4760.864162778856
+        + u * -5009.862066804302)))))));
+    s * p
+}
+
+pub trait NormalDistribution {
+    fn normal_pdf(self: Self) -> Self;
+    fn normal_log_pdf(self: Self) -> Self;
+    fn normal_cdf(self: Self) -> Self;
+    fn normal_probit(self: Self) -> Self;
+}
+impl NormalDistribution for f32 {
+    #[inline]
+    fn normal_pdf(self: Self) -> f32 {
+        let exponent = -0.5 * self * self;
+        // fast_exp clips instead of underflowing, so defer to std for the far tails
+        if exponent < EXP_MIN_F32 {
+            return FRAC_1_SQRT_2PI_F32 * exponent.exp();
+        }
+        FRAC_1_SQRT_2PI_F32 * exponent.fast_exp()
+    }
+
+    #[inline]
+    fn normal_log_pdf(self: Self) -> f32 {
+        -0.5 * self * self - LN_SQRT_2PI_F32
+    }
+
+    #[inline]
+    fn normal_cdf(self: Self) -> f32 {
+        0.5 * (-f32_consts::FRAC_1_SQRT_2 * self).fast_erfc()
+    }
+
+    #[inline]
+    fn normal_probit(self: Self) -> f32 {
+        if !(self > 0.0 && self < 1.0) {
+            return if self == 0.0 {
+                f32::NEG_INFINITY
+            } else if self == 1.0 {
+                f32::INFINITY
+            } else {
+                f32::NAN
+            };
+        }
+        // Work with the smaller tail so that q is exact and 1 - 2q never cancels
+        let q = if self < 0.5 { self } else { 1.0 - self };
+        let w = -(4.0 * q * (1.0 - q)).fast_ln();
+        let erfcinv = if w < PROBIT_FAR_TAIL_W_F32 {
+            erfinv_giles_f32(1.0 - 2.0 * q, w)
+        } else {
+            erfcinv_far_tail_f32(w)
+        };
+        let z = f32_consts::SQRT_2 * erfcinv;
+        if self < 0.5 { -z } else { z }
+    }
+}
+impl NormalDistribution for f64 {
+    #[inline]
+    fn normal_pdf(self: Self) -> f64 {
+        let exponent = -0.5 * self * self;
+        // fast_exp clips instead of underflowing, so defer to std for the far tails
+        if exponent < EXP_MIN_F64 {
+            return FRAC_1_SQRT_2PI_F64 * exponent.exp();
+        }
+        FRAC_1_SQRT_2PI_F64 * exponent.fast_exp()
+    }
+
+    #[inline]
+    fn normal_log_pdf(self: Self) -> f64 {
+        -0.5 * self * self - LN_SQRT_2PI_F64
+    }
+
+    #[inline]
+    fn normal_cdf(self: Self) -> f64 {
+        0.5 * (-f64_consts::FRAC_1_SQRT_2 * self).fast_erfc()
+    }
+
+    #[inline]
+    fn normal_probit(self: Self) -> f64 {
+        if !(self > 0.0 && self < 1.0) {
+            return if self == 0.0 {
+                f64::NEG_INFINITY
+            } else if self == 1.0 {
+                f64::INFINITY
+            } else {
+                f64::NAN
+            };
+        }
+        // Work with the smaller tail so that q is exact and 1 - 2q never cancels
+        let q = if self < 0.5 { self } else { 1.0 - self };
+        let w = -(4.0 * q * (1.0 - q)).fast_ln();
+        let erfcinv = if w < PROBIT_FAR_TAIL_W_F64 {
+            erfinv_giles_f64(1.0 - 2.0 * q, w)
+        } else {
+            erfcinv_far_tail_f64(w)
+        };
+        let z = f64_consts::SQRT_2 * erfcinv;
+        if self < 0.5 { -z } else { z }
+    }
+}
+impl NormalDistribution for Vec<f32> {
+    #[inline]
+    fn normal_pdf(self: Self) -> Vec<f32> {
+        self.into_iter().map(f32::normal_pdf).collect::<Vec<f32>>()
+    }
+
+    #[inline]
+    fn normal_log_pdf(self: Self) -> Vec<f32> {
+        self.into_iter().map(f32::normal_log_pdf).collect::<Vec<f32>>()
+    }
+
+    #[inline]
+    fn normal_cdf(self: Self) -> Vec<f32> {
+        self.into_iter().map(f32::normal_cdf).collect::<Vec<f32>>()
+    }
+
+    #[inline]
+    fn normal_probit(self: Self) -> Vec<f32> {
+        self.into_iter().map(f32::normal_probit).collect::<Vec<f32>>()
+    }
+}
+impl NormalDistribution for Vec<f64> {
+    #[inline]
+    fn normal_pdf(self: Self) -> Vec<f64> {
+        self.into_iter().map(f64::normal_pdf).collect::<Vec<f64>>()
+    }
+
+    #[inline]
+    fn normal_log_pdf(self: Self) -> Vec<f64> {
+        self.into_iter().map(f64::normal_log_pdf).collect::<Vec<f64>>()
+    }
+
+    #[inline]
+    fn normal_cdf(self: Self) -> Vec<f64> {
+        self.into_iter().map(f64::normal_cdf).collect::<Vec<f64>>()
+    }
+
+    #[inline]
+    fn normal_probit(self: Self) -> Vec<f64> {
+        self.into_iter().map(f64::normal_probit).collect::<Vec<f64>>()
+    }
+}
+
+pub trait ParallelNormalDistribution {
+    fn par_normal_pdf(self: Self) -> Self;
+    fn par_normal_log_pdf(self: Self) -> Self;
+    fn par_normal_cdf(self: Self) -> Self;
+    fn par_normal_probit(self: Self) -> Self;
+}
+impl ParallelNormalDistribution for Vec<f32> {
+    #[inline]
+    fn par_normal_pdf(self: Self) -> Vec<f32> {
+        self.into_par_iter().map(f32::normal_pdf).collect::<Vec<f32>>()
+    }
+
+    #[inline]
+    fn par_normal_log_pdf(self: Self) -> Vec<f32> {
+        self.into_par_iter().map(f32::normal_log_pdf).collect::<Vec<f32>>()
+    }
+
+    #[inline]
+    fn par_normal_cdf(self: Self) -> Vec<f32> {
+        self.into_par_iter().map(f32::normal_cdf).collect::<Vec<f32>>()
+    }
+
+    #[inline]
+    fn par_normal_probit(self: Self) -> Vec<f32> {
+        self.into_par_iter().map(f32::normal_probit).collect::<Vec<f32>>()
+    }
+}
+impl ParallelNormalDistribution for Vec<f64> {
+    #[inline]
+    fn par_normal_pdf(self: Self) -> Vec<f64> {
+        self.into_par_iter().map(f64::normal_pdf).collect::<Vec<f64>>()
+    }
+
+    #[inline]
+    fn par_normal_log_pdf(self: Self) -> Vec<f64> {
+        self.into_par_iter().map(f64::normal_log_pdf).collect::<Vec<f64>>()
+    }
+
+    #[inline]
+    fn par_normal_cdf(self: Self) -> Vec<f64> {
+        self.into_par_iter().map(f64::normal_cdf).collect::<Vec<f64>>()
+    }
+
+    #[inline]
+    fn par_normal_probit(self: Self) -> Vec<f64> {
+        self.into_par_iter().map(f64::normal_probit).collect::<Vec<f64>>()
+    }
+}
@@ -1,4 +1,4 @@
-#[cfg(test)]
+#![cfg(test)]
 
 use super::exact;
 
@@ -13,13 +13,12 @@ fn calculate_percentage_error<T>(vector1: &[T], vector2: &[T]) -> T
     let mut total_error = T::zero();
     for i in 0..n {
         let diff = (vector1[i] - vector2[i]).abs();
-        let error = diff / if vector1[i] == T::zero() { T::min_positive_value() } else { vector1[i] };
+        let error = diff / if vector1[i] == T::zero() { T::min_positive_value() } else { vector1[i].abs() };
         total_error = total_error + error;
     }
 
     let average_error = total_error / T::from(n).unwrap();
-    let percentage_error = average_error * T::from(100).expect("Cannot convert 100 to type T");
-    percentage_error
+    average_error * T::from(100).expect("Cannot convert 100 to type T")
 }
 
 macro_rules! panic_if_nan_or_print {
@@ -35,6 +34,7 @@ macro_rules! panic_if_nan_or_print {
 
 pub mod f64 {
     use crate::*;
+    use crate::stats::*;
     use super::exact;
     use super::calculate_percentage_error;
     
@@ -103,76 +103,1453 @@ pub mod f64 {
         );
         panic_if_nan_or_print!(percentage_error,  "fast_sigmoid<f64> percentage error")
     }
+
+    pub fn ln() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_ln()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::ln(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_ln<f64> percentage error")
+    }
+
+    pub fn log2() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log2()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::log2(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log2<f64> percentage error")
+    }
+
+    pub fn log10() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log10()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::log10(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log10<f64> percentage error")
+    }
+
+    pub fn sqrt<const ITERATIONS: usize>() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x >= 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_sqrt_newton::<ITERATIONS>()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::sqrt(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_sqrt_newton::<{}><f64> percentage error", ITERATIONS))
+    }
+
+    pub fn inv_sqrt<const ITERATIONS: usize>() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_inv_sqrt_newton::<ITERATIONS>()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::inv_sqrt(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_inv_sqrt_newton::<{}><f64> percentage error", ITERATIONS))
+    }
+
+    pub fn atan() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_atan()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::atan(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atan<f64> percentage error")
+    }
+
+    pub fn atan2() -> Result<f64, Box<dyn std::error::Error>> {
+        // Pair each y with a scrambled x so that every quadrant is sampled
+        let xy = X_F64.iter().enumerate().map(|(i, &y)| (X_F64[(i * 7919) % X_F64.len()], y)).collect::<Vec<(f64, f64)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| y.fast_atan2(x)).collect::<Vec<f64>>(),
+            &xy.iter().map(|&(x, y)| exact::f64::atan2(y, x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atan2<f64> percentage error")
+    }
+
+    pub fn asin() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x.abs() <= 1.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_asin()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::asin(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_asin<f64> percentage error")
+    }
+
+    pub fn acos() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x.abs() <= 1.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_acos()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::acos(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_acos<f64> percentage error")
+    }
+
+    pub fn sinh() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_sinh()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::sinh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sinh<f64> percentage error")
+    }
+
+    pub fn cosh() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_cosh()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::cosh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_cosh<f64> percentage error")
+    }
+
+    pub fn tanh() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_tanh()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::tanh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_tanh<f64> percentage error")
+    }
+
+    pub fn pow() -> Result<f64, Box<dyn std::error::Error>> {
+        // Bases in (0, 2] paired with scrambled exponents in [-100, 100), wherever the result is representable
+        let xy = X_F64.iter().enumerate()
+            .map(|(i, &x)| (x.abs() / 5.0, 10.0 * X_F64[(i * 7919) % X_F64.len()]))
+            .filter(|&(x, y)| exact::f64::pow(x, y).is_normal())
+            .collect::<Vec<(f64, f64)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_pow(y)).collect::<Vec<f64>>(),
+            &xy.iter().map(|&(x, y)| exact::f64::pow(x, y)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_pow<f64> percentage error")
+    }
+
+    pub fn powi() -> Result<f64, Box<dyn std::error::Error>> {
+        // Every base, including negative ones, paired with exponents in [-20, 20], wherever the result is representable
+        let xn = X_F64.iter().enumerate()
+            .map(|(i, &x)| (x, (i % 41) as i32 - 20))
+            .filter(|&(x, n)| exact::f64::powi(x, n).is_normal())
+            .collect::<Vec<(f64, i32)>>();
+        let percentage_error = calculate_percentage_error(
+            &xn.iter().map(|&(x, n)| x.fast_powi(n)).collect::<Vec<f64>>(),
+            &xn.iter().map(|&(x, n)| exact::f64::powi(x, n)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_powi<f64> percentage error")
+    }
+
+    pub fn erf() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_erf()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::erf(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_erf<f64> percentage error")
+    }
+
+    pub fn erfc() -> Result<f64, Box<dyn std::error::Error>> {
+        // Skip the far tail, where erfc underflows
+        let x = X_F64.iter().copied().filter(|&x| exact::f64::erfc(x).is_normal()).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_erfc()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::erfc(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_erfc<f64> percentage error")
+    }
+
+    pub fn erfinv() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().map(|&x| x / 10.0).filter(|&x| x.abs() < 1.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_erfinv()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::erfinv(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_erfinv<f64> percentage error")
+    }
+
+    pub fn gamma() -> Result<f64, Box<dyn std::error::Error>> {
+        // Skip the poles at zero and the negative integers
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0 || x != x.round()).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_gamma()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::gamma(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_gamma<f64> percentage error")
+    }
+
+    pub fn lgamma() -> Result<f64, Box<dyn std::error::Error>> {
+        // Also skip the zeros at 1 and 2, where the relative error is meaningless
+        let x = X_F64.iter().copied().filter(|&x| (x > 0.0 || x != x.round()) && exact::f64::lgamma(x).abs() > 0.01).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_lgamma()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::lgamma(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_lgamma<f64> percentage error")
+    }
+
+    pub fn digamma() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| (x > 0.0 || x != x.round()) && exact::f64::digamma(x).abs() > 0.01).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_digamma()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::digamma(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_digamma<f64> percentage error")
+    }
+
+    pub fn cbrt<const ITERATIONS: usize>() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_cbrt_newton::<ITERATIONS>()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::cbrt(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_cbrt_newton::<{}><f64> percentage error", ITERATIONS))
+    }
+
+    pub fn hypot() -> Result<f64, Box<dyn std::error::Error>> {
+        // Pair each x with a scrambled y so that every ratio of components is sampled
+        let xy = X_F64.iter().enumerate().map(|(i, &x)| (x, X_F64[(i * 7919) % X_F64.len()])).collect::<Vec<(f64, f64)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_hypot(y)).collect::<Vec<f64>>(),
+            &xy.iter().map(|&(x, y)| exact::f64::hypot(x, y)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_hypot<f64> percentage error")
+    }
+
+    // Geometric samples on both sides of zero, where the grid from config.rs is far too coarse
+    fn near_zero() -> Vec<f64> {
+        (-1200..=-300).map(|e| 10.0f64.powf(e as f64 / 100.0)).flat_map(|x| [x, -x]).collect()
+    }
+
+    pub fn expm1() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_expm1()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::expm1(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_expm1<f64> percentage error")
+    }
+
+    pub fn expm1_near_zero() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_expm1()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::expm1(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_expm1<f64> percentage error near zero")
+    }
+
+    pub fn log1p() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > -1.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log1p()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::log1p(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log1p<f64> percentage error")
+    }
+
+    pub fn log1p_near_zero() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log1p()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::log1p(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log1p<f64> percentage error near zero")
+    }
+
+    pub fn exp10() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_exp10()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::exp10(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_exp10<f64> percentage error")
+    }
+
+    pub fn db_to_gain() -> Result<f64, Box<dyn std::error::Error>> {
+        // Cover +-100 dB
+        let db = X_F64.iter().map(|&x| 10.0 * x).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &db.iter().map(|&x| x.db_to_gain()).collect::<Vec<f64>>(),
+            &db.iter().map(|&x| exact::f64::db_to_gain(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "db_to_gain<f64> percentage error")
+    }
+
+    pub fn gain_to_db() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.gain_to_db()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::gain_to_db(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "gain_to_db<f64> percentage error")
+    }
+
+    pub fn db_to_power() -> Result<f64, Box<dyn std::error::Error>> {
+        // Cover +-100 dB
+        let db = X_F64.iter().map(|&x| 10.0 * x).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &db.iter().map(|&x| x.db_to_power()).collect::<Vec<f64>>(),
+            &db.iter().map(|&x| exact::f64::db_to_power(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "db_to_power<f64> percentage error")
+    }
+
+    pub fn power_to_db() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.power_to_db()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::power_to_db(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "power_to_db<f64> percentage error")
+    }
+
+    pub fn sincos_sin() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_sincos().0).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::sin(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sincos<f64> sin percentage error")
+    }
+
+    pub fn sincos_cos() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_sincos().1).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::cos(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sincos<f64> cos percentage error")
+    }
+
+    pub fn sincos_lookup_sin() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.lookup_sincos().0).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::sin(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "lookup_sincos<f64> sin percentage error")
+    }
+
+    pub fn sincos_lookup_cos() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.lookup_sincos().1).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::cos(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "lookup_sincos<f64> cos percentage error")
+    }
+
+    pub fn gelu() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_gelu()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::gelu(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_gelu<f64> percentage error")
+    }
+
+    pub fn gelu_erf() -> Result<f64, Box<dyn std::error::Error>> {
+        // Skip the far negative tail, where GELU underflows
+        let x = X_F64.iter().copied().filter(|&x| exact::f64::gelu_erf(x).is_normal()).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_gelu_erf()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::gelu_erf(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_gelu_erf<f64> percentage error")
+    }
+
+    pub fn silu() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_silu()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::silu(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_silu<f64> percentage error")
+    }
+
+    pub fn softplus() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_softplus()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::softplus(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_softplus<f64> percentage error")
+    }
+
+    pub fn mish() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_mish()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::mish(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_mish<f64> percentage error")
+    }
+
+    pub fn elu() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_elu(1.0)).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::elu(x, 1.0)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_elu<f64> percentage error")
+    }
+
+    pub fn normal_pdf() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.normal_pdf()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::normal_pdf(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_pdf<f64> percentage error")
+    }
+
+    pub fn normal_log_pdf() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.normal_log_pdf()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::normal_log_pdf(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_log_pdf<f64> percentage error")
+    }
+
+    pub fn normal_cdf() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.normal_cdf()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::normal_cdf(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_cdf<f64> percentage error")
+    }
+
+    pub fn normal_probit() -> Result<f64, Box<dyn std::error::Error>> {
+        let p = X_F64.iter().map(|&x| 0.5 + x / 20.0).filter(|&p| p > 0.0 && p < 1.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &p.iter().map(|&p| p.normal_probit()).collect::<Vec<f64>>(),
+            &p.iter().map(|&p| exact::f64::normal_probit(p)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_probit<f64> percentage error")
+    }
+
+    // Pairs of every x with a scrambled y, ordered so that x >= y for log_sub_exp
+    fn scrambled_pairs() -> Vec<(f64, f64)> {
+        X_F64.iter().enumerate().map(|(i, &x)| (x, X_F64[(i * 7919) % X_F64.len()])).collect()
+    }
+
+    pub fn log_add_exp() -> Result<f64, Box<dyn std::error::Error>> {
+        let xy = scrambled_pairs();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_log_add_exp(y)).collect::<Vec<f64>>(),
+            &xy.iter().map(|&(x, y)| exact::f64::log_add_exp(x, y)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log_add_exp<f64> percentage error")
+    }
+
+    pub fn log_sub_exp() -> Result<f64, Box<dyn std::error::Error>> {
+        let xy = scrambled_pairs().into_iter().filter(|&(x, y)| x > y).collect::<Vec<(f64, f64)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_log_sub_exp(y)).collect::<Vec<f64>>(),
+            &xy.iter().map(|&(x, y)| exact::f64::log_sub_exp(x, y)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log_sub_exp<f64> percentage error")
+    }
+
+    pub fn logsumexp() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = scrambled_pairs().into_iter().map(|(_, y)| y).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.chunks(16).map(|chunk| chunk.fast_logsumexp()).collect::<Vec<f64>>(),
+            &x.chunks(16).map(exact::f64::logsumexp).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_logsumexp<f64> percentage error")
+    }
+
+    pub fn softmax() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = scrambled_pairs().into_iter().map(|(_, y)| y).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.chunks(16).flat_map(|row| row.fast_softmax()).collect::<Vec<f64>>(),
+            &x.chunks(16).flat_map(exact::f64::softmax).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_softmax<f64> percentage error")
+    }
+
+    pub fn log_softmax() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = scrambled_pairs().into_iter().map(|(_, y)| y).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.chunks(16).flat_map(|row| row.fast_log_softmax()).collect::<Vec<f64>>(),
+            &x.chunks(16).flat_map(exact::f64::log_softmax).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log_softmax<f64> percentage error")
+    }
+
+    // Pairs of probability distributions over 16 outcomes, made by the reference softmax
+    fn distribution_pairs() -> Vec<(Vec<f64>, Vec<f64>)> {
+        let xy = scrambled_pairs();
+        xy.chunks(16).map(|chunk| {
+            let (x, y): (Vec<f64>, Vec<f64>) = chunk.iter().copied().unzip();
+            (exact::f64::softmax(&x), exact::f64::softmax(&y))
+        }).collect()
+    }
+
+    pub fn entropy() -> Result<f64, Box<dyn std::error::Error>> {
+        let pq = distribution_pairs();
+        let percentage_error = calculate_percentage_error(
+            &pq.iter().map(|(p, _)| p.fast_entropy()).collect::<Vec<f64>>(),
+            &pq.iter().map(|(p, _)| exact::f64::entropy(p)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_entropy<f64> percentage error")
+    }
+
+    pub fn cross_entropy() -> Result<f64, Box<dyn std::error::Error>> {
+        let pq = distribution_pairs();
+        let percentage_error = calculate_percentage_error(
+            &pq.iter().map(|(p, q)| p.fast_cross_entropy(q)).collect::<Vec<f64>>(),
+            &pq.iter().map(|(p, q)| exact::f64::cross_entropy(p, q)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_cross_entropy<f64> percentage error")
+    }
+
+    pub fn kl_divergence() -> Result<f64, Box<dyn std::error::Error>> {
+        let pq = distribution_pairs();
+        let percentage_error = calculate_percentage_error(
+            &pq.iter().map(|(p, q)| p.fast_kl_divergence(q)).collect::<Vec<f64>>(),
+            &pq.iter().map(|(p, q)| exact::f64::kl_divergence(p, q)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_kl_divergence<f64> percentage error")
+    }
+
+    pub fn binary_cross_entropy() -> Result<f64, Box<dyn std::error::Error>> {
+        let xy = scrambled_pairs();
+        let (y, p): (Vec<f64>, Vec<f64>) = xy.iter().map(|&(x, y)| (exact::f64::sigmoid(x), exact::f64::sigmoid(y))).unzip();
+        let percentage_error = calculate_percentage_error(
+            &y.chunks(16).zip(p.chunks(16)).map(|(y, p)| y.fast_binary_cross_entropy(p)).collect::<Vec<f64>>(),
+            &y.chunks(16).zip(p.chunks(16)).map(|(y, p)| exact::f64::binary_cross_entropy(y, p)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_binary_cross_entropy<f64> percentage error")
+    }
+
+    pub fn sinc() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_sinc()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::sinc(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sinc<f64> percentage error")
+    }
+
+    pub fn lanczos() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_lanczos(3.0)).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::lanczos(x, 3.0)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_lanczos<f64> percentage error")
+    }
+
+    pub fn lanczos_lookup() -> Result<f64, Box<dyn std::error::Error>> {
+        let table = crate::lookup::LanczosLookupTable::<f64>::new(3.0);
+        let percentage_error = calculate_percentage_error(
+            &table.map_lookups(X_F64).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::lanczos(x, 3.0)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "LanczosLookupTable<f64> percentage error")
+    }
+
+    pub fn asinh() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().map(|&x| x * x.abs()).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_asinh()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::asinh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_asinh<f64> percentage error")
+    }
+
+    pub fn asinh_near_zero() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_asinh()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::asinh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_asinh<f64> percentage error near zero")
+    }
+
+    pub fn acosh() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().map(|&x| 1.0 + x * x).filter(|&x| x > 1.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_acosh()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::acosh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_acosh<f64> percentage error")
+    }
+
+    pub fn atanh() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().map(|&x| x / 10.0).filter(|&x| x.abs() < 1.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_atanh()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::atanh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atanh<f64> percentage error")
+    }
+
+    pub fn atanh_near_zero() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_atanh()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::atanh(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atanh<f64> percentage error near zero")
+    }
+
+    // The samples further than 1e-3 from every pole at offset + k * pi
+    fn away_from_poles(offset: f64) -> Vec<f64> {
+        X_F64.iter().copied().filter(|&x| {
+            let k = ((x - offset) / std::f64::consts::PI).round();
+            (x - offset - k * std::f64::consts::PI).abs() > 1e-3
+        }).collect()
+    }
+
+    pub fn sec() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = away_from_poles(std::f64::consts::FRAC_PI_2);
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_sec()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::sec(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sec<f64> percentage error")
+    }
+
+    pub fn csc() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = away_from_poles(0.0);
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_csc()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::csc(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_csc<f64> percentage error")
+    }
+
+    pub fn cot() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = away_from_poles(0.0);
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_cot()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::cot(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_cot<f64> percentage error")
+    }
+
+    pub fn j0() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_j0()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::j0(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_j0<f64> percentage error")
+    }
+
+    pub fn j1() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_j1()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::j1(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_j1<f64> percentage error")
+    }
+
+    pub fn y0() -> Result<f64, Box<dyn std::error::Error>> {
+        // Y0 is only defined for positive x
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_y0()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::y0(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_y0<f64> percentage error")
+    }
+
+    pub fn i0() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_i0()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::i0(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_i0<f64> percentage error")
+    }
+
+    pub fn j0_lookup() -> Result<f64, Box<dyn std::error::Error>> {
+        // Skip the samples next to the zeros, where the relative error of a table is meaningless
+        let table = crate::lookup::BesselLookupTable::<f64>::j0(10.0);
+        let x = X_F64.iter().copied().filter(|&x| exact::f64::j0(x).abs() > 0.01).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &table.map_lookups(x.clone()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::j0(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "BesselLookupTable::j0<f64> percentage error")
+    }
+
+    pub fn i0_lookup() -> Result<f64, Box<dyn std::error::Error>> {
+        let table = crate::lookup::BesselLookupTable::<f64>::i0(10.0);
+        let percentage_error = calculate_percentage_error(
+            &table.map_lookups(X_F64).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::i0(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "BesselLookupTable::i0<f64> percentage error")
+    }
+
+    pub fn recip<const ITERATIONS: usize>() -> Result<f64, Box<dyn std::error::Error>> {
+        let x = X_F64.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f64>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_recip_newton::<ITERATIONS>()).collect::<Vec<f64>>(),
+            &x.iter().map(|&x| exact::f64::recip(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_recip_newton::<{}><f64> percentage error", ITERATIONS))
+    }
+
+    pub fn div() -> Result<f64, Box<dyn std::error::Error>> {
+        // Positive pairs, so that errors of opposite sign don't cancel in the average
+        let xy = scrambled_pairs().into_iter().map(|(x, y)| (x.abs(), y.abs())).filter(|&(_, y)| y > 0.0).collect::<Vec<(f64, f64)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_div(y)).collect::<Vec<f64>>(),
+            &xy.iter().map(|&(x, y)| exact::f64::div(x, y)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_div<f64> percentage error")
+    }
+
+    pub fn sigmoid_newton<const ITERATIONS: usize>() -> Result<f64, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F64.iter().map(|&x| x.fast_sigmoid_newton::<ITERATIONS>()).collect::<Vec<f64>>(),
+            &X_F64.iter().map(|&x| exact::f64::sigmoid(x)).collect::<Vec<f64>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_sigmoid_newton::<{}><f64> percentage error", ITERATIONS))
+    }
 }
 
-pub mod f32 {
-    use crate::*;
-    use super::exact;
-    use super::calculate_percentage_error;
+pub mod f32 {
+    use crate::*;
+    use crate::stats::*;
+    use super::exact;
+    use super::calculate_percentage_error;
+
+    include!("x.rs");
+
+    pub fn pow2() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_pow2()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::pow2(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_pow2<f32> percentage error")
+    }
+
+    pub fn exp() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_exp()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::exp(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_exp<f32> percentage error")
+    }
+
+    pub fn cos() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_cos()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::cos(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_cos<f32> percentage error")
+    }
+    
+    pub fn cos_lookup() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.lookup_cos()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::cos(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error,  "lookup_cos<f32> percentage error")
+    }
+
+    pub fn sin() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_sin()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::sin(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sin<f32> percentage error")
+    }
+
+    pub fn sin_lookup() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.lookup_sin()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::sin(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error,  "lookup_sin<f32> percentage error")
+    }
+
+    pub fn tan() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_tan()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::tan(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_tan<f32> percentage error")
+    }
+
+    pub fn sigmoid() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_sigmoid()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::sigmoid(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error,  "fast_sigmoid<f32> percentage error")
+    }
+
+    pub fn ln() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_ln()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::ln(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_ln<f32> percentage error")
+    }
+
+    pub fn log2() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log2()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::log2(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log2<f32> percentage error")
+    }
+
+    pub fn log10() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log10()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::log10(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log10<f32> percentage error")
+    }
+
+    pub fn sqrt<const ITERATIONS: usize>() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x >= 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_sqrt_newton::<ITERATIONS>()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::sqrt(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_sqrt_newton::<{}><f32> percentage error", ITERATIONS))
+    }
+
+    pub fn inv_sqrt<const ITERATIONS: usize>() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_inv_sqrt_newton::<ITERATIONS>()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::inv_sqrt(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_inv_sqrt_newton::<{}><f32> percentage error", ITERATIONS))
+    }
+
+    pub fn atan() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_atan()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::atan(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atan<f32> percentage error")
+    }
+
+    pub fn atan2() -> Result<f32, Box<dyn std::error::Error>> {
+        // Pair each y with a scrambled x so that every quadrant is sampled
+        let xy = X_F32.iter().enumerate().map(|(i, &y)| (X_F32[(i * 7919) % X_F32.len()], y)).collect::<Vec<(f32, f32)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| y.fast_atan2(x)).collect::<Vec<f32>>(),
+            &xy.iter().map(|&(x, y)| exact::f32::atan2(y, x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atan2<f32> percentage error")
+    }
+
+    pub fn asin() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x.abs() <= 1.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_asin()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::asin(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_asin<f32> percentage error")
+    }
+
+    pub fn acos() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x.abs() <= 1.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_acos()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::acos(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_acos<f32> percentage error")
+    }
+
+    pub fn sinh() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_sinh()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::sinh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sinh<f32> percentage error")
+    }
+
+    pub fn cosh() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_cosh()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::cosh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_cosh<f32> percentage error")
+    }
+
+    pub fn tanh() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_tanh()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::tanh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_tanh<f32> percentage error")
+    }
+
+    pub fn pow() -> Result<f32, Box<dyn std::error::Error>> {
+        // Bases in (0, 2] paired with scrambled exponents in [-100, 100), wherever the result is representable
+        let xy = X_F32.iter().enumerate()
+            .map(|(i, &x)| (x.abs() / 5.0, 10.0 * X_F32[(i * 7919) % X_F32.len()]))
+            .filter(|&(x, y)| exact::f32::pow(x, y).is_normal())
+            .collect::<Vec<(f32, f32)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_pow(y)).collect::<Vec<f32>>(),
+            &xy.iter().map(|&(x, y)| exact::f32::pow(x, y)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_pow<f32> percentage error")
+    }
+
+    pub fn powi() -> Result<f32, Box<dyn std::error::Error>> {
+        // Every base, including negative ones, paired with exponents in [-20, 20], wherever the result is representable
+        let xn = X_F32.iter().enumerate()
+            .map(|(i, &x)| (x, (i % 41) as i32 - 20))
+            .filter(|&(x, n)| exact::f32::powi(x, n).is_normal())
+            .collect::<Vec<(f32, i32)>>();
+        let percentage_error = calculate_percentage_error(
+            &xn.iter().map(|&(x, n)| x.fast_powi(n)).collect::<Vec<f32>>(),
+            &xn.iter().map(|&(x, n)| exact::f32::powi(x, n)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_powi<f32> percentage error")
+    }
 
-    include!("x.rs");
+    pub fn erf() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_erf()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::erf(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_erf<f32> percentage error")
+    }
 
-    pub fn pow2() -> Result<f32, Box<dyn std::error::Error>> {
+    pub fn erfc() -> Result<f32, Box<dyn std::error::Error>> {
+        // Skip the far tail, where erfc underflows
+        let x = X_F32.iter().copied().filter(|&x| exact::f32::erfc(x).is_normal()).collect::<Vec<f32>>();
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.fast_pow2()).collect::<Vec<f32>>(),
-            &X_F32.iter().map(|&x| exact::f32::pow2(x)).collect::<Vec<f32>>()
+            &x.iter().map(|&x| x.fast_erfc()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::erfc(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error, "fast_pow2<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, "fast_erfc<f32> percentage error")
     }
 
-    pub fn exp() -> Result<f32, Box<dyn std::error::Error>> {
+    pub fn erfinv() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().map(|&x| x / 10.0).filter(|&x| x.abs() < 1.0).collect::<Vec<f32>>();
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.fast_exp()).collect::<Vec<f32>>(),
-            &X_F32.iter().map(|&x| exact::f32::exp(x)).collect::<Vec<f32>>()
+            &x.iter().map(|&x| x.fast_erfinv()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::erfinv(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error, "fast_exp<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, "fast_erfinv<f32> percentage error")
     }
 
-    pub fn cos() -> Result<f32, Box<dyn std::error::Error>> {
+    pub fn gamma() -> Result<f32, Box<dyn std::error::Error>> {
+        // Skip the poles at zero and the negative integers
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0 || x != x.round()).collect::<Vec<f32>>();
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.fast_cos()).collect::<Vec<f32>>(),
-            &X_F32.iter().map(|&x| exact::f32::cos(x)).collect::<Vec<f32>>()
+            &x.iter().map(|&x| x.fast_gamma()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::gamma(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error, "fast_cos<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, "fast_gamma<f32> percentage error")
     }
-    
-    pub fn cos_lookup() -> Result<f32, Box<dyn std::error::Error>> {
+
+    pub fn lgamma() -> Result<f32, Box<dyn std::error::Error>> {
+        // Also skip the zeros at 1 and 2, where the relative error is meaningless
+        let x = X_F32.iter().copied().filter(|&x| (x > 0.0 || x != x.round()) && exact::f32::lgamma(x).abs() > 0.01).collect::<Vec<f32>>();
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.lookup_cos()).collect::<Vec<f32>>(),
-            &X_F32.iter().map(|&x| exact::f32::cos(x)).collect::<Vec<f32>>()
+            &x.iter().map(|&x| x.fast_lgamma()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::lgamma(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error,  "lookup_cos<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, "fast_lgamma<f32> percentage error")
     }
 
-    pub fn sin() -> Result<f32, Box<dyn std::error::Error>> {
+    pub fn digamma() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| (x > 0.0 || x != x.round()) && exact::f32::digamma(x).abs() > 0.01).collect::<Vec<f32>>();
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.fast_sin()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| x.fast_digamma()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::digamma(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_digamma<f32> percentage error")
+    }
+
+    pub fn cbrt<const ITERATIONS: usize>() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_cbrt_newton::<ITERATIONS>()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::cbrt(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_cbrt_newton::<{}><f32> percentage error", ITERATIONS))
+    }
+
+    pub fn hypot() -> Result<f32, Box<dyn std::error::Error>> {
+        // Pair each x with a scrambled y so that every ratio of components is sampled
+        let xy = X_F32.iter().enumerate().map(|(i, &x)| (x, X_F32[(i * 7919) % X_F32.len()])).collect::<Vec<(f32, f32)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_hypot(y)).collect::<Vec<f32>>(),
+            &xy.iter().map(|&(x, y)| exact::f32::hypot(x, y)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_hypot<f32> percentage error")
+    }
+
+    // Geometric samples on both sides of zero, where the grid from config.rs is far too coarse
+    fn near_zero() -> Vec<f32> {
+        (-1200..=-300).map(|e| 10.0f32.powf(e as f32 / 100.0)).flat_map(|x| [x, -x]).collect()
+    }
+
+    pub fn expm1() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_expm1()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::expm1(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_expm1<f32> percentage error")
+    }
+
+    pub fn expm1_near_zero() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_expm1()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::expm1(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_expm1<f32> percentage error near zero")
+    }
+
+    pub fn log1p() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > -1.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log1p()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::log1p(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log1p<f32> percentage error")
+    }
+
+    pub fn log1p_near_zero() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_log1p()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::log1p(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log1p<f32> percentage error near zero")
+    }
+
+    pub fn exp10() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_exp10()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::exp10(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_exp10<f32> percentage error")
+    }
+
+    pub fn db_to_gain() -> Result<f32, Box<dyn std::error::Error>> {
+        // Cover +-100 dB
+        let db = X_F32.iter().map(|&x| 10.0 * x).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &db.iter().map(|&x| x.db_to_gain()).collect::<Vec<f32>>(),
+            &db.iter().map(|&x| exact::f32::db_to_gain(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "db_to_gain<f32> percentage error")
+    }
+
+    pub fn gain_to_db() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.gain_to_db()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::gain_to_db(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "gain_to_db<f32> percentage error")
+    }
+
+    pub fn db_to_power() -> Result<f32, Box<dyn std::error::Error>> {
+        // Cover +-100 dB
+        let db = X_F32.iter().map(|&x| 10.0 * x).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &db.iter().map(|&x| x.db_to_power()).collect::<Vec<f32>>(),
+            &db.iter().map(|&x| exact::f32::db_to_power(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "db_to_power<f32> percentage error")
+    }
+
+    pub fn power_to_db() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.power_to_db()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::power_to_db(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "power_to_db<f32> percentage error")
+    }
+
+    pub fn sincos_sin() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_sincos().0).collect::<Vec<f32>>(),
             &X_F32.iter().map(|&x| exact::f32::sin(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error, "fast_sin<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, "fast_sincos<f32> sin percentage error")
     }
 
-    pub fn sin_lookup() -> Result<f32, Box<dyn std::error::Error>> {
+    pub fn sincos_cos() -> Result<f32, Box<dyn std::error::Error>> {
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.lookup_sin()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| x.fast_sincos().1).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::cos(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sincos<f32> cos percentage error")
+    }
+
+    pub fn sincos_lookup_sin() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.lookup_sincos().0).collect::<Vec<f32>>(),
             &X_F32.iter().map(|&x| exact::f32::sin(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error,  "lookup_sin<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, "lookup_sincos<f32> sin percentage error")
     }
 
-    pub fn tan() -> Result<f32, Box<dyn std::error::Error>> {
+    pub fn sincos_lookup_cos() -> Result<f32, Box<dyn std::error::Error>> {
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.fast_tan()).collect::<Vec<f32>>(),
-            &X_F32.iter().map(|&x| exact::f32::tan(x)).collect::<Vec<f32>>()
+            &X_F32.iter().map(|&x| x.lookup_sincos().1).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::cos(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error, "fast_tan<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, "lookup_sincos<f32> cos percentage error")
     }
 
-    pub fn sigmoid() -> Result<f32, Box<dyn std::error::Error>> {
+    pub fn gelu() -> Result<f32, Box<dyn std::error::Error>> {
         let percentage_error = calculate_percentage_error(
-            &X_F32.iter().map(|&x| x.fast_sigmoid()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| x.fast_gelu()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::gelu(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_gelu<f32> percentage error")
+    }
+
+    pub fn gelu_erf() -> Result<f32, Box<dyn std::error::Error>> {
+        // Skip the far negative tail, where GELU underflows
+        let x = X_F32.iter().copied().filter(|&x| exact::f32::gelu_erf(x).is_normal()).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_gelu_erf()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::gelu_erf(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_gelu_erf<f32> percentage error")
+    }
+
+    pub fn silu() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_silu()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::silu(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_silu<f32> percentage error")
+    }
+
+    pub fn softplus() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_softplus()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::softplus(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_softplus<f32> percentage error")
+    }
+
+    pub fn mish() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_mish()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::mish(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_mish<f32> percentage error")
+    }
+
+    pub fn elu() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_elu(1.0)).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::elu(x, 1.0)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_elu<f32> percentage error")
+    }
+
+    pub fn normal_pdf() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.normal_pdf()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::normal_pdf(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_pdf<f32> percentage error")
+    }
+
+    pub fn normal_log_pdf() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.normal_log_pdf()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::normal_log_pdf(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_log_pdf<f32> percentage error")
+    }
+
+    pub fn normal_cdf() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.normal_cdf()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::normal_cdf(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_cdf<f32> percentage error")
+    }
+
+    pub fn normal_probit() -> Result<f32, Box<dyn std::error::Error>> {
+        let p = X_F32.iter().map(|&x| 0.5 + x / 20.0).filter(|&p| p > 0.0 && p < 1.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &p.iter().map(|&p| p.normal_probit()).collect::<Vec<f32>>(),
+            &p.iter().map(|&p| exact::f32::normal_probit(p)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "normal_probit<f32> percentage error")
+    }
+
+    // Pairs of every x with a scrambled y, ordered so that x >= y for log_sub_exp
+    fn scrambled_pairs() -> Vec<(f32, f32)> {
+        X_F32.iter().enumerate().map(|(i, &x)| (x, X_F32[(i * 7919) % X_F32.len()])).collect()
+    }
+
+    pub fn log_add_exp() -> Result<f32, Box<dyn std::error::Error>> {
+        let xy = scrambled_pairs();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_log_add_exp(y)).collect::<Vec<f32>>(),
+            &xy.iter().map(|&(x, y)| exact::f32::log_add_exp(x, y)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log_add_exp<f32> percentage error")
+    }
+
+    pub fn log_sub_exp() -> Result<f32, Box<dyn std::error::Error>> {
+        let xy = scrambled_pairs().into_iter().filter(|&(x, y)| x > y).collect::<Vec<(f32, f32)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_log_sub_exp(y)).collect::<Vec<f32>>(),
+            &xy.iter().map(|&(x, y)| exact::f32::log_sub_exp(x, y)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log_sub_exp<f32> percentage error")
+    }
+
+    pub fn logsumexp() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = scrambled_pairs().into_iter().map(|(_, y)| y).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.chunks(16).map(|chunk| chunk.fast_logsumexp()).collect::<Vec<f32>>(),
+            &x.chunks(16).map(exact::f32::logsumexp).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_logsumexp<f32> percentage error")
+    }
+
+    pub fn softmax() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = scrambled_pairs().into_iter().map(|(_, y)| y).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.chunks(16).flat_map(|row| row.fast_softmax()).collect::<Vec<f32>>(),
+            &x.chunks(16).flat_map(exact::f32::softmax).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_softmax<f32> percentage error")
+    }
+
+    pub fn log_softmax() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = scrambled_pairs().into_iter().map(|(_, y)| y).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.chunks(16).flat_map(|row| row.fast_log_softmax()).collect::<Vec<f32>>(),
+            &x.chunks(16).flat_map(exact::f32::log_softmax).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_log_softmax<f32> percentage error")
+    }
+
+    // Pairs of probability distributions over 16 outcomes, made by the reference softmax
+    fn distribution_pairs() -> Vec<(Vec<f32>, Vec<f32>)> {
+        let xy = scrambled_pairs();
+        xy.chunks(16).map(|chunk| {
+            let (x, y): (Vec<f32>, Vec<f32>) = chunk.iter().copied().unzip();
+            (exact::f32::softmax(&x), exact::f32::softmax(&y))
+        }).collect()
+    }
+
+    pub fn entropy() -> Result<f32, Box<dyn std::error::Error>> {
+        let pq = distribution_pairs();
+        let percentage_error = calculate_percentage_error(
+            &pq.iter().map(|(p, _)| p.fast_entropy()).collect::<Vec<f32>>(),
+            &pq.iter().map(|(p, _)| exact::f32::entropy(p)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_entropy<f32> percentage error")
+    }
+
+    pub fn cross_entropy() -> Result<f32, Box<dyn std::error::Error>> {
+        let pq = distribution_pairs();
+        let percentage_error = calculate_percentage_error(
+            &pq.iter().map(|(p, q)| p.fast_cross_entropy(q)).collect::<Vec<f32>>(),
+            &pq.iter().map(|(p, q)| exact::f32::cross_entropy(p, q)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_cross_entropy<f32> percentage error")
+    }
+
+    pub fn kl_divergence() -> Result<f32, Box<dyn std::error::Error>> {
+        let pq = distribution_pairs();
+        let percentage_error = calculate_percentage_error(
+            &pq.iter().map(|(p, q)| p.fast_kl_divergence(q)).collect::<Vec<f32>>(),
+            &pq.iter().map(|(p, q)| exact::f32::kl_divergence(p, q)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_kl_divergence<f32> percentage error")
+    }
+
+    pub fn binary_cross_entropy() -> Result<f32, Box<dyn std::error::Error>> {
+        let xy = scrambled_pairs();
+        let (y, p): (Vec<f32>, Vec<f32>) = xy.iter().map(|&(x, y)| (exact::f32::sigmoid(x), exact::f32::sigmoid(y))).unzip();
+        let percentage_error = calculate_percentage_error(
+            &y.chunks(16).zip(p.chunks(16)).map(|(y, p)| y.fast_binary_cross_entropy(p)).collect::<Vec<f32>>(),
+            &y.chunks(16).zip(p.chunks(16)).map(|(y, p)| exact::f32::binary_cross_entropy(y, p)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_binary_cross_entropy<f32> percentage error")
+    }
+
+    pub fn sinc() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_sinc()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::sinc(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sinc<f32> percentage error")
+    }
+
+    pub fn lanczos() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_lanczos(3.0)).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::lanczos(x, 3.0)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_lanczos<f32> percentage error")
+    }
+
+    pub fn lanczos_lookup() -> Result<f32, Box<dyn std::error::Error>> {
+        let table = crate::lookup::LanczosLookupTable::<f32>::new(3.0);
+        let percentage_error = calculate_percentage_error(
+            &table.map_lookups(X_F32).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::lanczos(x, 3.0)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "LanczosLookupTable<f32> percentage error")
+    }
+
+    pub fn asinh() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().map(|&x| x * x.abs()).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_asinh()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::asinh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_asinh<f32> percentage error")
+    }
+
+    pub fn asinh_near_zero() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_asinh()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::asinh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_asinh<f32> percentage error near zero")
+    }
+
+    pub fn acosh() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().map(|&x| 1.0 + x * x).filter(|&x| x > 1.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_acosh()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::acosh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_acosh<f32> percentage error")
+    }
+
+    pub fn atanh() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().map(|&x| x / 10.0).filter(|&x| x.abs() < 1.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_atanh()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::atanh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atanh<f32> percentage error")
+    }
+
+    pub fn atanh_near_zero() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = near_zero();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_atanh()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::atanh(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_atanh<f32> percentage error near zero")
+    }
+
+    // The samples further than 1e-3 from every pole at offset + k * pi
+    fn away_from_poles(offset: f32) -> Vec<f32> {
+        X_F32.iter().copied().filter(|&x| {
+            let k = ((x - offset) / std::f32::consts::PI).round();
+            (x - offset - k * std::f32::consts::PI).abs() > 1e-3
+        }).collect()
+    }
+
+    pub fn sec() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = away_from_poles(std::f32::consts::FRAC_PI_2);
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_sec()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::sec(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_sec<f32> percentage error")
+    }
+
+    pub fn csc() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = away_from_poles(0.0);
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_csc()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::csc(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_csc<f32> percentage error")
+    }
+
+    pub fn cot() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = away_from_poles(0.0);
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_cot()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::cot(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_cot<f32> percentage error")
+    }
+
+    pub fn j0() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_j0()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::j0(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_j0<f32> percentage error")
+    }
+
+    pub fn j1() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_j1()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::j1(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_j1<f32> percentage error")
+    }
+
+    pub fn y0() -> Result<f32, Box<dyn std::error::Error>> {
+        // Y0 is only defined for positive x
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_y0()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::y0(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_y0<f32> percentage error")
+    }
+
+    pub fn i0() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_i0()).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::i0(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_i0<f32> percentage error")
+    }
+
+    pub fn j0_lookup() -> Result<f32, Box<dyn std::error::Error>> {
+        // Skip the samples next to the zeros, where the relative error of a table is meaningless
+        let table = crate::lookup::BesselLookupTable::<f32>::j0(10.0);
+        let x = X_F32.iter().copied().filter(|&x| exact::f32::j0(x).abs() > 0.01).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &table.map_lookups(x.clone()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::j0(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "BesselLookupTable::j0<f32> percentage error")
+    }
+
+    pub fn i0_lookup() -> Result<f32, Box<dyn std::error::Error>> {
+        let table = crate::lookup::BesselLookupTable::<f32>::i0(10.0);
+        let percentage_error = calculate_percentage_error(
+            &table.map_lookups(X_F32).collect::<Vec<f32>>(),
+            &X_F32.iter().map(|&x| exact::f32::i0(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "BesselLookupTable::i0<f32> percentage error")
+    }
+
+    pub fn recip<const ITERATIONS: usize>() -> Result<f32, Box<dyn std::error::Error>> {
+        let x = X_F32.iter().copied().filter(|&x| x > 0.0).collect::<Vec<f32>>();
+        let percentage_error = calculate_percentage_error(
+            &x.iter().map(|&x| x.fast_recip_newton::<ITERATIONS>()).collect::<Vec<f32>>(),
+            &x.iter().map(|&x| exact::f32::recip(x)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, format!("fast_recip_newton::<{}><f32> percentage error", ITERATIONS))
+    }
+
+    pub fn div() -> Result<f32, Box<dyn std::error::Error>> {
+        // Positive pairs, so that errors of opposite sign don't cancel in the average
+        let xy = scrambled_pairs().into_iter().map(|(x, y)| (x.abs(), y.abs())).filter(|&(_, y)| y > 0.0).collect::<Vec<(f32, f32)>>();
+        let percentage_error = calculate_percentage_error(
+            &xy.iter().map(|&(x, y)| x.fast_div(y)).collect::<Vec<f32>>(),
+            &xy.iter().map(|&(x, y)| exact::f32::div(x, y)).collect::<Vec<f32>>()
+        );
+        panic_if_nan_or_print!(percentage_error, "fast_div<f32> percentage error")
+    }
+
+    pub fn sigmoid_newton<const ITERATIONS: usize>() -> Result<f32, Box<dyn std::error::Error>> {
+        let percentage_error = calculate_percentage_error(
+            &X_F32.iter().map(|&x| x.fast_sigmoid_newton::<ITERATIONS>()).collect::<Vec<f32>>(),
             &X_F32.iter().map(|&x| exact::f32::sigmoid(x)).collect::<Vec<f32>>()
         );
-        panic_if_nan_or_print!(percentage_error,  "fast_sigmoid<f32> percentage error")
+        panic_if_nan_or_print!(percentage_error, format!("fast_sigmoid_newton::<{}><f32> percentage error", ITERATIONS))
     }
 }
\ No newline at end of file
@@ -22,6 +22,462 @@ pub mod f64 {
     pub fn sigmoid(n: f64) -> f64 {
         (1. + (-n).exp()).recip()
     }
+
+    pub fn ln(n: f64) -> f64 {
+        n.ln()
+    }
+
+    pub fn log2(n: f64) -> f64 {
+        n.log2()
+    }
+
+    pub fn log10(n: f64) -> f64 {
+        n.log10()
+    }
+
+    pub fn sqrt(n: f64) -> f64 {
+        n.sqrt()
+    }
+
+    pub fn inv_sqrt(n: f64) -> f64 {
+        n.sqrt().recip()
+    }
+
+    pub fn atan(n: f64) -> f64 {
+        n.atan()
+    }
+
+    pub fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+
+    pub fn asin(n: f64) -> f64 {
+        n.asin()
+    }
+
+    pub fn acos(n: f64) -> f64 {
+        n.acos()
+    }
+
+    pub fn sinh(n: f64) -> f64 {
+        n.sinh()
+    }
+
+    pub fn cosh(n: f64) -> f64 {
+        n.cosh()
+    }
+
+    pub fn tanh(n: f64) -> f64 {
+        n.tanh()
+    }
+
+    pub fn pow(x: f64, y: f64) -> f64 {
+        x.powf(y)
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        x.powi(n)
+    }
+
+    // std has no erf, so the reference sums the (non-alternating) series
+    // erf(x) = 2/sqrt(pi) * exp(-x^2) * sum_n (2x^2)^n x / (1 * 3 * ... * (2n + 1)) for small
+    // arguments, and evaluates the continued fraction of erfc backwards for large ones
+    fn erf_series(n: f64) -> f64 {
+        let two_z = 2.0 * n * n;
+        let mut term = n;
+        let mut sum = n;
+        let mut k = 0.0;
+        while term.abs() > 1e-17 * sum.abs() {
+            k += 1.0;
+            term *= two_z / (2.0 * k + 1.0);
+            sum += term;
+        }
+        std::f64::consts::FRAC_2_SQRT_PI * (-n * n).exp() * sum
+    }
+
+    fn erfc_continued_fraction(n: f64) -> f64 {
+        let mut f = n;
+        for k in (1..=500).rev() {
+            f = n + (k as f64 / 2.0) / f;
+        }
+        (-n * n).exp() / (std::f64::consts::PI.sqrt() * f)
+    }
+
+    pub fn erf(n: f64) -> f64 {
+        if n.abs() < 2.5 {
+            erf_series(n)
+        } else {
+            (1.0 - erfc_continued_fraction(n.abs())).copysign(n)
+        }
+    }
+
+    pub fn erfc(n: f64) -> f64 {
+        if n.abs() < 2.5 {
+            1.0 - erf_series(n)
+        } else if n > 0.0 {
+            erfc_continued_fraction(n)
+        } else {
+            2.0 - erfc_continued_fraction(-n)
+        }
+    }
+
+    // Bisection on the reference erf, switching to erfc to keep precision in the tails
+    pub fn erfinv(n: f64) -> f64 {
+        if n.abs() >= 1.0 || n.is_nan() {
+            return if n.abs() == 1.0 { f64::INFINITY.copysign(n) } else { f64::NAN };
+        }
+        if n == 0.0 {
+            return n;
+        }
+        let target = n.abs();
+        let (mut lower, mut upper) = (0.0f64, 10.0f64);
+        for _ in 0..200 {
+            let mid = 0.5 * (lower + upper);
+            let below_target = if target < 0.5 { erf(mid) < target } else { erfc(mid) > 1.0 - target };
+            if below_target { lower = mid } else { upper = mid }
+        }
+        (0.5 * (lower + upper)).copysign(n)
+    }
+
+    // std has no (stable) gamma function, so the references use the Lanczos approximation
+    // with g = 7 and 9 coefficients, which is accurate to about 1e-15, reflected below 0.5
+    const LANCZOS_G: f64 = 7.0;
+    const LANCZOS_COEFFICIENTS: [f64; 9] = [
+        0.9999999999998099,
+        676.5203681218851,
+        -1259.1392167224028,
+        771.3234287776531,
+        -176.6150291621406,
+        12.507343278686905,
+        -0.13857109526572012,
+        9.984369578019572e-6,
+        1.5056327351493116e-7,
+    ];
+
+    fn lanczos_sum(n: f64) -> f64 {
+        LANCZOS_COEFFICIENTS
+            .iter()
+            .enumerate()
+            .skip(1)
+            .fold(LANCZOS_COEFFICIENTS[0], |sum, (i, &c)| sum + c / (n + i as f64))
+    }
+
+    pub fn gamma(n: f64) -> f64 {
+        if n < 0.5 {
+            return std::f64::consts::PI / ((std::f64::consts::PI * n).sin() * gamma(1.0 - n));
+        }
+        let x = n - 1.0;
+        let t = x + LANCZOS_G + 0.5;
+        // t^(x + 0.5) is split in two so that it doesn't overflow before the result does
+        let half_power = t.powf(0.5 * (x + 0.5));
+        std::f64::consts::TAU.sqrt() * half_power * ((-t).exp() * half_power) * lanczos_sum(x)
+    }
+
+    pub fn lgamma(n: f64) -> f64 {
+        if n < 0.5 {
+            return (std::f64::consts::PI / (std::f64::consts::PI * n).sin().abs()).ln() - lgamma(1.0 - n);
+        }
+        let x = n - 1.0;
+        let t = x + LANCZOS_G + 0.5;
+        0.5 * std::f64::consts::TAU.ln() + (x + 0.5) * t.ln() - t + lanczos_sum(x).ln()
+    }
+
+    // Shifted up to x >= 20 with the recurrence relation, then summed with the asymptotic series
+    pub fn digamma(n: f64) -> f64 {
+        if n < 0.5 {
+            return digamma(1.0 - n) - std::f64::consts::PI / (std::f64::consts::PI * n).tan();
+        }
+        let mut x = n;
+        let mut result = 0.0;
+        while x < 20.0 {
+            result -= x.recip();
+            x += 1.0;
+        }
+        let x2 = (x * x).recip();
+        result + x.ln() - 0.5 / x
+            - x2 * (1.0 / 12.0 - x2 * (1.0 / 120.0 - x2 * (1.0 / 252.0 - x2 * (1.0 / 240.0 - x2 * (1.0 / 132.0)))))
+    }
+
+    pub fn cbrt(n: f64) -> f64 {
+        n.cbrt()
+    }
+
+    pub fn hypot(x: f64, y: f64) -> f64 {
+        x.hypot(y)
+    }
+
+    pub fn expm1(n: f64) -> f64 {
+        n.exp_m1()
+    }
+
+    pub fn log1p(n: f64) -> f64 {
+        n.ln_1p()
+    }
+
+    pub fn exp10(n: f64) -> f64 {
+        10.0f64.powf(n)
+    }
+
+    pub fn db_to_gain(n: f64) -> f64 {
+        10.0f64.powf(n / 20.0)
+    }
+
+    pub fn gain_to_db(n: f64) -> f64 {
+        20.0 * n.log10()
+    }
+
+    pub fn db_to_power(n: f64) -> f64 {
+        10.0f64.powf(n / 10.0)
+    }
+
+    pub fn power_to_db(n: f64) -> f64 {
+        10.0 * n.log10()
+    }
+
+    // 0.5 * (1 + tanh(z)) cancels to zero in the negative tail, so use the equivalent 1 / (1 + exp(-2z))
+    pub fn gelu(n: f64) -> f64 {
+        let z = (2.0 / std::f64::consts::PI).sqrt() * (n + 0.044715 * n.powi(3));
+        n * (1.0 + (-2.0 * z).exp()).recip()
+    }
+
+    pub fn gelu_erf(n: f64) -> f64 {
+        0.5 * n * erfc(-n * std::f64::consts::FRAC_1_SQRT_2)
+    }
+
+    pub fn silu(n: f64) -> f64 {
+        n * sigmoid(n)
+    }
+
+    pub fn softplus(n: f64) -> f64 {
+        n.max(0.0) + (-n.abs()).exp().ln_1p()
+    }
+
+    pub fn mish(n: f64) -> f64 {
+        n * softplus(n).tanh()
+    }
+
+    pub fn elu(n: f64, alpha: f64) -> f64 {
+        if n > 0.0 { n } else { alpha * n.exp_m1() }
+    }
+
+    pub fn normal_pdf(n: f64) -> f64 {
+        (-0.5 * n * n).exp() / (2.0 * std::f64::consts::PI).sqrt()
+    }
+
+    pub fn normal_log_pdf(n: f64) -> f64 {
+        -0.5 * n * n - (2.0 * std::f64::consts::PI).sqrt().ln()
+    }
+
+    pub fn normal_cdf(n: f64) -> f64 {
+        0.5 * erfc(-n * std::f64::consts::FRAC_1_SQRT_2)
+    }
+
+    // Bisection on the lower tail of the reference cdf, which keeps full relative precision
+    // for tiny probabilities, reflected for p > 0.5
+    pub fn normal_probit(n: f64) -> f64 {
+        if !(n > 0.0 && n < 1.0) {
+            return if n == 0.0 { f64::NEG_INFINITY } else if n == 1.0 { f64::INFINITY } else { f64::NAN };
+        }
+        if n == 0.5 {
+            return 0.0;
+        }
+        let (target, sign) = if n < 0.5 { (n, -1.0) } else { (1.0 - n, 1.0) };
+        let (mut lower, mut upper) = (0.0f64, 40.0f64);
+        for _ in 0..200 {
+            let mid = 0.5 * (lower + upper);
+            if normal_cdf(-mid) > target { lower = mid } else { upper = mid }
+        }
+        sign * 0.5 * (lower + upper)
+    }
+
+    pub fn log_add_exp(a: f64, b: f64) -> f64 {
+        let max = a.max(b);
+        max + ((a - max).exp() + (b - max).exp()).ln()
+    }
+
+    pub fn log_sub_exp(a: f64, b: f64) -> f64 {
+        a + (-(b - a).exp_m1()).ln()
+    }
+
+    pub fn logsumexp(x: &[f64]) -> f64 {
+        let max = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        max + x.iter().map(|&x| (x - max).exp()).sum::<f64>().ln()
+    }
+
+    pub fn softmax(x: &[f64]) -> Vec<f64> {
+        let max = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let sum = x.iter().map(|&x| (x - max).exp()).sum::<f64>();
+        x.iter().map(|&x| (x - max).exp() / sum).collect()
+    }
+
+    pub fn log_softmax(x: &[f64]) -> Vec<f64> {
+        let logsumexp = logsumexp(x);
+        x.iter().map(|&x| x - logsumexp).collect()
+    }
+
+    fn xlogy(x: f64, y: f64) -> f64 {
+        if x == 0.0 { 0.0 } else { x * y.ln() }
+    }
+
+    pub fn entropy(p: &[f64]) -> f64 {
+        -p.iter().map(|&p| xlogy(p, p)).sum::<f64>()
+    }
+
+    pub fn cross_entropy(p: &[f64], q: &[f64]) -> f64 {
+        -p.iter().zip(q).map(|(&p, &q)| xlogy(p, q)).sum::<f64>()
+    }
+
+    pub fn kl_divergence(p: &[f64], q: &[f64]) -> f64 {
+        p.iter().zip(q).map(|(&p, &q)| xlogy(p, p / q)).sum::<f64>()
+    }
+
+    pub fn binary_cross_entropy(y: &[f64], p: &[f64]) -> f64 {
+        let sum = y.iter().zip(p).map(|(&y, &p)| -(xlogy(y, p) + xlogy(1.0 - y, 1.0 - p))).sum::<f64>();
+        sum / y.len() as f64
+    }
+
+    // std's sin(pi * n) is only approximately zero at the integers, so pin the true zeros there
+    pub fn sinc(n: f64) -> f64 {
+        if n == 0.0 {
+            1.0
+        } else if n.fract() == 0.0 {
+            0.0
+        } else {
+            (std::f64::consts::PI * n).sin() / (std::f64::consts::PI * n)
+        }
+    }
+
+    pub fn lanczos(n: f64, a: f64) -> f64 {
+        if n.abs() < a { sinc(n) * sinc(n / a) } else { 0.0 }
+    }
+
+    pub fn asinh(n: f64) -> f64 {
+        n.asinh()
+    }
+
+    pub fn acosh(n: f64) -> f64 {
+        n.acosh()
+    }
+
+    // std's atanh cancels near -1, so evaluate it on |n| where 1 - |n| is exact
+    pub fn atanh(n: f64) -> f64 {
+        (0.5 * (2.0 * n.abs() / (1.0 - n.abs())).ln_1p()).copysign(n)
+    }
+
+    pub fn sec(n: f64) -> f64 {
+        n.cos().recip()
+    }
+
+    pub fn csc(n: f64) -> f64 {
+        n.sin().recip()
+    }
+
+    pub fn cot(n: f64) -> f64 {
+        n.tan().recip()
+    }
+
+    // std has no Bessel functions. J0 and J1 come from Bessel's integral
+    //     Jn(x) = 1/pi * integral of cos(n t - x sin(t)) for t from 0 to pi,
+    // where the midpoint rule converges geometrically because the integrand is smooth and periodic
+    fn bessel_integral(order: f64, n: f64) -> f64 {
+        let steps = 64 + 2 * n.abs() as usize;
+        let step = std::f64::consts::PI / steps as f64;
+        let sum = (0..steps)
+            .map(|i| {
+                let t = (i as f64 + 0.5) * step;
+                (order * t - n * t.sin()).cos()
+            })
+            .sum::<f64>();
+        sum / steps as f64
+    }
+
+    pub fn j0(n: f64) -> f64 {
+        bessel_integral(0.0, n)
+    }
+
+    // The quadrature is only zero at zero up to rounding, so pin it there
+    pub fn j1(n: f64) -> f64 {
+        if n == 0.0 { n } else { bessel_integral(1.0, n) }
+    }
+
+    // Hankel's asymptotic P and Q, summed until the terms stop shrinking
+    fn hankel_pq(order: f64, n: f64) -> (f64, f64) {
+        let mu = 4.0 * order * order;
+        let (mut p, mut q) = (1.0, 0.0);
+        let mut term = 1.0f64;
+        for k in 1..100 {
+            let next = term * (mu - ((2 * k - 1) * (2 * k - 1)) as f64) / (8.0 * k as f64 * n);
+            if next.abs() >= term.abs() || next == 0.0 {
+                break;
+            }
+            term = next;
+            // The signs go +, +, -, - for k = 0, 1, 2, 3 and repeat
+            let signed = if k % 4 < 2 { term } else { -term };
+            if k % 2 == 0 { p += signed } else { q += signed }
+        }
+        (p, q)
+    }
+
+    // Y0 from its power series below 12, and from Hankel's expansion above where the series cancels
+    pub fn y0(n: f64) -> f64 {
+        const EULER_GAMMA: f64 = 0.5772156649015329;
+        if n <= 0.0 || n.is_nan() {
+            return if n == 0.0 { f64::NEG_INFINITY } else { f64::NAN };
+        }
+        if n < 12.0 {
+            let quarter_n_2 = 0.25 * n * n;
+            let (mut j0, mut sum) = (1.0, 0.0);
+            let (mut term, mut harmonic) = (1.0, 0.0);
+            for k in 1..100 {
+                term *= -quarter_n_2 / (k * k) as f64;
+                harmonic += 1.0 / k as f64;
+                j0 += term;
+                sum -= harmonic * term;
+            }
+            std::f64::consts::FRAC_2_PI * (((0.5 * n).ln() + EULER_GAMMA) * j0 + sum)
+        } else {
+            let (p, q) = hankel_pq(0.0, n);
+            let chi = n - std::f64::consts::FRAC_PI_4;
+            (std::f64::consts::FRAC_2_PI / n).sqrt() * (p * chi.sin() + q * chi.cos())
+        }
+    }
+
+    // The power series, whose terms are all positive
+    pub fn i0(n: f64) -> f64 {
+        let quarter_n_2 = 0.25 * n * n;
+        let (mut sum, mut term) = (1.0, 1.0);
+        let mut k = 0.0;
+        while term > 1e-17 * sum {
+            k += 1.0;
+            term *= quarter_n_2 / (k * k);
+            sum += term;
+        }
+        sum
+    }
+
+    pub fn recip(n: f64) -> f64 {
+        n.recip()
+    }
+
+    pub fn div(n: f64, d: f64) -> f64 {
+        n / d
+    }
+
+    pub fn floor(n: f64) -> f64 {
+        n.floor()
+    }
+
+    pub fn round(n: f64) -> f64 {
+        n.round()
+    }
+
+    pub fn fmod(n: f64, d: f64) -> f64 {
+        n % d
+    }
+
+    pub fn wrap_to_tau(n: f64) -> f64 {
+        n.rem_euclid(std::f64::consts::TAU)
+    }
 }
 
 pub mod f32 {
@@ -48,4 +504,272 @@ pub mod f32 {
     pub fn sigmoid(n: f32) -> f32 {
         (1. + (-n).exp()).recip()
     }
+
+    pub fn ln(n: f32) -> f32 {
+        n.ln()
+    }
+
+    pub fn log2(n: f32) -> f32 {
+        n.log2()
+    }
+
+    pub fn log10(n: f32) -> f32 {
+        n.log10()
+    }
+
+    pub fn sqrt(n: f32) -> f32 {
+        n.sqrt()
+    }
+
+    pub fn inv_sqrt(n: f32) -> f32 {
+        n.sqrt().recip()
+    }
+
+    pub fn atan(n: f32) -> f32 {
+        n.atan()
+    }
+
+    pub fn atan2(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+
+    pub fn asin(n: f32) -> f32 {
+        n.asin()
+    }
+
+    pub fn acos(n: f32) -> f32 {
+        n.acos()
+    }
+
+    pub fn sinh(n: f32) -> f32 {
+        n.sinh()
+    }
+
+    pub fn cosh(n: f32) -> f32 {
+        n.cosh()
+    }
+
+    pub fn tanh(n: f32) -> f32 {
+        n.tanh()
+    }
+
+    pub fn pow(x: f32, y: f32) -> f32 {
+        x.powf(y)
+    }
+
+    pub fn powi(x: f32, n: i32) -> f32 {
+        x.powi(n)
+    }
+
+    pub fn erf(n: f32) -> f32 {
+        super::f64::erf(n as f64) as f32
+    }
+
+    pub fn erfc(n: f32) -> f32 {
+        super::f64::erfc(n as f64) as f32
+    }
+
+    pub fn erfinv(n: f32) -> f32 {
+        super::f64::erfinv(n as f64) as f32
+    }
+
+    pub fn gamma(n: f32) -> f32 {
+        super::f64::gamma(n as f64) as f32
+    }
+
+    pub fn lgamma(n: f32) -> f32 {
+        super::f64::lgamma(n as f64) as f32
+    }
+
+    pub fn digamma(n: f32) -> f32 {
+        super::f64::digamma(n as f64) as f32
+    }
+
+    pub fn cbrt(n: f32) -> f32 {
+        n.cbrt()
+    }
+
+    pub fn hypot(x: f32, y: f32) -> f32 {
+        x.hypot(y)
+    }
+
+    pub fn expm1(n: f32) -> f32 {
+        n.exp_m1()
+    }
+
+    pub fn log1p(n: f32) -> f32 {
+        n.ln_1p()
+    }
+
+    pub fn exp10(n: f32) -> f32 {
+        10.0f32.powf(n)
+    }
+
+    pub fn db_to_gain(n: f32) -> f32 {
+        10.0f32.powf(n / 20.0)
+    }
+
+    pub fn gain_to_db(n: f32) -> f32 {
+        20.0 * n.log10()
+    }
+
+    pub fn db_to_power(n: f32) -> f32 {
+        10.0f32.powf(n / 10.0)
+    }
+
+    pub fn power_to_db(n: f32) -> f32 {
+        10.0 * n.log10()
+    }
+
+    pub fn gelu(n: f32) -> f32 {
+        super::f64::gelu(n as f64) as f32
+    }
+
+    pub fn gelu_erf(n: f32) -> f32 {
+        super::f64::gelu_erf(n as f64) as f32
+    }
+
+    pub fn silu(n: f32) -> f32 {
+        super::f64::silu(n as f64) as f32
+    }
+
+    pub fn softplus(n: f32) -> f32 {
+        super::f64::softplus(n as f64) as f32
+    }
+
+    pub fn mish(n: f32) -> f32 {
+        super::f64::mish(n as f64) as f32
+    }
+
+    pub fn elu(n: f32, alpha: f32) -> f32 {
+        super::f64::elu(n as f64, alpha as f64) as f32
+    }
+
+    pub fn normal_pdf(n: f32) -> f32 {
+        super::f64::normal_pdf(n as f64) as f32
+    }
+
+    pub fn normal_log_pdf(n: f32) -> f32 {
+        super::f64::normal_log_pdf(n as f64) as f32
+    }
+
+    pub fn normal_cdf(n: f32) -> f32 {
+        super::f64::normal_cdf(n as f64) as f32
+    }
+
+    pub fn normal_probit(n: f32) -> f32 {
+        super::f64::normal_probit(n as f64) as f32
+    }
+
+    pub fn log_add_exp(a: f32, b: f32) -> f32 {
+        super::f64::log_add_exp(a as f64, b as f64) as f32
+    }
+
+    pub fn log_sub_exp(a: f32, b: f32) -> f32 {
+        super::f64::log_sub_exp(a as f64, b as f64) as f32
+    }
+
+    pub fn logsumexp(x: &[f32]) -> f32 {
+        super::f64::logsumexp(&x.iter().map(|&x| x as f64).collect::<Vec<f64>>()) as f32
+    }
+
+    pub fn softmax(x: &[f32]) -> Vec<f32> {
+        super::f64::softmax(&x.iter().map(|&x| x as f64).collect::<Vec<f64>>()).into_iter().map(|x| x as f32).collect()
+    }
+
+    pub fn log_softmax(x: &[f32]) -> Vec<f32> {
+        super::f64::log_softmax(&x.iter().map(|&x| x as f64).collect::<Vec<f64>>()).into_iter().map(|x| x as f32).collect()
+    }
+
+    fn to_f64(x: &[f32]) -> Vec<f64> {
+        x.iter().map(|&x| x as f64).collect()
+    }
+
+    pub fn entropy(p: &[f32]) -> f32 {
+        super::f64::entropy(&to_f64(p)) as f32
+    }
+
+    pub fn cross_entropy(p: &[f32], q: &[f32]) -> f32 {
+        super::f64::cross_entropy(&to_f64(p), &to_f64(q)) as f32
+    }
+
+    pub fn kl_divergence(p: &[f32], q: &[f32]) -> f32 {
+        super::f64::kl_divergence(&to_f64(p), &to_f64(q)) as f32
+    }
+
+    pub fn binary_cross_entropy(y: &[f32], p: &[f32]) -> f32 {
+        super::f64::binary_cross_entropy(&to_f64(y), &to_f64(p)) as f32
+    }
+
+    pub fn sinc(n: f32) -> f32 {
+        super::f64::sinc(n as f64) as f32
+    }
+
+    pub fn lanczos(n: f32, a: f32) -> f32 {
+        super::f64::lanczos(n as f64, a as f64) as f32
+    }
+
+    pub fn asinh(n: f32) -> f32 {
+        n.asinh()
+    }
+
+    pub fn acosh(n: f32) -> f32 {
+        n.acosh()
+    }
+
+    pub fn atanh(n: f32) -> f32 {
+        super::f64::atanh(n as f64) as f32
+    }
+
+    pub fn sec(n: f32) -> f32 {
+        super::f64::sec(n as f64) as f32
+    }
+
+    pub fn csc(n: f32) -> f32 {
+        super::f64::csc(n as f64) as f32
+    }
+
+    pub fn cot(n: f32) -> f32 {
+        super::f64::cot(n as f64) as f32
+    }
+
+    pub fn j0(n: f32) -> f32 {
+        super::f64::j0(n as f64) as f32
+    }
+
+    pub fn j1(n: f32) -> f32 {
+        super::f64::j1(n as f64) as f32
+    }
+
+    pub fn y0(n: f32) -> f32 {
+        super::f64::y0(n as f64) as f32
+    }
+
+    pub fn i0(n: f32) -> f32 {
+        super::f64::i0(n as f64) as f32
+    }
+
+    pub fn recip(n: f32) -> f32 {
+        n.recip()
+    }
+
+    pub fn div(n: f32, d: f32) -> f32 {
+        n / d
+    }
+
+    pub fn floor(n: f32) -> f32 {
+        n.floor()
+    }
+
+    pub fn round(n: f32) -> f32 {
+        n.round()
+    }
+
+    pub fn fmod(n: f32, d: f32) -> f32 {
+        n % d
+    }
+
+    pub fn wrap_to_tau(n: f32) -> f32 {
+        n.rem_euclid(std::f32::consts::TAU)
+    }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-mod exact;
+pub mod exact;
 
 mod comparisons;
 pub use comparisons::*;
\ No newline at end of file
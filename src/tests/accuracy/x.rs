@@ -1,6 +1,6 @@
 #[cfg(test)]
-#[allow(dead_code)]
+#[allow(dead_code, clippy::approx_constant, clippy::large_const_arrays)]
 pub const X_F32: [f32; 20000] = [-10.0, -9.999, -9.998, -9.997, -9.996, -9.995, -9.994, -9.993, -9.992, -9.991, -9.99, -9.989, -9.988, -9.987, -9.986, -9.985, -9.984, -9.983, -9.982, -9.981, -9.98, -9.979, -9.978, -9.977, -9.976, -9.975, -9.974, -9.973, -9.972, -9.971, -9.97, -9.969, -9.968, -9.967, -9.966, -9.965, -9.964, -9.963, -9.962, -9.961, -9.96, -9.959, -9.958, -9.957, -9.956, -9.955, -9.954, -9.953, -9.952, -9.951, -9.95, -9.949, -9.948, -9.947, -9.946, -9.945, -9.944, -9.943, -9.942, -9.941, -9.94, -9.939, -9.938, -9.937, -9.936, -9.935, -9.934, -9.933, -9.932, -9.931, -9.93, -9.929, -9.928, -9.927, -9.926, -9.925, -9.924, -9.923, -9.922, -9.921, -9.92, -9.919, -9.918, -9.917, -9.916, -9.915, -9.914, -9.913, -9.912, -9.911, -9.91, -9.909, -9.908, -9.907, -9.906, -9.905, -9.904, -9.903, -9.902, -9.901, -9.9, -9.899, -9.898, -9.897, -9.896, -9.895, -9.894, -9.893, -9.892, -9.891, -9.89, -9.889, -9.888, -9.887, -9.886, -9.885, -9.884, -9.883, -9.882, -9.881, -9.88, -9.879, -9.878, -9.877, -9.876, -9.875, -9.874, -9.873, -9.872, -9.871, -9.87, -9.869, -9.868, -9.867, -9.866, -9.865, -9.864, -9.863, -9.862, -9.861, -9.86, -9.859, -9.858, -9.857, -9.856, -9.855, -9.854, -9.853, -9.852, -9.851, -9.85, -9.849, -9.848, -9.847, -9.846, -9.845, -9.844, -9.843, -9.842, -9.841, -9.84, -9.839, -9.838, -9.837, -9.836, -9.835, -9.834, -9.833, -9.832, -9.831, -9.83, -9.829, -9.828, -9.827, -9.826, -9.825, -9.824, -9.823, -9.822, -9.821, -9.82, -9.819, -9.818, -9.817, -9.816, -9.815, -9.814, -9.813, -9.812, -9.811, -9.81, -9.809, -9.808, -9.807, -9.806, -9.805, -9.804, -9.803, -9.802, -9.801, -9.8, -9.799, -9.798, -9.797, -9.796, -9.795, -9.794, -9.793, -9.792, -9.791, -9.79, -9.789, -9.788, -9.787, -9.786, -9.785, -9.784, -9.783, -9.782, -9.781, -9.78, -9.779, -9.778, -9.777, -9.776, -9.775, -9.774, -9.773, -9.772, -9.771, -9.77, -9.769, -9.768, -9.767, -9.766, -9.765, -9.764, -9.763, -9.762, -9.761, -9.76, -9.759, -9.758, -9.757, -9.756, -9.755, -9.754, -9.753, -9.752, -9.751, -9.75, -9.749, -9.748, -9.747, -9.746, -9.745, -9.744, -9.743, -9.742, -9.741, -9.74, -9.739, -9.738, -9.7369995, -9.736, -9.735, -9.734, -9.733, -9.732, -9.731, -9.73, -9.729, -9.728, -9.727, -9.726, -9.725, -9.724, -9.723, -9.722, -9.721, -9.72, -9.719, -9.718, -9.717, -9.716, -9.715, -9.714, -9.713, -9.712, -9.711, -9.71, -9.709, -9.708, -9.707, -9.706, -9.705, -9.704, -9.703, -9.702, -9.701, -9.7, -9.699, -9.698, -9.697, -9.696, -9.695, -9.694, -9.693, -9.692, -9.691, -9.69, -9.689, -9.688, -9.687, -9.686, -9.685, -9.684, -9.683, -9.682, -9.681, -9.68, -9.679, -9.678, -9.677, -9.676, -9.675, -9.674, -9.673, -9.672, -9.671, -9.67, -9.669, -9.668, -9.667, -9.666, -9.665, -9.664, -9.663, -9.662, -9.661, -9.66, -9.659, -9.658, -9.657, -9.656, -9.655, -9.654, -9.653, -9.652, -9.651, -9.65, -9.649, -9.648, -9.647, -9.646, -9.645, -9.644, -9.643, -9.642, -9.641, -9.64, -9.639, -9.6380005, -9.637, -9.636, -9.635, -9.634, -9.633, -9.632, -9.631, -9.63, -9.629, -9.628, -9.627, -9.626, -9.625, -9.624, -9.623, -9.622, -9.621, -9.62, -9.619, -9.618, -9.617, -9.616, -9.615, -9.614, -9.613, -9.6119995, -9.611, -9.61, -9.609, -9.608, -9.607, -9.606, -9.605, -9.604, -9.603, -9.602, -9.601, -9.6, -9.599, -9.598, -9.597, -9.596, -9.595, -9.594, -9.593, -9.592, -9.591, -9.59, -9.589, -9.588, -9.587, -9.586, -9.585, -9.584, -9.583, -9.582, -9.581, -9.58, -9.579, -9.578, -9.577, -9.576, -9.575, -9.574, -9.573, -9.572, -9.571, -9.57, -9.569, -9.568, -9.567, -9.566, -9.565, -9.564, -9.563, -9.562, -9.561, -9.56, -9.559, -9.558, -9.557, -9.556, -9.555, -9.554, -9.553, -9.552, -9.551, -9.55, -9.549, -9.548, -9.547, -9.546, -9.545, -9.544, -9.543, -9.542, -9.541, -9.54, -9.539, -9.538, -9.537, -9.536, -9.535, -9.534, -9.533, -9.532, -9.531, -9.53, -9.529, -9.528, -9.527, -9.526, -9.525, -9.524, -9.523, -9.522, -9.521, -9.52, -9.519, -9.518, -9.517, -9.516, -9.515, -9.514, -9.5130005, -9.512, -9.511, -9.51, -9.509, -9.508, -9.507, -9.506, -9.505, -9.504, -9.503, -9.502, -9.501, -9.5, -9.499, -9.498, -9.497, -9.496, -9.495, -9.494, -9.493, -9.492, -9.491, -9.49, -9.489, -9.488, -9.4869995, -9.486, -9.485, -9.484, -9.483, -9.482, -9.481, -9.48, -9.479, -9.478, -9.477, -9.476, -9.475, -9.474, -9.473, -9.472, -9.471, -9.47, -9.469, -9.468, -9.467, -9.466, -9.465, -9.464, -9.463, -9.462, -9.461, -9.46, -9.459, -9.458, -9.457, -9.456, -9.455, -9.454, -9.453, -9.452, -9.451, -9.45, -9.449, -9.448, -9.4470005, -9.446, -9.445, -9.444, -9.443, -9.442, -9.441, -9.44, -9.439, -9.438, -9.437, -9.436, -9.435, -9.434, -9.433, -9.432, -9.431, -9.43, -9.429, -9.4279995, -9.427, -9.426, -9.425, -9.424, -9.423, -9.422, -9.421, -9.42, -9.419, -9.418, -9.417, -9.416, -9.415, -9.414, -9.413, -9.412, -9.411, -9.41, -9.409, -9.408, -9.407, -9.406, -9.405, -9.404, -9.403, -9.402, -9.401, -9.4, -9.399, -9.398, -9.397, -9.396, -9.395, -9.394, -9.393, -9.392, -9.391, -9.39, -9.389, -9.3880005, -9.387, -9.386, -9.385, -9.384, -9.383, -9.382, -9.381, -9.38, -9.379, -9.378, -9.377, -9.376, -9.375, -9.374, -9.373, -9.372, -9.371, -9.37, -9.369, -9.368, -9.367, -9.366, -9.365, -9.364, -9.363, -9.3619995, -9.361, -9.36, -9.359, -9.358, -9.357, -9.356, -9.355, -9.354, -9.353, -9.352, -9.351, -9.35, -9.349, -9.348, -9.347, -9.346, -9.345, -9.344, -9.343, -9.342, -9.341, -9.34, -9.339, -9.338, -9.337, -9.336, -9.335, -9.334, -9.333, -9.332, -9.331, -9.33, -9.329, -9.328, -9.327, -9.326, -9.325, -9.324, -9.323, -9.3220005, -9.321, -9.32, -9.319, -9.318, -9.317, -9.316, -9.315, -9.314, -9.313, -9.312, -9.311, -9.31, -9.309, -9.308, -9.307, -9.306, -9.305, -9.304, -9.3029995, -9.302, -9.301, -9.3, -9.299, -9.298, -9.297, -9.296, -9.295, -9.294, -9.293, -9.292, -9.291, -9.29, -9.289, -9.288, -9.287, -9.286, -9.285, -9.284, -9.283, -9.282, -9.281, -9.28, -9.279, -9.278, -9.277, -9.276, -9.275, -9.274, -9.273, -9.272, -9.271, -9.27, -9.269, -9.268, -9.267, -9.266, -9.265, -9.264, -9.2630005, -9.262, -9.261, -9.26, -9.259, -9.258, -9.257, -9.256, -9.255, -9.254, -9.253, -9.252, -9.251, -9.25, -9.249, -9.248, -9.247, -9.246, -9.245, -9.244, -9.243, -9.242, -9.241, -9.24, -9.239, -9.238, -9.2369995, -9.236, -9.235, -9.234, -9.233, -9.232, -9.231, -9.23, -9.229, -9.228, -9.227, -9.226, -9.225, -9.224, -9.223, -9.222, -9.221, -9.22, -9.219, -9.218, -9.217, -9.216, -9.215, -9.214, -9.213, -9.212, -9.211, -9.21, -9.209, -9.208, -9.207, -9.206, -9.205, -9.204, -9.203, -9.202, -9.201, -9.2, -9.199, -9.198, -9.1970005, -9.196, -9.195, -9.194, -9.193, -9.192, -9.191, -9.19, -9.189, -9.188, -9.187, -9.186, -9.185, -9.184, -9.183, -9.182, -9.181, -9.18, -9.179, -9.1779995, -9.177, -9.176, -9.175, -9.174, -9.173, -9.172, -9.171, -9.17, -9.169, -9.168, -9.167, -9.166, -9.165, -9.164, -9.163, -9.162, -9.161, -9.16, -9.159, -9.158, -9.157, -9.156, -9.155, -9.154, -9.153, -9.152, -9.151, -9.15, -9.149, -9.148, -9.147, -9.146, -9.145, -9.144, -9.143, -9.142, -9.141, -9.14, -9.139, -9.1380005, -9.137, -9.136, -9.135, -9.134, -9.133, -9.132, -9.131, -9.13, -9.129, -9.128, -9.127, -9.126, -9.125, -9.124, -9.123, -9.122, -9.121, -9.12, -9.119, -9.118, -9.117, -9.116, -9.115, -9.114, -9.113, -9.1119995, -9.111, -9.11, -9.109, -9.108, -9.107, -9.106, -9.105, -9.104, -9.103, -9.102, -9.101, -9.1, -9.099, -9.098, -9.097, -9.096, -9.095, -9.094, -9.093, -9.092, -9.091, -9.09, -9.089, -9.088, -9.087, -9.086, -9.085, -9.084, -9.083, -9.082, -9.081, -9.08, -9.079, -9.078, -9.077, -9.076, -9.075, -9.074, -9.073, -9.0720005, -9.071, -9.07, -9.069, -9.068, -9.067, -9.066, -9.065, -9.064, -9.063, -9.062, -9.061, -9.06, -9.059, -9.058, -9.057, -9.056, -9.055, -9.054, -9.0529995, -9.052, -9.051, -9.05, -9.049, -9.048, -9.047, -9.046, -9.045, -9.044, -9.043, -9.042, -9.041, -9.04, -9.039, -9.038, -9.037, -9.036, -9.035, -9.034, -9.033, -9.032, -9.031, -9.03, -9.029, -9.028, -9.027, -9.026, -9.025, -9.024, -9.023, -9.022, -9.021, -9.02, -9.019, -9.018, -9.017, -9.016, -9.015, -9.014, -9.0130005, -9.012, -9.011, -9.01, -9.009, -9.008, -9.007, -9.006, -9.005, -9.004, -9.003, -9.002, -9.001, -9.0, -8.999, -8.998, -8.997, -8.996, -8.995, -8.9939995, -8.993, -8.992, -8.991, -8.99, -8.989, -8.988, -8.9869995, -8.986, -8.985, -8.984, -8.983, -8.982, -8.981, -8.98, -8.979, -8.978, -8.977, -8.976, -8.975, -8.974, -8.973, -8.972, -8.971, -8.97, -8.969, -8.968, -8.967, -8.966, -8.965, -8.964, -8.963, -8.962, -8.961, -8.96, -8.959, -8.958, -8.957, -8.956, -8.955, -8.954, -8.953, -8.952, -8.951, -8.95, -8.949, -8.948, -8.9470005, -8.946, -8.945, -8.944, -8.943, -8.942, -8.941, -8.940001, -8.939, -8.938, -8.937, -8.936, -8.934999, -8.934, -8.933, -8.932, -8.931, -8.93, -8.929, -8.9279995, -8.927, -8.926, -8.925, -8.924, -8.923, -8.922, -8.921, -8.92, -8.919, -8.918, -8.917, -8.916, -8.915, -8.914, -8.913, -8.912, -8.911, -8.91, -8.909, -8.908, -8.907, -8.906, -8.905, -8.904, -8.903, -8.902, -8.901, -8.9, -8.899, -8.898, -8.897, -8.896, -8.895, -8.894, -8.893, -8.892, -8.891, -8.89, -8.889, -8.8880005, -8.887, -8.886, -8.885, -8.884, -8.883, -8.882, -8.8810005, -8.88, -8.879, -8.878, -8.877, -8.876, -8.875, -8.874, -8.873, -8.872, -8.871, -8.87, -8.8689995, -8.868, -8.867, -8.866, -8.865, -8.864, -8.863, -8.8619995, -8.861, -8.86, -8.859, -8.858, -8.857, -8.856, -8.855, -8.854, -8.853, -8.852, -8.851, -8.85, -8.849, -8.848, -8.847, -8.846, -8.845, -8.844, -8.843, -8.842, -8.841, -8.84, -8.839, -8.838, -8.837, -8.836, -8.835, -8.834, -8.833, -8.832, -8.831, -8.83, -8.829, -8.828, -8.827, -8.826, -8.825, -8.824, -8.823, -8.8220005, -8.821, -8.82, -8.819, -8.818, -8.817, -8.816, -8.815001, -8.814, -8.813, -8.812, -8.811, -8.809999, -8.809, -8.808, -8.807, -8.806, -8.805, -8.804, -8.8029995, -8.802, -8.801, -8.8, -8.799, -8.798, -8.797, -8.796, -8.795, -8.794, -8.793, -8.792, -8.791, -8.79, -8.789, -8.788, -8.787, -8.786, -8.785, -8.784, -8.783, -8.782, -8.781, -8.78, -8.779, -8.778, -8.777, -8.776, -8.775, -8.774, -8.773, -8.772, -8.771, -8.77, -8.769, -8.768, -8.767, -8.766, -8.765, -8.764, -8.7630005, -8.762, -8.761, -8.76, -8.759, -8.758, -8.757, -8.7560005, -8.755, -8.754, -8.753, -8.752, -8.751, -8.75, -8.749, -8.748, -8.747, -8.746, -8.745, -8.7439995, -8.743, -8.742, -8.741, -8.74, -8.739, -8.738, -8.7369995, -8.736, -8.735, -8.734, -8.733, -8.732, -8.731, -8.73, -8.729, -8.728, -8.727, -8.726, -8.725, -8.724, -8.723, -8.722, -8.721, -8.72, -8.719, -8.718, -8.717, -8.716, -8.715, -8.714, -8.713, -8.712, -8.711, -8.71, -8.709, -8.708, -8.707, -8.706, -8.705, -8.704, -8.703, -8.702, -8.701, -8.7, -8.699, -8.698, -8.6970005, -8.696, -8.695, -8.694, -8.693, -8.692, -8.691, -8.690001, -8.689, -8.688, -8.687, -8.686, -8.684999, -8.684, -8.683, -8.682, -8.681, -8.68, -8.679, -8.6779995, -8.677, -8.676, -8.675, -8.674, -8.673, -8.672, -8.671, -8.67, -8.669, -8.668, -8.667, -8.666, -8.665, -8.664, -8.663, -8.662, -8.661, -8.66, -8.659, -8.658, -8.657, -8.656, -8.655, -8.654, -8.653, -8.652, -8.651, -8.65, -8.649, -8.648, -8.647, -8.646, -8.645, -8.644, -8.643, -8.642, -8.641, -8.64, -8.639, -8.6380005, -8.637, -8.636, -8.635, -8.634, -8.633, -8.632, -8.6310005, -8.63, -8.629, -8.628, -8.627, -8.626, -8.625, -8.624, -8.623, -8.622, -8.621, -8.62, -8.6189995, -8.618, -8.617, -8.616, -8.615, -8.614, -8.613, -8.6119995, -8.611, -8.61, -8.609, -8.608, -8.607, -8.606, -8.605, -8.604, -8.603, -8.602, -8.601, -8.6, -8.599, -8.598, -8.597, -8.596, -8.595, -8.594, -8.593, -8.592, -8.591, -8.59, -8.589, -8.588, -8.587, -8.586, -8.585, -8.584, -8.583, -8.582, -8.581, -8.58, -8.579, -8.578, -8.577, -8.576, -8.575, -8.574, -8.573, -8.5720005, -8.571, -8.57, -8.569, -8.568, -8.567, -8.566, -8.565001, -8.564, -8.563, -8.562, -8.561, -8.559999, -8.559, -8.558, -8.557, -8.556, -8.555, -8.554, -8.5529995, -8.552, -8.551, -8.55, -8.549, -8.548, -8.547, -8.546, -8.545, -8.544, -8.543, -8.542, -8.541, -8.54, -8.539, -8.538, -8.537, -8.536, -8.535, -8.534, -8.533, -8.532, -8.531, -8.53, -8.529, -8.528, -8.527, -8.526, -8.525, -8.524, -8.523, -8.522, -8.521, -8.52, -8.519, -8.518, -8.517, -8.516, -8.515, -8.514, -8.5130005, -8.512, -8.511, -8.51, -8.509, -8.508, -8.507, -8.5060005, -8.505, -8.504, -8.503, -8.502, -8.501, -8.5, -8.499, -8.498, -8.497, -8.496, -8.495, -8.4939995, -8.493, -8.492, -8.491, -8.49, -8.489, -8.488, -8.4869995, -8.486, -8.485, -8.484, -8.483, -8.482, -8.481, -8.48, -8.479, -8.478, -8.477, -8.476, -8.475, -8.474, -8.473, -8.472, -8.471, -8.47, -8.469, -8.468, -8.467, -8.466, -8.465, -8.464, -8.463, -8.462, -8.461, -8.46, -8.459, -8.458, -8.457, -8.456, -8.455, -8.454, -8.453, -8.452, -8.451, -8.45, -8.449, -8.448, -8.4470005, -8.446, -8.445, -8.444, -8.443, -8.442, -8.441, -8.440001, -8.439, -8.438, -8.437, -8.436, -8.434999, -8.434, -8.433, -8.432, -8.431, -8.43, -8.429, -8.4279995, -8.427, -8.426, -8.425, -8.424, -8.423, -8.422, -8.421, -8.42, -8.419, -8.418, -8.417, -8.416, -8.415, -8.414, -8.413, -8.412, -8.411, -8.41, -8.409, -8.408, -8.407, -8.406, -8.405, -8.404, -8.403, -8.402, -8.401, -8.4, -8.399, -8.398, -8.397, -8.396, -8.395, -8.394, -8.393, -8.392, -8.391, -8.39, -8.389, -8.3880005, -8.387, -8.386, -8.385, -8.384, -8.383, -8.382, -8.3810005, -8.38, -8.379, -8.378, -8.377, -8.376, -8.375, -8.374, -8.373, -8.372, -8.371, -8.37, -8.3689995, -8.368, -8.367, -8.366, -8.365, -8.364, -8.363, -8.3619995, -8.361, -8.36, -8.359, -8.358, -8.357, -8.356, -8.355, -8.354, -8.353, -8.352, -8.351, -8.35, -8.349, -8.348, -8.347, -8.346, -8.345, -8.344, -8.343, -8.342, -8.341, -8.34, -8.339, -8.338, -8.337, -8.336, -8.335, -8.334, -8.333, -8.332, -8.331, -8.33, -8.329, -8.328, -8.327, -8.326, -8.325, -8.324, -8.323, -8.3220005, -8.321, -8.32, -8.319, -8.318, -8.317, -8.316, -8.315001, -8.314, -8.313, -8.312, -8.311, -8.309999, -8.309, -8.308, -8.307, -8.306, -8.305, -8.304, -8.3029995, -8.302, -8.301, -8.3, -8.299, -8.298, -8.297, -8.296, -8.295, -8.294, -8.293, -8.292, -8.291, -8.29, -8.289, -8.288, -8.287, -8.286, -8.285, -8.284, -8.283, -8.282, -8.281, -8.28, -8.279, -8.278, -8.277, -8.276, -8.275, -8.274, -8.273, -8.272, -8.271, -8.27, -8.269, -8.268, -8.267, -8.266, -8.265, -8.264, -8.2630005, -8.262, -8.261, -8.26, -8.259, -8.258, -8.257, -8.2560005, -8.255, -8.254, -8.253, -8.252, -8.251, -8.25, -8.249, -8.248, -8.247, -8.246, -8.245, -8.2439995, -8.243, -8.242, -8.241, -8.24, -8.239, -8.238, -8.2369995, -8.236, -8.235, -8.234, -8.233, -8.232, -8.231, -8.23, -8.229, -8.228, -8.227, -8.226, -8.225, -8.224, -8.223, -8.222, -8.221, -8.22, -8.219, -8.218, -8.217, -8.216, -8.215, -8.214, -8.213, -8.212, -8.211, -8.21, -8.209, -8.208, -8.207, -8.206, -8.205, -8.204, -8.203, -8.202, -8.201, -8.2, -8.199, -8.198, -8.1970005, -8.196, -8.195, -8.194, -8.193, -8.192, -8.191, -8.190001, -8.189, -8.188, -8.187, -8.186, -8.184999, -8.184, -8.183, -8.182, -8.181, -8.18, -8.179, -8.1779995, -8.177, -8.176, -8.175, -8.174, -8.173, -8.172, -8.171, -8.17, -8.169, -8.168, -8.167, -8.166, -8.165, -8.164, -8.163, -8.162, -8.161, -8.16, -8.159, -8.158, -8.157, -8.156, -8.155, -8.154, -8.153, -8.152, -8.151, -8.15, -8.149, -8.148, -8.147, -8.146, -8.145, -8.144, -8.143, -8.142, -8.141, -8.14, -8.139, -8.1380005, -8.137, -8.136, -8.135, -8.134, -8.133, -8.132, -8.1310005, -8.13, -8.129, -8.128, -8.127, -8.126, -8.125, -8.124, -8.123, -8.122, -8.121, -8.12, -8.1189995, -8.118, -8.117, -8.116, -8.115, -8.114, -8.113, -8.1119995, -8.111, -8.11, -8.109, -8.108, -8.107, -8.106, -8.105, -8.104, -8.103, -8.102, -8.101, -8.1, -8.099, -8.098, -8.097, -8.096, -8.095, -8.094, -8.093, -8.092, -8.091, -8.09, -8.089, -8.088, -8.087, -8.086, -8.085, -8.084, -8.083, -8.082, -8.081, -8.08, -8.079, -8.078, -8.077, -8.076, -8.075, -8.074, -8.073, -8.0720005, -8.071, -8.07, -8.069, -8.068, -8.067, -8.066, -8.065001, -8.064, -8.063, -8.062, -8.061, -8.059999, -8.059, -8.058, -8.057, -8.056, -8.055, -8.054, -8.0529995, -8.052, -8.051, -8.05, -8.049, -8.048, -8.047, -8.046, -8.045, -8.044, -8.043, -8.042, -8.041, -8.04, -8.039, -8.038, -8.037, -8.036, -8.035, -8.034, -8.033, -8.032, -8.031, -8.03, -8.029, -8.028, -8.027, -8.026, -8.025, -8.024, -8.023, -8.022, -8.021, -8.02, -8.019, -8.018, -8.017, -8.016, -8.015, -8.014, -8.0130005, -8.012, -8.011, -8.01, -8.009, -8.008, -8.007, -8.0060005, -8.005, -8.004, -8.003, -8.002, -8.001, -8.0, -7.999, -7.998, -7.9969997, -7.9960003, -7.995, -7.994, -7.993, -7.992, -7.991, -7.99, -7.9890003, -7.988, -7.987, -7.986, -7.9849997, -7.984, -7.983, -7.9820004, -7.981, -7.98, -7.979, -7.9779997, -7.977, -7.976, -7.975, -7.974, -7.973, -7.972, -7.9709997, -7.9700003, -7.969, -7.968, -7.967, -7.966, -7.965, -7.9639997, -7.9630003, -7.962, -7.961, -7.96, -7.959, -7.958, -7.957, -7.9560003, -7.955, -7.954, -7.953, -7.9519997, -7.951, -7.95, -7.949, -7.948, -7.947, -7.946, -7.9449997, -7.9440002, -7.943, -7.942, -7.941, -7.94, -7.939, -7.9379997, -7.9370003, -7.936, -7.935, -7.934, -7.933, -7.932, -7.9309998, -7.9300003, -7.929, -7.928, -7.927, -7.926, -7.925, -7.924, -7.9230003, -7.922, -7.921, -7.92, -7.9189997, -7.918, -7.917, -7.916, -7.915, -7.914, -7.913, -7.9119997, -7.9110003, -7.91, -7.909, -7.908, -7.907, -7.906, -7.9049997, -7.9040003, -7.903, -7.902, -7.901, -7.9, -7.899, -7.898, -7.8970003, -7.896, -7.895, -7.894, -7.8929996, -7.892, -7.891, -7.8900003, -7.889, -7.888, -7.887, -7.8859997, -7.885, -7.884, -7.883, -7.882, -7.881, -7.88, -7.8789997, -7.8780003, -7.877, -7.876, -7.875, -7.874, -7.873, -7.8719997, -7.8710003, -7.87, -7.869, -7.868, -7.867, -7.866, -7.865, -7.8640003, -7.863, -7.862, -7.861, -7.8599997, -7.859, -7.858, -7.8570004, -7.856, -7.855, -7.854, -7.8529997, -7.852, -7.851, -7.85, -7.849, -7.848, -7.847, -7.8459997, -7.8450003, -7.844, -7.843, -7.842, -7.841, -7.84, -7.8389997, -7.8380003, -7.837, -7.836, -7.835, -7.834, -7.833, -7.832, -7.8310003, -7.83, -7.829, -7.828, -7.8269997, -7.826, -7.825, -7.824, -7.823, -7.822, -7.821, -7.8199997, -7.8190002, -7.818, -7.817, -7.816, -7.815, -7.814, -7.8129997, -7.8120003, -7.811, -7.81, -7.809, -7.808, -7.807, -7.8059998, -7.8050003, -7.804, -7.803, -7.802, -7.801, -7.8, -7.799, -7.7980003, -7.797, -7.796, -7.795, -7.7939997, -7.793, -7.792, -7.791, -7.79, -7.789, -7.788, -7.7869997, -7.7860003, -7.785, -7.784, -7.783, -7.782, -7.781, -7.7799997, -7.7790003, -7.778, -7.777, -7.776, -7.775, -7.774, -7.773, -7.7720003, -7.771, -7.77, -7.769, -7.7679996, -7.767, -7.766, -7.7650003, -7.764, -7.763, -7.762, -7.7609997, -7.76, -7.759, -7.758, -7.757, -7.756, -7.755, -7.7539997, -7.7530003, -7.752, -7.751, -7.75, -7.749, -7.748, -7.7469997, -7.7460003, -7.745, -7.744, -7.743, -7.742, -7.741, -7.74, -7.7390003, -7.738, -7.737, -7.736, -7.7349997, -7.734, -7.733, -7.7320004, -7.731, -7.73, -7.729, -7.7279997, -7.727, -7.726, -7.725, -7.724, -7.723, -7.722, -7.7209997, -7.7200003, -7.719, -7.718, -7.717, -7.716, -7.715, -7.7139997, -7.7130003, -7.712, -7.711, -7.71, -7.709, -7.708, -7.707, -7.7060003, -7.705, -7.704, -7.703, -7.7019997, -7.701, -7.7, -7.699, -7.698, -7.697, -7.696, -7.6949997, -7.6940002, -7.693, -7.692, -7.691, -7.69, -7.689, -7.6879997, -7.6870003, -7.686, -7.685, -7.684, -7.683, -7.682, -7.6809998, -7.6800003, -7.679, -7.678, -7.677, -7.676, -7.675, -7.674, -7.6730003, -7.672, -7.671, -7.67, -7.6689997, -7.668, -7.667, -7.666, -7.665, -7.664, -7.663, -7.6619997, -7.6610003, -7.66, -7.659, -7.658, -7.657, -7.656, -7.6549997, -7.6540003, -7.653, -7.652, -7.651, -7.65, -7.649, -7.648, -7.6470003, -7.646, -7.645, -7.644, -7.6429996, -7.642, -7.641, -7.6400003, -7.639, -7.638, -7.637, -7.6359997, -7.635, -7.634, -7.633, -7.632, -7.631, -7.63, -7.6289997, -7.6280003, -7.627, -7.626, -7.625, -7.624, -7.623, -7.6219997, -7.6210003, -7.62, -7.619, -7.618, -7.617, -7.616, -7.615, -7.6140003, -7.613, -7.612, -7.611, -7.6099997, -7.609, -7.608, -7.6070004, -7.606, -7.605, -7.604, -7.6029997, -7.602, -7.601, -7.6, -7.599, -7.598, -7.597, -7.5959997, -7.5950003, -7.594, -7.593, -7.592, -7.591, -7.59, -7.5889997, -7.5880003, -7.587, -7.586, -7.585, -7.584, -7.583, -7.582, -7.5810003, -7.58, -7.579, -7.578, -7.5769997, -7.576, -7.575, -7.574, -7.573, -7.572, -7.571, -7.5699997, -7.5690002, -7.568, -7.567, -7.566, -7.565, -7.564, -7.5629997, -7.5620003, -7.561, -7.56, -7.559, -7.558, -7.557, -7.5559998, -7.5550003, -7.554, -7.553, -7.552, -7.551, -7.55, -7.549, -7.5480003, -7.547, -7.546, -7.545, -7.5439997, -7.543, -7.542, -7.541, -7.54, -7.539, -7.538, -7.5369997, -7.5360003, -7.535, -7.534, -7.533, -7.532, -7.531, -7.5299997, -7.5290003, -7.528, -7.527, -7.526, -7.525, -7.524, -7.523, -7.5220003, -7.521, -7.52, -7.519, -7.5179996, -7.517, -7.516, -7.5150003, -7.514, -7.513, -7.512, -7.5109997, -7.51, -7.509, -7.508, -7.507, -7.506, -7.505, -7.5039997, -7.5030003, -7.502, -7.501, -7.5, -7.499, -7.498, -7.4969997, -7.4960003, -7.495, -7.494, -7.493, -7.492, -7.491, -7.49, -7.4890003, -7.488, -7.487, -7.486, -7.4849997, -7.484, -7.483, -7.4820004, -7.481, -7.48, -7.479, -7.4779997, -7.477, -7.476, -7.475, -7.474, -7.473, -7.472, -7.4709997, -7.4700003, -7.469, -7.468, -7.467, -7.466, -7.465, -7.4639997, -7.4630003, -7.462, -7.461, -7.46, -7.459, -7.458, -7.457, -7.4560003, -7.455, -7.454, -7.453, -7.4519997, -7.451, -7.45, -7.449, -7.448, -7.447, -7.446, -7.4449997, -7.4440002, -7.443, -7.442, -7.441, -7.44, -7.439, -7.4379997, -7.4370003, -7.436, -7.435, -7.434, -7.433, -7.432, -7.4309998, -7.4300003, -7.429, -7.428, -7.427, -7.426, -7.425, -7.424, -7.4230003, -7.422, -7.421, -7.42, -7.4189997, -7.418, -7.417, -7.416, -7.415, -7.414, -7.413, -7.4119997, -7.4110003, -7.41, -7.409, -7.408, -7.407, -7.406, -7.4049997, -7.4040003, -7.403, -7.402, -7.401, -7.4, -7.399, -7.398, -7.3970003, -7.396, -7.395, -7.394, -7.3929996, -7.392, -7.391, -7.3900003, -7.389, -7.388, -7.387, -7.3859997, -7.385, -7.384, -7.383, -7.382, -7.381, -7.38, -7.3789997, -7.3780003, -7.377, -7.376, -7.375, -7.374, -7.373, -7.3719997, -7.3710003, -7.37, -7.369, -7.368, -7.367, -7.366, -7.365, -7.3640003, -7.363, -7.362, -7.361, -7.3599997, -7.359, -7.358, -7.3570004, -7.356, -7.355, -7.354, -7.3529997, -7.352, -7.351, -7.35, -7.349, -7.348, -7.347, -7.3459997, -7.3450003, -7.344, -7.343, -7.342, -7.341, -7.34, -7.3389997, -7.3380003, -7.337, -7.336, -7.335, -7.334, -7.333, -7.332, -7.3310003, -7.33, -7.329, -7.328, -7.3269997, -7.326, -7.325, -7.324, -7.323, -7.322, -7.321, -7.3199997, -7.3190002, -7.318, -7.317, -7.316, -7.315, -7.314, -7.3129997, -7.3120003, -7.311, -7.31, -7.309, -7.308, -7.307, -7.3059998, -7.3050003, -7.304, -7.303, -7.302, -7.301, -7.3, -7.299, -7.2980003, -7.297, -7.296, -7.295, -7.2939997, -7.293, -7.292, -7.291, -7.29, -7.289, -7.288, -7.2869997, -7.2860003, -7.285, -7.284, -7.283, -7.282, -7.281, -7.2799997, -7.2790003, -7.278, -7.277, -7.276, -7.275, -7.274, -7.273, -7.2720003, -7.271, -7.27, -7.269, -7.2679996, -7.267, -7.266, -7.2650003, -7.264, -7.263, -7.262, -7.2609997, -7.26, -7.259, -7.258, -7.257, -7.256, -7.255, -7.2539997, -7.2530003, -7.252, -7.251, -7.25, -7.249, -7.248, -7.2469997, -7.2460003, -7.245, -7.244, -7.243, -7.242, -7.241, -7.24, -7.2390003, -7.238, -7.237, -7.236, -7.2349997, -7.234, -7.233, -7.2320004, -7.231, -7.23, -7.229, -7.2279997, -7.227, -7.226, -7.225, -7.224, -7.223, -7.222, -7.2209997, -7.2200003, -7.219, -7.218, -7.217, -7.216, -7.215, -7.2139997, -7.2130003, -7.212, -7.211, -7.21, -7.209, -7.208, -7.207, -7.2060003, -7.205, -7.204, -7.203, -7.2019997, -7.201, -7.2, -7.199, -7.198, -7.197, -7.196, -7.1949997, -7.1940002, -7.193, -7.192, -7.191, -7.19, -7.189, -7.1879997, -7.1870003, -7.186, -7.185, -7.184, -7.183, -7.182, -7.1809998, -7.1800003, -7.179, -7.178, -7.177, -7.176, -7.175, -7.174, -7.1730003, -7.172, -7.171, -7.17, -7.1689997, -7.168, -7.167, -7.166, -7.165, -7.164, -7.163, -7.1619997, -7.1610003, -7.16, -7.159, -7.158, -7.157, -7.156, -7.1549997, -7.1540003, -7.153, -7.152, -7.151, -7.15, -7.149, -7.148, -7.1470003, -7.146, -7.145, -7.144, -7.1429996, -7.142, -7.141, -7.1400003, -7.139, -7.138, -7.137, -7.1359997, -7.135, -7.134, -7.133, -7.132, -7.131, -7.13, -7.1289997, -7.1280003, -7.127, -7.126, -7.125, -7.124, -7.123, -7.1219997, -7.1210003, -7.12, -7.119, -7.118, -7.117, -7.116, -7.115, -7.1140003, -7.113, -7.112, -7.111, -7.1099997, -7.109, -7.108, -7.1070004, -7.106, -7.105, -7.104, -7.1029997, -7.102, -7.101, -7.1, -7.099, -7.098, -7.097, -7.0959997, -7.0950003, -7.094, -7.093, -7.092, -7.091, -7.09, -7.0889997, -7.0880003, -7.087, -7.086, -7.085, -7.084, -7.083, -7.082, -7.0810003, -7.08, -7.079, -7.078, -7.0769997, -7.076, -7.075, -7.074, -7.073, -7.072, -7.071, -7.0699997, -7.0690002, -7.068, -7.067, -7.066, -7.065, -7.064, -7.0629997, -7.0620003, -7.061, -7.06, -7.059, -7.058, -7.057, -7.0559998, -7.0550003, -7.054, -7.053, -7.052, -7.051, -7.05, -7.049, -7.0480003, -7.047, -7.046, -7.045, -7.0439997, -7.043, -7.042, -7.041, -7.04, -7.039, -7.038, -7.0369997, -7.0360003, -7.035, -7.034, -7.033, -7.032, -7.031, -7.0299997, -7.0290003, -7.028, -7.027, -7.026, -7.025, -7.024, -7.023, -7.0220003, -7.021, -7.02, -7.019, -7.0179996, -7.017, -7.016, -7.0150003, -7.014, -7.013, -7.012, -7.0109997, -7.01, -7.009, -7.008, -7.007, -7.006, -7.005, -7.0039997, -7.0030003, -7.002, -7.001, -7.0, -6.999, -6.998, -6.9969997, -6.9960003, -6.995, -6.994, -6.993, -6.992, -6.991, -6.99, -6.9890003, -6.988, -6.987, -6.986, -6.9849997, -6.984, -6.983, -6.9820004, -6.981, -6.98, -6.979, -6.9779997, -6.977, -6.976, -6.975, -6.974, -6.973, -6.972, -6.9709997, -6.9700003, -6.969, -6.968, -6.967, -6.966, -6.965, -6.9639997, -6.9630003, -6.962, -6.961, -6.96, -6.959, -6.958, -6.957, -6.9560003, -6.955, -6.954, -6.953, -6.9519997, -6.951, -6.95, -6.949, -6.948, -6.947, -6.946, -6.9449997, -6.9440002, -6.943, -6.942, -6.941, -6.94, -6.939, -6.9379997, -6.9370003, -6.936, -6.935, -6.934, -6.933, -6.932, -6.9309998, -6.9300003, -6.929, -6.928, -6.927, -6.926, -6.925, -6.924, -6.9230003, -6.922, -6.921, -6.92, -6.9189997, -6.918, -6.917, -6.916, -6.915, -6.914, -6.913, -6.9119997, -6.9110003, -6.91, -6.909, -6.908, -6.907, -6.906, -6.9049997, -6.9040003, -6.903, -6.902, -6.901, -6.9, -6.899, -6.898, -6.8970003, -6.896, -6.895, -6.894, -6.8929996, -6.892, -6.891, -6.8900003, -6.889, -6.888, -6.887, -6.8859997, -6.885, -6.884, -6.883, -6.882, -6.881, -6.88, -6.8789997, -6.8780003, -6.877, -6.876, -6.875, -6.874, -6.873, -6.8719997, -6.8710003, -6.87, -6.869, -6.868, -6.867, -6.866, -6.865, -6.8640003, -6.863, -6.862, -6.861, -6.8599997, -6.859, -6.858, -6.8570004, -6.856, -6.855, -6.854, -6.8529997, -6.852, -6.851, -6.85, -6.849, -6.848, -6.847, -6.8459997, -6.8450003, -6.844, -6.843, -6.842, -6.841, -6.84, -6.8389997, -6.8380003, -6.837, -6.836, -6.835, -6.834, -6.833, -6.832, -6.8310003, -6.83, -6.829, -6.828, -6.8269997, -6.826, -6.825, -6.824, -6.823, -6.822, -6.821, -6.8199997, -6.8190002, -6.818, -6.817, -6.816, -6.815, -6.814, -6.8129997, -6.8120003, -6.811, -6.81, -6.809, -6.808, -6.807, -6.8059998, -6.8050003, -6.804, -6.803, -6.802, -6.801, -6.8, -6.799, -6.7980003, -6.797, -6.796, -6.795, -6.7939997, -6.793, -6.792, -6.791, -6.79, -6.789, -6.788, -6.7869997, -6.7860003, -6.785, -6.784, -6.783, -6.782, -6.781, -6.7799997, -6.7790003, -6.778, -6.777, -6.776, -6.775, -6.774, -6.773, -6.7720003, -6.771, -6.77, -6.769, -6.7679996, -6.767, -6.766, -6.7650003, -6.764, -6.763, -6.762, -6.7609997, -6.76, -6.759, -6.758, -6.757, -6.756, -6.755, -6.7539997, -6.7530003, -6.752, -6.751, -6.75, -6.749, -6.748, -6.7469997, -6.7460003, -6.745, -6.744, -6.743, -6.742, -6.741, -6.74, -6.7390003, -6.738, -6.737, -6.736, -6.7349997, -6.734, -6.733, -6.7320004, -6.731, -6.73, -6.729, -6.7279997, -6.727, -6.726, -6.725, -6.724, -6.723, -6.722, -6.7209997, -6.7200003, -6.719, -6.718, -6.717, -6.716, -6.715, -6.7139997, -6.7130003, -6.712, -6.711, -6.71, -6.709, -6.708, -6.707, -6.7060003, -6.705, -6.704, -6.703, -6.7019997, -6.701, -6.7, -6.699, -6.698, -6.697, -6.696, -6.6949997, -6.6940002, -6.693, -6.692, -6.691, -6.69, -6.689, -6.6879997, -6.6870003, -6.686, -6.685, -6.684, -6.683, -6.682, -6.6809998, -6.6800003, -6.679, -6.678, -6.677, -6.676, -6.675, -6.674, -6.6730003, -6.672, -6.671, -6.67, -6.6689997, -6.668, -6.667, -6.666, -6.665, -6.664, -6.663, -6.6619997, -6.6610003, -6.66, -6.659, -6.658, -6.657, -6.656, -6.6549997, -6.6540003, -6.653, -6.652, -6.651, -6.65, -6.649, -6.648, -6.6470003, -6.646, -6.645, -6.644, -6.6429996, -6.642, -6.641, -6.6400003, -6.639, -6.638, -6.637, -6.6359997, -6.635, -6.634, -6.633, -6.632, -6.631, -6.63, -6.6289997, -6.6280003, -6.627, -6.626, -6.625, -6.624, -6.623, -6.6219997, -6.6210003, -6.62, -6.619, -6.618, -6.617, -6.616, -6.615, -6.6140003, -6.613, -6.612, -6.611, -6.6099997, -6.609, -6.608, -6.6070004, -6.606, -6.605, -6.604, -6.6029997, -6.602, -6.601, -6.6, -6.599, -6.598, -6.597, -6.5959997, -6.5950003, -6.594, -6.593, -6.592, -6.591, -6.59, -6.5889997, -6.5880003, -6.587, -6.586, -6.585, -6.584, -6.583, -6.582, -6.5810003, -6.58, -6.579, -6.578, -6.5769997, -6.576, -6.575, -6.574, -6.573, -6.572, -6.571, -6.5699997, -6.5690002, -6.568, -6.567, -6.566, -6.565, -6.564, -6.5629997, -6.5620003, -6.561, -6.56, -6.559, -6.558, -6.557, -6.5559998, -6.5550003, -6.554, -6.553, -6.552, -6.551, -6.55, -6.549, -6.5480003, -6.547, -6.546, -6.545, -6.5439997, -6.543, -6.542, -6.541, -6.54, -6.539, -6.538, -6.5369997, -6.5360003, -6.535, -6.534, -6.533, -6.532, -6.531, -6.5299997, -6.5290003, -6.528, -6.527, -6.526, -6.525, -6.524, -6.523, -6.5220003, -6.521, -6.52, -6.519, -6.5179996, -6.517, -6.516, -6.5150003, -6.514, -6.513, -6.512, -6.5109997, -6.51, -6.509, -6.508, -6.507, -6.506, -6.505, -6.5039997, -6.5030003, -6.502, -6.501, -6.5, -6.499, -6.498, -6.4969997, -6.4960003, -6.495, -6.494, -6.493, -6.492, -6.491, -6.49, -6.4890003, -6.488, -6.487, -6.486, -6.4849997, -6.484, -6.483, -6.4820004, -6.481, -6.48, -6.479, -6.4779997, -6.477, -6.476, -6.475, -6.474, -6.473, -6.472, -6.4709997, -6.4700003, -6.469, -6.468, -6.467, -6.466, -6.465, -6.4639997, -6.4630003, -6.462, -6.461, -6.46, -6.459, -6.458, -6.457, -6.4560003, -6.455, -6.454, -6.453, -6.4519997, -6.451, -6.45, -6.449, -6.448, -6.447, -6.446, -6.4449997, -6.4440002, -6.443, -6.442, -6.441, -6.44, -6.439, -6.4379997, -6.4370003, -6.436, -6.435, -6.434, -6.433, -6.432, -6.4309998, -6.4300003, -6.429, -6.428, -6.427, -6.426, -6.425, -6.424, -6.4230003, -6.422, -6.421, -6.42, -6.4189997, -6.418, -6.417, -6.416, -6.415, -6.414, -6.413, -6.4119997, -6.4110003, -6.41, -6.409, -6.408, -6.407, -6.406, -6.4049997, -6.4040003, -6.403, -6.402, -6.401, -6.4, -6.399, -6.398, -6.3970003, -6.396, -6.395, -6.394, -6.3929996, -6.392, -6.391, -6.3900003, -6.389, -6.388, -6.387, -6.3859997, -6.385, -6.384, -6.383, -6.382, -6.381, -6.38, -6.3789997, -6.3780003, -6.377, -6.376, -6.375, -6.374, -6.373, -6.3719997, -6.3710003, -6.37, -6.369, -6.368, -6.367, -6.366, -6.365, -6.3640003, -6.363, -6.362, -6.361, -6.3599997, -6.359, -6.358, -6.3570004, -6.356, -6.355, -6.354, -6.3529997, -6.352, -6.351, -6.35, -6.349, -6.348, -6.347, -6.3459997, -6.3450003, -6.344, -6.343, -6.342, -6.341, -6.34, -6.3389997, -6.3380003, -6.337, -6.336, -6.335, -6.334, -6.333, -6.332, -6.3310003, -6.33, -6.329, -6.328, -6.3269997, -6.326, -6.325, -6.324, -6.323, -6.322, -6.321, -6.3199997, -6.3190002, -6.318, -6.317, -6.316, -6.315, -6.314, -6.3129997, -6.3120003, -6.311, -6.31, -6.309, -6.308, -6.307, -6.3059998, -6.3050003, -6.304, -6.303, -6.302, -6.301, -6.3, -6.299, -6.2980003, -6.297, -6.296, -6.295, -6.2939997, -6.293, -6.292, -6.291, -6.29, -6.289, -6.288, -6.2869997, -6.2860003, -6.285, -6.284, -6.283, -6.282, -6.281, -6.2799997, -6.2790003, -6.278, -6.277, -6.276, -6.275, -6.274, -6.273, -6.2720003, -6.271, -6.27, -6.269, -6.2679996, -6.267, -6.266, -6.2650003, -6.264, -6.263, -6.262, -6.2609997, -6.26, -6.259, -6.258, -6.257, -6.256, -6.255, -6.2539997, -6.2530003, -6.252, -6.251, -6.25, -6.249, -6.248, -6.2469997, -6.2460003, -6.245, -6.244, -6.243, -6.242, -6.241, -6.24, -6.2390003, -6.238, -6.237, -6.236, -6.2349997, -6.234, -6.233, -6.2320004, -6.231, -6.23, -6.229, -6.2279997, -6.227, -6.226, -6.225, -6.224, -6.223, -6.222, -6.2209997, -6.2200003, -6.219, -6.218, -6.217, -6.216, -6.215, -6.2139997, -6.2130003, -6.212, -6.211, -6.21, -6.209, -6.208, -6.207, -6.2060003, -6.205, -6.204, -6.203, -6.2019997, -6.201, -6.2, -6.199, -6.198, -6.197, -6.196, -6.1949997, -6.1940002, -6.193, -6.192, -6.191, -6.19, -6.189, -6.1879997, -6.1870003, -6.186, -6.185, -6.184, -6.183, -6.182, -6.1809998, -6.1800003, -6.179, -6.178, -6.177, -6.176, -6.175, -6.174, -6.1730003, -6.172, -6.171, -6.17, -6.1689997, -6.168, -6.167, -6.166, -6.165, -6.164, -6.163, -6.1619997, -6.1610003, -6.16, -6.159, -6.158, -6.157, -6.156, -6.1549997, -6.1540003, -6.153, -6.152, -6.151, -6.15, -6.149, -6.148, -6.1470003, -6.146, -6.145, -6.144, -6.1429996, -6.142, -6.141, -6.1400003, -6.139, -6.138, -6.137, -6.1359997, -6.135, -6.134, -6.133, -6.132, -6.131, -6.13, -6.1289997, -6.1280003, -6.127, -6.126, -6.125, -6.124, -6.123, -6.1219997, -6.1210003, -6.12, -6.119, -6.118, -6.117, -6.116, -6.115, -6.1140003, -6.113, -6.112, -6.111, -6.1099997, -6.109, -6.108, -6.1070004, -6.106, -6.105, -6.104, -6.1029997, -6.102, -6.101, -6.1, -6.099, -6.098, -6.097, -6.0959997, -6.0950003, -6.094, -6.093, -6.092, -6.091, -6.09, -6.0889997, -6.0880003, -6.087, -6.086, -6.085, -6.084, -6.083, -6.082, -6.0810003, -6.08, -6.079, -6.078, -6.0769997, -6.076, -6.075, -6.074, -6.073, -6.072, -6.071, -6.0699997, -6.0690002, -6.068, -6.067, -6.066, -6.065, -6.064, -6.0629997, -6.0620003, -6.061, -6.06, -6.059, -6.058, -6.057, -6.0559998, -6.0550003, -6.054, -6.053, -6.052, -6.051, -6.05, -6.049, -6.0480003, -6.047, -6.046, -6.045, -6.0439997, -6.043, -6.042, -6.041, -6.04, -6.039, -6.038, -6.0369997, -6.0360003, -6.035, -6.034, -6.033, -6.032, -6.031, -6.0299997, -6.0290003, -6.028, -6.027, -6.026, -6.025, -6.024, -6.023, -6.0220003, -6.021, -6.02, -6.019, -6.0179996, -6.017, -6.016, -6.0150003, -6.014, -6.013, -6.012, -6.0109997, -6.01, -6.009, -6.008, -6.007, -6.006, -6.005, -6.0039997, -6.0030003, -6.002, -6.001, -6.0, -5.999, -5.998, -5.997, -5.996, -5.995, -5.994, -5.993, -5.992, -5.991, -5.99, -5.989, -5.988, -5.987, -5.986, -5.985, -5.984, -5.983, -5.982, -5.981, -5.98, -5.979, -5.978, -5.977, -5.976, -5.975, -5.974, -5.973, -5.972, -5.971, -5.97, -5.969, -5.968, -5.967, -5.966, -5.965, -5.964, -5.963, -5.962, -5.961, -5.96, -5.959, -5.958, -5.957, -5.956, -5.955, -5.954, -5.953, -5.952, -5.951, -5.95, -5.949, -5.948, -5.947, -5.946, -5.945, -5.944, -5.943, -5.942, -5.941, -5.94, -5.939, -5.938, -5.937, -5.936, -5.935, -5.934, -5.933, -5.932, -5.931, -5.93, -5.929, -5.928, -5.927, -5.926, -5.925, -5.924, -5.923, -5.922, -5.921, -5.92, -5.919, -5.918, -5.917, -5.916, -5.915, -5.914, -5.913, -5.912, -5.911, -5.91, -5.909, -5.908, -5.907, -5.906, -5.905, -5.904, -5.903, -5.902, -5.901, -5.9, -5.899, -5.898, -5.897, -5.896, -5.895, -5.894, -5.893, -5.892, -5.891, -5.89, -5.889, -5.888, -5.887, -5.886, -5.885, -5.884, -5.883, -5.882, -5.881, -5.88, -5.879, -5.878, -5.877, -5.876, -5.875, -5.874, -5.873, -5.872, -5.871, -5.87, -5.869, -5.868, -5.867, -5.866, -5.865, -5.864, -5.863, -5.862, -5.861, -5.86, -5.859, -5.858, -5.857, -5.856, -5.855, -5.854, -5.853, -5.852, -5.851, -5.85, -5.849, -5.848, -5.847, -5.846, -5.845, -5.844, -5.843, -5.842, -5.841, -5.84, -5.839, -5.838, -5.837, -5.836, -5.835, -5.834, -5.833, -5.832, -5.831, -5.83, -5.829, -5.828, -5.827, -5.826, -5.825, -5.824, -5.823, -5.822, -5.821, -5.82, -5.819, -5.818, -5.817, -5.816, -5.815, -5.814, -5.813, -5.812, -5.811, -5.81, -5.809, -5.808, -5.807, -5.806, -5.805, -5.804, -5.803, -5.802, -5.801, -5.8, -5.799, -5.798, -5.797, -5.796, -5.795, -5.794, -5.793, -5.792, -5.791, -5.79, -5.789, -5.788, -5.787, -5.786, -5.785, -5.784, -5.783, -5.782, -5.781, -5.78, -5.779, -5.778, -5.777, -5.776, -5.775, -5.774, -5.773, -5.772, -5.771, -5.77, -5.769, -5.768, -5.767, -5.766, -5.765, -5.764, -5.763, -5.762, -5.761, -5.76, -5.759, -5.758, -5.757, -5.756, -5.755, -5.754, -5.753, -5.752, -5.751, -5.75, -5.749, -5.748, -5.747, -5.746, -5.745, -5.744, -5.743, -5.742, -5.741, -5.74, -5.739, -5.738, -5.737, -5.736, -5.735, -5.734, -5.733, -5.732, -5.731, -5.73, -5.729, -5.728, -5.727, -5.726, -5.725, -5.724, -5.723, -5.722, -5.721, -5.72, -5.719, -5.718, -5.717, -5.716, -5.715, -5.714, -5.713, -5.712, -5.711, -5.71, -5.709, -5.708, -5.707, -5.706, -5.705, -5.704, -5.703, -5.702, -5.701, -5.7, -5.699, -5.698, -5.697, -5.696, -5.695, -5.694, -5.693, -5.692, -5.691, -5.69, -5.689, -5.688, -5.687, -5.686, -5.685, -5.684, -5.683, -5.682, -5.681, -5.68, -5.679, -5.678, -5.677, -5.676, -5.675, -5.674, -5.673, -5.672, -5.671, -5.67, -5.669, -5.668, -5.667, -5.666, -5.665, -5.664, -5.663, -5.662, -5.661, -5.66, -5.659, -5.658, -5.657, -5.656, -5.655, -5.654, -5.653, -5.652, -5.651, -5.65, -5.649, -5.648, -5.647, -5.646, -5.645, -5.644, -5.643, -5.642, -5.641, -5.64, -5.639, -5.638, -5.637, -5.636, -5.635, -5.634, -5.633, -5.632, -5.631, -5.63, -5.629, -5.628, -5.627, -5.626, -5.625, -5.624, -5.623, -5.622, -5.621, -5.62, -5.619, -5.618, -5.617, -5.616, -5.615, -5.614, -5.613, -5.612, -5.611, -5.61, -5.609, -5.608, -5.607, -5.606, -5.605, -5.604, -5.603, -5.602, -5.601, -5.6, -5.599, -5.598, -5.597, -5.596, -5.595, -5.594, -5.593, -5.592, -5.591, -5.59, -5.589, -5.588, -5.587, -5.586, -5.585, -5.584, -5.583, -5.582, -5.581, -5.58, -5.579, -5.578, -5.577, -5.576, -5.575, -5.574, -5.573, -5.572, -5.571, -5.57, -5.569, -5.568, -5.567, -5.566, -5.565, -5.564, -5.563, -5.562, -5.561, -5.56, -5.559, -5.558, -5.557, -5.556, -5.555, -5.554, -5.553, -5.552, -5.551, -5.55, -5.549, -5.548, -5.547, -5.546, -5.545, -5.544, -5.543, -5.542, -5.541, -5.54, -5.539, -5.538, -5.537, -5.536, -5.535, -5.534, -5.533, -5.532, -5.531, -5.53, -5.529, -5.528, -5.527, -5.526, -5.525, -5.524, -5.523, -5.522, -5.521, -5.52, -5.519, -5.518, -5.517, -5.516, -5.515, -5.514, -5.513, -5.512, -5.511, -5.51, -5.509, -5.508, -5.507, -5.506, -5.505, -5.504, -5.503, -5.502, -5.501, -5.5, -5.499, -5.498, -5.497, -5.496, -5.495, -5.494, -5.493, -5.492, -5.491, -5.49, -5.489, -5.488, -5.487, -5.486, -5.485, -5.484, -5.483, -5.482, -5.481, -5.48, -5.479, -5.478, -5.477, -5.476, -5.475, -5.474, -5.473, -5.472, -5.471, -5.47, -5.469, -5.468, -5.467, -5.466, -5.465, -5.464, -5.463, -5.462, -5.461, -5.46, -5.459, -5.458, -5.457, -5.456, -5.455, -5.454, -5.453, -5.452, -5.451, -5.45, -5.449, -5.448, -5.447, -5.446, -5.445, -5.444, -5.443, -5.442, -5.441, -5.44, -5.439, -5.438, -5.437, -5.436, -5.435, -5.434, -5.433, -5.432, -5.431, -5.43, -5.429, -5.428, -5.427, -5.426, -5.425, -5.424, -5.423, -5.422, -5.421, -5.42, -5.419, -5.418, -5.417, -5.416, -5.415, -5.414, -5.413, -5.412, -5.411, -5.41, -5.409, -5.408, -5.407, -5.406, -5.405, -5.404, -5.403, -5.402, -5.401, -5.4, -5.399, -5.398, -5.397, -5.396, -5.395, -5.394, -5.393, -5.392, -5.391, -5.39, -5.389, -5.388, -5.387, -5.386, -5.385, -5.384, -5.383, -5.382, -5.381, -5.38, -5.379, -5.378, -5.377, -5.376, -5.375, -5.374, -5.373, -5.372, -5.371, -5.37, -5.369, -5.368, -5.367, -5.366, -5.365, -5.364, -5.363, -5.362, -5.361, -5.36, -5.359, -5.358, -5.357, -5.356, -5.355, -5.354, -5.353, -5.352, -5.351, -5.35, -5.349, -5.348, -5.347, -5.346, -5.345, -5.344, -5.343, -5.342, -5.341, -5.34, -5.339, -5.338, -5.337, -5.336, -5.335, -5.334, -5.333, -5.332, -5.331, -5.33, -5.329, -5.328, -5.327, -5.326, -5.325, -5.324, -5.323, -5.322, -5.321, -5.32, -5.319, -5.318, -5.317, -5.316, -5.315, -5.314, -5.313, -5.312, -5.311, -5.31, -5.309, -5.308, -5.307, -5.306, -5.305, -5.304, -5.303, -5.302, -5.301, -5.3, -5.299, -5.298, -5.297, -5.296, -5.295, -5.294, -5.293, -5.292, -5.291, -5.29, -5.289, -5.288, -5.287, -5.286, -5.285, -5.284, -5.283, -5.282, -5.281, -5.28, -5.279, -5.278, -5.277, -5.276, -5.275, -5.274, -5.273, -5.272, -5.271, -5.27, -5.269, -5.268, -5.267, -5.266, -5.265, -5.264, -5.263, -5.262, -5.261, -5.26, -5.259, -5.258, -5.257, -5.256, -5.255, -5.254, -5.253, -5.252, -5.251, -5.25, -5.249, -5.248, -5.247, -5.246, -5.245, -5.244, -5.243, -5.242, -5.241, -5.24, -5.239, -5.238, -5.237, -5.236, -5.235, -5.234, -5.233, -5.232, -5.231, -5.23, -5.229, -5.228, -5.227, -5.226, -5.225, -5.224, -5.223, -5.222, -5.221, -5.22, -5.219, -5.218, -5.217, -5.216, -5.215, -5.214, -5.213, -5.212, -5.211, -5.21, -5.209, -5.208, -5.207, -5.206, -5.205, -5.204, -5.203, -5.202, -5.201, -5.2, -5.199, -5.198, -5.197, -5.196, -5.195, -5.194, -5.193, -5.192, -5.191, -5.19, -5.189, -5.188, -5.187, -5.186, -5.185, -5.184, -5.183, -5.182, -5.181, -5.18, -5.179, -5.178, -5.177, -5.176, -5.175, -5.174, -5.173, -5.172, -5.171, -5.17, -5.169, -5.168, -5.167, -5.166, -5.165, -5.164, -5.163, -5.162, -5.161, -5.16, -5.159, -5.158, -5.157, -5.156, -5.155, -5.154, -5.153, -5.152, -5.151, -5.15, -5.149, -5.148, -5.147, -5.146, -5.145, -5.144, -5.143, -5.142, -5.141, -5.14, -5.139, -5.138, -5.137, -5.136, -5.135, -5.134, -5.133, -5.132, -5.131, -5.13, -5.129, -5.128, -5.127, -5.126, -5.125, -5.124, -5.123, -5.122, -5.121, -5.12, -5.119, -5.118, -5.117, -5.116, -5.115, -5.114, -5.113, -5.112, -5.111, -5.11, -5.109, -5.108, -5.107, -5.106, -5.105, -5.104, -5.103, -5.102, -5.101, -5.1, -5.099, -5.098, -5.097, -5.096, -5.095, -5.094, -5.093, -5.092, -5.091, -5.09, -5.089, -5.088, -5.087, -5.086, -5.085, -5.084, -5.083, -5.082, -5.081, -5.08, -5.079, -5.078, -5.077, -5.076, -5.075, -5.074, -5.073, -5.072, -5.071, -5.07, -5.069, -5.068, -5.067, -5.066, -5.065, -5.064, -5.063, -5.062, -5.061, -5.06, -5.059, -5.058, -5.057, -5.056, -5.055, -5.054, -5.053, -5.052, -5.051, -5.05, -5.049, -5.048, -5.047, -5.046, -5.045, -5.044, -5.043, -5.042, -5.041, -5.04, -5.039, -5.038, -5.037, -5.036, -5.035, -5.034, -5.033, -5.032, -5.031, -5.03, -5.029, -5.028, -5.027, -5.026, -5.025, -5.024, -5.023, -5.022, -5.021, -5.02, -5.019, -5.018, -5.017, -5.016, -5.015, -5.014, -5.013, -5.012, -5.011, -5.01, -5.009, -5.008, -5.007, -5.006, -5.005, -5.004, -5.003, -5.002, -5.001, -5.0, -4.999, -4.998, -4.997, -4.996, -4.995, -4.994, -4.993, -4.992, -4.991, -4.99, -4.989, -4.988, -4.987, -4.986, -4.985, -4.984, -4.983, -4.982, -4.981, -4.98, -4.979, -4.978, -4.977, -4.976, -4.975, -4.974, -4.973, -4.972, -4.971, -4.97, -4.969, -4.968, -4.967, -4.966, -4.965, -4.964, -4.963, -4.962, -4.961, -4.96, -4.959, -4.958, -4.957, -4.956, -4.955, -4.954, -4.953, -4.952, -4.951, -4.95, -4.949, -4.948, -4.947, -4.946, -4.945, -4.944, -4.943, -4.942, -4.941, -4.94, -4.939, -4.938, -4.937, -4.936, -4.935, -4.934, -4.933, -4.932, -4.931, -4.93, -4.929, -4.928, -4.927, -4.926, -4.925, -4.924, -4.923, -4.922, -4.921, -4.92, -4.919, -4.918, -4.917, -4.916, -4.915, -4.914, -4.913, -4.912, -4.911, -4.91, -4.909, -4.908, -4.907, -4.906, -4.905, -4.904, -4.903, -4.902, -4.901, -4.9, -4.899, -4.898, -4.897, -4.896, -4.895, -4.894, -4.893, -4.892, -4.891, -4.89, -4.889, -4.888, -4.887, -4.886, -4.885, -4.884, -4.883, -4.882, -4.881, -4.88, -4.879, -4.878, -4.877, -4.876, -4.875, -4.874, -4.873, -4.872, -4.871, -4.87, -4.869, -4.868, -4.867, -4.866, -4.865, -4.864, -4.863, -4.862, -4.861, -4.86, -4.859, -4.858, -4.857, -4.856, -4.855, -4.854, -4.853, -4.852, -4.851, -4.85, -4.849, -4.848, -4.847, -4.846, -4.845, -4.844, -4.843, -4.842, -4.841, -4.84, -4.839, -4.838, -4.837, -4.836, -4.835, -4.834, -4.833, -4.832, -4.831, -4.83, -4.829, -4.828, -4.827, -4.826, -4.825, -4.824, -4.823, -4.822, -4.821, -4.82, -4.819, -4.818, -4.817, -4.816, -4.815, -4.814, -4.813, -4.812, -4.811, -4.81, -4.809, -4.808, -4.807, -4.806, -4.805, -4.804, -4.803, -4.802, -4.801, -4.8, -4.799, -4.798, -4.797, -4.796, -4.795, -4.794, -4.793, -4.792, -4.791, -4.79, -4.789, -4.788, -4.787, -4.786, -4.785, -4.784, -4.783, -4.782, -4.781, -4.78, -4.779, -4.778, -4.777, -4.776, -4.775, -4.774, -4.773, -4.772, -4.771, -4.77, -4.769, -4.768, -4.767, -4.766, -4.765, -4.764, -4.763, -4.762, -4.761, -4.76, -4.759, -4.758, -4.757, -4.756, -4.755, -4.754, -4.753, -4.752, -4.751, -4.75, -4.749, -4.748, -4.747, -4.746, -4.745, -4.744, -4.743, -4.742, -4.741, -4.74, -4.739, -4.738, -4.737, -4.736, -4.735, -4.734, -4.733, -4.732, -4.731, -4.73, -4.729, -4.728, -4.727, -4.726, -4.725, -4.724, -4.723, -4.722, -4.721, -4.72, -4.719, -4.718, -4.717, -4.716, -4.715, -4.714, -4.713, -4.712, -4.711, -4.71, -4.709, -4.708, -4.707, -4.706, -4.705, -4.704, -4.703, -4.702, -4.701, -4.7, -4.699, -4.698, -4.697, -4.696, -4.695, -4.694, -4.693, -4.692, -4.691, -4.69, -4.689, -4.688, -4.687, -4.686, -4.685, -4.684, -4.683, -4.682, -4.681, -4.68, -4.679, -4.678, -4.677, -4.676, -4.675, -4.674, -4.673, -4.672, -4.671, -4.67, -4.669, -4.668, -4.667, -4.666, -4.665, -4.664, -4.663, -4.662, -4.661, -4.66, -4.659, -4.658, -4.657, -4.656, -4.655, -4.654, -4.653, -4.652, -4.651, -4.65, -4.649, -4.648, -4.647, -4.646, -4.645, -4.644, -4.643, -4.642, -4.641, -4.64, -4.639, -4.638, -4.637, -4.636, -4.635, -4.634, -4.633, -4.632, -4.631, -4.63, -4.629, -4.628, -4.627, -4.626, -4.625, -4.624, -4.623, -4.622, -4.621, -4.62, -4.619, -4.618, -4.617, -4.616, -4.615, -4.614, -4.613, -4.612, -4.611, -4.61, -4.609, -4.608, -4.607, -4.606, -4.605, -4.604, -4.603, -4.602, -4.601, -4.6, -4.599, -4.598, -4.597, -4.596, -4.595, -4.594, -4.593, -4.592, -4.591, -4.59, -4.589, -4.588, -4.587, -4.586, -4.585, -4.584, -4.583, -4.582, -4.581, -4.58, -4.579, -4.578, -4.577, -4.576, -4.575, -4.574, -4.573, -4.572, -4.571, -4.57, -4.569, -4.568, -4.567, -4.566, -4.565, -4.564, -4.563, -4.562, -4.561, -4.56, -4.559, -4.558, -4.557, -4.556, -4.555, -4.554, -4.553, -4.552, -4.551, -4.55, -4.549, -4.548, -4.547, -4.546, -4.545, -4.544, -4.543, -4.542, -4.541, -4.54, -4.539, -4.538, -4.537, -4.536, -4.535, -4.534, -4.533, -4.532, -4.531, -4.53, -4.529, -4.528, -4.527, -4.526, -4.525, -4.524, -4.523, -4.522, -4.521, -4.52, -4.519, -4.518, -4.517, -4.516, -4.515, -4.514, -4.513, -4.512, -4.511, -4.51, -4.509, -4.508, -4.507, -4.506, -4.505, -4.504, -4.503, -4.502, -4.501, -4.5, -4.499, -4.498, -4.497, -4.496, -4.495, -4.494, -4.493, -4.492, -4.491, -4.49, -4.489, -4.488, -4.487, -4.486, -4.485, -4.484, -4.483, -4.482, -4.481, -4.48, -4.479, -4.478, -4.477, -4.476, -4.475, -4.474, -4.473, -4.472, -4.471, -4.47, -4.469, -4.468, -4.467, -4.466, -4.465, -4.464, -4.463, -4.462, -4.461, -4.46, -4.459, -4.458, -4.457, -4.456, -4.455, -4.454, -4.453, -4.452, -4.451, -4.45, -4.449, -4.448, -4.447, -4.446, -4.445, -4.444, -4.443, -4.442, -4.441, -4.44, -4.439, -4.438, -4.437, -4.436, -4.435, -4.434, -4.433, -4.432, -4.431, -4.43, -4.429, -4.428, -4.427, -4.426, -4.425, -4.424, -4.423, -4.422, -4.421, -4.42, -4.419, -4.418, -4.417, -4.416, -4.415, -4.414, -4.413, -4.412, -4.411, -4.41, -4.409, -4.408, -4.407, -4.406, -4.405, -4.404, -4.403, -4.402, -4.401, -4.4, -4.399, -4.398, -4.397, -4.396, -4.395, -4.394, -4.393, -4.392, -4.391, -4.39, -4.389, -4.388, -4.387, -4.386, -4.385, -4.384, -4.383, -4.382, -4.381, -4.38, -4.379, -4.378, -4.377, -4.376, -4.375, -4.374, -4.373, -4.372, -4.371, -4.37, -4.369, -4.368, -4.367, -4.366, -4.365, -4.364, -4.363, -4.362, -4.361, -4.36, -4.359, -4.358, -4.357, -4.356, -4.355, -4.354, -4.353, -4.352, -4.351, -4.35, -4.349, -4.348, -4.347, -4.346, -4.345, -4.344, -4.343, -4.342, -4.341, -4.34, -4.339, -4.338, -4.337, -4.336, -4.335, -4.334, -4.333, -4.332, -4.331, -4.33, -4.329, -4.328, -4.327, -4.326, -4.325, -4.324, -4.323, -4.322, -4.321, -4.32, -4.319, -4.318, -4.317, -4.316, -4.315, -4.314, -4.313, -4.312, -4.311, -4.31, -4.309, -4.308, -4.307, -4.306, -4.305, -4.304, -4.303, -4.302, -4.301, -4.3, -4.299, -4.298, -4.297, -4.296, -4.295, -4.294, -4.293, -4.292, -4.291, -4.29, -4.289, -4.288, -4.287, -4.286, -4.285, -4.284, -4.283, -4.282, -4.281, -4.28, -4.279, -4.278, -4.277, -4.276, -4.275, -4.274, -4.273, -4.272, -4.271, -4.27, -4.269, -4.268, -4.267, -4.266, -4.265, -4.264, -4.263, -4.262, -4.261, -4.26, -4.259, -4.258, -4.257, -4.256, -4.255, -4.254, -4.253, -4.252, -4.251, -4.25, -4.249, -4.248, -4.247, -4.246, -4.245, -4.244, -4.243, -4.242, -4.241, -4.24, -4.239, -4.238, -4.237, -4.236, -4.235, -4.234, -4.233, -4.232, -4.231, -4.23, -4.229, -4.228, -4.227, -4.226, -4.225, -4.224, -4.223, -4.222, -4.221, -4.22, -4.219, -4.218, -4.217, -4.216, -4.215, -4.214, -4.213, -4.212, -4.211, -4.21, -4.209, -4.208, -4.207, -4.206, -4.205, -4.204, -4.203, -4.202, -4.201, -4.2, -4.199, -4.198, -4.197, -4.196, -4.195, -4.194, -4.193, -4.192, -4.191, -4.19, -4.189, -4.188, -4.187, -4.186, -4.185, -4.184, -4.183, -4.182, -4.181, -4.18, -4.179, -4.178, -4.177, -4.176, -4.175, -4.174, -4.173, -4.172, -4.171, -4.17, -4.169, -4.168, -4.167, -4.166, -4.165, -4.164, -4.163, -4.162, -4.161, -4.16, -4.159, -4.158, -4.157, -4.156, -4.155, -4.154, -4.153, -4.152, -4.151, -4.15, -4.149, -4.148, -4.147, -4.146, -4.145, -4.144, -4.143, -4.142, -4.141, -4.14, -4.139, -4.138, -4.137, -4.136, -4.135, -4.134, -4.133, -4.132, -4.131, -4.13, -4.129, -4.128, -4.127, -4.126, -4.125, -4.124, -4.123, -4.122, -4.121, -4.12, -4.119, -4.118, -4.117, -4.116, -4.115, -4.114, -4.113, -4.112, -4.111, -4.11, -4.109, -4.108, -4.107, -4.106, -4.105, -4.104, -4.103, -4.102, -4.101, -4.1, -4.099, -4.098, -4.097, -4.096, -4.095, -4.094, -4.093, -4.092, -4.091, -4.09, -4.089, -4.088, -4.087, -4.086, -4.085, -4.084, -4.083, -4.082, -4.081, -4.08, -4.079, -4.078, -4.077, -4.076, -4.075, -4.074, -4.073, -4.072, -4.071, -4.07, -4.069, -4.068, -4.067, -4.066, -4.065, -4.064, -4.063, -4.062, -4.061, -4.06, -4.059, -4.058, -4.057, -4.056, -4.055, -4.054, -4.053, -4.052, -4.051, -4.05, -4.049, -4.048, -4.047, -4.046, -4.045, -4.044, -4.043, -4.042, -4.041, -4.04, -4.039, -4.038, -4.037, -4.036, -4.035, -4.034, -4.033, -4.032, -4.031, -4.03, -4.029, -4.028, -4.027, -4.026, -4.025, -4.024, -4.023, -4.022, -4.021, -4.02, -4.019, -4.018, -4.017, -4.016, -4.015, -4.014, -4.013, -4.012, -4.011, -4.01, -4.009, -4.008, -4.007, -4.006, -4.005, -4.004, -4.003, -4.002, -4.001, -4.0, -3.999, -3.9980001, -3.9970002, -3.9959998, -3.995, -3.994, -3.993, -3.992, -3.9910002, -3.9899998, -3.9889998, -3.988, -3.987, -3.986, -3.9850001, -3.9840002, -3.9829998, -3.9819999, -3.981, -3.98, -3.979, -3.9780002, -3.9770002, -3.9759998, -3.975, -3.974, -3.973, -3.9720001, -3.9710002, -3.9699998, -3.9689999, -3.968, -3.967, -3.966, -3.9650002, -3.9640002, -3.9629998, -3.962, -3.961, -3.96, -3.959, -3.9580002, -3.9569998, -3.9559999, -3.955, -3.954, -3.953, -3.9520001, -3.9510002, -3.9499998, -3.949, -3.948, -3.947, -3.946, -3.9450002, -3.9439998, -3.9429998, -3.942, -3.941, -3.94, -3.9390001, -3.9380002, -3.9369998, -3.9359999, -3.935, -3.934, -3.933, -3.9320002, -3.9310002, -3.9299998, -3.929, -3.928, -3.927, -3.926, -3.9250002, -3.9239998, -3.9229999, -3.922, -3.921, -3.92, -3.9190001, -3.9180002, -3.9169998, -3.916, -3.915, -3.914, -3.913, -3.9120002, -3.9109998, -3.9099998, -3.909, -3.908, -3.907, -3.9060001, -3.9050002, -3.9039998, -3.9029999, -3.902, -3.901, -3.9, -3.8990002, -3.8979998, -3.8969998, -3.896, -3.895, -3.894, -3.8930001, -3.8920002, -3.8909998, -3.8899999, -3.889, -3.888, -3.887, -3.8860002, -3.8850002, -3.8839998, -3.883, -3.882, -3.881, -3.88, -3.8790002, -3.8779998, -3.8769999, -3.876, -3.875, -3.874, -3.8730001, -3.8720002, -3.8709998, -3.87, -3.869, -3.868, -3.867, -3.8660002, -3.8649998, -3.8639998, -3.863, -3.862, -3.861, -3.8600001, -3.8590002, -3.8579998, -3.8569999, -3.856, -3.855, -3.854, -3.8530002, -3.8520002, -3.8509998, -3.85, -3.849, -3.848, -3.8470001, -3.8460002, -3.8449998, -3.8439999, -3.843, -3.842, -3.841, -3.8400002, -3.8390002, -3.8379998, -3.837, -3.836, -3.835, -3.834, -3.8330002, -3.8319998, -3.8309999, -3.83, -3.829, -3.828, -3.8270001, -3.8260002, -3.8249998, -3.824, -3.823, -3.822, -3.821, -3.8200002, -3.8189998, -3.8179998, -3.817, -3.816, -3.815, -3.8140001, -3.8130002, -3.8119998, -3.8109999, -3.81, -3.809, -3.808, -3.8070002, -3.8060002, -3.8049998, -3.804, -3.803, -3.802, -3.801, -3.8000002, -3.7989998, -3.7979999, -3.797, -3.796, -3.795, -3.7940001, -3.7930002, -3.7919998, -3.791, -3.79, -3.789, -3.788, -3.7870002, -3.7859998, -3.7849998, -3.784, -3.783, -3.782, -3.7810001, -3.7800002, -3.7789998, -3.7779999, -3.777, -3.776, -3.775, -3.7740002, -3.7729998, -3.7719998, -3.771, -3.77, -3.769, -3.7680001, -3.7670002, -3.7659998, -3.7649999, -3.764, -3.763, -3.762, -3.7610002, -3.7600002, -3.7589998, -3.758, -3.757, -3.756, -3.755, -3.7540002, -3.7529998, -3.7519999, -3.751, -3.75, -3.749, -3.7480001, -3.7470002, -3.7459998, -3.745, -3.744, -3.743, -3.742, -3.7410002, -3.7399998, -3.7389998, -3.738, -3.737, -3.736, -3.7350001, -3.7340002, -3.7329998, -3.7319999, -3.731, -3.73, -3.729, -3.7280002, -3.7270002, -3.7259998, -3.725, -3.724, -3.723, -3.7220001, -3.7210002, -3.7199998, -3.7189999, -3.718, -3.717, -3.716, -3.7150002, -3.7140002, -3.7129998, -3.712, -3.711, -3.71, -3.709, -3.7080002, -3.7069998, -3.7059999, -3.705, -3.704, -3.703, -3.7020001, -3.7010002, -3.6999998, -3.699, -3.698, -3.697, -3.696, -3.6950002, -3.6939998, -3.6929998, -3.692, -3.691, -3.69, -3.6890001, -3.6880002, -3.6869998, -3.6859999, -3.685, -3.684, -3.683, -3.6820002, -3.6810002, -3.6799998, -3.679, -3.678, -3.677, -3.676, -3.6750002, -3.6739998, -3.6729999, -3.672, -3.671, -3.67, -3.6690001, -3.6680002, -3.6669998, -3.666, -3.665, -3.664, -3.663, -3.6620002, -3.6609998, -3.6599998, -3.659, -3.658, -3.657, -3.6560001, -3.6550002, -3.6539998, -3.6529999, -3.652, -3.651, -3.65, -3.6490002, -3.6479998, -3.6469998, -3.646, -3.645, -3.644, -3.6430001, -3.6420002, -3.6409998, -3.6399999, -3.639, -3.638, -3.637, -3.6360002, -3.6350002, -3.6339998, -3.633, -3.632, -3.631, -3.63, -3.6290002, -3.6279998, -3.6269999, -3.626, -3.625, -3.624, -3.6230001, -3.6220002, -3.6209998, -3.62, -3.619, -3.618, -3.617, -3.6160002, -3.6149998, -3.6139998, -3.613, -3.612, -3.611, -3.6100001, -3.6090002, -3.6079998, -3.6069999, -3.606, -3.605, -3.604, -3.6030002, -3.6020002, -3.6009998, -3.6, -3.599, -3.598, -3.5970001, -3.5960002, -3.5949998, -3.5939999, -3.593, -3.592, -3.591, -3.5900002, -3.5890002, -3.5879998, -3.587, -3.586, -3.585, -3.584, -3.5830002, -3.5819998, -3.5809999, -3.58, -3.579, -3.578, -3.5770001, -3.5760002, -3.5749998, -3.574, -3.573, -3.572, -3.571, -3.5700002, -3.5689998, -3.5679998, -3.567, -3.566, -3.565, -3.5640001, -3.5630002, -3.5619998, -3.5609999, -3.56, -3.559, -3.558, -3.5570002, -3.5560002, -3.5549998, -3.554, -3.553, -3.552, -3.551, -3.5500002, -3.5489998, -3.5479999, -3.547, -3.546, -3.545, -3.5440001, -3.5430002, -3.5419998, -3.541, -3.54, -3.539, -3.538, -3.5370002, -3.5359998, -3.5349998, -3.534, -3.533, -3.532, -3.5310001, -3.5300002, -3.5289998, -3.5279999, -3.527, -3.526, -3.525, -3.5240002, -3.5229998, -3.5219998, -3.521, -3.52, -3.519, -3.5180001, -3.5170002, -3.5159998, -3.5149999, -3.514, -3.513, -3.512, -3.5110002, -3.5100002, -3.5089998, -3.508, -3.507, -3.506, -3.505, -3.5040002, -3.5029998, -3.5019999, -3.501, -3.5, -3.499, -3.4980001, -3.4970002, -3.4959998, -3.495, -3.494, -3.493, -3.492, -3.4910002, -3.4899998, -3.4889998, -3.488, -3.487, -3.486, -3.4850001, -3.4840002, -3.4829998, -3.4819999, -3.481, -3.48, -3.479, -3.4780002, -3.4770002, -3.4759998, -3.475, -3.474, -3.473, -3.4720001, -3.4710002, -3.4699998, -3.4689999, -3.468, -3.467, -3.466, -3.4650002, -3.4640002, -3.4629998, -3.462, -3.461, -3.46, -3.459, -3.4580002, -3.4569998, -3.4559999, -3.455, -3.454, -3.453, -3.4520001, -3.4510002, -3.4499998, -3.449, -3.448, -3.447, -3.446, -3.4450002, -3.4439998, -3.4429998, -3.442, -3.441, -3.44, -3.4390001, -3.4380002, -3.4369998, -3.4359999, -3.435, -3.434, -3.433, -3.4320002, -3.4310002, -3.4299998, -3.429, -3.428, -3.427, -3.426, -3.4250002, -3.4239998, -3.4229999, -3.422, -3.421, -3.42, -3.4190001, -3.4180002, -3.4169998, -3.416, -3.415, -3.414, -3.413, -3.4120002, -3.4109998, -3.4099998, -3.409, -3.408, -3.407, -3.4060001, -3.4050002, -3.4039998, -3.4029999, -3.402, -3.401, -3.4, -3.3990002, -3.3979998, -3.3969998, -3.396, -3.395, -3.394, -3.3930001, -3.3920002, -3.3909998, -3.3899999, -3.389, -3.388, -3.387, -3.3860002, -3.3850002, -3.3839998, -3.383, -3.382, -3.381, -3.38, -3.3790002, -3.3779998, -3.3769999, -3.376, -3.375, -3.374, -3.3730001, -3.3720002, -3.3709998, -3.37, -3.369, -3.368, -3.367, -3.3660002, -3.3649998, -3.3639998, -3.363, -3.362, -3.361, -3.3600001, -3.3590002, -3.3579998, -3.3569999, -3.356, -3.355, -3.354, -3.3530002, -3.3520002, -3.3509998, -3.35, -3.349, -3.348, -3.3470001, -3.3460002, -3.3449998, -3.3439999, -3.343, -3.342, -3.341, -3.3400002, -3.3390002, -3.3379998, -3.337, -3.336, -3.335, -3.334, -3.3330002, -3.3319998, -3.3309999, -3.33, -3.329, -3.328, -3.3270001, -3.3260002, -3.3249998, -3.324, -3.323, -3.322, -3.321, -3.3200002, -3.3189998, -3.3179998, -3.317, -3.316, -3.315, -3.3140001, -3.3130002, -3.3119998, -3.3109999, -3.31, -3.309, -3.308, -3.3070002, -3.3060002, -3.3049998, -3.304, -3.303, -3.302, -3.301, -3.3000002, -3.2989998, -3.2979999, -3.297, -3.296, -3.295, -3.2940001, -3.2930002, -3.2919998, -3.291, -3.29, -3.289, -3.288, -3.2870002, -3.2859998, -3.2849998, -3.284, -3.283, -3.282, -3.2810001, -3.2800002, -3.2789998, -3.2779999, -3.277, -3.276, -3.275, -3.2740002, -3.2729998, -3.2719998, -3.271, -3.27, -3.269, -3.2680001, -3.2670002, -3.2659998, -3.2649999, -3.264, -3.263, -3.262, -3.2610002, -3.2600002, -3.2589998, -3.258, -3.257, -3.256, -3.255, -3.2540002, -3.2529998, -3.2519999, -3.251, -3.25, -3.249, -3.2480001, -3.2470002, -3.2459998, -3.245, -3.244, -3.243, -3.242, -3.2410002, -3.2399998, -3.2389998, -3.238, -3.237, -3.236, -3.2350001, -3.2340002, -3.2329998, -3.2319999, -3.231, -3.23, -3.229, -3.2280002, -3.2270002, -3.2259998, -3.225, -3.224, -3.223, -3.2220001, -3.2210002, -3.2199998, -3.2189999, -3.218, -3.217, -3.216, -3.2150002, -3.2140002, -3.2129998, -3.212, -3.211, -3.21, -3.209, -3.2080002, -3.2069998, -3.2059999, -3.205, -3.204, -3.203, -3.2020001, -3.2010002, -3.1999998, -3.199, -3.198, -3.197, -3.196, -3.1950002, -3.1939998, -3.1929998, -3.192, -3.191, -3.19, -3.1890001, -3.1880002, -3.1869998, -3.1859999, -3.185, -3.184, -3.183, -3.1820002, -3.1810002, -3.1799998, -3.179, -3.178, -3.177, -3.176, -3.1750002, -3.1739998, -3.1729999, -3.172, -3.171, -3.17, -3.1690001, -3.1680002, -3.1669998, -3.166, -3.165, -3.164, -3.163, -3.1620002, -3.1609998, -3.1599998, -3.159, -3.158, -3.157, -3.1560001, -3.1550002, -3.1539998, -3.1529999, -3.152, -3.151, -3.15, -3.1490002, -3.1479998, -3.1469998, -3.146, -3.145, -3.144, -3.1430001, -3.1420002, -3.1409998, -3.1399999, -3.139, -3.138, -3.137, -3.1360002, -3.1350002, -3.1339998, -3.133, -3.132, -3.131, -3.13, -3.1290002, -3.1279998, -3.1269999, -3.126, -3.125, -3.124, -3.1230001, -3.1220002, -3.1209998, -3.12, -3.119, -3.118, -3.117, -3.1160002, -3.1149998, -3.1139998, -3.113, -3.112, -3.111, -3.1100001, -3.1090002, -3.1079998, -3.1069999, -3.106, -3.105, -3.104, -3.1030002, -3.1020002, -3.1009998, -3.1, -3.099, -3.098, -3.0970001, -3.0960002, -3.0949998, -3.0939999, -3.093, -3.092, -3.091, -3.0900002, -3.0890002, -3.0879998, -3.087, -3.086, -3.085, -3.084, -3.0830002, -3.0819998, -3.0809999, -3.08, -3.079, -3.078, -3.0770001, -3.0760002, -3.0749998, -3.074, -3.073, -3.072, -3.071, -3.0700002, -3.0689998, -3.0679998, -3.067, -3.066, -3.065, -3.0640001, -3.0630002, -3.0619998, -3.0609999, -3.06, -3.059, -3.058, -3.0570002, -3.0560002, -3.0549998, -3.054, -3.053, -3.052, -3.051, -3.0500002, -3.0489998, -3.0479999, -3.047, -3.046, -3.045, -3.0440001, -3.0430002, -3.0419998, -3.041, -3.04, -3.039, -3.038, -3.0370002, -3.0359998, -3.0349998, -3.034, -3.033, -3.032, -3.0310001, -3.0300002, -3.0289998, -3.0279999, -3.027, -3.026, -3.025, -3.0240002, -3.0229998, -3.0219998, -3.021, -3.02, -3.019, -3.0180001, -3.0170002, -3.0159998, -3.0149999, -3.014, -3.013, -3.012, -3.0110002, -3.0100002, -3.0089998, -3.008, -3.007, -3.006, -3.005, -3.0040002, -3.0029998, -3.0019999, -3.001, -3.0, -2.999, -2.9980001, -2.9970002, -2.9959998, -2.995, -2.994, -2.993, -2.992, -2.9910002, -2.9899998, -2.9889998, -2.988, -2.987, -2.986, -2.9850001, -2.9840002, -2.9829998, -2.9819999, -2.981, -2.98, -2.979, -2.9780002, -2.9770002, -2.9759998, -2.975, -2.974, -2.973, -2.9720001, -2.9710002, -2.9699998, -2.9689999, -2.968, -2.967, -2.966, -2.9650002, -2.9640002, -2.9629998, -2.962, -2.961, -2.96, -2.959, -2.9580002, -2.9569998, -2.9559999, -2.955, -2.954, -2.953, -2.9520001, -2.9510002, -2.9499998, -2.949, -2.948, -2.947, -2.946, -2.9450002, -2.9439998, -2.9429998, -2.942, -2.941, -2.94, -2.9390001, -2.9380002, -2.9369998, -2.9359999, -2.935, -2.934, -2.933, -2.9320002, -2.9310002, -2.9299998, -2.929, -2.928, -2.927, -2.926, -2.9250002, -2.9239998, -2.9229999, -2.922, -2.921, -2.92, -2.9190001, -2.9180002, -2.9169998, -2.916, -2.915, -2.914, -2.913, -2.9120002, -2.9109998, -2.9099998, -2.909, -2.908, -2.907, -2.9060001, -2.9050002, -2.9039998, -2.9029999, -2.902, -2.901, -2.9, -2.8990002, -2.8979998, -2.8969998, -2.896, -2.895, -2.894, -2.8930001, -2.8920002, -2.8909998, -2.8899999, -2.889, -2.888, -2.887, -2.8860002, -2.8850002, -2.8839998, -2.883, -2.882, -2.881, -2.88, -2.8790002, -2.8779998, -2.8769999, -2.876, -2.875, -2.874, -2.8730001, -2.8720002, -2.8709998, -2.87, -2.869, -2.868, -2.867, -2.8660002, -2.8649998, -2.8639998, -2.863, -2.862, -2.861, -2.8600001, -2.8590002, -2.8579998, -2.8569999, -2.856, -2.855, -2.854, -2.8530002, -2.8520002, -2.8509998, -2.85, -2.849, -2.848, -2.8470001, -2.8460002, -2.8449998, -2.8439999, -2.843, -2.842, -2.841, -2.8400002, -2.8390002, -2.8379998, -2.837, -2.836, -2.835, -2.834, -2.8330002, -2.8319998, -2.8309999, -2.83, -2.829, -2.828, -2.8270001, -2.8260002, -2.8249998, -2.824, -2.823, -2.822, -2.821, -2.8200002, -2.8189998, -2.8179998, -2.817, -2.816, -2.815, -2.8140001, -2.8130002, -2.8119998, -2.8109999, -2.81, -2.809, -2.808, -2.8070002, -2.8060002, -2.8049998, -2.804, -2.803, -2.802, -2.801, -2.8000002, -2.7989998, -2.7979999, -2.797, -2.796, -2.795, -2.7940001, -2.7930002, -2.7919998, -2.791, -2.79, -2.789, -2.788, -2.7870002, -2.7859998, -2.7849998, -2.784, -2.783, -2.782, -2.7810001, -2.7800002, -2.7789998, -2.7779999, -2.777, -2.776, -2.775, -2.7740002, -2.7729998, -2.7719998, -2.771, -2.77, -2.769, -2.7680001, -2.7670002, -2.7659998, -2.7649999, -2.764, -2.763, -2.762, -2.7610002, -2.7600002, -2.7589998, -2.758, -2.757, -2.756, -2.755, -2.7540002, -2.7529998, -2.7519999, -2.751, -2.75, -2.749, -2.7480001, -2.7470002, -2.7459998, -2.745, -2.744, -2.743, -2.742, -2.7410002, -2.7399998, -2.7389998, -2.738, -2.737, -2.736, -2.7350001, -2.7340002, -2.7329998, -2.7319999, -2.731, -2.73, -2.729, -2.7280002, -2.7270002, -2.7259998, -2.725, -2.724, -2.723, -2.7220001, -2.7210002, -2.7199998, -2.7189999, -2.718, -2.717, -2.716, -2.7150002, -2.7140002, -2.7129998, -2.712, -2.711, -2.71, -2.709, -2.7080002, -2.7069998, -2.7059999, -2.705, -2.704, -2.703, -2.7020001, -2.7010002, -2.6999998, -2.699, -2.698, -2.697, -2.696, -2.6950002, -2.6939998, -2.6929998, -2.692, -2.691, -2.69, -2.6890001, -2.6880002, -2.6869998, -2.6859999, -2.685, -2.684, -2.683, -2.6820002, -2.6810002, -2.6799998, -2.679, -2.678, -2.677, -2.676, -2.6750002, -2.6739998, -2.6729999, -2.672, -2.671, -2.67, -2.6690001, -2.6680002, -2.6669998, -2.666, -2.665, -2.664, -2.663, -2.6620002, -2.6609998, -2.6599998, -2.659, -2.658, -2.657, -2.6560001, -2.6550002, -2.6539998, -2.6529999, -2.652, -2.651, -2.65, -2.6490002, -2.6479998, -2.6469998, -2.646, -2.645, -2.644, -2.6430001, -2.6420002, -2.6409998, -2.6399999, -2.639, -2.638, -2.637, -2.6360002, -2.6350002, -2.6339998, -2.633, -2.632, -2.631, -2.63, -2.6290002, -2.6279998, -2.6269999, -2.626, -2.625, -2.624, -2.6230001, -2.6220002, -2.6209998, -2.62, -2.619, -2.618, -2.617, -2.6160002, -2.6149998, -2.6139998, -2.613, -2.612, -2.611, -2.6100001, -2.6090002, -2.6079998, -2.6069999, -2.606, -2.605, -2.604, -2.6030002, -2.6020002, -2.6009998, -2.6, -2.599, -2.598, -2.5970001, -2.5960002, -2.5949998, -2.5939999, -2.593, -2.592, -2.591, -2.5900002, -2.5890002, -2.5879998, -2.587, -2.586, -2.585, -2.584, -2.5830002, -2.5819998, -2.5809999, -2.58, -2.579, -2.578, -2.5770001, -2.5760002, -2.5749998, -2.574, -2.573, -2.572, -2.571, -2.5700002, -2.5689998, -2.5679998, -2.567, -2.566, -2.565, -2.5640001, -2.5630002, -2.5619998, -2.5609999, -2.56, -2.559, -2.558, -2.5570002, -2.5560002, -2.5549998, -2.554, -2.553, -2.552, -2.551, -2.5500002, -2.5489998, -2.5479999, -2.547, -2.546, -2.545, -2.5440001, -2.5430002, -2.5419998, -2.541, -2.54, -2.539, -2.538, -2.5370002, -2.5359998, -2.5349998, -2.534, -2.533, -2.532, -2.5310001, -2.5300002, -2.5289998, -2.5279999, -2.527, -2.526, -2.525, -2.5240002, -2.5229998, -2.5219998, -2.521, -2.52, -2.519, -2.5180001, -2.5170002, -2.5159998, -2.5149999, -2.514, -2.513, -2.512, -2.5110002, -2.5100002, -2.5089998, -2.508, -2.507, -2.506, -2.505, -2.5040002, -2.5029998, -2.5019999, -2.501, -2.5, -2.499, -2.4980001, -2.4970002, -2.4959998, -2.495, -2.494, -2.493, -2.492, -2.4910002, -2.4899998, -2.4889998, -2.488, -2.487, -2.486, -2.4850001, -2.4840002, -2.4829998, -2.4819999, -2.481, -2.48, -2.479, -2.4780002, -2.4770002, -2.4759998, -2.475, -2.474, -2.473, -2.4720001, -2.4710002, -2.4699998, -2.4689999, -2.468, -2.467, -2.466, -2.4650002, -2.4640002, -2.4629998, -2.462, -2.461, -2.46, -2.459, -2.4580002, -2.4569998, -2.4559999, -2.455, -2.454, -2.453, -2.4520001, -2.4510002, -2.4499998, -2.449, -2.448, -2.447, -2.446, -2.4450002, -2.4439998, -2.4429998, -2.442, -2.441, -2.44, -2.4390001, -2.4380002, -2.4369998, -2.4359999, -2.435, -2.434, -2.433, -2.4320002, -2.4310002, -2.4299998, -2.429, -2.428, -2.427, -2.426, -2.4250002, -2.4239998, -2.4229999, -2.422, -2.421, -2.42, -2.4190001, -2.4180002, -2.4169998, -2.416, -2.415, -2.414, -2.413, -2.4120002, -2.4109998, -2.4099998, -2.409, -2.408, -2.407, -2.4060001, -2.4050002, -2.4039998, -2.4029999, -2.402, -2.401, -2.4, -2.3990002, -2.3979998, -2.3969998, -2.396, -2.395, -2.394, -2.3930001, -2.3920002, -2.3909998, -2.3899999, -2.389, -2.388, -2.387, -2.3860002, -2.3850002, -2.3839998, -2.383, -2.382, -2.381, -2.38, -2.3790002, -2.3779998, -2.3769999, -2.376, -2.375, -2.374, -2.3730001, -2.3720002, -2.3709998, -2.37, -2.369, -2.368, -2.367, -2.3660002, -2.3649998, -2.3639998, -2.363, -2.362, -2.361, -2.3600001, -2.3590002, -2.3579998, -2.3569999, -2.356, -2.355, -2.354, -2.3530002, -2.3520002, -2.3509998, -2.35, -2.349, -2.348, -2.3470001, -2.3460002, -2.3449998, -2.3439999, -2.343, -2.342, -2.341, -2.3400002, -2.3390002, -2.3379998, -2.337, -2.336, -2.335, -2.334, -2.3330002, -2.3319998, -2.3309999, -2.33, -2.329, -2.328, -2.3270001, -2.3260002, -2.3249998, -2.324, -2.323, -2.322, -2.321, -2.3200002, -2.3189998, -2.3179998, -2.317, -2.316, -2.315, -2.3140001, -2.3130002, -2.3119998, -2.3109999, -2.31, -2.309, -2.308, -2.3070002, -2.3060002, -2.3049998, -2.304, -2.303, -2.302, -2.301, -2.3000002, -2.2989998, -2.2979999, -2.297, -2.296, -2.295, -2.2940001, -2.2930002, -2.2919998, -2.291, -2.29, -2.289, -2.288, -2.2870002, -2.2859998, -2.2849998, -2.284, -2.283, -2.282, -2.2810001, -2.2800002, -2.2789998, -2.2779999, -2.277, -2.276, -2.275, -2.2740002, -2.2729998, -2.2719998, -2.271, -2.27, -2.269, -2.2680001, -2.2670002, -2.2659998, -2.2649999, -2.264, -2.263, -2.262, -2.2610002, -2.2600002, -2.2589998, -2.258, -2.257, -2.256, -2.255, -2.2540002, -2.2529998, -2.2519999, -2.251, -2.25, -2.249, -2.2480001, -2.2470002, -2.2459998, -2.245, -2.244, -2.243, -2.242, -2.2410002, -2.2399998, -2.2389998, -2.238, -2.237, -2.236, -2.2350001, -2.2340002, -2.2329998, -2.2319999, -2.231, -2.23, -2.229, -2.2280002, -2.2270002, -2.2259998, -2.225, -2.224, -2.223, -2.2220001, -2.2210002, -2.2199998, -2.2189999, -2.218, -2.217, -2.216, -2.2150002, -2.2140002, -2.2129998, -2.212, -2.211, -2.21, -2.209, -2.2080002, -2.2069998, -2.2059999, -2.205, -2.204, -2.203, -2.2020001, -2.2010002, -2.1999998, -2.199, -2.198, -2.197, -2.196, -2.1950002, -2.1939998, -2.1929998, -2.192, -2.191, -2.19, -2.1890001, -2.1880002, -2.1869998, -2.1859999, -2.185, -2.184, -2.183, -2.1820002, -2.1810002, -2.1799998, -2.179, -2.178, -2.177, -2.176, -2.1750002, -2.1739998, -2.1729999, -2.172, -2.171, -2.17, -2.1690001, -2.1680002, -2.1669998, -2.166, -2.165, -2.164, -2.163, -2.1620002, -2.1609998, -2.1599998, -2.159, -2.158, -2.157, -2.1560001, -2.1550002, -2.1539998, -2.1529999, -2.152, -2.151, -2.15, -2.1490002, -2.1479998, -2.1469998, -2.146, -2.145, -2.144, -2.1430001, -2.1420002, -2.1409998, -2.1399999, -2.139, -2.138, -2.137, -2.1360002, -2.1350002, -2.1339998, -2.133, -2.132, -2.131, -2.13, -2.1290002, -2.1279998, -2.1269999, -2.126, -2.125, -2.124, -2.1230001, -2.1220002, -2.1209998, -2.12, -2.119, -2.118, -2.117, -2.1160002, -2.1149998, -2.1139998, -2.113, -2.112, -2.111, -2.1100001, -2.1090002, -2.1079998, -2.1069999, -2.106, -2.105, -2.104, -2.1030002, -2.1020002, -2.1009998, -2.1, -2.099, -2.098, -2.0970001, -2.0960002, -2.0949998, -2.0939999, -2.093, -2.092, -2.091, -2.0900002, -2.0890002, -2.0879998, -2.087, -2.086, -2.085, -2.084, -2.0830002, -2.0819998, -2.0809999, -2.08, -2.079, -2.078, -2.0770001, -2.0760002, -2.0749998, -2.074, -2.073, -2.072, -2.071, -2.0700002, -2.0689998, -2.0679998, -2.067, -2.066, -2.065, -2.0640001, -2.0630002, -2.0619998, -2.0609999, -2.06, -2.059, -2.058, -2.0570002, -2.0560002, -2.0549998, -2.054, -2.053, -2.052, -2.051, -2.0500002, -2.0489998, -2.0479999, -2.047, -2.046, -2.045, -2.0440001, -2.0430002, -2.0419998, -2.041, -2.04, -2.039, -2.038, -2.0370002, -2.0359998, -2.0349998, -2.034, -2.033, -2.032, -2.0310001, -2.0300002, -2.0289998, -2.0279999, -2.027, -2.026, -2.025, -2.0240002, -2.0229998, -2.0219998, -2.021, -2.02, -2.019, -2.0180001, -2.0170002, -2.0159998, -2.0149999, -2.014, -2.013, -2.012, -2.0110002, -2.0100002, -2.0089998, -2.008, -2.007, -2.006, -2.005, -2.0040002, -2.0029998, -2.0019999, -2.001, -2.0, -1.9989996, -1.9980001, -1.9969997, -1.9960003, -1.9949999, -1.9940004, -1.993, -1.9919996, -1.9910002, -1.9899998, -1.9890003, -1.9879999, -1.9870005, -1.9860001, -1.9849997, -1.9840002, -1.9829998, -1.9820004, -1.981, -1.9799995, -1.9790001, -1.9779997, -1.9770002, -1.9759998, -1.9750004, -1.974, -1.9729996, -1.9720001, -1.9709997, -1.9700003, -1.9689999, -1.9680004, -1.967, -1.9659996, -1.9650002, -1.9639997, -1.9630003, -1.9619999, -1.9610004, -1.96, -1.9589996, -1.9580002, -1.9569998, -1.9560003, -1.9549999, -1.9540005, -1.9530001, -1.9519997, -1.9510002, -1.9499998, -1.9490004, -1.948, -1.9469995, -1.9460001, -1.9449997, -1.9440002, -1.9429998, -1.9420004, -1.941, -1.9399996, -1.9390001, -1.9379997, -1.9370003, -1.9359999, -1.9350004, -1.934, -1.9329996, -1.9320002, -1.9309998, -1.9300003, -1.9289999, -1.9280005, -1.927, -1.9259996, -1.9250002, -1.9239998, -1.9230003, -1.9219999, -1.9209995, -1.9200001, -1.9189997, -1.9180002, -1.9169998, -1.9160004, -1.915, -1.9139996, -1.9130001, -1.9119997, -1.9110003, -1.9099998, -1.9090004, -1.908, -1.9069996, -1.9060001, -1.9049997, -1.9040003, -1.9029999, -1.9020004, -1.901, -1.8999996, -1.8990002, -1.8979998, -1.8970003, -1.8959999, -1.8950005, -1.894, -1.8929996, -1.8920002, -1.8909998, -1.8900003, -1.8889999, -1.8879995, -1.8870001, -1.8859997, -1.8850002, -1.8839998, -1.8830004, -1.882, -1.8809996, -1.8800001, -1.8789997, -1.8780003, -1.8769999, -1.8760004, -1.875, -1.8739996, -1.8730001, -1.8719997, -1.8710003, -1.8699999, -1.8690004, -1.868, -1.8669996, -1.8660002, -1.8649998, -1.8640003, -1.8629999, -1.8620005, -1.8610001, -1.8599997, -1.8590002, -1.8579998, -1.8570004, -1.856, -1.8549995, -1.8540001, -1.8529997, -1.8520002, -1.8509998, -1.8500004, -1.849, -1.8479996, -1.8470001, -1.8459997, -1.8450003, -1.8439999, -1.8430004, -1.842, -1.8409996, -1.8400002, -1.8389997, -1.8380003, -1.8369999, -1.8360004, -1.835, -1.8339996, -1.8330002, -1.8319998, -1.8310003, -1.8299999, -1.8290005, -1.8280001, -1.8269997, -1.8260002, -1.8249998, -1.8240004, -1.823, -1.8219995, -1.8210001, -1.8199997, -1.8190002, -1.8179998, -1.8170004, -1.816, -1.8149996, -1.8140001, -1.8129997, -1.8120003, -1.8109999, -1.8100004, -1.809, -1.8079996, -1.8070002, -1.8059998, -1.8050003, -1.8039999, -1.8030005, -1.802, -1.8009996, -1.8000002, -1.7989998, -1.7980003, -1.7969999, -1.7959995, -1.7950001, -1.7939997, -1.7930002, -1.7919998, -1.7910004, -1.79, -1.7889996, -1.7880001, -1.7869997, -1.7860003, -1.7849998, -1.7840004, -1.783, -1.7819996, -1.7810001, -1.7799997, -1.7790003, -1.7779999, -1.7770004, -1.776, -1.7749996, -1.7740002, -1.7729998, -1.7720003, -1.7709999, -1.7700005, -1.769, -1.7679996, -1.7670002, -1.7659998, -1.7650003, -1.7639999, -1.7629995, -1.7620001, -1.7609997, -1.7600002, -1.7589998, -1.7580004, -1.757, -1.7559996, -1.7550001, -1.7539997, -1.7530003, -1.7519999, -1.7510004, -1.75, -1.7489996, -1.7480001, -1.7469997, -1.7460003, -1.7449999, -1.7440004, -1.743, -1.7419996, -1.7410002, -1.7399998, -1.7390003, -1.7379999, -1.7370005, -1.7360001, -1.7349997, -1.7340002, -1.7329998, -1.7320004, -1.731, -1.7299995, -1.7290001, -1.7279997, -1.7270002, -1.7259998, -1.7250004, -1.724, -1.7229996, -1.7220001, -1.7209997, -1.7200003, -1.7189999, -1.7180004, -1.717, -1.7159996, -1.7150002, -1.7139997, -1.7130003, -1.7119999, -1.7110004, -1.71, -1.7089996, -1.7080002, -1.7069998, -1.7060003, -1.7049999, -1.7040005, -1.7030001, -1.7019997, -1.7010002, -1.6999998, -1.6990004, -1.698, -1.6969995, -1.6960001, -1.6949997, -1.6940002, -1.6929998, -1.6920004, -1.691, -1.6899996, -1.6890001, -1.6879997, -1.6870003, -1.6859999, -1.6850004, -1.684, -1.6829996, -1.6820002, -1.6809998, -1.6800003, -1.6789999, -1.6780005, -1.677, -1.6759996, -1.6750002, -1.6739998, -1.6730003, -1.6719999, -1.6709995, -1.6700001, -1.6689997, -1.6680002, -1.6669998, -1.6660004, -1.665, -1.6639996, -1.6630001, -1.6619997, -1.6610003, -1.6599998, -1.6590004, -1.658, -1.6569996, -1.6560001, -1.6549997, -1.6540003, -1.6529999, -1.6520004, -1.651, -1.6499996, -1.6490002, -1.6479998, -1.6470003, -1.6459999, -1.6450005, -1.644, -1.6429996, -1.6420002, -1.6409998, -1.6400003, -1.6389999, -1.6379995, -1.6370001, -1.6359997, -1.6350002, -1.6339998, -1.6330004, -1.632, -1.6309996, -1.6300001, -1.6289997, -1.6280003, -1.6269999, -1.6260004, -1.625, -1.6239996, -1.6230001, -1.6219997, -1.6210003, -1.6199999, -1.6190004, -1.618, -1.6169996, -1.6160002, -1.6149998, -1.6140003, -1.6129999, -1.6120005, -1.6110001, -1.6099997, -1.6090002, -1.6079998, -1.6070004, -1.606, -1.6049995, -1.6040001, -1.6029997, -1.6020002, -1.6009998, -1.6000004, -1.599, -1.5979996, -1.5970001, -1.5959997, -1.5950003, -1.5939999, -1.5930004, -1.592, -1.5909996, -1.5900002, -1.5889997, -1.5880003, -1.5869999, -1.5860004, -1.585, -1.5839996, -1.5830002, -1.5819998, -1.5810003, -1.5799999, -1.5790005, -1.5780001, -1.5769997, -1.5760002, -1.5749998, -1.5740004, -1.573, -1.5719995, -1.5710001, -1.5699997, -1.5690002, -1.5679998, -1.5670004, -1.566, -1.5649996, -1.5640001, -1.5629997, -1.5620003, -1.5609999, -1.5600004, -1.559, -1.5579996, -1.5570002, -1.5559998, -1.5550003, -1.5539999, -1.5530005, -1.552, -1.5509996, -1.5500002, -1.5489998, -1.5480003, -1.5469999, -1.5459995, -1.5450001, -1.5439997, -1.5430002, -1.5419998, -1.5410004, -1.54, -1.5389996, -1.5380001, -1.5369997, -1.5360003, -1.5349998, -1.5340004, -1.533, -1.5319996, -1.5310001, -1.5299997, -1.5290003, -1.5279999, -1.5270004, -1.526, -1.5249996, -1.5240002, -1.5229998, -1.5220003, -1.5209999, -1.5200005, -1.519, -1.5179996, -1.5170002, -1.5159998, -1.5150003, -1.5139999, -1.5129995, -1.5120001, -1.5109997, -1.5100002, -1.5089998, -1.5080004, -1.507, -1.5059996, -1.5050001, -1.5039997, -1.5030003, -1.5019999, -1.5010004, -1.5, -1.4989996, -1.4980001, -1.4969997, -1.4960003, -1.4949999, -1.4940004, -1.493, -1.4919996, -1.4910002, -1.4899998, -1.4890003, -1.4879999, -1.4870005, -1.4860001, -1.4849997, -1.4840002, -1.4829998, -1.4820004, -1.481, -1.4799995, -1.4790001, -1.4779997, -1.4770002, -1.4759998, -1.4750004, -1.474, -1.4729996, -1.4720001, -1.4709997, -1.4700003, -1.4689999, -1.4680004, -1.467, -1.4659996, -1.4650002, -1.4639997, -1.4630003, -1.4619999, -1.4610004, -1.46, -1.4589996, -1.4580002, -1.4569998, -1.4560003, -1.4549999, -1.4540005, -1.4530001, -1.4519997, -1.4510002, -1.4499998, -1.4490004, -1.448, -1.4469995, -1.4460001, -1.4449997, -1.4440002, -1.4429998, -1.4420004, -1.441, -1.4399996, -1.4390001, -1.4379997, -1.4370003, -1.4359999, -1.4350004, -1.434, -1.4329996, -1.4320002, -1.4309998, -1.4300003, -1.4289999, -1.4280005, -1.427, -1.4259996, -1.4250002, -1.4239998, -1.4230003, -1.4219999, -1.4209995, -1.4200001, -1.4189997, -1.4180002, -1.4169998, -1.4160004, -1.415, -1.4139996, -1.4130001, -1.4119997, -1.4110003, -1.4099998, -1.4090004, -1.408, -1.4069996, -1.4060001, -1.4049997, -1.4040003, -1.4029999, -1.4020004, -1.401, -1.3999996, -1.3990002, -1.3979998, -1.3970003, -1.3959999, -1.3950005, -1.394, -1.3929996, -1.3920002, -1.3909998, -1.3900003, -1.3889999, -1.3879995, -1.3870001, -1.3859997, -1.3850002, -1.3839998, -1.3830004, -1.382, -1.3809996, -1.3800001, -1.3789997, -1.3780003, -1.3769999, -1.3760004, -1.375, -1.3739996, -1.3730001, -1.3719997, -1.3710003, -1.3699999, -1.3690004, -1.368, -1.3669996, -1.3660002, -1.3649998, -1.3640003, -1.3629999, -1.3620005, -1.3610001, -1.3599997, -1.3590002, -1.3579998, -1.3570004, -1.356, -1.3549995, -1.3540001, -1.3529997, -1.3520002, -1.3509998, -1.3500004, -1.349, -1.3479996, -1.3470001, -1.3459997, -1.3450003, -1.3439999, -1.3430004, -1.342, -1.3409996, -1.3400002, -1.3389997, -1.3380003, -1.3369999, -1.3360004, -1.335, -1.3339996, -1.3330002, -1.3319998, -1.3310003, -1.3299999, -1.3290005, -1.3280001, -1.3269997, -1.3260002, -1.3249998, -1.3240004, -1.323, -1.3219995, -1.3210001, -1.3199997, -1.3190002, -1.3179998, -1.3170004, -1.316, -1.3149996, -1.3140001, -1.3129997, -1.3120003, -1.3109999, -1.3100004, -1.309, -1.3079996, -1.3070002, -1.3059998, -1.3050003, -1.3039999, -1.3030005, -1.302, -1.3009996, -1.3000002, -1.2989998, -1.2980003, -1.2969999, -1.2959995, -1.2950001, -1.2939997, -1.2930002, -1.2919998, -1.2910004, -1.29, -1.2889996, -1.2880001, -1.2869997, -1.2860003, -1.2849998, -1.2840004, -1.283, -1.2819996, -1.2810001, -1.2799997, -1.2790003, -1.2779999, -1.2770004, -1.276, -1.2749996, -1.2740002, -1.2729998, -1.2720003, -1.2709999, -1.2700005, -1.269, -1.2679996, -1.2670002, -1.2659998, -1.2650003, -1.2639999, -1.2629995, -1.2620001, -1.2609997, -1.2600002, -1.2589998, -1.2580004, -1.257, -1.2559996, -1.2550001, -1.2539997, -1.2530003, -1.2519999, -1.2510004, -1.25, -1.2489996, -1.2480001, -1.2469997, -1.2460003, -1.2449999, -1.2440004, -1.243, -1.2419996, -1.2410002, -1.2399998, -1.2390003, -1.2379999, -1.2370005, -1.2360001, -1.2349997, -1.2340002, -1.2329998, -1.2320004, -1.231, -1.2299995, -1.2290001, -1.2279997, -1.2270002, -1.2259998, -1.2250004, -1.224, -1.2229996, -1.2220001, -1.2209997, -1.2200003, -1.2189999, -1.2180004, -1.217, -1.2159996, -1.2150002, -1.2139997, -1.2130003, -1.2119999, -1.2110004, -1.21, -1.2089996, -1.2080002, -1.2069998, -1.2060003, -1.2049999, -1.2040005, -1.2030001, -1.2019997, -1.2010002, -1.1999998, -1.1990004, -1.198, -1.1969995, -1.1960001, -1.1949997, -1.1940002, -1.1929998, -1.1920004, -1.191, -1.1899996, -1.1890001, -1.1879997, -1.1870003, -1.1859999, -1.1850004, -1.184, -1.1829996, -1.1820002, -1.1809998, -1.1800003, -1.1789999, -1.1780005, -1.177, -1.1759996, -1.1750002, -1.1739998, -1.1730003, -1.1719999, -1.1709995, -1.1700001, -1.1689997, -1.1680002, -1.1669998, -1.1660004, -1.165, -1.1639996, -1.1630001, -1.1619997, -1.1610003, -1.1599998, -1.1590004, -1.158, -1.1569996, -1.1560001, -1.1549997, -1.1540003, -1.1529999, -1.1520004, -1.151, -1.1499996, -1.1490002, -1.1479998, -1.1470003, -1.1459999, -1.1450005, -1.144, -1.1429996, -1.1420002, -1.1409998, -1.1400003, -1.1389999, -1.1379995, -1.1370001, -1.1359997, -1.1350002, -1.1339998, -1.1330004, -1.132, -1.1309996, -1.1300001, -1.1289997, -1.1280003, -1.1269999, -1.1260004, -1.125, -1.1239996, -1.1230001, -1.1219997, -1.1210003, -1.1199999, -1.1190004, -1.118, -1.1169996, -1.1160002, -1.1149998, -1.1140003, -1.1129999, -1.1120005, -1.1110001, -1.1099997, -1.1090002, -1.1079998, -1.1070004, -1.106, -1.1049995, -1.1040001, -1.1029997, -1.1020002, -1.1009998, -1.1000004, -1.099, -1.0979996, -1.0970001, -1.0959997, -1.0950003, -1.0939999, -1.0930004, -1.092, -1.0909996, -1.0900002, -1.0889997, -1.0880003, -1.0869999, -1.0860004, -1.085, -1.0839996, -1.0830002, -1.0819998, -1.0810003, -1.0799999, -1.0790005, -1.0780001, -1.0769997, -1.0760002, -1.0749998, -1.0740004, -1.073, -1.0719995, -1.0710001, -1.0699997, -1.0690002, -1.0679998, -1.0670004, -1.066, -1.0649996, -1.0640001, -1.0629997, -1.0620003, -1.0609999, -1.0600004, -1.059, -1.0579996, -1.0570002, -1.0559998, -1.0550003, -1.0539999, -1.0530005, -1.052, -1.0509996, -1.0500002, -1.0489998, -1.0480003, -1.0469999, -1.0459995, -1.0450001, -1.0439997, -1.0430002, -1.0419998, -1.0410004, -1.04, -1.0389996, -1.0380001, -1.0369997, -1.0360003, -1.0349998, -1.0340004, -1.033, -1.0319996, -1.0310001, -1.0299997, -1.0290003, -1.0279999, -1.0270004, -1.026, -1.0249996, -1.0240002, -1.0229998, -1.0220003, -1.0209999, -1.0200005, -1.019, -1.0179996, -1.0170002, -1.0159998, -1.0150003, -1.0139999, -1.0129995, -1.0120001, -1.0109997, -1.0100002, -1.0089998, -1.0080004, -1.007, -1.0059996, -1.0050001, -1.0039997, -1.0030003, -1.0019999, -1.0010004, -1.0, -0.9989996, -0.99800014, -0.99699974, -0.9960003, -0.9949999, -0.99400043, -0.99300003, -0.9919996, -0.9910002, -0.9899998, -0.9890003, -0.9879999, -0.98700047, -0.98600006, -0.98499966, -0.9840002, -0.9829998, -0.98200035, -0.98099995, -0.97999954, -0.9790001, -0.9779997, -0.97700024, -0.97599983, -0.9750004, -0.974, -0.9729996, -0.9720001, -0.9709997, -0.97000027, -0.96899986, -0.9680004, -0.967, -0.9659996, -0.96500015, -0.96399975, -0.9630003, -0.9619999, -0.96100044, -0.96000004, -0.95899963, -0.9580002, -0.9569998, -0.9560003, -0.9549999, -0.9540005, -0.95300007, -0.95199966, -0.9510002, -0.9499998, -0.94900036, -0.94799995, -0.94699955, -0.9460001, -0.9449997, -0.94400024, -0.94299984, -0.9420004, -0.941, -0.9399996, -0.9390001, -0.9379997, -0.9370003, -0.9359999, -0.9350004, -0.934, -0.9329996, -0.93200016, -0.93099976, -0.9300003, -0.9289999, -0.92800045, -0.92700005, -0.92599964, -0.9250002, -0.9239998, -0.92300034, -0.92199993, -0.9209995, -0.9200001, -0.9189997, -0.9180002, -0.9169998, -0.91600037, -0.91499996, -0.91399956, -0.9130001, -0.9119997, -0.91100025, -0.90999985, -0.9090004, -0.908, -0.9069996, -0.90600014, -0.90499973, -0.9040003, -0.9029999, -0.9020004, -0.901, -0.8999996, -0.89900017, -0.89799976, -0.8970003, -0.8959999, -0.89500046, -0.89400005, -0.89299965, -0.8920002, -0.8909998, -0.89000034, -0.88899994, -0.88799953, -0.8870001, -0.8859997, -0.8850002, -0.8839998, -0.8830004, -0.88199997, -0.88099957, -0.8800001, -0.8789997, -0.87800026, -0.87699986, -0.8760004, -0.875, -0.8739996, -0.87300014, -0.87199974, -0.8710003, -0.8699999, -0.86900043, -0.86800003, -0.8669996, -0.8660002, -0.8649998, -0.8640003, -0.8629999, -0.86200047, -0.86100006, -0.85999966, -0.8590002, -0.8579998, -0.85700035, -0.85599995, -0.85499954, -0.8540001, -0.8529997, -0.85200024, -0.85099983, -0.8500004, -0.849, -0.8479996, -0.8470001, -0.8459997, -0.84500027, -0.84399986, -0.8430004, -0.842, -0.8409996, -0.84000015, -0.83899975, -0.8380003, -0.8369999, -0.83600044, -0.83500004, -0.83399963, -0.8330002, -0.8319998, -0.8310003, -0.8299999, -0.8290005, -0.82800007, -0.82699966, -0.8260002, -0.8249998, -0.82400036, -0.82299995, -0.82199955, -0.8210001, -0.8199997, -0.81900024, -0.81799984, -0.8170004, -0.816, -0.8149996, -0.8140001, -0.8129997, -0.8120003, -0.8109999, -0.8100004, -0.809, -0.8079996, -0.80700016, -0.80599976, -0.8050003, -0.8039999, -0.80300045, -0.80200005, -0.80099964, -0.8000002, -0.7989998, -0.79800034, -0.79699993, -0.7959995, -0.7950001, -0.7939997, -0.7930002, -0.7919998, -0.79100037, -0.78999996, -0.78899956, -0.7880001, -0.7869997, -0.78600025, -0.78499985, -0.7840004, -0.783, -0.7819996, -0.78100014, -0.77999973, -0.7790003, -0.7779999, -0.7770004, -0.776, -0.7749996, -0.77400017, -0.77299976, -0.7720003, -0.7709999, -0.77000046, -0.76900005, -0.76799965, -0.7670002, -0.7659998, -0.76500034, -0.76399994, -0.76299953, -0.7620001, -0.7609997, -0.7600002, -0.7589998, -0.7580004, -0.75699997, -0.75599957, -0.7550001, -0.7539997, -0.75300026, -0.75199986, -0.7510004, -0.75, -0.7489996, -0.74800014, -0.74699974, -0.7460003, -0.7449999, -0.74400043, -0.74300003, -0.7419996, -0.7410002, -0.7399998, -0.7390003, -0.7379999, -0.73700047, -0.73600006, -0.73499966, -0.7340002, -0.7329998, -0.73200035, -0.73099995, -0.72999954, -0.7290001, -0.7279997, -0.72700024, -0.72599983, -0.7250004, -0.724, -0.7229996, -0.7220001, -0.7209997, -0.72000027, -0.71899986, -0.7180004, -0.717, -0.7159996, -0.71500015, -0.71399975, -0.7130003, -0.7119999, -0.71100044, -0.71000004, -0.70899963, -0.7080002, -0.7069998, -0.7060003, -0.7049999, -0.7040005, -0.70300007, -0.70199966, -0.7010002, -0.6999998, -0.69900036, -0.69799995, -0.69699955, -0.6960001, -0.6949997, -0.69400024, -0.69299984, -0.6920004, -0.691, -0.6899996, -0.6890001, -0.6879997, -0.6870003, -0.6859999, -0.6850004, -0.684, -0.6829996, -0.68200016, -0.68099976, -0.6800003, -0.6789999, -0.67800045, -0.67700005, -0.67599964, -0.6750002, -0.6739998, -0.67300034, -0.67199993, -0.6709995, -0.6700001, -0.6689997, -0.6680002, -0.6669998, -0.66600037, -0.66499996, -0.66399956, -0.6630001, -0.6619997, -0.66100025, -0.65999985, -0.6590004, -0.658, -0.6569996, -0.65600014, -0.65499973, -0.6540003, -0.6529999, -0.6520004, -0.651, -0.6499996, -0.64900017, -0.64799976, -0.6470003, -0.6459999, -0.64500046, -0.64400005, -0.64299965, -0.6420002, -0.6409998, -0.64000034, -0.63899994, -0.63799953, -0.6370001, -0.6359997, -0.6350002, -0.6339998, -0.6330004, -0.63199997, -0.63099957, -0.6300001, -0.6289997, -0.62800026, -0.62699986, -0.6260004, -0.625, -0.6239996, -0.62300014, -0.62199974, -0.6210003, -0.6199999, -0.61900043, -0.61800003, -0.6169996, -0.6160002, -0.6149998, -0.6140003, -0.6129999, -0.61200047, -0.61100006, -0.60999966, -0.6090002, -0.6079998, -0.60700035, -0.60599995, -0.60499954, -0.6040001, -0.6029997, -0.60200024, -0.60099983, -0.6000004, -0.599, -0.5979996, -0.5970001, -0.5959997, -0.59500027, -0.59399986, -0.5930004, -0.592, -0.5909996, -0.59000015, -0.58899975, -0.5880003, -0.5869999, -0.58600044, -0.58500004, -0.58399963, -0.5830002, -0.5819998, -0.5810003, -0.5799999, -0.5790005, -0.57800007, -0.57699966, -0.5760002, -0.5749998, -0.57400036, -0.57299995, -0.57199955, -0.5710001, -0.5699997, -0.56900024, -0.56799984, -0.5670004, -0.566, -0.5649996, -0.5640001, -0.5629997, -0.5620003, -0.5609999, -0.5600004, -0.559, -0.5579996, -0.55700016, -0.55599976, -0.5550003, -0.5539999, -0.55300045, -0.55200005, -0.55099964, -0.5500002, -0.5489998, -0.54800034, -0.54699993, -0.5459995, -0.5450001, -0.5439997, -0.5430002, -0.5419998, -0.54100037, -0.53999996, -0.53899956, -0.5380001, -0.5369997, -0.53600025, -0.53499985, -0.5340004, -0.533, -0.5319996, -0.53100014, -0.52999973, -0.5290003, -0.5279999, -0.5270004, -0.526, -0.5249996, -0.52400017, -0.52299976, -0.5220003, -0.5209999, -0.52000046, -0.51900005, -0.51799965, -0.5170002, -0.5159998, -0.51500034, -0.51399994, -0.51299953, -0.5120001, -0.5109997, -0.5100002, -0.5089998, -0.5080004, -0.50699997, -0.50599957, -0.5050001, -0.5039997, -0.50300026, -0.50199986, -0.5010004, -0.5, -0.4989996, -0.49800014, -0.49699974, -0.4960003, -0.4949999, -0.49400043, -0.49300003, -0.49199963, -0.49100018, -0.48999977, -0.48900032, -0.48799992, -0.48700047, -0.48600006, -0.48499966, -0.4840002, -0.4829998, -0.48200035, -0.48099995, -0.47999954, -0.4790001, -0.4779997, -0.47700024, -0.47599983, -0.47500038, -0.47399998, -0.47299957, -0.47200012, -0.47099972, -0.47000027, -0.46899986, -0.4680004, -0.467, -0.4659996, -0.46500015, -0.46399975, -0.4630003, -0.4619999, -0.46100044, -0.46000004, -0.45899963, -0.45800018, -0.45699978, -0.45600033, -0.45499992, -0.45400047, -0.45300007, -0.45199966, -0.4510002, -0.4499998, -0.44900036, -0.44799995, -0.44699955, -0.4460001, -0.4449997, -0.44400024, -0.44299984, -0.4420004, -0.44099998, -0.43999958, -0.43900013, -0.43799973, -0.43700027, -0.43599987, -0.43500042, -0.43400002, -0.4329996, -0.43200016, -0.43099976, -0.4300003, -0.4289999, -0.42800045, -0.42700005, -0.42599964, -0.4250002, -0.4239998, -0.42300034, -0.42199993, -0.42099953, -0.42000008, -0.41899967, -0.41800022, -0.41699982, -0.41600037, -0.41499996, -0.41399956, -0.4130001, -0.4119997, -0.41100025, -0.40999985, -0.4090004, -0.408, -0.4069996, -0.40600014, -0.40499973, -0.40400028, -0.40299988, -0.40200043, -0.40100002, -0.39999962, -0.39900017, -0.39799976, -0.3970003, -0.3959999, -0.39500046, -0.39400005, -0.39299965, -0.3920002, -0.3909998, -0.39000034, -0.38899994, -0.38799953, -0.38700008, -0.38599968, -0.38500023, -0.38399982, -0.38300037, -0.38199997, -0.38099957, -0.3800001, -0.3789997, -0.37800026, -0.37699986, -0.3760004, -0.375, -0.3739996, -0.37300014, -0.37199974, -0.3710003, -0.3699999, -0.36900043, -0.36800003, -0.36699963, -0.36600018, -0.36499977, -0.36400032, -0.36299992, -0.36200047, -0.36100006, -0.35999966, -0.3590002, -0.3579998, -0.35700035, -0.35599995, -0.35499954, -0.3540001, -0.3529997, -0.35200024, -0.35099983, -0.35000038, -0.34899998, -0.34799957, -0.34700012, -0.34599972, -0.34500027, -0.34399986, -0.3430004, -0.342, -0.3409996, -0.34000015, -0.33899975, -0.3380003, -0.3369999, -0.33600044, -0.33500004, -0.33399963, -0.33300018, -0.33199978, -0.33100033, -0.32999992, -0.32900047, -0.32800007, -0.32699966, -0.3260002, -0.3249998, -0.32400036, -0.32299995, -0.32199955, -0.3210001, -0.3199997, -0.31900024, -0.31799984, -0.3170004, -0.31599998, -0.31499958, -0.31400013, -0.31299973, -0.31200027, -0.31099987, -0.31000042, -0.30900002, -0.3079996, -0.30700016, -0.30599976, -0.3050003, -0.3039999, -0.30300045, -0.30200005, -0.30099964, -0.3000002, -0.2989998, -0.29800034, -0.29699993, -0.29599953, -0.29500008, -0.29399967, -0.29300022, -0.29199982, -0.29100037, -0.28999996, -0.28899956, -0.2880001, -0.2869997, -0.28600025, -0.28499985, -0.2840004, -0.283, -0.2819996, -0.28100014, -0.27999973, -0.27900028, -0.27799988, -0.27700043, -0.27600002, -0.27499962, -0.27400017, -0.27299976, -0.2720003, -0.2709999, -0.27000046, -0.26900005, -0.26799965, -0.2670002, -0.2659998, -0.26500034, -0.26399994, -0.26299953, -0.26200008, -0.26099968, -0.26000023, -0.25899982, -0.25800037, -0.25699997, -0.25599957, -0.2550001, -0.2539997, -0.25300026, -0.25199986, -0.2510004, -0.25, -0.2489996, -0.24800014, -0.24699974, -0.24600029, -0.24499989, -0.24400043, -0.24300003, -0.24199963, -0.24100018, -0.23999977, -0.23900032, -0.23799992, -0.23700047, -0.23600006, -0.23499966, -0.2340002, -0.2329998, -0.23200035, -0.23099995, -0.22999954, -0.22900009, -0.22799969, -0.22700024, -0.22599983, -0.22500038, -0.22399998, -0.22299957, -0.22200012, -0.22099972, -0.22000027, -0.21899986, -0.21800041, -0.21700001, -0.2159996, -0.21500015, -0.21399975, -0.2130003, -0.2119999, -0.21100044, -0.21000004, -0.20899963, -0.20800018, -0.20699978, -0.20600033, -0.20499992, -0.20400047, -0.20300007, -0.20199966, -0.20100021, -0.19999981, -0.19900036, -0.19799995, -0.19699955, -0.1960001, -0.1949997, -0.19400024, -0.19299984, -0.19200039, -0.19099998, -0.18999958, -0.18900013, -0.18799973, -0.18700027, -0.18599987, -0.18500042, -0.18400002, -0.18299961, -0.18200016, -0.18099976, -0.1800003, -0.1789999, -0.17800045, -0.17700005, -0.17599964, -0.17500019, -0.17399979, -0.17300034, -0.17199993, -0.17099953, -0.17000008, -0.16899967, -0.16800022, -0.16699982, -0.16600037, -0.16499996, -0.16399956, -0.1630001, -0.1619997, -0.16100025, -0.15999985, -0.1590004, -0.15799999, -0.15699959, -0.15600014, -0.15499973, -0.15400028, -0.15299988, -0.15200043, -0.15100002, -0.14999962, -0.14900017, -0.14799976, -0.14700031, -0.14599991, -0.14500046, -0.14400005, -0.14299965, -0.1420002, -0.1409998, -0.14000034, -0.13899994, -0.13799953, -0.13700008, -0.13599968, -0.13500023, -0.13399982, -0.13300037, -0.13199997, -0.13099957, -0.13000011, -0.12899971, -0.12800026, -0.12699986, -0.1260004, -0.125, -0.123999596, -0.123000145, -0.12199974, -0.12100029, -0.119999886, -0.119000435, -0.11800003, -0.116999626, -0.116000175, -0.11499977, -0.11400032, -0.112999916, -0.112000465, -0.11100006, -0.10999966, -0.109000206, -0.1079998, -0.10700035, -0.10599995, -0.10499954, -0.10400009, -0.10299969, -0.10200024, -0.10099983, -0.10000038, -0.09899998, -0.09799957, -0.09700012, -0.09599972, -0.09500027, -0.09399986, -0.09300041, -0.09200001, -0.0909996, -0.09000015, -0.08899975, -0.0880003, -0.08699989, -0.08600044, -0.08500004, -0.083999634, -0.08300018, -0.08199978, -0.08100033, -0.07999992, -0.07900047, -0.07800007, -0.076999664, -0.07600021, -0.07499981, -0.07400036, -0.072999954, -0.07199955, -0.0710001, -0.069999695, -0.069000244, -0.06799984, -0.06700039, -0.065999985, -0.06499958, -0.06400013, -0.062999725, -0.062000275, -0.06099987, -0.06000042, -0.059000015, -0.05799961, -0.05700016, -0.055999756, -0.055000305, -0.0539999, -0.05300045, -0.052000046, -0.05099964, -0.05000019, -0.048999786, -0.048000336, -0.04699993, -0.045999527, -0.045000076, -0.043999672, -0.04300022, -0.041999817, -0.041000366, -0.03999996, -0.038999557, -0.038000107, -0.036999702, -0.03600025, -0.034999847, -0.034000397, -0.032999992, -0.031999588, -0.031000137, -0.029999733, -0.029000282, -0.027999878, -0.027000427, -0.026000023, -0.024999619, -0.024000168, -0.022999763, -0.022000313, -0.020999908, -0.020000458, -0.019000053, -0.017999649, -0.017000198, -0.015999794, -0.015000343, -0.013999939, -0.012999535, -0.012000084, -0.01099968, -0.010000229, -0.0089998245, -0.008000374, -0.0069999695, -0.005999565, -0.0050001144, -0.00399971, -0.0030002594, -0.001999855, -0.0010004044, 0.0, 0.0010004044, 0.001999855, 0.0030002594, 0.00399971, 0.0050001144, 0.005999565, 0.0069999695, 0.008000374, 0.0089998245, 0.010000229, 0.01099968, 0.012000084, 0.012999535, 0.013999939, 0.015000343, 0.015999794, 0.017000198, 0.017999649, 0.019000053, 0.020000458, 0.020999908, 0.022000313, 0.022999763, 0.024000168, 0.024999619, 0.026000023, 0.027000427, 0.027999878, 0.029000282, 0.029999733, 0.031000137, 0.031999588, 0.032999992, 0.034000397, 0.034999847, 0.03600025, 0.036999702, 0.038000107, 0.038999557, 0.03999996, 0.041000366, 0.041999817, 0.04300022, 0.043999672, 0.045000076, 0.045999527, 0.04699993, 0.048000336, 0.048999786, 0.05000019, 0.05099964, 0.052000046, 0.05300045, 0.0539999, 0.055000305, 0.055999756, 0.05700016, 0.05799961, 0.059000015, 0.06000042, 0.06099987, 0.062000275, 0.062999725, 0.06400013, 0.06499958, 0.065999985, 0.06700039, 0.06799984, 0.069000244, 0.069999695, 0.0710001, 0.07199955, 0.072999954, 0.07400036, 0.07499981, 0.07600021, 0.076999664, 0.07800007, 0.07900047, 0.07999992, 0.08100033, 0.08199978, 0.08300018, 0.083999634, 0.08500004, 0.08600044, 0.08699989, 0.0880003, 0.08899975, 0.09000015, 0.0909996, 0.09200001, 0.09300041, 0.09399986, 0.09500027, 0.09599972, 0.09700012, 0.09799957, 0.09899998, 0.10000038, 0.10099983, 0.10200024, 0.10299969, 0.10400009, 0.10499954, 0.10599995, 0.10700035, 0.1079998, 0.109000206, 0.10999966, 0.11100006, 0.112000465, 0.112999916, 0.11400032, 0.11499977, 0.116000175, 0.116999626, 0.11800003, 0.119000435, 0.119999886, 0.12100029, 0.12199974, 0.123000145, 0.123999596, 0.125, 0.1260004, 0.12699986, 0.12800026, 0.12899971, 0.13000011, 0.13099957, 0.13199997, 0.13300037, 0.13399982, 0.13500023, 0.13599968, 0.13700008, 0.13799953, 0.13899994, 0.14000034, 0.1409998, 0.1420002, 0.14299965, 0.14400005, 0.14500046, 0.14599991, 0.14700031, 0.14799976, 0.14900017, 0.14999962, 0.15100002, 0.15200043, 0.15299988, 0.15400028, 0.15499973, 0.15600014, 0.15699959, 0.15799999, 0.1590004, 0.15999985, 0.16100025, 0.1619997, 0.1630001, 0.16399956, 0.16499996, 0.16600037, 0.16699982, 0.16800022, 0.16899967, 0.17000008, 0.17099953, 0.17199993, 0.17300034, 0.17399979, 0.17500019, 0.17599964, 0.17700005, 0.17800045, 0.1789999, 0.1800003, 0.18099976, 0.18200016, 0.18299961, 0.18400002, 0.18500042, 0.18599987, 0.18700027, 0.18799973, 0.18900013, 0.18999958, 0.19099998, 0.19200039, 0.19299984, 0.19400024, 0.1949997, 0.1960001, 0.19699955, 0.19799995, 0.19900036, 0.19999981, 0.20100021, 0.20199966, 0.20300007, 0.20400047, 0.20499992, 0.20600033, 0.20699978, 0.20800018, 0.20899963, 0.21000004, 0.21100044, 0.2119999, 0.2130003, 0.21399975, 0.21500015, 0.2159996, 0.21700001, 0.21800041, 0.21899986, 0.22000027, 0.22099972, 0.22200012, 0.22299957, 0.22399998, 0.22500038, 0.22599983, 0.22700024, 0.22799969, 0.22900009, 0.22999954, 0.23099995, 0.23200035, 0.2329998, 0.2340002, 0.23499966, 0.23600006, 0.23700047, 0.23799992, 0.23900032, 0.23999977, 0.24100018, 0.24199963, 0.24300003, 0.24400043, 0.24499989, 0.24600029, 0.24699974, 0.24800014, 0.2489996, 0.25, 0.2510004, 0.25199986, 0.25300026, 0.2539997, 0.2550001, 0.25599957, 0.25699997, 0.25800037, 0.25899982, 0.26000023, 0.26099968, 0.26200008, 0.26299953, 0.26399994, 0.26500034, 0.2659998, 0.2670002, 0.26799965, 0.26900005, 0.27000046, 0.2709999, 0.2720003, 0.27299976, 0.27400017, 0.27499962, 0.27600002, 0.27700043, 0.27799988, 0.27900028, 0.27999973, 0.28100014, 0.2819996, 0.283, 0.2840004, 0.28499985, 0.28600025, 0.2869997, 0.2880001, 0.28899956, 0.28999996, 0.29100037, 0.29199982, 0.29300022, 0.29399967, 0.29500008, 0.29599953, 0.29699993, 0.29800034, 0.2989998, 0.3000002, 0.30099964, 0.30200005, 0.30300045, 0.3039999, 0.3050003, 0.30599976, 0.30700016, 0.3079996, 0.30900002, 0.31000042, 0.31099987, 0.31200027, 0.31299973, 0.31400013, 0.31499958, 0.31599998, 0.3170004, 0.31799984, 0.31900024, 0.3199997, 0.3210001, 0.32199955, 0.32299995, 0.32400036, 0.3249998, 0.3260002, 0.32699966, 0.32800007, 0.32900047, 0.32999992, 0.33100033, 0.33199978, 0.33300018, 0.33399963, 0.33500004, 0.33600044, 0.3369999, 0.3380003, 0.33899975, 0.34000015, 0.3409996, 0.342, 0.3430004, 0.34399986, 0.34500027, 0.34599972, 0.34700012, 0.34799957, 0.34899998, 0.35000038, 0.35099983, 0.35200024, 0.3529997, 0.3540001, 0.35499954, 0.35599995, 0.35700035, 0.3579998, 0.3590002, 0.35999966, 0.36100006, 0.36200047, 0.36299992, 0.36400032, 0.36499977, 0.36600018, 0.36699963, 0.36800003, 0.36900043, 0.3699999, 0.3710003, 0.37199974, 0.37300014, 0.3739996, 0.375, 0.3760004, 0.37699986, 0.37800026, 0.3789997, 0.3800001, 0.38099957, 0.38199997, 0.38300037, 0.38399982, 0.38500023, 0.38599968, 0.38700008, 0.38799953, 0.38899994, 0.39000034, 0.3909998, 0.3920002, 0.39299965, 0.39400005, 0.39500046, 0.3959999, 0.3970003, 0.39799976, 0.39900017, 0.39999962, 0.40100002, 0.40200043, 0.40299988, 0.40400028, 0.40499973, 0.40600014, 0.4069996, 0.408, 0.4090004, 0.40999985, 0.41100025, 0.4119997, 0.4130001, 0.41399956, 0.41499996, 0.41600037, 0.41699982, 0.41800022, 0.41899967, 0.42000008, 0.42099953, 0.42199993, 0.42300034, 0.4239998, 0.4250002, 0.42599964, 0.42700005, 0.42800045, 0.4289999, 0.4300003, 0.43099976, 0.43200016, 0.4329996, 0.43400002, 0.43500042, 0.43599987, 0.43700027, 0.43799973, 0.43900013, 0.43999958, 0.44099998, 0.4420004, 0.44299984, 0.44400024, 0.4449997, 0.4460001, 0.44699955, 0.44799995, 0.44900036, 0.4499998, 0.4510002, 0.45199966, 0.45300007, 0.45400047, 0.45499992, 0.45600033, 0.45699978, 0.45800018, 0.45899963, 0.46000004, 0.46100044, 0.4619999, 0.4630003, 0.46399975, 0.46500015, 0.4659996, 0.467, 0.4680004, 0.46899986, 0.47000027, 0.47099972, 0.47200012, 0.47299957, 0.47399998, 0.47500038, 0.47599983, 0.47700024, 0.4779997, 0.4790001, 0.47999954, 0.48099995, 0.48200035, 0.4829998, 0.4840002, 0.48499966, 0.48600006, 0.48700047, 0.48799992, 0.48900032, 0.48999977, 0.49100018, 0.49199963, 0.49300003, 0.49400043, 0.4949999, 0.4960003, 0.49699974, 0.49800014, 0.4989996, 0.5, 0.5010004, 0.50199986, 0.50300026, 0.5039997, 0.5050001, 0.50599957, 0.50699997, 0.5080004, 0.5089998, 0.5100002, 0.5109997, 0.5120001, 0.51299953, 0.51399994, 0.51500034, 0.5159998, 0.5170002, 0.51799965, 0.51900005, 0.52000046, 0.5209999, 0.5220003, 0.52299976, 0.52400017, 0.5249996, 0.526, 0.5270004, 0.5279999, 0.5290003, 0.52999973, 0.53100014, 0.5319996, 0.533, 0.5340004, 0.53499985, 0.53600025, 0.5369997, 0.5380001, 0.53899956, 0.53999996, 0.54100037, 0.5419998, 0.5430002, 0.5439997, 0.5450001, 0.5459995, 0.54699993, 0.54800034, 0.5489998, 0.5500002, 0.55099964, 0.55200005, 0.55300045, 0.5539999, 0.5550003, 0.55599976, 0.55700016, 0.5579996, 0.559, 0.5600004, 0.5609999, 0.5620003, 0.5629997, 0.5640001, 0.5649996, 0.566, 0.5670004, 0.56799984, 0.56900024, 0.5699997, 0.5710001, 0.57199955, 0.57299995, 0.57400036, 0.5749998, 0.5760002, 0.57699966, 0.57800007, 0.5790005, 0.5799999, 0.5810003, 0.5819998, 0.5830002, 0.58399963, 0.58500004, 0.58600044, 0.5869999, 0.5880003, 0.58899975, 0.59000015, 0.5909996, 0.592, 0.5930004, 0.59399986, 0.59500027, 0.5959997, 0.5970001, 0.5979996, 0.599, 0.6000004, 0.60099983, 0.60200024, 0.6029997, 0.6040001, 0.60499954, 0.60599995, 0.60700035, 0.6079998, 0.6090002, 0.60999966, 0.61100006, 0.61200047, 0.6129999, 0.6140003, 0.6149998, 0.6160002, 0.6169996, 0.61800003, 0.61900043, 0.6199999, 0.6210003, 0.62199974, 0.62300014, 0.6239996, 0.625, 0.6260004, 0.62699986, 0.62800026, 0.6289997, 0.6300001, 0.63099957, 0.63199997, 0.6330004, 0.6339998, 0.6350002, 0.6359997, 0.6370001, 0.63799953, 0.63899994, 0.64000034, 0.6409998, 0.6420002, 0.64299965, 0.64400005, 0.64500046, 0.6459999, 0.6470003, 0.64799976, 0.64900017, 0.6499996, 0.651, 0.6520004, 0.6529999, 0.6540003, 0.65499973, 0.65600014, 0.6569996, 0.658, 0.6590004, 0.65999985, 0.66100025, 0.6619997, 0.6630001, 0.66399956, 0.66499996, 0.66600037, 0.6669998, 0.6680002, 0.6689997, 0.6700001, 0.6709995, 0.67199993, 0.67300034, 0.6739998, 0.6750002, 0.67599964, 0.67700005, 0.67800045, 0.6789999, 0.6800003, 0.68099976, 0.68200016, 0.6829996, 0.684, 0.6850004, 0.6859999, 0.6870003, 0.6879997, 0.6890001, 0.6899996, 0.691, 0.6920004, 0.69299984, 0.69400024, 0.6949997, 0.6960001, 0.69699955, 0.69799995, 0.69900036, 0.6999998, 0.7010002, 0.70199966, 0.70300007, 0.7040005, 0.7049999, 0.7060003, 0.7069998, 0.7080002, 0.70899963, 0.71000004, 0.71100044, 0.7119999, 0.7130003, 0.71399975, 0.71500015, 0.7159996, 0.717, 0.7180004, 0.71899986, 0.72000027, 0.7209997, 0.7220001, 0.7229996, 0.724, 0.7250004, 0.72599983, 0.72700024, 0.7279997, 0.7290001, 0.72999954, 0.73099995, 0.73200035, 0.7329998, 0.7340002, 0.73499966, 0.73600006, 0.73700047, 0.7379999, 0.7390003, 0.7399998, 0.7410002, 0.7419996, 0.74300003, 0.74400043, 0.7449999, 0.7460003, 0.74699974, 0.74800014, 0.7489996, 0.75, 0.7510004, 0.75199986, 0.75300026, 0.7539997, 0.7550001, 0.75599957, 0.75699997, 0.7580004, 0.7589998, 0.7600002, 0.7609997, 0.7620001, 0.76299953, 0.76399994, 0.76500034, 0.7659998, 0.7670002, 0.76799965, 0.76900005, 0.77000046, 0.7709999, 0.7720003, 0.77299976, 0.77400017, 0.7749996, 0.776, 0.7770004, 0.7779999, 0.7790003, 0.77999973, 0.78100014, 0.7819996, 0.783, 0.7840004, 0.78499985, 0.78600025, 0.7869997, 0.7880001, 0.78899956, 0.78999996, 0.79100037, 0.7919998, 0.7930002, 0.7939997, 0.7950001, 0.7959995, 0.79699993, 0.79800034, 0.7989998, 0.8000002, 0.80099964, 0.80200005, 0.80300045, 0.8039999, 0.8050003, 0.80599976, 0.80700016, 0.8079996, 0.809, 0.8100004, 0.8109999, 0.8120003, 0.8129997, 0.8140001, 0.8149996, 0.816, 0.8170004, 0.81799984, 0.81900024, 0.8199997, 0.8210001, 0.82199955, 0.82299995, 0.82400036, 0.8249998, 0.8260002, 0.82699966, 0.82800007, 0.8290005, 0.8299999, 0.8310003, 0.8319998, 0.8330002, 0.83399963, 0.83500004, 0.83600044, 0.8369999, 0.8380003, 0.83899975, 0.84000015, 0.8409996, 0.842, 0.8430004, 0.84399986, 0.84500027, 0.8459997, 0.8470001, 0.8479996, 0.849, 0.8500004, 0.85099983, 0.85200024, 0.8529997, 0.8540001, 0.85499954, 0.85599995, 0.85700035, 0.8579998, 0.8590002, 0.85999966, 0.86100006, 0.86200047, 0.8629999, 0.8640003, 0.8649998, 0.8660002, 0.8669996, 0.86800003, 0.86900043, 0.8699999, 0.8710003, 0.87199974, 0.87300014, 0.8739996, 0.875, 0.8760004, 0.87699986, 0.87800026, 0.8789997, 0.8800001, 0.88099957, 0.88199997, 0.8830004, 0.8839998, 0.8850002, 0.8859997, 0.8870001, 0.88799953, 0.88899994, 0.89000034, 0.8909998, 0.8920002, 0.89299965, 0.89400005, 0.89500046, 0.8959999, 0.8970003, 0.89799976, 0.89900017, 0.8999996, 0.901, 0.9020004, 0.9029999, 0.9040003, 0.90499973, 0.90600014, 0.9069996, 0.908, 0.9090004, 0.90999985, 0.91100025, 0.9119997, 0.9130001, 0.91399956, 0.91499996, 0.91600037, 0.9169998, 0.9180002, 0.9189997, 0.9200001, 0.9209995, 0.92199993, 0.92300034, 0.9239998, 0.9250002, 0.92599964, 0.92700005, 0.92800045, 0.9289999, 0.9300003, 0.93099976, 0.93200016, 0.9329996, 0.934, 0.9350004, 0.9359999, 0.9370003, 0.9379997, 0.9390001, 0.9399996, 0.941, 0.9420004, 0.94299984, 0.94400024, 0.9449997, 0.9460001, 0.94699955, 0.94799995, 0.94900036, 0.9499998, 0.9510002, 0.95199966, 0.95300007, 0.9540005, 0.9549999, 0.9560003, 0.9569998, 0.9580002, 0.95899963, 0.96000004, 0.96100044, 0.9619999, 0.9630003, 0.96399975, 0.96500015, 0.9659996, 0.967, 0.9680004, 0.96899986, 0.97000027, 0.9709997, 0.9720001, 0.9729996, 0.974, 0.9750004, 0.97599983, 0.97700024, 0.9779997, 0.9790001, 0.97999954, 0.98099995, 0.98200035, 0.9829998, 0.9840002, 0.98499966, 0.98600006, 0.98700047, 0.9879999, 0.9890003, 0.9899998, 0.9910002, 0.9919996, 0.99300003, 0.99400043, 0.9949999, 0.9960003, 0.99699974, 0.99800014, 0.9989996, 1.0, 1.0010004, 1.0019999, 1.0030003, 1.0039997, 1.0050001, 1.0059996, 1.007, 1.0080004, 1.0089998, 1.0100002, 1.0109997, 1.0120001, 1.0129995, 1.0139999, 1.0150003, 1.0159998, 1.0170002, 1.0179996, 1.019, 1.0200005, 1.0209999, 1.0220003, 1.0229998, 1.0240002, 1.0249996, 1.026, 1.0270004, 1.0279999, 1.0290003, 1.0299997, 1.0310001, 1.0319996, 1.033, 1.0340004, 1.0349998, 1.0360003, 1.0369997, 1.0380001, 1.0389996, 1.04, 1.0410004, 1.0419998, 1.0430002, 1.0439997, 1.0450001, 1.0459995, 1.0469999, 1.0480003, 1.0489998, 1.0500002, 1.0509996, 1.052, 1.0530005, 1.0539999, 1.0550003, 1.0559998, 1.0570002, 1.0579996, 1.059, 1.0600004, 1.0609999, 1.0620003, 1.0629997, 1.0640001, 1.0649996, 1.066, 1.0670004, 1.0679998, 1.0690002, 1.0699997, 1.0710001, 1.0719995, 1.073, 1.0740004, 1.0749998, 1.0760002, 1.0769997, 1.0780001, 1.0790005, 1.0799999, 1.0810003, 1.0819998, 1.0830002, 1.0839996, 1.085, 1.0860004, 1.0869999, 1.0880003, 1.0889997, 1.0900002, 1.0909996, 1.092, 1.0930004, 1.0939999, 1.0950003, 1.0959997, 1.0970001, 1.0979996, 1.099, 1.1000004, 1.1009998, 1.1020002, 1.1029997, 1.1040001, 1.1049995, 1.106, 1.1070004, 1.1079998, 1.1090002, 1.1099997, 1.1110001, 1.1120005, 1.1129999, 1.1140003, 1.1149998, 1.1160002, 1.1169996, 1.118, 1.1190004, 1.1199999, 1.1210003, 1.1219997, 1.1230001, 1.1239996, 1.125, 1.1260004, 1.1269999, 1.1280003, 1.1289997, 1.1300001, 1.1309996, 1.132, 1.1330004, 1.1339998, 1.1350002, 1.1359997, 1.1370001, 1.1379995, 1.1389999, 1.1400003, 1.1409998, 1.1420002, 1.1429996, 1.144, 1.1450005, 1.1459999, 1.1470003, 1.1479998, 1.1490002, 1.1499996, 1.151, 1.1520004, 1.1529999, 1.1540003, 1.1549997, 1.1560001, 1.1569996, 1.158, 1.1590004, 1.1599998, 1.1610003, 1.1619997, 1.1630001, 1.1639996, 1.165, 1.1660004, 1.1669998, 1.1680002, 1.1689997, 1.1700001, 1.1709995, 1.1719999, 1.1730003, 1.1739998, 1.1750002, 1.1759996, 1.177, 1.1780005, 1.1789999, 1.1800003, 1.1809998, 1.1820002, 1.1829996, 1.184, 1.1850004, 1.1859999, 1.1870003, 1.1879997, 1.1890001, 1.1899996, 1.191, 1.1920004, 1.1929998, 1.1940002, 1.1949997, 1.1960001, 1.1969995, 1.198, 1.1990004, 1.1999998, 1.2010002, 1.2019997, 1.2030001, 1.2040005, 1.2049999, 1.2060003, 1.2069998, 1.2080002, 1.2089996, 1.21, 1.2110004, 1.2119999, 1.2130003, 1.2139997, 1.2150002, 1.2159996, 1.217, 1.2180004, 1.2189999, 1.2200003, 1.2209997, 1.2220001, 1.2229996, 1.224, 1.2250004, 1.2259998, 1.2270002, 1.2279997, 1.2290001, 1.2299995, 1.231, 1.2320004, 1.2329998, 1.2340002, 1.2349997, 1.2360001, 1.2370005, 1.2379999, 1.2390003, 1.2399998, 1.2410002, 1.2419996, 1.243, 1.2440004, 1.2449999, 1.2460003, 1.2469997, 1.2480001, 1.2489996, 1.25, 1.2510004, 1.2519999, 1.2530003, 1.2539997, 1.2550001, 1.2559996, 1.257, 1.2580004, 1.2589998, 1.2600002, 1.2609997, 1.2620001, 1.2629995, 1.2639999, 1.2650003, 1.2659998, 1.2670002, 1.2679996, 1.269, 1.2700005, 1.2709999, 1.2720003, 1.2729998, 1.2740002, 1.2749996, 1.276, 1.2770004, 1.2779999, 1.2790003, 1.2799997, 1.2810001, 1.2819996, 1.283, 1.2840004, 1.2849998, 1.2860003, 1.2869997, 1.2880001, 1.2889996, 1.29, 1.2910004, 1.2919998, 1.2930002, 1.2939997, 1.2950001, 1.2959995, 1.2969999, 1.2980003, 1.2989998, 1.3000002, 1.3009996, 1.302, 1.3030005, 1.3039999, 1.3050003, 1.3059998, 1.3070002, 1.3079996, 1.309, 1.3100004, 1.3109999, 1.3120003, 1.3129997, 1.3140001, 1.3149996, 1.316, 1.3170004, 1.3179998, 1.3190002, 1.3199997, 1.3210001, 1.3219995, 1.323, 1.3240004, 1.3249998, 1.3260002, 1.3269997, 1.3280001, 1.3290005, 1.3299999, 1.3310003, 1.3319998, 1.3330002, 1.3339996, 1.335, 1.3360004, 1.3369999, 1.3380003, 1.3389997, 1.3400002, 1.3409996, 1.342, 1.3430004, 1.3439999, 1.3450003, 1.3459997, 1.3470001, 1.3479996, 1.349, 1.3500004, 1.3509998, 1.3520002, 1.3529997, 1.3540001, 1.3549995, 1.356, 1.3570004, 1.3579998, 1.3590002, 1.3599997, 1.3610001, 1.3620005, 1.3629999, 1.3640003, 1.3649998, 1.3660002, 1.3669996, 1.368, 1.3690004, 1.3699999, 1.3710003, 1.3719997, 1.3730001, 1.3739996, 1.375, 1.3760004, 1.3769999, 1.3780003, 1.3789997, 1.3800001, 1.3809996, 1.382, 1.3830004, 1.3839998, 1.3850002, 1.3859997, 1.3870001, 1.3879995, 1.3889999, 1.3900003, 1.3909998, 1.3920002, 1.3929996, 1.394, 1.3950005, 1.3959999, 1.3970003, 1.3979998, 1.3990002, 1.3999996, 1.401, 1.4020004, 1.4029999, 1.4040003, 1.4049997, 1.4060001, 1.4069996, 1.408, 1.4090004, 1.4099998, 1.4110003, 1.4119997, 1.4130001, 1.4139996, 1.415, 1.4160004, 1.4169998, 1.4180002, 1.4189997, 1.4200001, 1.4209995, 1.4219999, 1.4230003, 1.4239998, 1.4250002, 1.4259996, 1.427, 1.4280005, 1.4289999, 1.4300003, 1.4309998, 1.4320002, 1.4329996, 1.434, 1.4350004, 1.4359999, 1.4370003, 1.4379997, 1.4390001, 1.4399996, 1.441, 1.4420004, 1.4429998, 1.4440002, 1.4449997, 1.4460001, 1.4469995, 1.448, 1.4490004, 1.4499998, 1.4510002, 1.4519997, 1.4530001, 1.4540005, 1.4549999, 1.4560003, 1.4569998, 1.4580002, 1.4589996, 1.46, 1.4610004, 1.4619999, 1.4630003, 1.4639997, 1.4650002, 1.4659996, 1.467, 1.4680004, 1.4689999, 1.4700003, 1.4709997, 1.4720001, 1.4729996, 1.474, 1.4750004, 1.4759998, 1.4770002, 1.4779997, 1.4790001, 1.4799995, 1.481, 1.4820004, 1.4829998, 1.4840002, 1.4849997, 1.4860001, 1.4870005, 1.4879999, 1.4890003, 1.4899998, 1.4910002, 1.4919996, 1.493, 1.4940004, 1.4949999, 1.4960003, 1.4969997, 1.4980001, 1.4989996, 1.5, 1.5010004, 1.5019999, 1.5030003, 1.5039997, 1.5050001, 1.5059996, 1.507, 1.5080004, 1.5089998, 1.5100002, 1.5109997, 1.5120001, 1.5129995, 1.5139999, 1.5150003, 1.5159998, 1.5170002, 1.5179996, 1.519, 1.5200005, 1.5209999, 1.5220003, 1.5229998, 1.5240002, 1.5249996, 1.526, 1.5270004, 1.5279999, 1.5290003, 1.5299997, 1.5310001, 1.5319996, 1.533, 1.5340004, 1.5349998, 1.5360003, 1.5369997, 1.5380001, 1.5389996, 1.54, 1.5410004, 1.5419998, 1.5430002, 1.5439997, 1.5450001, 1.5459995, 1.5469999, 1.5480003, 1.5489998, 1.5500002, 1.5509996, 1.552, 1.5530005, 1.5539999, 1.5550003, 1.5559998, 1.5570002, 1.5579996, 1.559, 1.5600004, 1.5609999, 1.5620003, 1.5629997, 1.5640001, 1.5649996, 1.566, 1.5670004, 1.5679998, 1.5690002, 1.5699997, 1.5710001, 1.5719995, 1.573, 1.5740004, 1.5749998, 1.5760002, 1.5769997, 1.5780001, 1.5790005, 1.5799999, 1.5810003, 1.5819998, 1.5830002, 1.5839996, 1.585, 1.5860004, 1.5869999, 1.5880003, 1.5889997, 1.5900002, 1.5909996, 1.592, 1.5930004, 1.5939999, 1.5950003, 1.5959997, 1.5970001, 1.5979996, 1.599, 1.6000004, 1.6009998, 1.6020002, 1.6029997, 1.6040001, 1.6049995, 1.606, 1.6070004, 1.6079998, 1.6090002, 1.6099997, 1.6110001, 1.6120005, 1.6129999, 1.6140003, 1.6149998, 1.6160002, 1.6169996, 1.618, 1.6190004, 1.6199999, 1.6210003, 1.6219997, 1.6230001, 1.6239996, 1.625, 1.6260004, 1.6269999, 1.6280003, 1.6289997, 1.6300001, 1.6309996, 1.632, 1.6330004, 1.6339998, 1.6350002, 1.6359997, 1.6370001, 1.6379995, 1.6389999, 1.6400003, 1.6409998, 1.6420002, 1.6429996, 1.644, 1.6450005, 1.6459999, 1.6470003, 1.6479998, 1.6490002, 1.6499996, 1.651, 1.6520004, 1.6529999, 1.6540003, 1.6549997, 1.6560001, 1.6569996, 1.658, 1.6590004, 1.6599998, 1.6610003, 1.6619997, 1.6630001, 1.6639996, 1.665, 1.6660004, 1.6669998, 1.6680002, 1.6689997, 1.6700001, 1.6709995, 1.6719999, 1.6730003, 1.6739998, 1.6750002, 1.6759996, 1.677, 1.6780005, 1.6789999, 1.6800003, 1.6809998, 1.6820002, 1.6829996, 1.684, 1.6850004, 1.6859999, 1.6870003, 1.6879997, 1.6890001, 1.6899996, 1.691, 1.6920004, 1.6929998, 1.6940002, 1.6949997, 1.6960001, 1.6969995, 1.698, 1.6990004, 1.6999998, 1.7010002, 1.7019997, 1.7030001, 1.7040005, 1.7049999, 1.7060003, 1.7069998, 1.7080002, 1.7089996, 1.71, 1.7110004, 1.7119999, 1.7130003, 1.7139997, 1.7150002, 1.7159996, 1.717, 1.7180004, 1.7189999, 1.7200003, 1.7209997, 1.7220001, 1.7229996, 1.724, 1.7250004, 1.7259998, 1.7270002, 1.7279997, 1.7290001, 1.7299995, 1.731, 1.7320004, 1.7329998, 1.7340002, 1.7349997, 1.7360001, 1.7370005, 1.7379999, 1.7390003, 1.7399998, 1.7410002, 1.7419996, 1.743, 1.7440004, 1.7449999, 1.7460003, 1.7469997, 1.7480001, 1.7489996, 1.75, 1.7510004, 1.7519999, 1.7530003, 1.7539997, 1.7550001, 1.7559996, 1.757, 1.7580004, 1.7589998, 1.7600002, 1.7609997, 1.7620001, 1.7629995, 1.7639999, 1.7650003, 1.7659998, 1.7670002, 1.7679996, 1.769, 1.7700005, 1.7709999, 1.7720003, 1.7729998, 1.7740002, 1.7749996, 1.776, 1.7770004, 1.7779999, 1.7790003, 1.7799997, 1.7810001, 1.7819996, 1.783, 1.7840004, 1.7849998, 1.7860003, 1.7869997, 1.7880001, 1.7889996, 1.79, 1.7910004, 1.7919998, 1.7930002, 1.7939997, 1.7950001, 1.7959995, 1.7969999, 1.7980003, 1.7989998, 1.8000002, 1.8009996, 1.802, 1.8030005, 1.8039999, 1.8050003, 1.8059998, 1.8070002, 1.8079996, 1.809, 1.8100004, 1.8109999, 1.8120003, 1.8129997, 1.8140001, 1.8149996, 1.816, 1.8170004, 1.8179998, 1.8190002, 1.8199997, 1.8210001, 1.8219995, 1.823, 1.8240004, 1.8249998, 1.8260002, 1.8269997, 1.8280001, 1.8290005, 1.8299999, 1.8310003, 1.8319998, 1.8330002, 1.8339996, 1.835, 1.8360004, 1.8369999, 1.8380003, 1.8389997, 1.8400002, 1.8409996, 1.842, 1.8430004, 1.8439999, 1.8450003, 1.8459997, 1.8470001, 1.8479996, 1.849, 1.8500004, 1.8509998, 1.8520002, 1.8529997, 1.8540001, 1.8549995, 1.856, 1.8570004, 1.8579998, 1.8590002, 1.8599997, 1.8610001, 1.8620005, 1.8629999, 1.8640003, 1.8649998, 1.8660002, 1.8669996, 1.868, 1.8690004, 1.8699999, 1.8710003, 1.8719997, 1.8730001, 1.8739996, 1.875, 1.8760004, 1.8769999, 1.8780003, 1.8789997, 1.8800001, 1.8809996, 1.882, 1.8830004, 1.8839998, 1.8850002, 1.8859997, 1.8870001, 1.8879995, 1.8889999, 1.8900003, 1.8909998, 1.8920002, 1.8929996, 1.894, 1.8950005, 1.8959999, 1.8970003, 1.8979998, 1.8990002, 1.8999996, 1.901, 1.9020004, 1.9029999, 1.9040003, 1.9049997, 1.9060001, 1.9069996, 1.908, 1.9090004, 1.9099998, 1.9110003, 1.9119997, 1.9130001, 1.9139996, 1.915, 1.9160004, 1.9169998, 1.9180002, 1.9189997, 1.9200001, 1.9209995, 1.9219999, 1.9230003, 1.9239998, 1.9250002, 1.9259996, 1.927, 1.9280005, 1.9289999, 1.9300003, 1.9309998, 1.9320002, 1.9329996, 1.934, 1.9350004, 1.9359999, 1.9370003, 1.9379997, 1.9390001, 1.9399996, 1.941, 1.9420004, 1.9429998, 1.9440002, 1.9449997, 1.9460001, 1.9469995, 1.948, 1.9490004, 1.9499998, 1.9510002, 1.9519997, 1.9530001, 1.9540005, 1.9549999, 1.9560003, 1.9569998, 1.9580002, 1.9589996, 1.96, 1.9610004, 1.9619999, 1.9630003, 1.9639997, 1.9650002, 1.9659996, 1.967, 1.9680004, 1.9689999, 1.9700003, 1.9709997, 1.9720001, 1.9729996, 1.974, 1.9750004, 1.9759998, 1.9770002, 1.9779997, 1.9790001, 1.9799995, 1.981, 1.9820004, 1.9829998, 1.9840002, 1.9849997, 1.9860001, 1.9870005, 1.9879999, 1.9890003, 1.9899998, 1.9910002, 1.9919996, 1.993, 1.9940004, 1.9949999, 1.9960003, 1.9969997, 1.9980001, 1.9989996, 2.0, 2.0010004, 2.0019999, 2.0030003, 2.0039997, 2.005, 2.0059996, 2.007, 2.0080004, 2.0089998, 2.0100002, 2.0109997, 2.012, 2.0129995, 2.014, 2.0150003, 2.0159998, 2.0170002, 2.0179996, 2.019, 2.0200005, 2.021, 2.0220003, 2.0229998, 2.0240002, 2.0249996, 2.026, 2.0270004, 2.0279999, 2.0290003, 2.0299997, 2.0310001, 2.0319996, 2.033, 2.0340004, 2.0349998, 2.0360003, 2.0369997, 2.038, 2.0389996, 2.04, 2.0410004, 2.0419998, 2.0430002, 2.0439997, 2.045, 2.0459995, 2.047, 2.0480003, 2.0489998, 2.0500002, 2.0509996, 2.052, 2.0530005, 2.054, 2.0550003, 2.0559998, 2.0570002, 2.0579996, 2.059, 2.0600004, 2.0609999, 2.0620003, 2.0629997, 2.0640001, 2.0649996, 2.066, 2.0670004, 2.0679998, 2.0690002, 2.0699997, 2.071, 2.0719995, 2.073, 2.0740004, 2.0749998, 2.0760002, 2.0769997, 2.078, 2.0790005, 2.08, 2.0810003, 2.0819998, 2.0830002, 2.0839996, 2.085, 2.0860004, 2.087, 2.0880003, 2.0889997, 2.0900002, 2.0909996, 2.092, 2.0930004, 2.0939999, 2.0950003, 2.0959997, 2.0970001, 2.0979996, 2.099, 2.1000004, 2.1009998, 2.1020002, 2.1029997, 2.104, 2.1049995, 2.106, 2.1070004, 2.1079998, 2.1090002, 2.1099997, 2.111, 2.1120005, 2.113, 2.1140003, 2.1149998, 2.1160002, 2.1169996, 2.118, 2.1190004, 2.12, 2.1210003, 2.1219997, 2.1230001, 2.1239996, 2.125, 2.1260004, 2.1269999, 2.1280003, 2.1289997, 2.13, 2.1309996, 2.132, 2.1330004, 2.1339998, 2.1350002, 2.1359997, 2.137, 2.1379995, 2.139, 2.1400003, 2.1409998, 2.1420002, 2.1429996, 2.144, 2.1450005, 2.146, 2.1470003, 2.1479998, 2.1490002, 2.1499996, 2.151, 2.1520004, 2.1529999, 2.1540003, 2.1549997, 2.1560001, 2.1569996, 2.158, 2.1590004, 2.1599998, 2.1610003, 2.1619997, 2.163, 2.1639996, 2.165, 2.1660004, 2.1669998, 2.1680002, 2.1689997, 2.17, 2.1709995, 2.172, 2.1730003, 2.1739998, 2.1750002, 2.1759996, 2.177, 2.1780005, 2.179, 2.1800003, 2.1809998, 2.1820002, 2.1829996, 2.184, 2.1850004, 2.1859999, 2.1870003, 2.1879997, 2.1890001, 2.1899996, 2.191, 2.1920004, 2.1929998, 2.1940002, 2.1949997, 2.196, 2.1969995, 2.198, 2.1990004, 2.1999998, 2.2010002, 2.2019997, 2.203, 2.2040005, 2.205, 2.2060003, 2.2069998, 2.2080002, 2.2089996, 2.21, 2.2110004, 2.212, 2.2130003, 2.2139997, 2.2150002, 2.2159996, 2.217, 2.2180004, 2.2189999, 2.2200003, 2.2209997, 2.2220001, 2.2229996, 2.224, 2.2250004, 2.2259998, 2.2270002, 2.2279997, 2.229, 2.2299995, 2.231, 2.2320004, 2.2329998, 2.2340002, 2.2349997, 2.236, 2.2370005, 2.238, 2.2390003, 2.2399998, 2.2410002, 2.2419996, 2.243, 2.2440004, 2.245, 2.2460003, 2.2469997, 2.2480001, 2.2489996, 2.25, 2.2510004, 2.2519999, 2.2530003, 2.2539997, 2.255, 2.2559996, 2.257, 2.2580004, 2.2589998, 2.2600002, 2.2609997, 2.262, 2.2629995, 2.264, 2.2650003, 2.2659998, 2.2670002, 2.2679996, 2.269, 2.2700005, 2.271, 2.2720003, 2.2729998, 2.2740002, 2.2749996, 2.276, 2.2770004, 2.2779999, 2.2790003, 2.2799997, 2.2810001, 2.2819996, 2.283, 2.2840004, 2.2849998, 2.2860003, 2.2869997, 2.288, 2.2889996, 2.29, 2.2910004, 2.2919998, 2.2930002, 2.2939997, 2.295, 2.2959995, 2.297, 2.2980003, 2.2989998, 2.3000002, 2.3009996, 2.302, 2.3030005, 2.304, 2.3050003, 2.3059998, 2.3070002, 2.3079996, 2.309, 2.3100004, 2.3109999, 2.3120003, 2.3129997, 2.3140001, 2.3149996, 2.316, 2.3170004, 2.3179998, 2.3190002, 2.3199997, 2.321, 2.3219995, 2.323, 2.3240004, 2.3249998, 2.3260002, 2.3269997, 2.328, 2.3290005, 2.33, 2.3310003, 2.3319998, 2.3330002, 2.3339996, 2.335, 2.3360004, 2.337, 2.3380003, 2.3389997, 2.3400002, 2.3409996, 2.342, 2.3430004, 2.3439999, 2.3450003, 2.3459997, 2.3470001, 2.3479996, 2.349, 2.3500004, 2.3509998, 2.3520002, 2.3529997, 2.354, 2.3549995, 2.356, 2.3570004, 2.3579998, 2.3590002, 2.3599997, 2.361, 2.3620005, 2.363, 2.3640003, 2.3649998, 2.3660002, 2.3669996, 2.368, 2.3690004, 2.37, 2.3710003, 2.3719997, 2.3730001, 2.3739996, 2.375, 2.3760004, 2.3769999, 2.3780003, 2.3789997, 2.38, 2.3809996, 2.382, 2.3830004, 2.3839998, 2.3850002, 2.3859997, 2.387, 2.3879995, 2.389, 2.3900003, 2.3909998, 2.3920002, 2.3929996, 2.394, 2.3950005, 2.396, 2.3970003, 2.3979998, 2.3990002, 2.3999996, 2.401, 2.4020004, 2.4029999, 2.4040003, 2.4049997, 2.4060001, 2.4069996, 2.408, 2.4090004, 2.4099998, 2.4110003, 2.4119997, 2.413, 2.4139996, 2.415, 2.4160004, 2.4169998, 2.4180002, 2.4189997, 2.42, 2.4209995, 2.422, 2.4230003, 2.4239998, 2.4250002, 2.4259996, 2.427, 2.4280005, 2.429, 2.4300003, 2.4309998, 2.4320002, 2.4329996, 2.434, 2.4350004, 2.4359999, 2.4370003, 2.4379997, 2.4390001, 2.4399996, 2.441, 2.4420004, 2.4429998, 2.4440002, 2.4449997, 2.446, 2.4469995, 2.448, 2.4490004, 2.4499998, 2.4510002, 2.4519997, 2.453, 2.4540005, 2.455, 2.4560003, 2.4569998, 2.4580002, 2.4589996, 2.46, 2.4610004, 2.462, 2.4630003, 2.4639997, 2.4650002, 2.4659996, 2.467, 2.4680004, 2.4689999, 2.4700003, 2.4709997, 2.4720001, 2.4729996, 2.474, 2.4750004, 2.4759998, 2.4770002, 2.4779997, 2.479, 2.4799995, 2.481, 2.4820004, 2.4829998, 2.4840002, 2.4849997, 2.486, 2.4870005, 2.488, 2.4890003, 2.4899998, 2.4910002, 2.4919996, 2.493, 2.4940004, 2.495, 2.4960003, 2.4969997, 2.4980001, 2.4989996, 2.5, 2.5010004, 2.5019999, 2.5030003, 2.5039997, 2.505, 2.5059996, 2.507, 2.5080004, 2.5089998, 2.5100002, 2.5109997, 2.512, 2.5129995, 2.514, 2.5150003, 2.5159998, 2.5170002, 2.5179996, 2.519, 2.5200005, 2.521, 2.5220003, 2.5229998, 2.5240002, 2.5249996, 2.526, 2.5270004, 2.5279999, 2.5290003, 2.5299997, 2.5310001, 2.5319996, 2.533, 2.5340004, 2.5349998, 2.5360003, 2.5369997, 2.538, 2.5389996, 2.54, 2.5410004, 2.5419998, 2.5430002, 2.5439997, 2.545, 2.5459995, 2.547, 2.5480003, 2.5489998, 2.5500002, 2.5509996, 2.552, 2.5530005, 2.554, 2.5550003, 2.5559998, 2.5570002, 2.5579996, 2.559, 2.5600004, 2.5609999, 2.5620003, 2.5629997, 2.5640001, 2.5649996, 2.566, 2.5670004, 2.5679998, 2.5690002, 2.5699997, 2.571, 2.5719995, 2.573, 2.5740004, 2.5749998, 2.5760002, 2.5769997, 2.578, 2.5790005, 2.58, 2.5810003, 2.5819998, 2.5830002, 2.5839996, 2.585, 2.5860004, 2.587, 2.5880003, 2.5889997, 2.5900002, 2.5909996, 2.592, 2.5930004, 2.5939999, 2.5950003, 2.5959997, 2.5970001, 2.5979996, 2.599, 2.6000004, 2.6009998, 2.6020002, 2.6029997, 2.604, 2.6049995, 2.606, 2.6070004, 2.6079998, 2.6090002, 2.6099997, 2.611, 2.6120005, 2.613, 2.6140003, 2.6149998, 2.6160002, 2.6169996, 2.618, 2.6190004, 2.62, 2.6210003, 2.6219997, 2.6230001, 2.6239996, 2.625, 2.6260004, 2.6269999, 2.6280003, 2.6289997, 2.63, 2.6309996, 2.632, 2.6330004, 2.6339998, 2.6350002, 2.6359997, 2.637, 2.6379995, 2.639, 2.6400003, 2.6409998, 2.6420002, 2.6429996, 2.644, 2.6450005, 2.646, 2.6470003, 2.6479998, 2.6490002, 2.6499996, 2.651, 2.6520004, 2.6529999, 2.6540003, 2.6549997, 2.6560001, 2.6569996, 2.658, 2.6590004, 2.6599998, 2.6610003, 2.6619997, 2.663, 2.6639996, 2.665, 2.6660004, 2.6669998, 2.6680002, 2.6689997, 2.67, 2.6709995, 2.672, 2.6730003, 2.6739998, 2.6750002, 2.6759996, 2.677, 2.6780005, 2.679, 2.6800003, 2.6809998, 2.6820002, 2.6829996, 2.684, 2.6850004, 2.6859999, 2.6870003, 2.6879997, 2.6890001, 2.6899996, 2.691, 2.6920004, 2.6929998, 2.6940002, 2.6949997, 2.696, 2.6969995, 2.698, 2.6990004, 2.6999998, 2.7010002, 2.7019997, 2.703, 2.7040005, 2.705, 2.7060003, 2.7069998, 2.7080002, 2.7089996, 2.71, 2.7110004, 2.712, 2.7130003, 2.7139997, 2.7150002, 2.7159996, 2.717, 2.7180004, 2.7189999, 2.7200003, 2.7209997, 2.7220001, 2.7229996, 2.724, 2.7250004, 2.7259998, 2.7270002, 2.7279997, 2.729, 2.7299995, 2.731, 2.7320004, 2.7329998, 2.7340002, 2.7349997, 2.736, 2.7370005, 2.738, 2.7390003, 2.7399998, 2.7410002, 2.7419996, 2.743, 2.7440004, 2.745, 2.7460003, 2.7469997, 2.7480001, 2.7489996, 2.75, 2.7510004, 2.7519999, 2.7530003, 2.7539997, 2.755, 2.7559996, 2.757, 2.7580004, 2.7589998, 2.7600002, 2.7609997, 2.762, 2.7629995, 2.764, 2.7650003, 2.7659998, 2.7670002, 2.7679996, 2.769, 2.7700005, 2.771, 2.7720003, 2.7729998, 2.7740002, 2.7749996, 2.776, 2.7770004, 2.7779999, 2.7790003, 2.7799997, 2.7810001, 2.7819996, 2.783, 2.7840004, 2.7849998, 2.7860003, 2.7869997, 2.788, 2.7889996, 2.79, 2.7910004, 2.7919998, 2.7930002, 2.7939997, 2.795, 2.7959995, 2.797, 2.7980003, 2.7989998, 2.8000002, 2.8009996, 2.802, 2.8030005, 2.804, 2.8050003, 2.8059998, 2.8070002, 2.8079996, 2.809, 2.8100004, 2.8109999, 2.8120003, 2.8129997, 2.8140001, 2.8149996, 2.816, 2.8170004, 2.8179998, 2.8190002, 2.8199997, 2.821, 2.8219995, 2.823, 2.8240004, 2.8249998, 2.8260002, 2.8269997, 2.828, 2.8290005, 2.83, 2.8310003, 2.8319998, 2.8330002, 2.8339996, 2.835, 2.8360004, 2.837, 2.8380003, 2.8389997, 2.8400002, 2.8409996, 2.842, 2.8430004, 2.8439999, 2.8450003, 2.8459997, 2.8470001, 2.8479996, 2.849, 2.8500004, 2.8509998, 2.8520002, 2.8529997, 2.854, 2.8549995, 2.856, 2.8570004, 2.8579998, 2.8590002, 2.8599997, 2.861, 2.8620005, 2.863, 2.8640003, 2.8649998, 2.8660002, 2.8669996, 2.868, 2.8690004, 2.87, 2.8710003, 2.8719997, 2.8730001, 2.8739996, 2.875, 2.8760004, 2.8769999, 2.8780003, 2.8789997, 2.88, 2.8809996, 2.882, 2.8830004, 2.8839998, 2.8850002, 2.8859997, 2.887, 2.8879995, 2.889, 2.8900003, 2.8909998, 2.8920002, 2.8929996, 2.894, 2.8950005, 2.896, 2.8970003, 2.8979998, 2.8990002, 2.8999996, 2.901, 2.9020004, 2.9029999, 2.9040003, 2.9049997, 2.9060001, 2.9069996, 2.908, 2.9090004, 2.9099998, 2.9110003, 2.9119997, 2.913, 2.9139996, 2.915, 2.9160004, 2.9169998, 2.9180002, 2.9189997, 2.92, 2.9209995, 2.922, 2.9230003, 2.9239998, 2.9250002, 2.9259996, 2.927, 2.9280005, 2.929, 2.9300003, 2.9309998, 2.9320002, 2.9329996, 2.934, 2.9350004, 2.9359999, 2.9370003, 2.9379997, 2.9390001, 2.9399996, 2.941, 2.9420004, 2.9429998, 2.9440002, 2.9449997, 2.946, 2.9469995, 2.948, 2.9490004, 2.9499998, 2.9510002, 2.9519997, 2.953, 2.9540005, 2.955, 2.9560003, 2.9569998, 2.9580002, 2.9589996, 2.96, 2.9610004, 2.962, 2.9630003, 2.9639997, 2.9650002, 2.9659996, 2.967, 2.9680004, 2.9689999, 2.9700003, 2.9709997, 2.9720001, 2.9729996, 2.974, 2.9750004, 2.9759998, 2.9770002, 2.9779997, 2.979, 2.9799995, 2.981, 2.9820004, 2.9829998, 2.9840002, 2.9849997, 2.986, 2.9870005, 2.988, 2.9890003, 2.9899998, 2.9910002, 2.9919996, 2.993, 2.9940004, 2.995, 2.9960003, 2.9969997, 2.9980001, 2.9989996, 3.0, 3.0010004, 3.0019999, 3.0030003, 3.0039997, 3.005, 3.0059996, 3.007, 3.0080004, 3.0089998, 3.0100002, 3.0109997, 3.012, 3.0129995, 3.014, 3.0150003, 3.0159998, 3.0170002, 3.0179996, 3.019, 3.0200005, 3.021, 3.0220003, 3.0229998, 3.0240002, 3.0249996, 3.026, 3.0270004, 3.0279999, 3.0290003, 3.0299997, 3.0310001, 3.0319996, 3.033, 3.0340004, 3.0349998, 3.0360003, 3.0369997, 3.038, 3.0389996, 3.04, 3.0410004, 3.0419998, 3.0430002, 3.0439997, 3.045, 3.0459995, 3.047, 3.0480003, 3.0489998, 3.0500002, 3.0509996, 3.052, 3.0530005, 3.054, 3.0550003, 3.0559998, 3.0570002, 3.0579996, 3.059, 3.0600004, 3.0609999, 3.0620003, 3.0629997, 3.0640001, 3.0649996, 3.066, 3.0670004, 3.0679998, 3.0690002, 3.0699997, 3.071, 3.0719995, 3.073, 3.0740004, 3.0749998, 3.0760002, 3.0769997, 3.078, 3.0790005, 3.08, 3.0810003, 3.0819998, 3.0830002, 3.0839996, 3.085, 3.0860004, 3.087, 3.0880003, 3.0889997, 3.0900002, 3.0909996, 3.092, 3.0930004, 3.0939999, 3.0950003, 3.0959997, 3.0970001, 3.0979996, 3.099, 3.1000004, 3.1009998, 3.1020002, 3.1029997, 3.104, 3.1049995, 3.106, 3.1070004, 3.1079998, 3.1090002, 3.1099997, 3.111, 3.1120005, 3.113, 3.1140003, 3.1149998, 3.1160002, 3.1169996, 3.118, 3.1190004, 3.12, 3.1210003, 3.1219997, 3.1230001, 3.1239996, 3.125, 3.1260004, 3.1269999, 3.1280003, 3.1289997, 3.13, 3.1309996, 3.132, 3.1330004, 3.1339998, 3.1350002, 3.1359997, 3.137, 3.1379995, 3.139, 3.1400003, 3.1409998, 3.1420002, 3.1429996, 3.144, 3.1450005, 3.146, 3.1470003, 3.1479998, 3.1490002, 3.1499996, 3.151, 3.1520004, 3.1529999, 3.1540003, 3.1549997, 3.1560001, 3.1569996, 3.158, 3.1590004, 3.1599998, 3.1610003, 3.1619997, 3.163, 3.1639996, 3.165, 3.1660004, 3.1669998, 3.1680002, 3.1689997, 3.17, 3.1709995, 3.172, 3.1730003, 3.1739998, 3.1750002, 3.1759996, 3.177, 3.1780005, 3.179, 3.1800003, 3.1809998, 3.1820002, 3.1829996, 3.184, 3.1850004, 3.1859999, 3.1870003, 3.1879997, 3.1890001, 3.1899996, 3.191, 3.1920004, 3.1929998, 3.1940002, 3.1949997, 3.196, 3.1969995, 3.198, 3.1990004, 3.1999998, 3.2010002, 3.2019997, 3.203, 3.2040005, 3.205, 3.2060003, 3.2069998, 3.2080002, 3.2089996, 3.21, 3.2110004, 3.212, 3.2130003, 3.2139997, 3.2150002, 3.2159996, 3.217, 3.2180004, 3.2189999, 3.2200003, 3.2209997, 3.2220001, 3.2229996, 3.224, 3.2250004, 3.2259998, 3.2270002, 3.2279997, 3.229, 3.2299995, 3.231, 3.2320004, 3.2329998, 3.2340002, 3.2349997, 3.236, 3.2370005, 3.238, 3.2390003, 3.2399998, 3.2410002, 3.2419996, 3.243, 3.2440004, 3.245, 3.2460003, 3.2469997, 3.2480001, 3.2489996, 3.25, 3.2510004, 3.2519999, 3.2530003, 3.2539997, 3.255, 3.2559996, 3.257, 3.2580004, 3.2589998, 3.2600002, 3.2609997, 3.262, 3.2629995, 3.264, 3.2650003, 3.2659998, 3.2670002, 3.2679996, 3.269, 3.2700005, 3.271, 3.2720003, 3.2729998, 3.2740002, 3.2749996, 3.276, 3.2770004, 3.2779999, 3.2790003, 3.2799997, 3.2810001, 3.2819996, 3.283, 3.2840004, 3.2849998, 3.2860003, 3.2869997, 3.288, 3.2889996, 3.29, 3.2910004, 3.2919998, 3.2930002, 3.2939997, 3.295, 3.2959995, 3.297, 3.2980003, 3.2989998, 3.3000002, 3.3009996, 3.302, 3.3030005, 3.304, 3.3050003, 3.3059998, 3.3070002, 3.3079996, 3.309, 3.3100004, 3.3109999, 3.3120003, 3.3129997, 3.3140001, 3.3149996, 3.316, 3.3170004, 3.3179998, 3.3190002, 3.3199997, 3.321, 3.3219995, 3.323, 3.3240004, 3.3249998, 3.3260002, 3.3269997, 3.328, 3.3290005, 3.33, 3.3310003, 3.3319998, 3.3330002, 3.3339996, 3.335, 3.3360004, 3.337, 3.3380003, 3.3389997, 3.3400002, 3.3409996, 3.342, 3.3430004, 3.3439999, 3.3450003, 3.3459997, 3.3470001, 3.3479996, 3.349, 3.3500004, 3.3509998, 3.3520002, 3.3529997, 3.354, 3.3549995, 3.356, 3.3570004, 3.3579998, 3.3590002, 3.3599997, 3.361, 3.3620005, 3.363, 3.3640003, 3.3649998, 3.3660002, 3.3669996, 3.368, 3.3690004, 3.37, 3.3710003, 3.3719997, 3.3730001, 3.3739996, 3.375, 3.3760004, 3.3769999, 3.3780003, 3.3789997, 3.38, 3.3809996, 3.382, 3.3830004, 3.3839998, 3.3850002, 3.3859997, 3.387, 3.3879995, 3.389, 3.3900003, 3.3909998, 3.3920002, 3.3929996, 3.394, 3.3950005, 3.396, 3.3970003, 3.3979998, 3.3990002, 3.3999996, 3.401, 3.4020004, 3.4029999, 3.4040003, 3.4049997, 3.4060001, 3.4069996, 3.408, 3.4090004, 3.4099998, 3.4110003, 3.4119997, 3.413, 3.4139996, 3.415, 3.4160004, 3.4169998, 3.4180002, 3.4189997, 3.42, 3.4209995, 3.422, 3.4230003, 3.4239998, 3.4250002, 3.4259996, 3.427, 3.4280005, 3.429, 3.4300003, 3.4309998, 3.4320002, 3.4329996, 3.434, 3.4350004, 3.4359999, 3.4370003, 3.4379997, 3.4390001, 3.4399996, 3.441, 3.4420004, 3.4429998, 3.4440002, 3.4449997, 3.446, 3.4469995, 3.448, 3.4490004, 3.4499998, 3.4510002, 3.4519997, 3.453, 3.4540005, 3.455, 3.4560003, 3.4569998, 3.4580002, 3.4589996, 3.46, 3.4610004, 3.462, 3.4630003, 3.4639997, 3.4650002, 3.4659996, 3.467, 3.4680004, 3.4689999, 3.4700003, 3.4709997, 3.4720001, 3.4729996, 3.474, 3.4750004, 3.4759998, 3.4770002, 3.4779997, 3.479, 3.4799995, 3.481, 3.4820004, 3.4829998, 3.4840002, 3.4849997, 3.486, 3.4870005, 3.488, 3.4890003, 3.4899998, 3.4910002, 3.4919996, 3.493, 3.4940004, 3.495, 3.4960003, 3.4969997, 3.4980001, 3.4989996, 3.5, 3.5010004, 3.5019999, 3.5030003, 3.5039997, 3.505, 3.5059996, 3.507, 3.5080004, 3.5089998, 3.5100002, 3.5109997, 3.512, 3.5129995, 3.514, 3.5150003, 3.5159998, 3.5170002, 3.5179996, 3.519, 3.5200005, 3.521, 3.5220003, 3.5229998, 3.5240002, 3.5249996, 3.526, 3.5270004, 3.5279999, 3.5290003, 3.5299997, 3.5310001, 3.5319996, 3.533, 3.5340004, 3.5349998, 3.5360003, 3.5369997, 3.538, 3.5389996, 3.54, 3.5410004, 3.5419998, 3.5430002, 3.5439997, 3.545, 3.5459995, 3.547, 3.5480003, 3.5489998, 3.5500002, 3.5509996, 3.552, 3.5530005, 3.554, 3.5550003, 3.5559998, 3.5570002, 3.5579996, 3.559, 3.5600004, 3.5609999, 3.5620003, 3.5629997, 3.5640001, 3.5649996, 3.566, 3.5670004, 3.5679998, 3.5690002, 3.5699997, 3.571, 3.5719995, 3.573, 3.5740004, 3.5749998, 3.5760002, 3.5769997, 3.578, 3.5790005, 3.58, 3.5810003, 3.5819998, 3.5830002, 3.5839996, 3.585, 3.5860004, 3.587, 3.5880003, 3.5889997, 3.5900002, 3.5909996, 3.592, 3.5930004, 3.5939999, 3.5950003, 3.5959997, 3.5970001, 3.5979996, 3.599, 3.6000004, 3.6009998, 3.6020002, 3.6029997, 3.604, 3.6049995, 3.606, 3.6070004, 3.6079998, 3.6090002, 3.6099997, 3.611, 3.6120005, 3.613, 3.6140003, 3.6149998, 3.6160002, 3.6169996, 3.618, 3.6190004, 3.62, 3.6210003, 3.6219997, 3.6230001, 3.6239996, 3.625, 3.6260004, 3.6269999, 3.6280003, 3.6289997, 3.63, 3.6309996, 3.632, 3.6330004, 3.6339998, 3.6350002, 3.6359997, 3.637, 3.6379995, 3.639, 3.6400003, 3.6409998, 3.6420002, 3.6429996, 3.644, 3.6450005, 3.646, 3.6470003, 3.6479998, 3.6490002, 3.6499996, 3.651, 3.6520004, 3.6529999, 3.6540003, 3.6549997, 3.6560001, 3.6569996, 3.658, 3.6590004, 3.6599998, 3.6610003, 3.6619997, 3.663, 3.6639996, 3.665, 3.6660004, 3.6669998, 3.6680002, 3.6689997, 3.67, 3.6709995, 3.672, 3.6730003, 3.6739998, 3.6750002, 3.6759996, 3.677, 3.6780005, 3.679, 3.6800003, 3.6809998, 3.6820002, 3.6829996, 3.684, 3.6850004, 3.6859999, 3.6870003, 3.6879997, 3.6890001, 3.6899996, 3.691, 3.6920004, 3.6929998, 3.6940002, 3.6949997, 3.696, 3.6969995, 3.698, 3.6990004, 3.6999998, 3.7010002, 3.7019997, 3.703, 3.7040005, 3.705, 3.7060003, 3.7069998, 3.7080002, 3.7089996, 3.71, 3.7110004, 3.712, 3.7130003, 3.7139997, 3.7150002, 3.7159996, 3.717, 3.7180004, 3.7189999, 3.7200003, 3.7209997, 3.7220001, 3.7229996, 3.724, 3.7250004, 3.7259998, 3.7270002, 3.7279997, 3.729, 3.7299995, 3.731, 3.7320004, 3.7329998, 3.7340002, 3.7349997, 3.736, 3.7370005, 3.738, 3.7390003, 3.7399998, 3.7410002, 3.7419996, 3.743, 3.7440004, 3.745, 3.7460003, 3.7469997, 3.7480001, 3.7489996, 3.75, 3.7510004, 3.7519999, 3.7530003, 3.7539997, 3.755, 3.7559996, 3.757, 3.7580004, 3.7589998, 3.7600002, 3.7609997, 3.762, 3.7629995, 3.764, 3.7650003, 3.7659998, 3.7670002, 3.7679996, 3.769, 3.7700005, 3.771, 3.7720003, 3.7729998, 3.7740002, 3.7749996, 3.776, 3.7770004, 3.7779999, 3.7790003, 3.7799997, 3.7810001, 3.7819996, 3.783, 3.7840004, 3.7849998, 3.7860003, 3.7869997, 3.788, 3.7889996, 3.79, 3.7910004, 3.7919998, 3.7930002, 3.7939997, 3.795, 3.7959995, 3.797, 3.7980003, 3.7989998, 3.8000002, 3.8009996, 3.802, 3.8030005, 3.804, 3.8050003, 3.8059998, 3.8070002, 3.8079996, 3.809, 3.8100004, 3.8109999, 3.8120003, 3.8129997, 3.8140001, 3.8149996, 3.816, 3.8170004, 3.8179998, 3.8190002, 3.8199997, 3.821, 3.8219995, 3.823, 3.8240004, 3.8249998, 3.8260002, 3.8269997, 3.828, 3.8290005, 3.83, 3.8310003, 3.8319998, 3.8330002, 3.8339996, 3.835, 3.8360004, 3.837, 3.8380003, 3.8389997, 3.8400002, 3.8409996, 3.842, 3.8430004, 3.8439999, 3.8450003, 3.8459997, 3.8470001, 3.8479996, 3.849, 3.8500004, 3.8509998, 3.8520002, 3.8529997, 3.854, 3.8549995, 3.856, 3.8570004, 3.8579998, 3.8590002, 3.8599997, 3.861, 3.8620005, 3.863, 3.8640003, 3.8649998, 3.8660002, 3.8669996, 3.868, 3.8690004, 3.87, 3.8710003, 3.8719997, 3.8730001, 3.8739996, 3.875, 3.8760004, 3.8769999, 3.8780003, 3.8789997, 3.88, 3.8809996, 3.882, 3.8830004, 3.8839998, 3.8850002, 3.8859997, 3.887, 3.8879995, 3.889, 3.8900003, 3.8909998, 3.8920002, 3.8929996, 3.894, 3.8950005, 3.896, 3.8970003, 3.8979998, 3.8990002, 3.8999996, 3.901, 3.9020004, 3.9029999, 3.9040003, 3.9049997, 3.9060001, 3.9069996, 3.908, 3.9090004, 3.9099998, 3.9110003, 3.9119997, 3.913, 3.9139996, 3.915, 3.9160004, 3.9169998, 3.9180002, 3.9189997, 3.92, 3.9209995, 3.922, 3.9230003, 3.9239998, 3.9250002, 3.9259996, 3.927, 3.9280005, 3.929, 3.9300003, 3.9309998, 3.9320002, 3.9329996, 3.934, 3.9350004, 3.9359999, 3.9370003, 3.9379997, 3.9390001, 3.9399996, 3.941, 3.9420004, 3.9429998, 3.9440002, 3.9449997, 3.946, 3.9469995, 3.948, 3.9490004, 3.9499998, 3.9510002, 3.9519997, 3.953, 3.9540005, 3.955, 3.9560003, 3.9569998, 3.9580002, 3.9589996, 3.96, 3.9610004, 3.962, 3.9630003, 3.9639997, 3.9650002, 3.9659996, 3.967, 3.9680004, 3.9689999, 3.9700003, 3.9709997, 3.9720001, 3.9729996, 3.974, 3.9750004, 3.9759998, 3.9770002, 3.9779997, 3.979, 3.9799995, 3.981, 3.9820004, 3.9829998, 3.9840002, 3.9849997, 3.986, 3.9870005, 3.988, 3.9890003, 3.9899998, 3.9910002, 3.9919996, 3.993, 3.9940004, 3.995, 3.9960003, 3.9969997, 3.9980001, 3.9989996, 4.0, 4.0010004, 4.002, 4.0030003, 4.0039997, 4.005, 4.0059996, 4.007, 4.0080004, 4.009, 4.01, 4.0109997, 4.012, 4.0129995, 4.014, 4.0150003, 4.016, 4.017, 4.0179996, 4.019, 4.0200005, 4.021, 4.0220003, 4.023, 4.024, 4.0249996, 4.026, 4.0270004, 4.028, 4.0290003, 4.0299997, 4.031, 4.0319996, 4.033, 4.0340004, 4.035, 4.0360003, 4.0369997, 4.038, 4.0389996, 4.04, 4.0410004, 4.042, 4.043, 4.0439997, 4.045, 4.0459995, 4.047, 4.0480003, 4.049, 4.05, 4.0509996, 4.052, 4.0530005, 4.054, 4.0550003, 4.0559998, 4.057, 4.0579996, 4.059, 4.0600004, 4.061, 4.0620003, 4.0629997, 4.064, 4.0649996, 4.066, 4.0670004, 4.068, 4.0690002, 4.0699997, 4.071, 4.0719995, 4.073, 4.0740004, 4.075, 4.076, 4.0769997, 4.078, 4.0790005, 4.08, 4.0810003, 4.082, 4.083, 4.0839996, 4.085, 4.0860004, 4.087, 4.0880003, 4.0889997, 4.09, 4.0909996, 4.092, 4.0930004, 4.094, 4.0950003, 4.0959997, 4.097, 4.0979996, 4.099, 4.1000004, 4.101, 4.102, 4.1029997, 4.104, 4.1049995, 4.106, 4.1070004, 4.108, 4.109, 4.1099997, 4.111, 4.1120005, 4.113, 4.1140003, 4.115, 4.116, 4.1169996, 4.118, 4.1190004, 4.12, 4.1210003, 4.1219997, 4.123, 4.1239996, 4.125, 4.1260004, 4.127, 4.1280003, 4.1289997, 4.13, 4.1309996, 4.132, 4.1330004, 4.134, 4.135, 4.1359997, 4.137, 4.1379995, 4.139, 4.1400003, 4.141, 4.142, 4.1429996, 4.144, 4.1450005, 4.146, 4.1470003, 4.148, 4.149, 4.1499996, 4.151, 4.1520004, 4.153, 4.1540003, 4.1549997, 4.156, 4.1569996, 4.158, 4.1590004, 4.16, 4.1610003, 4.1619997, 4.163, 4.1639996, 4.165, 4.1660004, 4.167, 4.168, 4.1689997, 4.17, 4.1709995, 4.172, 4.1730003, 4.174, 4.175, 4.1759996, 4.177, 4.1780005, 4.179, 4.1800003, 4.1809998, 4.182, 4.1829996, 4.184, 4.1850004, 4.186, 4.1870003, 4.1879997, 4.189, 4.1899996, 4.191, 4.1920004, 4.193, 4.1940002, 4.1949997, 4.196, 4.1969995, 4.198, 4.1990004, 4.2, 4.201, 4.2019997, 4.203, 4.2040005, 4.205, 4.2060003, 4.207, 4.208, 4.2089996, 4.21, 4.2110004, 4.212, 4.2130003, 4.2139997, 4.215, 4.2159996, 4.217, 4.2180004, 4.219, 4.2200003, 4.2209997, 4.222, 4.2229996, 4.224, 4.2250004, 4.226, 4.227, 4.2279997, 4.229, 4.2299995, 4.231, 4.2320004, 4.233, 4.234, 4.2349997, 4.236, 4.2370005, 4.238, 4.2390003, 4.24, 4.241, 4.2419996, 4.243, 4.2440004, 4.245, 4.2460003, 4.2469997, 4.248, 4.2489996, 4.25, 4.2510004, 4.252, 4.2530003, 4.2539997, 4.255, 4.2559996, 4.257, 4.2580004, 4.259, 4.26, 4.2609997, 4.262, 4.2629995, 4.264, 4.2650003, 4.266, 4.267, 4.2679996, 4.269, 4.2700005, 4.271, 4.2720003, 4.273, 4.274, 4.2749996, 4.276, 4.2770004, 4.278, 4.2790003, 4.2799997, 4.281, 4.2819996, 4.283, 4.2840004, 4.285, 4.2860003, 4.2869997, 4.288, 4.2889996, 4.29, 4.2910004, 4.292, 4.293, 4.2939997, 4.295, 4.2959995, 4.297, 4.2980003, 4.299, 4.3, 4.3009996, 4.302, 4.3030005, 4.304, 4.3050003, 4.3059998, 4.307, 4.3079996, 4.309, 4.3100004, 4.311, 4.3120003, 4.3129997, 4.314, 4.3149996, 4.316, 4.3170004, 4.318, 4.3190002, 4.3199997, 4.321, 4.3219995, 4.323, 4.3240004, 4.325, 4.326, 4.3269997, 4.328, 4.3290005, 4.33, 4.3310003, 4.332, 4.333, 4.3339996, 4.335, 4.3360004, 4.337, 4.3380003, 4.3389997, 4.34, 4.3409996, 4.342, 4.3430004, 4.344, 4.3450003, 4.3459997, 4.347, 4.3479996, 4.349, 4.3500004, 4.351, 4.352, 4.3529997, 4.354, 4.3549995, 4.356, 4.3570004, 4.358, 4.359, 4.3599997, 4.361, 4.3620005, 4.363, 4.3640003, 4.365, 4.366, 4.3669996, 4.368, 4.3690004, 4.37, 4.3710003, 4.3719997, 4.373, 4.3739996, 4.375, 4.3760004, 4.377, 4.3780003, 4.3789997, 4.38, 4.3809996, 4.382, 4.3830004, 4.384, 4.385, 4.3859997, 4.387, 4.3879995, 4.389, 4.3900003, 4.391, 4.392, 4.3929996, 4.394, 4.3950005, 4.396, 4.3970003, 4.398, 4.399, 4.3999996, 4.401, 4.4020004, 4.403, 4.4040003, 4.4049997, 4.406, 4.4069996, 4.408, 4.4090004, 4.41, 4.4110003, 4.4119997, 4.413, 4.4139996, 4.415, 4.4160004, 4.417, 4.418, 4.4189997, 4.42, 4.4209995, 4.422, 4.4230003, 4.424, 4.425, 4.4259996, 4.427, 4.4280005, 4.429, 4.4300003, 4.4309998, 4.432, 4.4329996, 4.434, 4.4350004, 4.436, 4.4370003, 4.4379997, 4.439, 4.4399996, 4.441, 4.4420004, 4.443, 4.4440002, 4.4449997, 4.446, 4.4469995, 4.448, 4.4490004, 4.45, 4.451, 4.4519997, 4.453, 4.4540005, 4.455, 4.4560003, 4.457, 4.458, 4.4589996, 4.46, 4.4610004, 4.462, 4.4630003, 4.4639997, 4.465, 4.4659996, 4.467, 4.4680004, 4.469, 4.4700003, 4.4709997, 4.472, 4.4729996, 4.474, 4.4750004, 4.476, 4.477, 4.4779997, 4.479, 4.4799995, 4.481, 4.4820004, 4.483, 4.484, 4.4849997, 4.486, 4.4870005, 4.488, 4.4890003, 4.49, 4.491, 4.4919996, 4.493, 4.4940004, 4.495, 4.4960003, 4.4969997, 4.498, 4.4989996, 4.5, 4.5010004, 4.502, 4.5030003, 4.5039997, 4.505, 4.5059996, 4.507, 4.5080004, 4.509, 4.51, 4.5109997, 4.512, 4.5129995, 4.514, 4.5150003, 4.516, 4.517, 4.5179996, 4.519, 4.5200005, 4.521, 4.5220003, 4.523, 4.524, 4.5249996, 4.526, 4.5270004, 4.528, 4.5290003, 4.5299997, 4.531, 4.5319996, 4.533, 4.5340004, 4.535, 4.5360003, 4.5369997, 4.538, 4.5389996, 4.54, 4.5410004, 4.542, 4.543, 4.5439997, 4.545, 4.5459995, 4.547, 4.5480003, 4.549, 4.55, 4.5509996, 4.552, 4.5530005, 4.554, 4.5550003, 4.5559998, 4.557, 4.5579996, 4.559, 4.5600004, 4.561, 4.5620003, 4.5629997, 4.564, 4.5649996, 4.566, 4.5670004, 4.568, 4.5690002, 4.5699997, 4.571, 4.5719995, 4.573, 4.5740004, 4.575, 4.576, 4.5769997, 4.578, 4.5790005, 4.58, 4.5810003, 4.582, 4.583, 4.5839996, 4.585, 4.5860004, 4.587, 4.5880003, 4.5889997, 4.59, 4.5909996, 4.592, 4.5930004, 4.594, 4.5950003, 4.5959997, 4.597, 4.5979996, 4.599, 4.6000004, 4.601, 4.602, 4.6029997, 4.604, 4.6049995, 4.606, 4.6070004, 4.608, 4.609, 4.6099997, 4.611, 4.6120005, 4.613, 4.6140003, 4.615, 4.616, 4.6169996, 4.618, 4.6190004, 4.62, 4.6210003, 4.6219997, 4.623, 4.6239996, 4.625, 4.6260004, 4.627, 4.6280003, 4.6289997, 4.63, 4.6309996, 4.632, 4.6330004, 4.634, 4.635, 4.6359997, 4.637, 4.6379995, 4.639, 4.6400003, 4.641, 4.642, 4.6429996, 4.644, 4.6450005, 4.646, 4.6470003, 4.648, 4.649, 4.6499996, 4.651, 4.6520004, 4.653, 4.6540003, 4.6549997, 4.656, 4.6569996, 4.658, 4.6590004, 4.66, 4.6610003, 4.6619997, 4.663, 4.6639996, 4.665, 4.6660004, 4.667, 4.668, 4.6689997, 4.67, 4.6709995, 4.672, 4.6730003, 4.674, 4.675, 4.6759996, 4.677, 4.6780005, 4.679, 4.6800003, 4.6809998, 4.682, 4.6829996, 4.684, 4.6850004, 4.686, 4.6870003, 4.6879997, 4.689, 4.6899996, 4.691, 4.6920004, 4.693, 4.6940002, 4.6949997, 4.696, 4.6969995, 4.698, 4.6990004, 4.7, 4.701, 4.7019997, 4.703, 4.7040005, 4.705, 4.7060003, 4.707, 4.708, 4.7089996, 4.71, 4.7110004, 4.712, 4.7130003, 4.7139997, 4.715, 4.7159996, 4.717, 4.7180004, 4.719, 4.7200003, 4.7209997, 4.722, 4.7229996, 4.724, 4.7250004, 4.726, 4.727, 4.7279997, 4.729, 4.7299995, 4.731, 4.7320004, 4.733, 4.734, 4.7349997, 4.736, 4.7370005, 4.738, 4.7390003, 4.74, 4.741, 4.7419996, 4.743, 4.7440004, 4.745, 4.7460003, 4.7469997, 4.748, 4.7489996, 4.75, 4.7510004, 4.752, 4.7530003, 4.7539997, 4.755, 4.7559996, 4.757, 4.7580004, 4.759, 4.76, 4.7609997, 4.762, 4.7629995, 4.764, 4.7650003, 4.766, 4.767, 4.7679996, 4.769, 4.7700005, 4.771, 4.7720003, 4.773, 4.774, 4.7749996, 4.776, 4.7770004, 4.778, 4.7790003, 4.7799997, 4.781, 4.7819996, 4.783, 4.7840004, 4.785, 4.7860003, 4.7869997, 4.788, 4.7889996, 4.79, 4.7910004, 4.792, 4.793, 4.7939997, 4.795, 4.7959995, 4.797, 4.7980003, 4.799, 4.8, 4.8009996, 4.802, 4.8030005, 4.804, 4.8050003, 4.8059998, 4.807, 4.8079996, 4.809, 4.8100004, 4.811, 4.8120003, 4.8129997, 4.814, 4.8149996, 4.816, 4.8170004, 4.818, 4.8190002, 4.8199997, 4.821, 4.8219995, 4.823, 4.8240004, 4.825, 4.826, 4.8269997, 4.828, 4.8290005, 4.83, 4.8310003, 4.832, 4.833, 4.8339996, 4.835, 4.8360004, 4.837, 4.8380003, 4.8389997, 4.84, 4.8409996, 4.842, 4.8430004, 4.844, 4.8450003, 4.8459997, 4.847, 4.8479996, 4.849, 4.8500004, 4.851, 4.852, 4.8529997, 4.854, 4.8549995, 4.856, 4.8570004, 4.858, 4.859, 4.8599997, 4.861, 4.8620005, 4.863, 4.8640003, 4.865, 4.866, 4.8669996, 4.868, 4.8690004, 4.87, 4.8710003, 4.8719997, 4.873, 4.8739996, 4.875, 4.8760004, 4.877, 4.8780003, 4.8789997, 4.88, 4.8809996, 4.882, 4.8830004, 4.884, 4.885, 4.8859997, 4.887, 4.8879995, 4.889, 4.8900003, 4.891, 4.892, 4.8929996, 4.894, 4.8950005, 4.896, 4.8970003, 4.898, 4.899, 4.8999996, 4.901, 4.9020004, 4.903, 4.9040003, 4.9049997, 4.906, 4.9069996, 4.908, 4.9090004, 4.91, 4.9110003, 4.9119997, 4.913, 4.9139996, 4.915, 4.9160004, 4.917, 4.918, 4.9189997, 4.92, 4.9209995, 4.922, 4.9230003, 4.924, 4.925, 4.9259996, 4.927, 4.9280005, 4.929, 4.9300003, 4.9309998, 4.932, 4.9329996, 4.934, 4.9350004, 4.936, 4.9370003, 4.9379997, 4.939, 4.9399996, 4.941, 4.9420004, 4.943, 4.9440002, 4.9449997, 4.946, 4.9469995, 4.948, 4.9490004, 4.95, 4.951, 4.9519997, 4.953, 4.9540005, 4.955, 4.9560003, 4.957, 4.958, 4.9589996, 4.96, 4.9610004, 4.962, 4.9630003, 4.9639997, 4.965, 4.9659996, 4.967, 4.9680004, 4.969, 4.9700003, 4.9709997, 4.972, 4.9729996, 4.974, 4.9750004, 4.976, 4.977, 4.9779997, 4.979, 4.9799995, 4.981, 4.9820004, 4.983, 4.984, 4.9849997, 4.986, 4.9870005, 4.988, 4.9890003, 4.99, 4.991, 4.9919996, 4.993, 4.9940004, 4.995, 4.9960003, 4.9969997, 4.998, 4.9989996, 5.0, 5.0010004, 5.002, 5.0030003, 5.0039997, 5.005, 5.0059996, 5.007, 5.0080004, 5.009, 5.01, 5.0109997, 5.012, 5.0129995, 5.014, 5.0150003, 5.016, 5.017, 5.0179996, 5.019, 5.0200005, 5.021, 5.0220003, 5.023, 5.024, 5.0249996, 5.026, 5.0270004, 5.028, 5.0290003, 5.0299997, 5.031, 5.0319996, 5.033, 5.0340004, 5.035, 5.0360003, 5.0369997, 5.038, 5.0389996, 5.04, 5.0410004, 5.042, 5.043, 5.0439997, 5.045, 5.0459995, 5.047, 5.0480003, 5.049, 5.05, 5.0509996, 5.052, 5.0530005, 5.054, 5.0550003, 5.0559998, 5.057, 5.0579996, 5.059, 5.0600004, 5.061, 5.0620003, 5.0629997, 5.064, 5.0649996, 5.066, 5.0670004, 5.068, 5.0690002, 5.0699997, 5.071, 5.0719995, 5.073, 5.0740004, 5.075, 5.076, 5.0769997, 5.078, 5.0790005, 5.08, 5.0810003, 5.082, 5.083, 5.0839996, 5.085, 5.0860004, 5.087, 5.0880003, 5.0889997, 5.09, 5.0909996, 5.092, 5.0930004, 5.094, 5.0950003, 5.0959997, 5.097, 5.0979996, 5.099, 5.1000004, 5.101, 5.102, 5.1029997, 5.104, 5.1049995, 5.106, 5.1070004, 5.108, 5.109, 5.1099997, 5.111, 5.1120005, 5.113, 5.1140003, 5.115, 5.116, 5.1169996, 5.118, 5.1190004, 5.12, 5.1210003, 5.1219997, 5.123, 5.1239996, 5.125, 5.1260004, 5.127, 5.1280003, 5.1289997, 5.13, 5.1309996, 5.132, 5.1330004, 5.134, 5.135, 5.1359997, 5.137, 5.1379995, 5.139, 5.1400003, 5.141, 5.142, 5.1429996, 5.144, 5.1450005, 5.146, 5.1470003, 5.148, 5.149, 5.1499996, 5.151, 5.1520004, 5.153, 5.1540003, 5.1549997, 5.156, 5.1569996, 5.158, 5.1590004, 5.16, 5.1610003, 5.1619997, 5.163, 5.1639996, 5.165, 5.1660004, 5.167, 5.168, 5.1689997, 5.17, 5.1709995, 5.172, 5.1730003, 5.174, 5.175, 5.1759996, 5.177, 5.1780005, 5.179, 5.1800003, 5.1809998, 5.182, 5.1829996, 5.184, 5.1850004, 5.186, 5.1870003, 5.1879997, 5.189, 5.1899996, 5.191, 5.1920004, 5.193, 5.1940002, 5.1949997, 5.196, 5.1969995, 5.198, 5.1990004, 5.2, 5.201, 5.2019997, 5.203, 5.2040005, 5.205, 5.2060003, 5.207, 5.208, 5.2089996, 5.21, 5.2110004, 5.212, 5.2130003, 5.2139997, 5.215, 5.2159996, 5.217, 5.2180004, 5.219, 5.2200003, 5.2209997, 5.222, 5.2229996, 5.224, 5.2250004, 5.226, 5.227, 5.2279997, 5.229, 5.2299995, 5.231, 5.2320004, 5.233, 5.234, 5.2349997, 5.236, 5.2370005, 5.238, 5.2390003, 5.24, 5.241, 5.2419996, 5.243, 5.2440004, 5.245, 5.2460003, 5.2469997, 5.248, 5.2489996, 5.25, 5.2510004, 5.252, 5.2530003, 5.2539997, 5.255, 5.2559996, 5.257, 5.2580004, 5.259, 5.26, 5.2609997, 5.262, 5.2629995, 5.264, 5.2650003, 5.266, 5.267, 5.2679996, 5.269, 5.2700005, 5.271, 5.2720003, 5.273, 5.274, 5.2749996, 5.276, 5.2770004, 5.278, 5.2790003, 5.2799997, 5.281, 5.2819996, 5.283, 5.2840004, 5.285, 5.2860003, 5.2869997, 5.288, 5.2889996, 5.29, 5.2910004, 5.292, 5.293, 5.2939997, 5.295, 5.2959995, 5.297, 5.2980003, 5.299, 5.3, 5.3009996, 5.302, 5.3030005, 5.304, 5.3050003, 5.3059998, 5.307, 5.3079996, 5.309, 5.3100004, 5.311, 5.3120003, 5.3129997, 5.314, 5.3149996, 5.316, 5.3170004, 5.318, 5.3190002, 5.3199997, 5.321, 5.3219995, 5.323, 5.3240004, 5.325, 5.326, 5.3269997, 5.328, 5.3290005, 5.33, 5.3310003, 5.332, 5.333, 5.3339996, 5.335, 5.3360004, 5.337, 5.3380003, 5.3389997, 5.34, 5.3409996, 5.342, 5.3430004, 5.344, 5.3450003, 5.3459997, 5.347, 5.3479996, 5.349, 5.3500004, 5.351, 5.352, 5.3529997, 5.354, 5.3549995, 5.356, 5.3570004, 5.358, 5.359, 5.3599997, 5.361, 5.3620005, 5.363, 5.3640003, 5.365, 5.366, 5.3669996, 5.368, 5.3690004, 5.37, 5.3710003, 5.3719997, 5.373, 5.3739996, 5.375, 5.3760004, 5.377, 5.3780003, 5.3789997, 5.38, 5.3809996, 5.382, 5.3830004, 5.384, 5.385, 5.3859997, 5.387, 5.3879995, 5.389, 5.3900003, 5.391, 5.392, 5.3929996, 5.394, 5.3950005, 5.396, 5.3970003, 5.398, 5.399, 5.3999996, 5.401, 5.4020004, 5.403, 5.4040003, 5.4049997, 5.406, 5.4069996, 5.408, 5.4090004, 5.41, 5.4110003, 5.4119997, 5.413, 5.4139996, 5.415, 5.4160004, 5.417, 5.418, 5.4189997, 5.42, 5.4209995, 5.422, 5.4230003, 5.424, 5.425, 5.4259996, 5.427, 5.4280005, 5.429, 5.4300003, 5.4309998, 5.432, 5.4329996, 5.434, 5.4350004, 5.436, 5.4370003, 5.4379997, 5.439, 5.4399996, 5.441, 5.4420004, 5.443, 5.4440002, 5.4449997, 5.446, 5.4469995, 5.448, 5.4490004, 5.45, 5.451, 5.4519997, 5.453, 5.4540005, 5.455, 5.4560003, 5.457, 5.458, 5.4589996, 5.46, 5.4610004, 5.462, 5.4630003, 5.4639997, 5.465, 5.4659996, 5.467, 5.4680004, 5.469, 5.4700003, 5.4709997, 5.472, 5.4729996, 5.474, 5.4750004, 5.476, 5.477, 5.4779997, 5.479, 5.4799995, 5.481, 5.4820004, 5.483, 5.484, 5.4849997, 5.486, 5.4870005, 5.488, 5.4890003, 5.49, 5.491, 5.4919996, 5.493, 5.4940004, 5.495, 5.4960003, 5.4969997, 5.498, 5.4989996, 5.5, 5.5010004, 5.502, 5.5030003, 5.5039997, 5.505, 5.5059996, 5.507, 5.5080004, 5.509, 5.51, 5.5109997, 5.512, 5.5129995, 5.514, 5.5150003, 5.516, 5.517, 5.5179996, 5.519, 5.5200005, 5.521, 5.5220003, 5.523, 5.524, 5.5249996, 5.526, 5.5270004, 5.528, 5.5290003, 5.5299997, 5.531, 5.5319996, 5.533, 5.5340004, 5.535, 5.5360003, 5.5369997, 5.538, 5.5389996, 5.54, 5.5410004, 5.542, 5.543, 5.5439997, 5.545, 5.5459995, 5.547, 5.5480003, 5.549, 5.55, 5.5509996, 5.552, 5.5530005, 5.554, 5.5550003, 5.5559998, 5.557, 5.5579996, 5.559, 5.5600004, 5.561, 5.5620003, 5.5629997, 5.564, 5.5649996, 5.566, 5.5670004, 5.568, 5.5690002, 5.5699997, 5.571, 5.5719995, 5.573, 5.5740004, 5.575, 5.576, 5.5769997, 5.578, 5.5790005, 5.58, 5.5810003, 5.582, 5.583, 5.5839996, 5.585, 5.5860004, 5.587, 5.5880003, 5.5889997, 5.59, 5.5909996, 5.592, 5.5930004, 5.594, 5.5950003, 5.5959997, 5.597, 5.5979996, 5.599, 5.6000004, 5.601, 5.602, 5.6029997, 5.604, 5.6049995, 5.606, 5.6070004, 5.608, 5.609, 5.6099997, 5.611, 5.6120005, 5.613, 5.6140003, 5.615, 5.616, 5.6169996, 5.618, 5.6190004, 5.62, 5.6210003, 5.6219997, 5.623, 5.6239996, 5.625, 5.6260004, 5.627, 5.6280003, 5.6289997, 5.63, 5.6309996, 5.632, 5.6330004, 5.634, 5.635, 5.6359997, 5.637, 5.6379995, 5.639, 5.6400003, 5.641, 5.642, 5.6429996, 5.644, 5.6450005, 5.646, 5.6470003, 5.648, 5.649, 5.6499996, 5.651, 5.6520004, 5.653, 5.6540003, 5.6549997, 5.656, 5.6569996, 5.658, 5.6590004, 5.66, 5.6610003, 5.6619997, 5.663, 5.6639996, 5.665, 5.6660004, 5.667, 5.668, 5.6689997, 5.67, 5.6709995, 5.672, 5.6730003, 5.674, 5.675, 5.6759996, 5.677, 5.6780005, 5.679, 5.6800003, 5.6809998, 5.682, 5.6829996, 5.684, 5.6850004, 5.686, 5.6870003, 5.6879997, 5.689, 5.6899996, 5.691, 5.6920004, 5.693, 5.6940002, 5.6949997, 5.696, 5.6969995, 5.698, 5.6990004, 5.7, 5.701, 5.7019997, 5.703, 5.7040005, 5.705, 5.7060003, 5.707, 5.708, 5.7089996, 5.71, 5.7110004, 5.712, 5.7130003, 5.7139997, 5.715, 5.7159996, 5.717, 5.7180004, 5.719, 5.7200003, 5.7209997, 5.722, 5.7229996, 5.724, 5.7250004, 5.726, 5.727, 5.7279997, 5.729, 5.7299995, 5.731, 5.7320004, 5.733, 5.734, 5.7349997, 5.736, 5.7370005, 5.738, 5.7390003, 5.74, 5.741, 5.7419996, 5.743, 5.7440004, 5.745, 5.7460003, 5.7469997, 5.748, 5.7489996, 5.75, 5.7510004, 5.752, 5.7530003, 5.7539997, 5.755, 5.7559996, 5.757, 5.7580004, 5.759, 5.76, 5.7609997, 5.762, 5.7629995, 5.764, 5.7650003, 5.766, 5.767, 5.7679996, 5.769, 5.7700005, 5.771, 5.7720003, 5.773, 5.774, 5.7749996, 5.776, 5.7770004, 5.778, 5.7790003, 5.7799997, 5.781, 5.7819996, 5.783, 5.7840004, 5.785, 5.7860003, 5.7869997, 5.788, 5.7889996, 5.79, 5.7910004, 5.792, 5.793, 5.7939997, 5.795, 5.7959995, 5.797, 5.7980003, 5.799, 5.8, 5.8009996, 5.802, 5.8030005, 5.804, 5.8050003, 5.8059998, 5.807, 5.8079996, 5.809, 5.8100004, 5.811, 5.8120003, 5.8129997, 5.814, 5.8149996, 5.816, 5.8170004, 5.818, 5.8190002, 5.8199997, 5.821, 5.8219995, 5.823, 5.8240004, 5.825, 5.826, 5.8269997, 5.828, 5.8290005, 5.83, 5.8310003, 5.832, 5.833, 5.8339996, 5.835, 5.8360004, 5.837, 5.8380003, 5.8389997, 5.84, 5.8409996, 5.842, 5.8430004, 5.844, 5.8450003, 5.8459997, 5.847, 5.8479996, 5.849, 5.8500004, 5.851, 5.852, 5.8529997, 5.854, 5.8549995, 5.856, 5.8570004, 5.858, 5.859, 5.8599997, 5.861, 5.8620005, 5.863, 5.8640003, 5.865, 5.866, 5.8669996, 5.868, 5.8690004, 5.87, 5.8710003, 5.8719997, 5.873, 5.8739996, 5.875, 5.8760004, 5.877, 5.8780003, 5.8789997, 5.88, 5.8809996, 5.882, 5.8830004, 5.884, 5.885, 5.8859997, 5.887, 5.8879995, 5.889, 5.8900003, 5.891, 5.892, 5.8929996, 5.894, 5.8950005, 5.896, 5.8970003, 5.898, 5.899, 5.8999996, 5.901, 5.9020004, 5.903, 5.9040003, 5.9049997, 5.906, 5.9069996, 5.908, 5.9090004, 5.91, 5.9110003, 5.9119997, 5.913, 5.9139996, 5.915, 5.9160004, 5.917, 5.918, 5.9189997, 5.92, 5.9209995, 5.922, 5.9230003, 5.924, 5.925, 5.9259996, 5.927, 5.9280005, 5.929, 5.9300003, 5.9309998, 5.932, 5.9329996, 5.934, 5.9350004, 5.936, 5.9370003, 5.9379997, 5.939, 5.9399996, 5.941, 5.9420004, 5.943, 5.9440002, 5.9449997, 5.946, 5.9469995, 5.948, 5.9490004, 5.95, 5.951, 5.9519997, 5.953, 5.9540005, 5.955, 5.9560003, 5.957, 5.958, 5.9589996, 5.96, 5.9610004, 5.962, 5.9630003, 5.9639997, 5.965, 5.9659996, 5.967, 5.9680004, 5.969, 5.9700003, 5.9709997, 5.972, 5.9729996, 5.974, 5.9750004, 5.976, 5.977, 5.9779997, 5.979, 5.9799995, 5.981, 5.9820004, 5.983, 5.984, 5.9849997, 5.986, 5.9870005, 5.988, 5.9890003, 5.99, 5.991, 5.9919996, 5.993, 5.9940004, 5.995, 5.9960003, 5.9969997, 5.998, 5.9989996, 6.0, 6.0009995, 6.002001, 6.0030003, 6.0039997, 6.004999, 6.0060005, 6.007, 6.0079994, 6.009001, 6.01, 6.0109997, 6.011999, 6.0130005, 6.014, 6.0149994, 6.0160007, 6.017, 6.0179996, 6.018999, 6.0200005, 6.021, 6.0219994, 6.0230007, 6.024, 6.0249996, 6.025999, 6.0270004, 6.028, 6.0289993, 6.0300007, 6.031, 6.0319996, 6.033001, 6.0340004, 6.035, 6.0359993, 6.0370007, 6.038, 6.0389996, 6.040001, 6.0410004, 6.042, 6.0429993, 6.0440006, 6.045, 6.0459995, 6.047001, 6.0480003, 6.049, 6.049999, 6.0510006, 6.052, 6.0529995, 6.054001, 6.0550003, 6.0559998, 6.056999, 6.0580006, 6.059, 6.0599995, 6.061001, 6.0620003, 6.0629997, 6.063999, 6.0650005, 6.066, 6.0669994, 6.068001, 6.0690002, 6.0699997, 6.070999, 6.0720005, 6.073, 6.0739994, 6.075001, 6.076, 6.0769997, 6.077999, 6.0790005, 6.08, 6.0809994, 6.0820007, 6.083, 6.0839996, 6.084999, 6.0860004, 6.087, 6.0879993, 6.0890007, 6.09, 6.0909996, 6.091999, 6.0930004, 6.094, 6.0949993, 6.0960007, 6.097, 6.0979996, 6.099001, 6.1000004, 6.101, 6.1019993, 6.1030006, 6.104, 6.1049995, 6.106001, 6.1070004, 6.108, 6.1089993, 6.1100006, 6.111, 6.1119995, 6.113001, 6.1140003, 6.115, 6.115999, 6.1170006, 6.118, 6.1189995, 6.120001, 6.1210003, 6.1219997, 6.122999, 6.1240005, 6.125, 6.1259995, 6.127001, 6.1280003, 6.1289997, 6.129999, 6.1310005, 6.132, 6.1329994, 6.134001, 6.135, 6.1359997, 6.136999, 6.1380005, 6.139, 6.1399994, 6.1410007, 6.142, 6.1429996, 6.143999, 6.1450005, 6.146, 6.1469994, 6.1480007, 6.149, 6.1499996, 6.150999, 6.1520004, 6.153, 6.1539993, 6.1550007, 6.156, 6.1569996, 6.158001, 6.1590004, 6.16, 6.1609993, 6.1620007, 6.163, 6.1639996, 6.165001, 6.1660004, 6.167, 6.1679993, 6.1690006, 6.17, 6.1709995, 6.172001, 6.1730003, 6.174, 6.174999, 6.1760006, 6.177, 6.1779995, 6.179001, 6.1800003, 6.1809998, 6.181999, 6.1830006, 6.184, 6.1849995, 6.186001, 6.1870003, 6.1879997, 6.188999, 6.1900005, 6.191, 6.1919994, 6.193001, 6.1940002, 6.1949997, 6.195999, 6.1970005, 6.198, 6.1989994, 6.200001, 6.201, 6.2019997, 6.202999, 6.2040005, 6.205, 6.2059994, 6.2070007, 6.208, 6.2089996, 6.209999, 6.2110004, 6.212, 6.2129993, 6.2140007, 6.215, 6.2159996, 6.216999, 6.2180004, 6.219, 6.2199993, 6.2210007, 6.222, 6.2229996, 6.224001, 6.2250004, 6.226, 6.2269993, 6.2280006, 6.229, 6.2299995, 6.231001, 6.2320004, 6.233, 6.2339993, 6.2350006, 6.236, 6.2369995, 6.238001, 6.2390003, 6.24, 6.240999, 6.2420006, 6.243, 6.2439995, 6.245001, 6.2460003, 6.2469997, 6.247999, 6.2490005, 6.25, 6.2509995, 6.252001, 6.2530003, 6.2539997, 6.254999, 6.2560005, 6.257, 6.2579994, 6.259001, 6.26, 6.2609997, 6.261999, 6.2630005, 6.264, 6.2649994, 6.2660007, 6.267, 6.2679996, 6.268999, 6.2700005, 6.271, 6.2719994, 6.2730007, 6.274, 6.2749996, 6.275999, 6.2770004, 6.278, 6.2789993, 6.2800007, 6.281, 6.2819996, 6.283001, 6.2840004, 6.285, 6.2859993, 6.2870007, 6.288, 6.2889996, 6.290001, 6.2910004, 6.292, 6.2929993, 6.2940006, 6.295, 6.2959995, 6.297001, 6.2980003, 6.299, 6.299999, 6.3010006, 6.302, 6.3029995, 6.304001, 6.3050003, 6.3059998, 6.306999, 6.3080006, 6.309, 6.3099995, 6.311001, 6.3120003, 6.3129997, 6.313999, 6.3150005, 6.316, 6.3169994, 6.318001, 6.3190002, 6.3199997, 6.320999, 6.3220005, 6.323, 6.3239994, 6.325001, 6.326, 6.3269997, 6.327999, 6.3290005, 6.33, 6.3309994, 6.3320007, 6.333, 6.3339996, 6.334999, 6.3360004, 6.337, 6.3379993, 6.3390007, 6.34, 6.3409996, 6.341999, 6.3430004, 6.344, 6.3449993, 6.3460007, 6.347, 6.3479996, 6.349001, 6.3500004, 6.351, 6.3519993, 6.3530006, 6.354, 6.3549995, 6.356001, 6.3570004, 6.358, 6.3589993, 6.3600006, 6.361, 6.3619995, 6.363001, 6.3640003, 6.365, 6.365999, 6.3670006, 6.368, 6.3689995, 6.370001, 6.3710003, 6.3719997, 6.372999, 6.3740005, 6.375, 6.3759995, 6.377001, 6.3780003, 6.3789997, 6.379999, 6.3810005, 6.382, 6.3829994, 6.384001, 6.385, 6.3859997, 6.386999, 6.3880005, 6.389, 6.3899994, 6.3910007, 6.392, 6.3929996, 6.393999, 6.3950005, 6.396, 6.3969994, 6.3980007, 6.399, 6.3999996, 6.400999, 6.4020004, 6.403, 6.4039993, 6.4050007, 6.406, 6.4069996, 6.408001, 6.4090004, 6.41, 6.4109993, 6.4120007, 6.413, 6.4139996, 6.415001, 6.4160004, 6.417, 6.4179993, 6.4190006, 6.42, 6.4209995, 6.422001, 6.4230003, 6.424, 6.424999, 6.4260006, 6.427, 6.4279995, 6.429001, 6.4300003, 6.4309998, 6.431999, 6.4330006, 6.434, 6.4349995, 6.436001, 6.4370003, 6.4379997, 6.438999, 6.4400005, 6.441, 6.4419994, 6.443001, 6.4440002, 6.4449997, 6.445999, 6.4470005, 6.448, 6.4489994, 6.450001, 6.451, 6.4519997, 6.452999, 6.4540005, 6.455, 6.4559994, 6.4570007, 6.458, 6.4589996, 6.459999, 6.4610004, 6.462, 6.4629993, 6.4640007, 6.465, 6.4659996, 6.466999, 6.4680004, 6.469, 6.4699993, 6.4710007, 6.472, 6.4729996, 6.474001, 6.4750004, 6.476, 6.4769993, 6.4780006, 6.479, 6.4799995, 6.481001, 6.4820004, 6.483, 6.4839993, 6.4850006, 6.486, 6.4869995, 6.488001, 6.4890003, 6.49, 6.490999, 6.4920006, 6.493, 6.4939995, 6.495001, 6.4960003, 6.4969997, 6.497999, 6.4990005, 6.5, 6.5009995, 6.502001, 6.5030003, 6.5039997, 6.504999, 6.5060005, 6.507, 6.5079994, 6.509001, 6.51, 6.5109997, 6.511999, 6.5130005, 6.514, 6.5149994, 6.5160007, 6.517, 6.5179996, 6.518999, 6.5200005, 6.521, 6.5219994, 6.5230007, 6.524, 6.5249996, 6.525999, 6.5270004, 6.528, 6.5289993, 6.5300007, 6.531, 6.5319996, 6.533001, 6.5340004, 6.535, 6.5359993, 6.5370007, 6.538, 6.5389996, 6.540001, 6.5410004, 6.542, 6.5429993, 6.5440006, 6.545, 6.5459995, 6.547001, 6.5480003, 6.549, 6.549999, 6.5510006, 6.552, 6.5529995, 6.554001, 6.5550003, 6.5559998, 6.556999, 6.5580006, 6.559, 6.5599995, 6.561001, 6.5620003, 6.5629997, 6.563999, 6.5650005, 6.566, 6.5669994, 6.568001, 6.5690002, 6.5699997, 6.570999, 6.5720005, 6.573, 6.5739994, 6.575001, 6.576, 6.5769997, 6.577999, 6.5790005, 6.58, 6.5809994, 6.5820007, 6.583, 6.5839996, 6.584999, 6.5860004, 6.587, 6.5879993, 6.5890007, 6.59, 6.5909996, 6.591999, 6.5930004, 6.594, 6.5949993, 6.5960007, 6.597, 6.5979996, 6.599001, 6.6000004, 6.601, 6.6019993, 6.6030006, 6.604, 6.6049995, 6.606001, 6.6070004, 6.608, 6.6089993, 6.6100006, 6.611, 6.6119995, 6.613001, 6.6140003, 6.615, 6.615999, 6.6170006, 6.618, 6.6189995, 6.620001, 6.6210003, 6.6219997, 6.622999, 6.6240005, 6.625, 6.6259995, 6.627001, 6.6280003, 6.6289997, 6.629999, 6.6310005, 6.632, 6.6329994, 6.634001, 6.635, 6.6359997, 6.636999, 6.6380005, 6.639, 6.6399994, 6.6410007, 6.642, 6.6429996, 6.643999, 6.6450005, 6.646, 6.6469994, 6.6480007, 6.649, 6.6499996, 6.650999, 6.6520004, 6.653, 6.6539993, 6.6550007, 6.656, 6.6569996, 6.658001, 6.6590004, 6.66, 6.6609993, 6.6620007, 6.663, 6.6639996, 6.665001, 6.6660004, 6.667, 6.6679993, 6.6690006, 6.67, 6.6709995, 6.672001, 6.6730003, 6.674, 6.674999, 6.6760006, 6.677, 6.6779995, 6.679001, 6.6800003, 6.6809998, 6.681999, 6.6830006, 6.684, 6.6849995, 6.686001, 6.6870003, 6.6879997, 6.688999, 6.6900005, 6.691, 6.6919994, 6.693001, 6.6940002, 6.6949997, 6.695999, 6.6970005, 6.698, 6.6989994, 6.700001, 6.701, 6.7019997, 6.702999, 6.7040005, 6.705, 6.7059994, 6.7070007, 6.708, 6.7089996, 6.709999, 6.7110004, 6.712, 6.7129993, 6.7140007, 6.715, 6.7159996, 6.716999, 6.7180004, 6.719, 6.7199993, 6.7210007, 6.722, 6.7229996, 6.724001, 6.7250004, 6.726, 6.7269993, 6.7280006, 6.729, 6.7299995, 6.731001, 6.7320004, 6.733, 6.7339993, 6.7350006, 6.736, 6.7369995, 6.738001, 6.7390003, 6.74, 6.740999, 6.7420006, 6.743, 6.7439995, 6.745001, 6.7460003, 6.7469997, 6.747999, 6.7490005, 6.75, 6.7509995, 6.752001, 6.7530003, 6.7539997, 6.754999, 6.7560005, 6.757, 6.7579994, 6.759001, 6.76, 6.7609997, 6.761999, 6.7630005, 6.764, 6.7649994, 6.7660007, 6.767, 6.7679996, 6.768999, 6.7700005, 6.771, 6.7719994, 6.7730007, 6.774, 6.7749996, 6.775999, 6.7770004, 6.778, 6.7789993, 6.7800007, 6.781, 6.7819996, 6.783001, 6.7840004, 6.785, 6.7859993, 6.7870007, 6.788, 6.7889996, 6.790001, 6.7910004, 6.792, 6.7929993, 6.7940006, 6.795, 6.7959995, 6.797001, 6.7980003, 6.799, 6.799999, 6.8010006, 6.802, 6.8029995, 6.804001, 6.8050003, 6.8059998, 6.806999, 6.8080006, 6.809, 6.8099995, 6.811001, 6.8120003, 6.8129997, 6.813999, 6.8150005, 6.816, 6.8169994, 6.818001, 6.8190002, 6.8199997, 6.820999, 6.8220005, 6.823, 6.8239994, 6.825001, 6.826, 6.8269997, 6.827999, 6.8290005, 6.83, 6.8309994, 6.8320007, 6.833, 6.8339996, 6.834999, 6.8360004, 6.837, 6.8379993, 6.8390007, 6.84, 6.8409996, 6.841999, 6.8430004, 6.844, 6.8449993, 6.8460007, 6.847, 6.8479996, 6.849001, 6.8500004, 6.851, 6.8519993, 6.8530006, 6.854, 6.8549995, 6.856001, 6.8570004, 6.858, 6.8589993, 6.8600006, 6.861, 6.8619995, 6.863001, 6.8640003, 6.865, 6.865999, 6.8670006, 6.868, 6.8689995, 6.870001, 6.8710003, 6.8719997, 6.872999, 6.8740005, 6.875, 6.8759995, 6.877001, 6.8780003, 6.8789997, 6.879999, 6.8810005, 6.882, 6.8829994, 6.884001, 6.885, 6.8859997, 6.886999, 6.8880005, 6.889, 6.8899994, 6.8910007, 6.892, 6.8929996, 6.893999, 6.8950005, 6.896, 6.8969994, 6.8980007, 6.899, 6.8999996, 6.900999, 6.9020004, 6.903, 6.9039993, 6.9050007, 6.906, 6.9069996, 6.908001, 6.9090004, 6.91, 6.9109993, 6.9120007, 6.913, 6.9139996, 6.915001, 6.9160004, 6.917, 6.9179993, 6.9190006, 6.92, 6.9209995, 6.922001, 6.9230003, 6.924, 6.924999, 6.9260006, 6.927, 6.9279995, 6.929001, 6.9300003, 6.9309998, 6.931999, 6.9330006, 6.934, 6.9349995, 6.936001, 6.9370003, 6.9379997, 6.938999, 6.9400005, 6.941, 6.9419994, 6.943001, 6.9440002, 6.9449997, 6.945999, 6.9470005, 6.948, 6.9489994, 6.950001, 6.951, 6.9519997, 6.952999, 6.9540005, 6.955, 6.9559994, 6.9570007, 6.958, 6.9589996, 6.959999, 6.9610004, 6.962, 6.9629993, 6.9640007, 6.965, 6.9659996, 6.966999, 6.9680004, 6.969, 6.9699993, 6.9710007, 6.972, 6.9729996, 6.974001, 6.9750004, 6.976, 6.9769993, 6.9780006, 6.979, 6.9799995, 6.981001, 6.9820004, 6.983, 6.9839993, 6.9850006, 6.986, 6.9869995, 6.988001, 6.9890003, 6.99, 6.990999, 6.9920006, 6.993, 6.9939995, 6.995001, 6.9960003, 6.9969997, 6.997999, 6.9990005, 7.0, 7.0009995, 7.002001, 7.0030003, 7.0039997, 7.004999, 7.0060005, 7.007, 7.0079994, 7.009001, 7.01, 7.0109997, 7.011999, 7.0130005, 7.014, 7.0149994, 7.0160007, 7.017, 7.0179996, 7.018999, 7.0200005, 7.021, 7.0219994, 7.0230007, 7.024, 7.0249996, 7.025999, 7.0270004, 7.028, 7.0289993, 7.0300007, 7.031, 7.0319996, 7.033001, 7.0340004, 7.035, 7.0359993, 7.0370007, 7.038, 7.0389996, 7.040001, 7.0410004, 7.042, 7.0429993, 7.0440006, 7.045, 7.0459995, 7.047001, 7.0480003, 7.049, 7.049999, 7.0510006, 7.052, 7.0529995, 7.054001, 7.0550003, 7.0559998, 7.056999, 7.0580006, 7.059, 7.0599995, 7.061001, 7.0620003, 7.0629997, 7.063999, 7.0650005, 7.066, 7.0669994, 7.068001, 7.0690002, 7.0699997, 7.070999, 7.0720005, 7.073, 7.0739994, 7.075001, 7.076, 7.0769997, 7.077999, 7.0790005, 7.08, 7.0809994, 7.0820007, 7.083, 7.0839996, 7.084999, 7.0860004, 7.087, 7.0879993, 7.0890007, 7.09, 7.0909996, 7.091999, 7.0930004, 7.094, 7.0949993, 7.0960007, 7.097, 7.0979996, 7.099001, 7.1000004, 7.101, 7.1019993, 7.1030006, 7.104, 7.1049995, 7.106001, 7.1070004, 7.108, 7.1089993, 7.1100006, 7.111, 7.1119995, 7.113001, 7.1140003, 7.115, 7.115999, 7.1170006, 7.118, 7.1189995, 7.120001, 7.1210003, 7.1219997, 7.122999, 7.1240005, 7.125, 7.1259995, 7.127001, 7.1280003, 7.1289997, 7.129999, 7.1310005, 7.132, 7.1329994, 7.134001, 7.135, 7.1359997, 7.136999, 7.1380005, 7.139, 7.1399994, 7.1410007, 7.142, 7.1429996, 7.143999, 7.1450005, 7.146, 7.1469994, 7.1480007, 7.149, 7.1499996, 7.150999, 7.1520004, 7.153, 7.1539993, 7.1550007, 7.156, 7.1569996, 7.158001, 7.1590004, 7.16, 7.1609993, 7.1620007, 7.163, 7.1639996, 7.165001, 7.1660004, 7.167, 7.1679993, 7.1690006, 7.17, 7.1709995, 7.172001, 7.1730003, 7.174, 7.174999, 7.1760006, 7.177, 7.1779995, 7.179001, 7.1800003, 7.1809998, 7.181999, 7.1830006, 7.184, 7.1849995, 7.186001, 7.1870003, 7.1879997, 7.188999, 7.1900005, 7.191, 7.1919994, 7.193001, 7.1940002, 7.1949997, 7.195999, 7.1970005, 7.198, 7.1989994, 7.200001, 7.201, 7.2019997, 7.202999, 7.2040005, 7.205, 7.2059994, 7.2070007, 7.208, 7.2089996, 7.209999, 7.2110004, 7.212, 7.2129993, 7.2140007, 7.215, 7.2159996, 7.216999, 7.2180004, 7.219, 7.2199993, 7.2210007, 7.222, 7.2229996, 7.224001, 7.2250004, 7.226, 7.2269993, 7.2280006, 7.229, 7.2299995, 7.231001, 7.2320004, 7.233, 7.2339993, 7.2350006, 7.236, 7.2369995, 7.238001, 7.2390003, 7.24, 7.240999, 7.2420006, 7.243, 7.2439995, 7.245001, 7.2460003, 7.2469997, 7.247999, 7.2490005, 7.25, 7.2509995, 7.252001, 7.2530003, 7.2539997, 7.254999, 7.2560005, 7.257, 7.2579994, 7.259001, 7.26, 7.2609997, 7.261999, 7.2630005, 7.264, 7.2649994, 7.2660007, 7.267, 7.2679996, 7.268999, 7.2700005, 7.271, 7.2719994, 7.2730007, 7.274, 7.2749996, 7.275999, 7.2770004, 7.278, 7.2789993, 7.2800007, 7.281, 7.2819996, 7.283001, 7.2840004, 7.285, 7.2859993, 7.2870007, 7.288, 7.2889996, 7.290001, 7.2910004, 7.292, 7.2929993, 7.2940006, 7.295, 7.2959995, 7.297001, 7.2980003, 7.299, 7.299999, 7.3010006, 7.302, 7.3029995, 7.304001, 7.3050003, 7.3059998, 7.306999, 7.3080006, 7.309, 7.3099995, 7.311001, 7.3120003, 7.3129997, 7.313999, 7.3150005, 7.316, 7.3169994, 7.318001, 7.3190002, 7.3199997, 7.320999, 7.3220005, 7.323, 7.3239994, 7.325001, 7.326, 7.3269997, 7.327999, 7.3290005, 7.33, 7.3309994, 7.3320007, 7.333, 7.3339996, 7.334999, 7.3360004, 7.337, 7.3379993, 7.3390007, 7.34, 7.3409996, 7.341999, 7.3430004, 7.344, 7.3449993, 7.3460007, 7.347, 7.3479996, 7.349001, 7.3500004, 7.351, 7.3519993, 7.3530006, 7.354, 7.3549995, 7.356001, 7.3570004, 7.358, 7.3589993, 7.3600006, 7.361, 7.3619995, 7.363001, 7.3640003, 7.365, 7.365999, 7.3670006, 7.368, 7.3689995, 7.370001, 7.3710003, 7.3719997, 7.372999, 7.3740005, 7.375, 7.3759995, 7.377001, 7.3780003, 7.3789997, 7.379999, 7.3810005, 7.382, 7.3829994, 7.384001, 7.385, 7.3859997, 7.386999, 7.3880005, 7.389, 7.3899994, 7.3910007, 7.392, 7.3929996, 7.393999, 7.3950005, 7.396, 7.3969994, 7.3980007, 7.399, 7.3999996, 7.400999, 7.4020004, 7.403, 7.4039993, 7.4050007, 7.406, 7.4069996, 7.408001, 7.4090004, 7.41, 7.4109993, 7.4120007, 7.413, 7.4139996, 7.415001, 7.4160004, 7.417, 7.4179993, 7.4190006, 7.42, 7.4209995, 7.422001, 7.4230003, 7.424, 7.424999, 7.4260006, 7.427, 7.4279995, 7.429001, 7.4300003, 7.4309998, 7.431999, 7.4330006, 7.434, 7.4349995, 7.436001, 7.4370003, 7.4379997, 7.438999, 7.4400005, 7.441, 7.4419994, 7.443001, 7.4440002, 7.4449997, 7.445999, 7.4470005, 7.448, 7.4489994, 7.450001, 7.451, 7.4519997, 7.452999, 7.4540005, 7.455, 7.4559994, 7.4570007, 7.458, 7.4589996, 7.459999, 7.4610004, 7.462, 7.4629993, 7.4640007, 7.465, 7.4659996, 7.466999, 7.4680004, 7.469, 7.4699993, 7.4710007, 7.472, 7.4729996, 7.474001, 7.4750004, 7.476, 7.4769993, 7.4780006, 7.479, 7.4799995, 7.481001, 7.4820004, 7.483, 7.4839993, 7.4850006, 7.486, 7.4869995, 7.488001, 7.4890003, 7.49, 7.490999, 7.4920006, 7.493, 7.4939995, 7.495001, 7.4960003, 7.4969997, 7.497999, 7.4990005, 7.5, 7.5009995, 7.502001, 7.5030003, 7.5039997, 7.504999, 7.5060005, 7.507, 7.5079994, 7.509001, 7.51, 7.5109997, 7.511999, 7.5130005, 7.514, 7.5149994, 7.5160007, 7.517, 7.5179996, 7.518999, 7.5200005, 7.521, 7.5219994, 7.5230007, 7.524, 7.5249996, 7.525999, 7.5270004, 7.528, 7.5289993, 7.5300007, 7.531, 7.5319996, 7.533001, 7.5340004, 7.535, 7.5359993, 7.5370007, 7.538, 7.5389996, 7.540001, 7.5410004, 7.542, 7.5429993, 7.5440006, 7.545, 7.5459995, 7.547001, 7.5480003, 7.549, 7.549999, 7.5510006, 7.552, 7.5529995, 7.554001, 7.5550003, 7.5559998, 7.556999, 7.5580006, 7.559, 7.5599995, 7.561001, 7.5620003, 7.5629997, 7.563999, 7.5650005, 7.566, 7.5669994, 7.568001, 7.5690002, 7.5699997, 7.570999, 7.5720005, 7.573, 7.5739994, 7.575001, 7.576, 7.5769997, 7.577999, 7.5790005, 7.58, 7.5809994, 7.5820007, 7.583, 7.5839996, 7.584999, 7.5860004, 7.587, 7.5879993, 7.5890007, 7.59, 7.5909996, 7.591999, 7.5930004, 7.594, 7.5949993, 7.5960007, 7.597, 7.5979996, 7.599001, 7.6000004, 7.601, 7.6019993, 7.6030006, 7.604, 7.6049995, 7.606001, 7.6070004, 7.608, 7.6089993, 7.6100006, 7.611, 7.6119995, 7.613001, 7.6140003, 7.615, 7.615999, 7.6170006, 7.618, 7.6189995, 7.620001, 7.6210003, 7.6219997, 7.622999, 7.6240005, 7.625, 7.6259995, 7.627001, 7.6280003, 7.6289997, 7.629999, 7.6310005, 7.632, 7.6329994, 7.634001, 7.635, 7.6359997, 7.636999, 7.6380005, 7.639, 7.6399994, 7.6410007, 7.642, 7.6429996, 7.643999, 7.6450005, 7.646, 7.6469994, 7.6480007, 7.649, 7.6499996, 7.650999, 7.6520004, 7.653, 7.6539993, 7.6550007, 7.656, 7.6569996, 7.658001, 7.6590004, 7.66, 7.6609993, 7.6620007, 7.663, 7.6639996, 7.665001, 7.6660004, 7.667, 7.6679993, 7.6690006, 7.67, 7.6709995, 7.672001, 7.6730003, 7.674, 7.674999, 7.6760006, 7.677, 7.6779995, 7.679001, 7.6800003, 7.6809998, 7.681999, 7.6830006, 7.684, 7.6849995, 7.686001, 7.6870003, 7.6879997, 7.688999, 7.6900005, 7.691, 7.6919994, 7.693001, 7.6940002, 7.6949997, 7.695999, 7.6970005, 7.698, 7.6989994, 7.700001, 7.701, 7.7019997, 7.702999, 7.7040005, 7.705, 7.7059994, 7.7070007, 7.708, 7.7089996, 7.709999, 7.7110004, 7.712, 7.7129993, 7.7140007, 7.715, 7.7159996, 7.716999, 7.7180004, 7.719, 7.7199993, 7.7210007, 7.722, 7.7229996, 7.724001, 7.7250004, 7.726, 7.7269993, 7.7280006, 7.729, 7.7299995, 7.731001, 7.7320004, 7.733, 7.7339993, 7.7350006, 7.736, 7.7369995, 7.738001, 7.7390003, 7.74, 7.740999, 7.7420006, 7.743, 7.7439995, 7.745001, 7.7460003, 7.7469997, 7.747999, 7.7490005, 7.75, 7.7509995, 7.752001, 7.7530003, 7.7539997, 7.754999, 7.7560005, 7.757, 7.7579994, 7.759001, 7.76, 7.7609997, 7.761999, 7.7630005, 7.764, 7.7649994, 7.7660007, 7.767, 7.7679996, 7.768999, 7.7700005, 7.771, 7.7719994, 7.7730007, 7.774, 7.7749996, 7.775999, 7.7770004, 7.778, 7.7789993, 7.7800007, 7.781, 7.7819996, 7.783001, 7.7840004, 7.785, 7.7859993, 7.7870007, 7.788, 7.7889996, 7.790001, 7.7910004, 7.792, 7.7929993, 7.7940006, 7.795, 7.7959995, 7.797001, 7.7980003, 7.799, 7.799999, 7.8010006, 7.802, 7.8029995, 7.804001, 7.8050003, 7.8059998, 7.806999, 7.8080006, 7.809, 7.8099995, 7.811001, 7.8120003, 7.8129997, 7.813999, 7.8150005, 7.816, 7.8169994, 7.818001, 7.8190002, 7.8199997, 7.820999, 7.8220005, 7.823, 7.8239994, 7.825001, 7.826, 7.8269997, 7.827999, 7.8290005, 7.83, 7.8309994, 7.8320007, 7.833, 7.8339996, 7.834999, 7.8360004, 7.837, 7.8379993, 7.8390007, 7.84, 7.8409996, 7.841999, 7.8430004, 7.844, 7.8449993, 7.8460007, 7.847, 7.8479996, 7.849001, 7.8500004, 7.851, 7.8519993, 7.8530006, 7.854, 7.8549995, 7.856001, 7.8570004, 7.858, 7.8589993, 7.8600006, 7.861, 7.8619995, 7.863001, 7.8640003, 7.865, 7.865999, 7.8670006, 7.868, 7.8689995, 7.870001, 7.8710003, 7.8719997, 7.872999, 7.8740005, 7.875, 7.8759995, 7.877001, 7.8780003, 7.8789997, 7.879999, 7.8810005, 7.882, 7.8829994, 7.884001, 7.885, 7.8859997, 7.886999, 7.8880005, 7.889, 7.8899994, 7.8910007, 7.892, 7.8929996, 7.893999, 7.8950005, 7.896, 7.8969994, 7.8980007, 7.899, 7.8999996, 7.900999, 7.9020004, 7.903, 7.9039993, 7.9050007, 7.906, 7.9069996, 7.908001, 7.9090004, 7.91, 7.9109993, 7.9120007, 7.913, 7.9139996, 7.915001, 7.9160004, 7.917, 7.9179993, 7.9190006, 7.92, 7.9209995, 7.922001, 7.9230003, 7.924, 7.924999, 7.9260006, 7.927, 7.9279995, 7.929001, 7.9300003, 7.9309998, 7.931999, 7.9330006, 7.934, 7.9349995, 7.936001, 7.9370003, 7.9379997, 7.938999, 7.9400005, 7.941, 7.9419994, 7.943001, 7.9440002, 7.9449997, 7.945999, 7.9470005, 7.948, 7.9489994, 7.950001, 7.951, 7.9519997, 7.952999, 7.9540005, 7.955, 7.9559994, 7.9570007, 7.958, 7.9589996, 7.959999, 7.9610004, 7.962, 7.9629993, 7.9640007, 7.965, 7.9659996, 7.966999, 7.9680004, 7.969, 7.9699993, 7.9710007, 7.972, 7.9729996, 7.974001, 7.9750004, 7.976, 7.9769993, 7.9780006, 7.979, 7.9799995, 7.981001, 7.9820004, 7.983, 7.9839993, 7.9850006, 7.986, 7.9869995, 7.988001, 7.9890003, 7.99, 7.990999, 7.9920006, 7.993, 7.9939995, 7.995001, 7.9960003, 7.9969997, 7.997999, 7.9990005, 8.0, 8.000999, 8.002001, 8.003, 8.004, 8.004999, 8.0060005, 8.007, 8.007999, 8.009001, 8.01, 8.011, 8.011999, 8.0130005, 8.014, 8.014999, 8.016001, 8.017, 8.018, 8.018999, 8.02, 8.021, 8.021999, 8.023001, 8.024, 8.025, 8.025999, 8.027, 8.028, 8.028999, 8.030001, 8.031, 8.032, 8.033001, 8.034, 8.035, 8.035999, 8.037001, 8.038, 8.039, 8.040001, 8.041, 8.042, 8.042999, 8.044001, 8.045, 8.046, 8.047001, 8.048, 8.049, 8.049999, 8.051001, 8.052, 8.0529995, 8.054001, 8.055, 8.056, 8.056999, 8.058001, 8.059, 8.059999, 8.061001, 8.062, 8.063, 8.063999, 8.065001, 8.066, 8.066999, 8.068001, 8.069, 8.07, 8.070999, 8.0720005, 8.073, 8.073999, 8.075001, 8.076, 8.077, 8.077999, 8.079, 8.08, 8.080999, 8.082001, 8.083, 8.084, 8.084999, 8.086, 8.087, 8.087999, 8.089001, 8.09, 8.091, 8.091999, 8.093, 8.094, 8.094999, 8.096001, 8.097, 8.098, 8.099001, 8.1, 8.101, 8.101999, 8.103001, 8.104, 8.105, 8.106001, 8.107, 8.108, 8.108999, 8.110001, 8.111, 8.1119995, 8.113001, 8.114, 8.115, 8.115999, 8.117001, 8.118, 8.1189995, 8.120001, 8.121, 8.122, 8.122999, 8.124001, 8.125, 8.125999, 8.127001, 8.128, 8.129, 8.129999, 8.1310005, 8.132, 8.132999, 8.134001, 8.135, 8.136, 8.136999, 8.1380005, 8.139, 8.139999, 8.141001, 8.142, 8.143, 8.143999, 8.145, 8.146, 8.146999, 8.148001, 8.149, 8.15, 8.150999, 8.152, 8.153, 8.153999, 8.155001, 8.156, 8.157, 8.158001, 8.159, 8.16, 8.160999, 8.162001, 8.163, 8.164, 8.165001, 8.166, 8.167, 8.167999, 8.169001, 8.17, 8.171, 8.172001, 8.173, 8.174, 8.174999, 8.176001, 8.177, 8.1779995, 8.179001, 8.18, 8.181, 8.181999, 8.183001, 8.184, 8.184999, 8.186001, 8.187, 8.188, 8.188999, 8.190001, 8.191, 8.191999, 8.193001, 8.194, 8.195, 8.195999, 8.1970005, 8.198, 8.198999, 8.200001, 8.201, 8.202, 8.202999, 8.204, 8.205, 8.205999, 8.207001, 8.208, 8.209, 8.209999, 8.211, 8.212, 8.212999, 8.214001, 8.215, 8.216, 8.216999, 8.218, 8.219, 8.219999, 8.221001, 8.222, 8.223, 8.224001, 8.225, 8.226, 8.226999, 8.228001, 8.229, 8.23, 8.231001, 8.232, 8.233, 8.233999, 8.235001, 8.236, 8.2369995, 8.238001, 8.239, 8.24, 8.240999, 8.242001, 8.243, 8.2439995, 8.245001, 8.246, 8.247, 8.247999, 8.249001, 8.25, 8.250999, 8.252001, 8.253, 8.254, 8.254999, 8.2560005, 8.257, 8.257999, 8.259001, 8.26, 8.261, 8.261999, 8.2630005, 8.264, 8.264999, 8.266001, 8.267, 8.268, 8.268999, 8.27, 8.271, 8.271999, 8.273001, 8.274, 8.275, 8.275999, 8.277, 8.278, 8.278999, 8.280001, 8.281, 8.282, 8.283001, 8.284, 8.285, 8.285999, 8.287001, 8.288, 8.289, 8.290001, 8.291, 8.292, 8.292999, 8.294001, 8.295, 8.296, 8.297001, 8.298, 8.299, 8.299999, 8.301001, 8.302, 8.3029995, 8.304001, 8.305, 8.306, 8.306999, 8.308001, 8.309, 8.309999, 8.311001, 8.312, 8.313, 8.313999, 8.315001, 8.316, 8.316999, 8.318001, 8.319, 8.32, 8.320999, 8.3220005, 8.323, 8.323999, 8.325001, 8.326, 8.327, 8.327999, 8.329, 8.33, 8.330999, 8.332001, 8.333, 8.334, 8.334999, 8.336, 8.337, 8.337999, 8.339001, 8.34, 8.341, 8.341999, 8.343, 8.344, 8.344999, 8.346001, 8.347, 8.348, 8.349001, 8.35, 8.351, 8.351999, 8.353001, 8.354, 8.355, 8.356001, 8.357, 8.358, 8.358999, 8.360001, 8.361, 8.3619995, 8.363001, 8.364, 8.365, 8.365999, 8.367001, 8.368, 8.3689995, 8.370001, 8.371, 8.372, 8.372999, 8.374001, 8.375, 8.375999, 8.377001, 8.378, 8.379, 8.379999, 8.3810005, 8.382, 8.382999, 8.384001, 8.385, 8.386, 8.386999, 8.3880005, 8.389, 8.389999, 8.391001, 8.392, 8.393, 8.393999, 8.395, 8.396, 8.396999, 8.398001, 8.399, 8.4, 8.400999, 8.402, 8.403, 8.403999, 8.405001, 8.406, 8.407, 8.408001, 8.409, 8.41, 8.410999, 8.412001, 8.413, 8.414, 8.415001, 8.416, 8.417, 8.417999, 8.419001, 8.42, 8.421, 8.422001, 8.423, 8.424, 8.424999, 8.426001, 8.427, 8.4279995, 8.429001, 8.43, 8.431, 8.431999, 8.433001, 8.434, 8.434999, 8.436001, 8.437, 8.438, 8.438999, 8.440001, 8.441, 8.441999, 8.443001, 8.444, 8.445, 8.445999, 8.4470005, 8.448, 8.448999, 8.450001, 8.451, 8.452, 8.452999, 8.454, 8.455, 8.455999, 8.457001, 8.458, 8.459, 8.459999, 8.461, 8.462, 8.462999, 8.464001, 8.465, 8.466, 8.466999, 8.468, 8.469, 8.469999, 8.471001, 8.472, 8.473, 8.474001, 8.475, 8.476, 8.476999, 8.478001, 8.479, 8.48, 8.481001, 8.482, 8.483, 8.483999, 8.485001, 8.486, 8.4869995, 8.488001, 8.489, 8.49, 8.490999, 8.492001, 8.493, 8.4939995, 8.495001, 8.496, 8.497, 8.497999, 8.499001, 8.5, 8.500999, 8.502001, 8.503, 8.504, 8.504999, 8.5060005, 8.507, 8.507999, 8.509001, 8.51, 8.511, 8.511999, 8.5130005, 8.514, 8.514999, 8.516001, 8.517, 8.518, 8.518999, 8.52, 8.521, 8.521999, 8.523001, 8.524, 8.525, 8.525999, 8.527, 8.528, 8.528999, 8.530001, 8.531, 8.532, 8.533001, 8.534, 8.535, 8.535999, 8.537001, 8.538, 8.539, 8.540001, 8.541, 8.542, 8.542999, 8.544001, 8.545, 8.546, 8.547001, 8.548, 8.549, 8.549999, 8.551001, 8.552, 8.5529995, 8.554001, 8.555, 8.556, 8.556999, 8.558001, 8.559, 8.559999, 8.561001, 8.562, 8.563, 8.563999, 8.565001, 8.566, 8.566999, 8.568001, 8.569, 8.57, 8.570999, 8.5720005, 8.573, 8.573999, 8.575001, 8.576, 8.577, 8.577999, 8.579, 8.58, 8.580999, 8.582001, 8.583, 8.584, 8.584999, 8.586, 8.587, 8.587999, 8.589001, 8.59, 8.591, 8.591999, 8.593, 8.594, 8.594999, 8.596001, 8.597, 8.598, 8.599001, 8.6, 8.601, 8.601999, 8.603001, 8.604, 8.605, 8.606001, 8.607, 8.608, 8.608999, 8.610001, 8.611, 8.6119995, 8.613001, 8.614, 8.615, 8.615999, 8.617001, 8.618, 8.6189995, 8.620001, 8.621, 8.622, 8.622999, 8.624001, 8.625, 8.625999, 8.627001, 8.628, 8.629, 8.629999, 8.6310005, 8.632, 8.632999, 8.634001, 8.635, 8.636, 8.636999, 8.6380005, 8.639, 8.639999, 8.641001, 8.642, 8.643, 8.643999, 8.645, 8.646, 8.646999, 8.648001, 8.649, 8.65, 8.650999, 8.652, 8.653, 8.653999, 8.655001, 8.656, 8.657, 8.658001, 8.659, 8.66, 8.660999, 8.662001, 8.663, 8.664, 8.665001, 8.666, 8.667, 8.667999, 8.669001, 8.67, 8.671, 8.672001, 8.673, 8.674, 8.674999, 8.676001, 8.677, 8.6779995, 8.679001, 8.68, 8.681, 8.681999, 8.683001, 8.684, 8.684999, 8.686001, 8.687, 8.688, 8.688999, 8.690001, 8.691, 8.691999, 8.693001, 8.694, 8.695, 8.695999, 8.6970005, 8.698, 8.698999, 8.700001, 8.701, 8.702, 8.702999, 8.704, 8.705, 8.705999, 8.707001, 8.708, 8.709, 8.709999, 8.711, 8.712, 8.712999, 8.714001, 8.715, 8.716, 8.716999, 8.718, 8.719, 8.719999, 8.721001, 8.722, 8.723, 8.724001, 8.725, 8.726, 8.726999, 8.728001, 8.729, 8.73, 8.731001, 8.732, 8.733, 8.733999, 8.735001, 8.736, 8.7369995, 8.738001, 8.739, 8.74, 8.740999, 8.742001, 8.743, 8.7439995, 8.745001, 8.746, 8.747, 8.747999, 8.749001, 8.75, 8.750999, 8.752001, 8.753, 8.754, 8.754999, 8.7560005, 8.757, 8.757999, 8.759001, 8.76, 8.761, 8.761999, 8.7630005, 8.764, 8.764999, 8.766001, 8.767, 8.768, 8.768999, 8.77, 8.771, 8.771999, 8.773001, 8.774, 8.775, 8.775999, 8.777, 8.778, 8.778999, 8.780001, 8.781, 8.782, 8.783001, 8.784, 8.785, 8.785999, 8.787001, 8.788, 8.789, 8.790001, 8.791, 8.792, 8.792999, 8.794001, 8.795, 8.796, 8.797001, 8.798, 8.799, 8.799999, 8.801001, 8.802, 8.8029995, 8.804001, 8.805, 8.806, 8.806999, 8.808001, 8.809, 8.809999, 8.811001, 8.812, 8.813, 8.813999, 8.815001, 8.816, 8.816999, 8.818001, 8.819, 8.82, 8.820999, 8.8220005, 8.823, 8.823999, 8.825001, 8.826, 8.827, 8.827999, 8.829, 8.83, 8.830999, 8.832001, 8.833, 8.834, 8.834999, 8.836, 8.837, 8.837999, 8.839001, 8.84, 8.841, 8.841999, 8.843, 8.844, 8.844999, 8.846001, 8.847, 8.848, 8.849001, 8.85, 8.851, 8.851999, 8.853001, 8.854, 8.855, 8.856001, 8.857, 8.858, 8.858999, 8.860001, 8.861, 8.8619995, 8.863001, 8.864, 8.865, 8.865999, 8.867001, 8.868, 8.8689995, 8.870001, 8.871, 8.872, 8.872999, 8.874001, 8.875, 8.875999, 8.877001, 8.878, 8.879, 8.879999, 8.8810005, 8.882, 8.882999, 8.884001, 8.885, 8.886, 8.886999, 8.8880005, 8.889, 8.889999, 8.891001, 8.892, 8.893, 8.893999, 8.895, 8.896, 8.896999, 8.898001, 8.899, 8.9, 8.900999, 8.902, 8.903, 8.903999, 8.905001, 8.906, 8.907, 8.908001, 8.909, 8.91, 8.910999, 8.912001, 8.913, 8.914, 8.915001, 8.916, 8.917, 8.917999, 8.919001, 8.92, 8.921, 8.922001, 8.923, 8.924, 8.924999, 8.926001, 8.927, 8.9279995, 8.929001, 8.93, 8.931, 8.931999, 8.933001, 8.934, 8.934999, 8.936001, 8.937, 8.938, 8.938999, 8.940001, 8.941, 8.941999, 8.943001, 8.944, 8.945, 8.945999, 8.9470005, 8.948, 8.948999, 8.950001, 8.951, 8.952, 8.952999, 8.954, 8.955, 8.955999, 8.957001, 8.958, 8.959, 8.959999, 8.961, 8.962, 8.962999, 8.964001, 8.965, 8.966, 8.966999, 8.968, 8.969, 8.969999, 8.971001, 8.972, 8.973, 8.974001, 8.975, 8.976, 8.976999, 8.978001, 8.979, 8.98, 8.981001, 8.982, 8.983, 8.983999, 8.985001, 8.986, 8.9869995, 8.988001, 8.989, 8.99, 8.990999, 8.992001, 8.993, 8.9939995, 8.995001, 8.996, 8.997, 8.997999, 8.999001, 9.0, 9.000999, 9.002001, 9.003, 9.004, 9.004999, 9.0060005, 9.007, 9.007999, 9.009001, 9.01, 9.011, 9.011999, 9.0130005, 9.014, 9.014999, 9.016001, 9.017, 9.018, 9.018999, 9.02, 9.021, 9.021999, 9.023001, 9.024, 9.025, 9.025999, 9.027, 9.028, 9.028999, 9.030001, 9.031, 9.032, 9.033001, 9.034, 9.035, 9.035999, 9.037001, 9.038, 9.039, 9.040001, 9.041, 9.042, 9.042999, 9.044001, 9.045, 9.046, 9.047001, 9.048, 9.049, 9.049999, 9.051001, 9.052, 9.0529995, 9.054001, 9.055, 9.056, 9.056999, 9.058001, 9.059, 9.059999, 9.061001, 9.062, 9.063, 9.063999, 9.065001, 9.066, 9.066999, 9.068001, 9.069, 9.07, 9.070999, 9.0720005, 9.073, 9.073999, 9.075001, 9.076, 9.077, 9.077999, 9.079, 9.08, 9.080999, 9.082001, 9.083, 9.084, 9.084999, 9.086, 9.087, 9.087999, 9.089001, 9.09, 9.091, 9.091999, 9.093, 9.094, 9.094999, 9.096001, 9.097, 9.098, 9.099001, 9.1, 9.101, 9.101999, 9.103001, 9.104, 9.105, 9.106001, 9.107, 9.108, 9.108999, 9.110001, 9.111, 9.1119995, 9.113001, 9.114, 9.115, 9.115999, 9.117001, 9.118, 9.1189995, 9.120001, 9.121, 9.122, 9.122999, 9.124001, 9.125, 9.125999, 9.127001, 9.128, 9.129, 9.129999, 9.1310005, 9.132, 9.132999, 9.134001, 9.135, 9.136, 9.136999, 9.1380005, 9.139, 9.139999, 9.141001, 9.142, 9.143, 9.143999, 9.145, 9.146, 9.146999, 9.148001, 9.149, 9.15, 9.150999, 9.152, 9.153, 9.153999, 9.155001, 9.156, 9.157, 9.158001, 9.159, 9.16, 9.160999, 9.162001, 9.163, 9.164, 9.165001, 9.166, 9.167, 9.167999, 9.169001, 9.17, 9.171, 9.172001, 9.173, 9.174, 9.174999, 9.176001, 9.177, 9.1779995, 9.179001, 9.18, 9.181, 9.181999, 9.183001, 9.184, 9.184999, 9.186001, 9.187, 9.188, 9.188999, 9.190001, 9.191, 9.191999, 9.193001, 9.194, 9.195, 9.195999, 9.1970005, 9.198, 9.198999, 9.200001, 9.201, 9.202, 9.202999, 9.204, 9.205, 9.205999, 9.207001, 9.208, 9.209, 9.209999, 9.211, 9.212, 9.212999, 9.214001, 9.215, 9.216, 9.216999, 9.218, 9.219, 9.219999, 9.221001, 9.222, 9.223, 9.224001, 9.225, 9.226, 9.226999, 9.228001, 9.229, 9.23, 9.231001, 9.232, 9.233, 9.233999, 9.235001, 9.236, 9.2369995, 9.238001, 9.239, 9.24, 9.240999, 9.242001, 9.243, 9.2439995, 9.245001, 9.246, 9.247, 9.247999, 9.249001, 9.25, 9.250999, 9.252001, 9.253, 9.254, 9.254999, 9.2560005, 9.257, 9.257999, 9.259001, 9.26, 9.261, 9.261999, 9.2630005, 9.264, 9.264999, 9.266001, 9.267, 9.268, 9.268999, 9.27, 9.271, 9.271999, 9.273001, 9.274, 9.275, 9.275999, 9.277, 9.278, 9.278999, 9.280001, 9.281, 9.282, 9.283001, 9.284, 9.285, 9.285999, 9.287001, 9.288, 9.289, 9.290001, 9.291, 9.292, 9.292999, 9.294001, 9.295, 9.296, 9.297001, 9.298, 9.299, 9.299999, 9.301001, 9.302, 9.3029995, 9.304001, 9.305, 9.306, 9.306999, 9.308001, 9.309, 9.309999, 9.311001, 9.312, 9.313, 9.313999, 9.315001, 9.316, 9.316999, 9.318001, 9.319, 9.32, 9.320999, 9.3220005, 9.323, 9.323999, 9.325001, 9.326, 9.327, 9.327999, 9.329, 9.33, 9.330999, 9.332001, 9.333, 9.334, 9.334999, 9.336, 9.337, 9.337999, 9.339001, 9.34, 9.341, 9.341999, 9.343, 9.344, 9.344999, 9.346001, 9.347, 9.348, 9.349001, 9.35, 9.351, 9.351999, 9.353001, 9.354, 9.355, 9.356001, 9.357, 9.358, 9.358999, 9.360001, 9.361, 9.3619995, 9.363001, 9.364, 9.365, 9.365999, 9.367001, 9.368, 9.3689995, 9.370001, 9.371, 9.372, 9.372999, 9.374001, 9.375, 9.375999, 9.377001, 9.378, 9.379, 9.379999, 9.3810005, 9.382, 9.382999, 9.384001, 9.385, 9.386, 9.386999, 9.3880005, 9.389, 9.389999, 9.391001, 9.392, 9.393, 9.393999, 9.395, 9.396, 9.396999, 9.398001, 9.399, 9.4, 9.400999, 9.402, 9.403, 9.403999, 9.405001, 9.406, 9.407, 9.408001, 9.409, 9.41, 9.410999, 9.412001, 9.413, 9.414, 9.415001, 9.416, 9.417, 9.417999, 9.419001, 9.42, 9.421, 9.422001, 9.423, 9.424, 9.424999, 9.426001, 9.427, 9.4279995, 9.429001, 9.43, 9.431, 9.431999, 9.433001, 9.434, 9.434999, 9.436001, 9.437, 9.438, 9.438999, 9.440001, 9.441, 9.441999, 9.443001, 9.444, 9.445, 9.445999, 9.4470005, 9.448, 9.448999, 9.450001, 9.451, 9.452, 9.452999, 9.454, 9.455, 9.455999, 9.457001, 9.458, 9.459, 9.459999, 9.461, 9.462, 9.462999, 9.464001, 9.465, 9.466, 9.466999, 9.468, 9.469, 9.469999, 9.471001, 9.472, 9.473, 9.474001, 9.475, 9.476, 9.476999, 9.478001, 9.479, 9.48, 9.481001, 9.482, 9.483, 9.483999, 9.485001, 9.486, 9.4869995, 9.488001, 9.489, 9.49, 9.490999, 9.492001, 9.493, 9.4939995, 9.495001, 9.496, 9.497, 9.497999, 9.499001, 9.5, 9.500999, 9.502001, 9.503, 9.504, 9.504999, 9.5060005, 9.507, 9.507999, 9.509001, 9.51, 9.511, 9.511999, 9.5130005, 9.514, 9.514999, 9.516001, 9.517, 9.518, 9.518999, 9.52, 9.521, 9.521999, 9.523001, 9.524, 9.525, 9.525999, 9.527, 9.528, 9.528999, 9.530001, 9.531, 9.532, 9.533001, 9.534, 9.535, 9.535999, 9.537001, 9.538, 9.539, 9.540001, 9.541, 9.542, 9.542999, 9.544001, 9.545, 9.546, 9.547001, 9.548, 9.549, 9.549999, 9.551001, 9.552, 9.5529995, 9.554001, 9.555, 9.556, 9.556999, 9.558001, 9.559, 9.559999, 9.561001, 9.562, 9.563, 9.563999, 9.565001, 9.566, 9.566999, 9.568001, 9.569, 9.57, 9.570999, 9.5720005, 9.573, 9.573999, 9.575001, 9.576, 9.577, 9.577999, 9.579, 9.58, 9.580999, 9.582001, 9.583, 9.584, 9.584999, 9.586, 9.587, 9.587999, 9.589001, 9.59, 9.591, 9.591999, 9.593, 9.594, 9.594999, 9.596001, 9.597, 9.598, 9.599001, 9.6, 9.601, 9.601999, 9.603001, 9.604, 9.605, 9.606001, 9.607, 9.608, 9.608999, 9.610001, 9.611, 9.6119995, 9.613001, 9.614, 9.615, 9.615999, 9.617001, 9.618, 9.6189995, 9.620001, 9.621, 9.622, 9.622999, 9.624001, 9.625, 9.625999, 9.627001, 9.628, 9.629, 9.629999, 9.6310005, 9.632, 9.632999, 9.634001, 9.635, 9.636, 9.636999, 9.6380005, 9.639, 9.639999, 9.641001, 9.642, 9.643, 9.643999, 9.645, 9.646, 9.646999, 9.648001, 9.649, 9.65, 9.650999, 9.652, 9.653, 9.653999, 9.655001, 9.656, 9.657, 9.658001, 9.659, 9.66, 9.660999, 9.662001, 9.663, 9.664, 9.665001, 9.666, 9.667, 9.667999, 9.669001, 9.67, 9.671, 9.672001, 9.673, 9.674, 9.674999, 9.676001, 9.677, 9.6779995, 9.679001, 9.68, 9.681, 9.681999, 9.683001, 9.684, 9.684999, 9.686001, 9.687, 9.688, 9.688999, 9.690001, 9.691, 9.691999, 9.693001, 9.694, 9.695, 9.695999, 9.6970005, 9.698, 9.698999, 9.700001, 9.701, 9.702, 9.702999, 9.704, 9.705, 9.705999, 9.707001, 9.708, 9.709, 9.709999, 9.711, 9.712, 9.712999, 9.714001, 9.715, 9.716, 9.716999, 9.718, 9.719, 9.719999, 9.721001, 9.722, 9.723, 9.724001, 9.725, 9.726, 9.726999, 9.728001, 9.729, 9.73, 9.731001, 9.732, 9.733, 9.733999, 9.735001, 9.736, 9.7369995, 9.738001, 9.739, 9.74, 9.740999, 9.742001, 9.743, 9.7439995, 9.745001, 9.746, 9.747, 9.747999, 9.749001, 9.75, 9.750999, 9.752001, 9.753, 9.754, 9.754999, 9.7560005, 9.757, 9.757999, 9.759001, 9.76, 9.761, 9.761999, 9.7630005, 9.764, 9.764999, 9.766001, 9.767, 9.768, 9.768999, 9.77, 9.771, 9.771999, 9.773001, 9.774, 9.775, 9.775999, 9.777, 9.778, 9.778999, 9.780001, 9.781, 9.782, 9.783001, 9.784, 9.785, 9.785999, 9.787001, 9.788, 9.789, 9.790001, 9.791, 9.792, 9.792999, 9.794001, 9.795, 9.796, 9.797001, 9.798, 9.799, 9.799999, 9.801001, 9.802, 9.8029995, 9.804001, 9.805, 9.806, 9.806999, 9.808001, 9.809, 9.809999, 9.811001, 9.812, 9.813, 9.813999, 9.815001, 9.816, 9.816999, 9.818001, 9.819, 9.82, 9.820999, 9.8220005, 9.823, 9.823999, 9.825001, 9.826, 9.827, 9.827999, 9.829, 9.83, 9.830999, 9.832001, 9.833, 9.834, 9.834999, 9.836, 9.837, 9.837999, 9.839001, 9.84, 9.841, 9.841999, 9.843, 9.844, 9.844999, 9.846001, 9.847, 9.848, 9.849001, 9.85, 9.851, 9.851999, 9.853001, 9.854, 9.855, 9.856001, 9.857, 9.858, 9.858999, 9.860001, 9.861, 9.8619995, 9.863001, 9.864, 9.865, 9.865999, 9.867001, 9.868, 9.8689995, 9.870001, 9.871, 9.872, 9.872999, 9.874001, 9.875, 9.875999, 9.877001, 9.878, 9.879, 9.879999, 9.8810005, 9.882, 9.882999, 9.884001, 9.885, 9.886, 9.886999, 9.8880005, 9.889, 9.889999, 9.891001, 9.892, 9.893, 9.893999, 9.895, 9.896, 9.896999, 9.898001, 9.899, 9.9, 9.900999, 9.902, 9.903, 9.903999, 9.905001, 9.906, 9.907, 9.908001, 9.909, 9.91, 9.910999, 9.912001, 9.913, 9.914, 9.915001, 9.916, 9.917, 9.917999, 9.919001, 9.92, 9.921, 9.922001, 9.923, 9.924, 9.924999, 9.926001, 9.927, 9.9279995, 9.929001, 9.93, 9.931, 9.931999, 9.933001, 9.934, 9.934999, 9.936001, 9.937, 9.938, 9.938999, 9.940001, 9.941, 9.941999, 9.943001, 9.944, 9.945, 9.945999, 9.9470005, 9.948, 9.948999, 9.950001, 9.951, 9.952, 9.952999, 9.954, 9.955, 9.955999, 9.957001, 9.958, 9.959, 9.959999, 9.961, 9.962, 9.962999, 9.964001, 9.965, 9.966, 9.966999, 9.968, 9.969, 9.969999, 9.971001, 9.972, 9.973, 9.974001, 9.975, 9.976, 9.976999, 9.978001, 9.979, 9.98, 9.981001, 9.982, 9.983, 9.983999, 9.985001, 9.986, 9.9869995, 9.988001, 9.989, 9.99, 9.990999, 9.992001, 9.993, 9.9939995, 9.995001, 9.996, 9.997, 9.997999, 9.999001];
 #[cfg(test)]
-#[allow(dead_code)]
+#[allow(dead_code, clippy::approx_constant, clippy::large_const_arrays)]
 pub const X_F64: [f64; 20000] = [-10.0, -9.999, -9.998, -9.997, -9.996, -9.995, -9.994, -9.993, -9.992, -9.991, -9.99, -9.989, -9.988, -9.987, -9.986, -9.985, -9.984, -9.983, -9.982, -9.981, -9.98, -9.979, -9.978, -9.977, -9.976, -9.975, -9.974, -9.973, -9.972, -9.971, -9.97, -9.969, -9.968, -9.967, -9.966, -9.965, -9.964, -9.963, -9.962, -9.961, -9.96, -9.959, -9.958, -9.957, -9.956, -9.955, -9.954, -9.953, -9.952, -9.951, -9.95, -9.949, -9.948, -9.947, -9.946, -9.945, -9.944, -9.943, -9.942, -9.941, -9.94, -9.939, -9.938, -9.937, -9.936, -9.935, -9.934, -9.933, -9.932, -9.931, -9.93, -9.929, -9.928, -9.927, -9.926, -9.925, -9.924, -9.923, -9.922, -9.921, -9.92, -9.919, -9.918, -9.917, -9.916, -9.915, -9.914, -9.913, -9.912, -9.911, -9.91, -9.909, -9.908, -9.907, -9.906, -9.905, -9.904, -9.903, -9.902, -9.901, -9.9, -9.899, -9.898, -9.897, -9.896, -9.895, -9.894, -9.893, -9.892, -9.891, -9.89, -9.889, -9.888, -9.887, -9.886, -9.885, -9.884, -9.883, -9.882, -9.881, -9.88, -9.879, -9.878, -9.877, -9.876, -9.875, -9.874, -9.873, -9.872, -9.871, -9.87, -9.869, -9.868, -9.867, -9.866, -9.865, -9.864, -9.863, -9.862, -9.861, -9.86, -9.859, -9.858, -9.857, -9.856, -9.855, -9.854, -9.853, -9.852, -9.851, -9.85, -9.849, -9.848, -9.847, -9.846, -9.845, -9.844, -9.843, -9.842, -9.841, -9.84, -9.839, -9.838, -9.837, -9.836, -9.835, -9.834, -9.833, -9.832, -9.831, -9.83, -9.829, -9.828, -9.827, -9.826, -9.825, -9.824, -9.823, -9.822, -9.821, -9.82, -9.819, -9.818, -9.817, -9.816, -9.815, -9.814, -9.813, -9.812, -9.811, -9.81, -9.809, -9.808, -9.807, -9.806, -9.805, -9.804, -9.803, -9.802, -9.801, -9.8, -9.799, -9.798, -9.797, -9.796, -9.795, -9.794, -9.793, -9.792, -9.791, -9.79, -9.789, -9.788, -9.787, -9.786, -9.785, -9.784, -9.783, -9.782, -9.781, -9.78, -9.779, -9.778, -9.777, -9.776, -9.775, -9.774, -9.773, -9.772, -9.771, -9.77, -9.769, -9.768, -9.767, -9.766, -9.765, -9.764, -9.763, -9.762, -9.761, -9.76, -9.759, -9.758, -9.757, -9.756, -9.755, -9.754, -9.753, -9.752, -9.751, -9.75, -9.749, -9.748, -9.747, -9.746, -9.745, -9.744, -9.743, -9.742, -9.741, -9.74, -9.739, -9.738, -9.737, -9.736, -9.735, -9.734, -9.733, -9.732, -9.731, -9.73, -9.729, -9.728, -9.727, -9.725999999999999, -9.725, -9.724, -9.723, -9.722, -9.721, -9.72, -9.719, -9.718, -9.717, -9.716, -9.715, -9.714, -9.713, -9.712, -9.711, -9.71, -9.709, -9.708, -9.707, -9.706, -9.705, -9.704, -9.703, -9.702, -9.701, -9.7, -9.699, -9.698, -9.697, -9.696, -9.695, -9.694, -9.693, -9.692, -9.691, -9.69, -9.689, -9.688, -9.687, -9.686, -9.685, -9.684, -9.683, -9.682, -9.681, -9.68, -9.679, -9.678, -9.677, -9.676, -9.675, -9.674, -9.673, -9.672, -9.671, -9.67, -9.669, -9.668, -9.667, -9.666, -9.665, -9.664, -9.663, -9.662, -9.661, -9.66, -9.659, -9.658, -9.657, -9.656, -9.655, -9.654, -9.653, -9.652, -9.651, -9.65, -9.649000000000001, -9.648, -9.647, -9.646, -9.645, -9.644, -9.643, -9.642, -9.641, -9.64, -9.639, -9.638, -9.637, -9.636, -9.635, -9.634, -9.633, -9.632, -9.631, -9.63, -9.629, -9.628, -9.627, -9.626, -9.625, -9.624, -9.623, -9.622, -9.621, -9.62, -9.619, -9.618, -9.617, -9.616, -9.615, -9.614, -9.613, -9.612, -9.611, -9.61, -9.609, -9.608, -9.607, -9.606, -9.605, -9.604, -9.603, -9.602, -9.600999999999999, -9.6, -9.599, -9.598, -9.597, -9.596, -9.595, -9.594, -9.593, -9.592, -9.591, -9.59, -9.589, -9.588, -9.587, -9.586, -9.585, -9.584, -9.583, -9.582, -9.581, -9.58, -9.579, -9.578, -9.577, -9.576, -9.575, -9.574, -9.573, -9.572, -9.571, -9.57, -9.569, -9.568, -9.567, -9.566, -9.565, -9.564, -9.563, -9.562, -9.561, -9.56, -9.559, -9.558, -9.557, -9.556, -9.555, -9.554, -9.553, -9.552, -9.551, -9.55, -9.549, -9.548, -9.547, -9.546, -9.545, -9.544, -9.543, -9.542, -9.541, -9.54, -9.539, -9.538, -9.537, -9.536, -9.535, -9.534, -9.533, -9.532, -9.531, -9.53, -9.529, -9.528, -9.527, -9.526, -9.525, -9.524000000000001, -9.523, -9.522, -9.521, -9.52, -9.519, -9.518, -9.517, -9.516, -9.515, -9.514, -9.513, -9.512, -9.511, -9.51, -9.509, -9.508, -9.507, -9.506, -9.505, -9.504, -9.503, -9.502, -9.501, -9.5, -9.499, -9.498, -9.497, -9.496, -9.495, -9.494, -9.493, -9.492, -9.491, -9.49, -9.489, -9.488, -9.487, -9.486, -9.485, -9.484, -9.483, -9.482, -9.481, -9.48, -9.479, -9.478, -9.477, -9.475999999999999, -9.475, -9.474, -9.473, -9.472, -9.471, -9.47, -9.469, -9.468, -9.467, -9.466, -9.465, -9.464, -9.463, -9.462, -9.461, -9.46, -9.459, -9.458, -9.457, -9.456, -9.455, -9.454, -9.453, -9.452, -9.451, -9.45, -9.449, -9.448, -9.447, -9.446, -9.445, -9.443999999999999, -9.443, -9.442, -9.441, -9.44, -9.439, -9.438, -9.437, -9.436, -9.435, -9.434, -9.433, -9.432, -9.431000000000001, -9.43, -9.429, -9.428, -9.427, -9.426, -9.425, -9.424, -9.423, -9.422, -9.421, -9.42, -9.419, -9.418, -9.417, -9.416, -9.415, -9.414, -9.413, -9.412, -9.411, -9.41, -9.409, -9.408, -9.407, -9.406, -9.405, -9.404, -9.403, -9.402, -9.401, -9.4, -9.399000000000001, -9.398, -9.397, -9.396, -9.395, -9.394, -9.393, -9.392, -9.391, -9.39, -9.389, -9.388, -9.387, -9.386, -9.385, -9.384, -9.383, -9.382, -9.381, -9.38, -9.379, -9.378, -9.377, -9.376, -9.375, -9.374, -9.373, -9.372, -9.371, -9.37, -9.369, -9.368, -9.367, -9.366, -9.365, -9.364, -9.363, -9.362, -9.361, -9.36, -9.359, -9.358, -9.357, -9.356, -9.355, -9.354, -9.353, -9.352, -9.350999999999999, -9.35, -9.349, -9.348, -9.347, -9.346, -9.345, -9.344, -9.343, -9.342, -9.341, -9.34, -9.339, -9.338, -9.337, -9.336, -9.335, -9.334, -9.333, -9.332, -9.331, -9.33, -9.329, -9.328, -9.327, -9.326, -9.325, -9.324, -9.323, -9.322, -9.321, -9.32, -9.318999999999999, -9.318, -9.317, -9.316, -9.315, -9.314, -9.313, -9.312, -9.311, -9.31, -9.309, -9.308, -9.307, -9.306000000000001, -9.305, -9.304, -9.303, -9.302, -9.301, -9.3, -9.299, -9.298, -9.297, -9.296, -9.295, -9.294, -9.293, -9.292, -9.291, -9.29, -9.289, -9.288, -9.287, -9.286, -9.285, -9.284, -9.283, -9.282, -9.281, -9.28, -9.279, -9.278, -9.277, -9.276, -9.275, -9.274000000000001, -9.273, -9.272, -9.271, -9.27, -9.269, -9.268, -9.267, -9.266, -9.265, -9.264, -9.263, -9.262, -9.261, -9.26, -9.259, -9.258, -9.257, -9.256, -9.255, -9.254, -9.253, -9.252, -9.251, -9.25, -9.249, -9.248, -9.247, -9.246, -9.245, -9.244, -9.243, -9.242, -9.241, -9.24, -9.239, -9.238, -9.237, -9.236, -9.235, -9.234, -9.233, -9.232, -9.231, -9.23, -9.229, -9.228, -9.227, -9.225999999999999, -9.225, -9.224, -9.223, -9.222, -9.221, -9.22, -9.219, -9.218, -9.217, -9.216, -9.215, -9.214, -9.213, -9.212, -9.211, -9.21, -9.209, -9.208, -9.207, -9.206, -9.205, -9.204, -9.203, -9.202, -9.201, -9.2, -9.199, -9.198, -9.197, -9.196, -9.195, -9.193999999999999, -9.193, -9.192, -9.191, -9.19, -9.189, -9.188, -9.187, -9.186, -9.185, -9.184, -9.183, -9.182, -9.181000000000001, -9.18, -9.179, -9.178, -9.177, -9.176, -9.175, -9.174, -9.173, -9.172, -9.171, -9.17, -9.169, -9.168, -9.167, -9.166, -9.165, -9.164, -9.163, -9.162, -9.161, -9.16, -9.159, -9.158, -9.157, -9.156, -9.155, -9.154, -9.153, -9.152, -9.151, -9.15, -9.149000000000001, -9.148, -9.147, -9.146, -9.145, -9.144, -9.143, -9.142, -9.141, -9.14, -9.139, -9.138, -9.137, -9.136, -9.135, -9.134, -9.133, -9.132, -9.131, -9.13, -9.129, -9.128, -9.127, -9.126, -9.125, -9.124, -9.123, -9.122, -9.121, -9.12, -9.119, -9.118, -9.117, -9.116, -9.115, -9.114, -9.113, -9.112, -9.111, -9.11, -9.109, -9.108, -9.107, -9.106, -9.105, -9.104, -9.103, -9.102, -9.100999999999999, -9.1, -9.099, -9.098, -9.097, -9.096, -9.095, -9.094, -9.093, -9.092, -9.091, -9.09, -9.089, -9.088, -9.087, -9.086, -9.085, -9.084, -9.083, -9.082, -9.081, -9.08, -9.079, -9.078, -9.077, -9.076, -9.075, -9.074, -9.073, -9.072, -9.071, -9.07, -9.068999999999999, -9.068, -9.067, -9.066, -9.065, -9.064, -9.063, -9.062, -9.061, -9.06, -9.059, -9.058, -9.057, -9.056000000000001, -9.055, -9.054, -9.053, -9.052, -9.051, -9.05, -9.049, -9.048, -9.047, -9.046, -9.045, -9.044, -9.043, -9.042, -9.041, -9.04, -9.039, -9.038, -9.037, -9.036, -9.035, -9.034, -9.033, -9.032, -9.031, -9.03, -9.029, -9.028, -9.027, -9.026, -9.025, -9.024000000000001, -9.023, -9.022, -9.021, -9.02, -9.019, -9.018, -9.017, -9.016, -9.015, -9.014, -9.013, -9.012, -9.011, -9.01, -9.009, -9.008, -9.007, -9.006, -9.005, -9.004, -9.003, -9.002, -9.001, -9.0, -8.999, -8.998, -8.997, -8.996, -8.995000000000001, -8.994, -8.993, -8.992, -8.991, -8.99, -8.989, -8.988, -8.987, -8.986, -8.985, -8.984, -8.983, -8.982, -8.981, -8.98, -8.979, -8.978, -8.977, -8.975999999999999, -8.975, -8.974, -8.973, -8.972, -8.971, -8.97, -8.969, -8.968, -8.967, -8.966, -8.965, -8.964, -8.963000000000001, -8.962, -8.961, -8.96, -8.959, -8.958, -8.957, -8.956, -8.955, -8.954, -8.953, -8.952, -8.951, -8.95, -8.949, -8.948, -8.947, -8.946, -8.945, -8.943999999999999, -8.943, -8.942, -8.941, -8.94, -8.939, -8.938, -8.937, -8.936, -8.935, -8.934, -8.933, -8.932, -8.931000000000001, -8.93, -8.929, -8.928, -8.927, -8.926, -8.925, -8.924, -8.923, -8.922, -8.921, -8.92, -8.919, -8.918, -8.917, -8.916, -8.915, -8.914, -8.913, -8.911999999999999, -8.911, -8.91, -8.909, -8.908, -8.907, -8.906, -8.905, -8.904, -8.903, -8.902, -8.901, -8.9, -8.899000000000001, -8.898, -8.897, -8.896, -8.895, -8.894, -8.893, -8.892, -8.891, -8.89, -8.889, -8.888, -8.887, -8.886, -8.885, -8.884, -8.883, -8.882, -8.881, -8.879999999999999, -8.879, -8.878, -8.877, -8.876, -8.875, -8.874, -8.873, -8.872, -8.871, -8.870000000000001, -8.869, -8.868, -8.867, -8.866, -8.865, -8.864, -8.863, -8.862, -8.861, -8.86, -8.859, -8.858, -8.857, -8.856, -8.855, -8.854, -8.853, -8.852, -8.850999999999999, -8.85, -8.849, -8.848, -8.847, -8.846, -8.845, -8.844, -8.843, -8.842, -8.841, -8.84, -8.839, -8.838000000000001, -8.837, -8.836, -8.835, -8.834, -8.833, -8.832, -8.831, -8.83, -8.829, -8.828, -8.827, -8.826, -8.825, -8.824, -8.823, -8.822, -8.821, -8.82, -8.818999999999999, -8.818, -8.817, -8.816, -8.815, -8.814, -8.813, -8.812, -8.811, -8.81, -8.809, -8.808, -8.807, -8.806000000000001, -8.805, -8.804, -8.803, -8.802, -8.801, -8.8, -8.799, -8.798, -8.797, -8.796, -8.795, -8.794, -8.793, -8.792, -8.791, -8.79, -8.789, -8.788, -8.786999999999999, -8.786, -8.785, -8.784, -8.783, -8.782, -8.781, -8.78, -8.779, -8.778, -8.777, -8.776, -8.775, -8.774000000000001, -8.773, -8.772, -8.771, -8.77, -8.769, -8.768, -8.767, -8.766, -8.765, -8.764, -8.763, -8.762, -8.761, -8.76, -8.759, -8.758, -8.757, -8.756, -8.754999999999999, -8.754, -8.753, -8.752, -8.751, -8.75, -8.749, -8.748, -8.747, -8.746, -8.745000000000001, -8.744, -8.743, -8.742, -8.741, -8.74, -8.739, -8.738, -8.737, -8.736, -8.735, -8.734, -8.733, -8.732, -8.731, -8.73, -8.729, -8.728, -8.727, -8.725999999999999, -8.725, -8.724, -8.723, -8.722, -8.721, -8.72, -8.719, -8.718, -8.717, -8.716, -8.715, -8.714, -8.713000000000001, -8.712, -8.711, -8.71, -8.709, -8.708, -8.707, -8.706, -8.705, -8.704, -8.703, -8.702, -8.701, -8.7, -8.699, -8.698, -8.697, -8.696, -8.695, -8.693999999999999, -8.693, -8.692, -8.691, -8.69, -8.689, -8.688, -8.687, -8.686, -8.685, -8.684, -8.683, -8.682, -8.681000000000001, -8.68, -8.679, -8.678, -8.677, -8.676, -8.675, -8.674, -8.673, -8.672, -8.671, -8.67, -8.669, -8.668, -8.667, -8.666, -8.665, -8.664, -8.663, -8.661999999999999, -8.661, -8.66, -8.659, -8.658, -8.657, -8.656, -8.655, -8.654, -8.653, -8.652, -8.651, -8.65, -8.649000000000001, -8.648, -8.647, -8.646, -8.645, -8.644, -8.643, -8.642, -8.641, -8.64, -8.639, -8.638, -8.637, -8.636, -8.635, -8.634, -8.633, -8.632, -8.631, -8.629999999999999, -8.629, -8.628, -8.627, -8.626, -8.625, -8.624, -8.623, -8.622, -8.621, -8.620000000000001, -8.619, -8.618, -8.617, -8.616, -8.615, -8.614, -8.613, -8.612, -8.611, -8.61, -8.609, -8.608, -8.607, -8.606, -8.605, -8.604, -8.603, -8.602, -8.600999999999999, -8.6, -8.599, -8.598, -8.597, -8.596, -8.595, -8.594, -8.593, -8.592, -8.591, -8.59, -8.589, -8.588000000000001, -8.587, -8.586, -8.585, -8.584, -8.583, -8.582, -8.581, -8.58, -8.579, -8.578, -8.577, -8.576, -8.575, -8.574, -8.573, -8.572, -8.571, -8.57, -8.568999999999999, -8.568, -8.567, -8.566, -8.565, -8.564, -8.563, -8.562, -8.561, -8.56, -8.559, -8.558, -8.557, -8.556000000000001, -8.555, -8.554, -8.553, -8.552, -8.551, -8.55, -8.549, -8.548, -8.547, -8.546, -8.545, -8.544, -8.543, -8.542, -8.541, -8.54, -8.539, -8.538, -8.536999999999999, -8.536, -8.535, -8.534, -8.533, -8.532, -8.531, -8.53, -8.529, -8.528, -8.527, -8.526, -8.525, -8.524000000000001, -8.523, -8.522, -8.521, -8.52, -8.519, -8.518, -8.517, -8.516, -8.515, -8.514, -8.513, -8.512, -8.511, -8.51, -8.509, -8.508, -8.507, -8.506, -8.504999999999999, -8.504, -8.503, -8.502, -8.501, -8.5, -8.499, -8.498, -8.497, -8.496, -8.495000000000001, -8.494, -8.493, -8.492, -8.491, -8.49, -8.489, -8.488, -8.487, -8.486, -8.485, -8.484, -8.483, -8.482, -8.481, -8.48, -8.479, -8.478, -8.477, -8.475999999999999, -8.475, -8.474, -8.473, -8.472, -8.471, -8.47, -8.469, -8.468, -8.467, -8.466, -8.465, -8.464, -8.463000000000001, -8.462, -8.461, -8.46, -8.459, -8.458, -8.457, -8.456, -8.455, -8.454, -8.453, -8.452, -8.451, -8.45, -8.449, -8.448, -8.447, -8.446, -8.445, -8.443999999999999, -8.443, -8.442, -8.441, -8.44, -8.439, -8.438, -8.437, -8.436, -8.435, -8.434, -8.433, -8.432, -8.431000000000001, -8.43, -8.429, -8.428, -8.427, -8.426, -8.425, -8.424, -8.423, -8.422, -8.421, -8.42, -8.419, -8.418, -8.417, -8.416, -8.415, -8.414, -8.413, -8.411999999999999, -8.411, -8.41, -8.409, -8.408, -8.407, -8.406, -8.405, -8.404, -8.403, -8.402, -8.401, -8.4, -8.399000000000001, -8.398, -8.397, -8.396, -8.395, -8.394, -8.393, -8.392, -8.391, -8.39, -8.389, -8.388, -8.387, -8.386, -8.385, -8.384, -8.383, -8.382, -8.381, -8.379999999999999, -8.379, -8.378, -8.377, -8.376, -8.375, -8.374, -8.373, -8.372, -8.371, -8.370000000000001, -8.369, -8.368, -8.367, -8.366, -8.365, -8.364, -8.363, -8.362, -8.361, -8.36, -8.359, -8.358, -8.357, -8.356, -8.355, -8.354, -8.353, -8.352, -8.350999999999999, -8.35, -8.349, -8.348, -8.347, -8.346, -8.345, -8.344, -8.343, -8.342, -8.341, -8.34, -8.339, -8.338000000000001, -8.337, -8.336, -8.335, -8.334, -8.333, -8.332, -8.331, -8.33, -8.329, -8.328, -8.327, -8.326, -8.325, -8.324, -8.323, -8.322, -8.321, -8.32, -8.318999999999999, -8.318, -8.317, -8.316, -8.315, -8.314, -8.313, -8.312, -8.311, -8.31, -8.309, -8.308, -8.307, -8.306000000000001, -8.305, -8.304, -8.303, -8.302, -8.301, -8.3, -8.299, -8.298, -8.297, -8.296, -8.295, -8.294, -8.293, -8.292, -8.291, -8.29, -8.289, -8.288, -8.286999999999999, -8.286, -8.285, -8.284, -8.283, -8.282, -8.281, -8.28, -8.279, -8.278, -8.277, -8.276, -8.275, -8.274000000000001, -8.273, -8.272, -8.271, -8.27, -8.269, -8.268, -8.267, -8.266, -8.265, -8.264, -8.263, -8.262, -8.261, -8.26, -8.259, -8.258, -8.257, -8.256, -8.254999999999999, -8.254, -8.253, -8.252, -8.251, -8.25, -8.249, -8.248, -8.247, -8.246, -8.245000000000001, -8.244, -8.243, -8.242, -8.241, -8.24, -8.239, -8.238, -8.237, -8.236, -8.235, -8.234, -8.233, -8.232, -8.231, -8.23, -8.229, -8.228, -8.227, -8.225999999999999, -8.225, -8.224, -8.223, -8.222, -8.221, -8.22, -8.219, -8.218, -8.217, -8.216, -8.215, -8.214, -8.213000000000001, -8.212, -8.211, -8.21, -8.209, -8.208, -8.207, -8.206, -8.205, -8.204, -8.203, -8.202, -8.201, -8.2, -8.199, -8.198, -8.197, -8.196, -8.195, -8.193999999999999, -8.193, -8.192, -8.191, -8.19, -8.189, -8.188, -8.187, -8.186, -8.185, -8.184, -8.183, -8.182, -8.181000000000001, -8.18, -8.179, -8.178, -8.177, -8.176, -8.175, -8.174, -8.173, -8.172, -8.171, -8.17, -8.169, -8.168, -8.167, -8.166, -8.165, -8.164, -8.163, -8.161999999999999, -8.161, -8.16, -8.159, -8.158, -8.157, -8.156, -8.155, -8.154, -8.153, -8.152, -8.151, -8.15, -8.149000000000001, -8.148, -8.147, -8.146, -8.145, -8.144, -8.143, -8.142, -8.141, -8.14, -8.139, -8.138, -8.137, -8.136, -8.135, -8.134, -8.133, -8.132, -8.131, -8.129999999999999, -8.129, -8.128, -8.127, -8.126, -8.125, -8.124, -8.123, -8.122, -8.121, -8.120000000000001, -8.119, -8.118, -8.117, -8.116, -8.115, -8.114, -8.113, -8.112, -8.111, -8.11, -8.109, -8.108, -8.107, -8.106, -8.105, -8.104, -8.103, -8.102, -8.100999999999999, -8.1, -8.099, -8.098, -8.097, -8.096, -8.095, -8.094, -8.093, -8.092, -8.091, -8.09, -8.089, -8.088000000000001, -8.087, -8.086, -8.085, -8.084, -8.083, -8.082, -8.081, -8.08, -8.079, -8.078, -8.077, -8.076, -8.075, -8.074, -8.073, -8.072, -8.071, -8.07, -8.068999999999999, -8.068, -8.067, -8.066, -8.065, -8.064, -8.063, -8.062, -8.061, -8.06, -8.059, -8.058, -8.057, -8.056000000000001, -8.055, -8.054, -8.053, -8.052, -8.051, -8.05, -8.049, -8.048, -8.047, -8.046, -8.045, -8.044, -8.043, -8.042, -8.041, -8.04, -8.039, -8.038, -8.036999999999999, -8.036, -8.035, -8.034, -8.033, -8.032, -8.031, -8.03, -8.029, -8.028, -8.027, -8.026, -8.025, -8.024000000000001, -8.023, -8.022, -8.021, -8.02, -8.019, -8.018, -8.017, -8.016, -8.015, -8.014, -8.013, -8.012, -8.011, -8.01, -8.009, -8.008, -8.007, -8.006, -8.004999999999999, -8.004, -8.003, -8.002, -8.001, -8.0, -7.9990000000000006, -7.998, -7.997, -7.996, -7.995, -7.994, -7.993, -7.992, -7.991, -7.99, -7.989, -7.9879999999999995, -7.987, -7.986000000000001, -7.984999999999999, -7.984, -7.9830000000000005, -7.982, -7.981, -7.98, -7.979, -7.978, -7.977, -7.976, -7.975, -7.974, -7.973, -7.9719999999999995, -7.971, -7.970000000000001, -7.968999999999999, -7.968, -7.9670000000000005, -7.966, -7.965, -7.964, -7.963, -7.962, -7.961, -7.96, -7.959, -7.958, -7.957, -7.9559999999999995, -7.955, -7.954000000000001, -7.952999999999999, -7.952, -7.9510000000000005, -7.95, -7.949, -7.948, -7.947, -7.946, -7.945, -7.944, -7.943, -7.942, -7.941, -7.9399999999999995, -7.939, -7.938000000000001, -7.936999999999999, -7.936, -7.9350000000000005, -7.934, -7.933, -7.932, -7.931, -7.93, -7.929, -7.928, -7.927, -7.926, -7.925, -7.9239999999999995, -7.923, -7.922000000000001, -7.920999999999999, -7.92, -7.9190000000000005, -7.918, -7.917, -7.916, -7.915, -7.914, -7.913, -7.912, -7.911, -7.91, -7.909, -7.9079999999999995, -7.907, -7.906000000000001, -7.904999999999999, -7.904, -7.9030000000000005, -7.902, -7.901, -7.9, -7.899, -7.898, -7.897, -7.896, -7.895, -7.894, -7.893, -7.8919999999999995, -7.891, -7.890000000000001, -7.888999999999999, -7.888, -7.8870000000000005, -7.886, -7.885, -7.884, -7.883, -7.882, -7.881, -7.88, -7.879, -7.878, -7.877, -7.8759999999999994, -7.875, -7.8740000000000006, -7.873, -7.872, -7.871, -7.87, -7.869, -7.868, -7.867, -7.866, -7.865, -7.864, -7.8629999999999995, -7.862, -7.861000000000001, -7.859999999999999, -7.859, -7.8580000000000005, -7.857, -7.856, -7.855, -7.854, -7.853, -7.852, -7.851, -7.85, -7.849, -7.848, -7.8469999999999995, -7.846, -7.845000000000001, -7.843999999999999, -7.843, -7.8420000000000005, -7.841, -7.84, -7.839, -7.838, -7.837, -7.836, -7.835, -7.834, -7.833, -7.832, -7.8309999999999995, -7.83, -7.829000000000001, -7.827999999999999, -7.827, -7.8260000000000005, -7.825, -7.824, -7.823, -7.822, -7.821, -7.82, -7.819, -7.818, -7.817, -7.816, -7.8149999999999995, -7.814, -7.813000000000001, -7.811999999999999, -7.811, -7.8100000000000005, -7.809, -7.808, -7.807, -7.806, -7.805, -7.804, -7.803, -7.802, -7.801, -7.8, -7.7989999999999995, -7.798, -7.797000000000001, -7.795999999999999, -7.795, -7.7940000000000005, -7.793, -7.792, -7.791, -7.79, -7.789, -7.788, -7.787, -7.786, -7.785, -7.784, -7.7829999999999995, -7.782, -7.781000000000001, -7.779999999999999, -7.779, -7.7780000000000005, -7.777, -7.776, -7.775, -7.774, -7.773, -7.772, -7.771, -7.77, -7.769, -7.768, -7.7669999999999995, -7.766, -7.765000000000001, -7.763999999999999, -7.763, -7.7620000000000005, -7.761, -7.76, -7.759, -7.758, -7.757, -7.756, -7.755, -7.754, -7.753, -7.752, -7.7509999999999994, -7.75, -7.7490000000000006, -7.748, -7.747, -7.746, -7.745, -7.744, -7.743, -7.742, -7.741, -7.74, -7.739, -7.7379999999999995, -7.737, -7.736000000000001, -7.734999999999999, -7.734, -7.7330000000000005, -7.732, -7.731, -7.73, -7.729, -7.728, -7.727, -7.726, -7.725, -7.724, -7.723, -7.7219999999999995, -7.721, -7.720000000000001, -7.718999999999999, -7.718, -7.7170000000000005, -7.716, -7.715, -7.714, -7.713, -7.712, -7.711, -7.71, -7.709, -7.708, -7.707, -7.7059999999999995, -7.705, -7.704000000000001, -7.702999999999999, -7.702, -7.7010000000000005, -7.7, -7.699, -7.698, -7.697, -7.696, -7.695, -7.694, -7.693, -7.692, -7.691, -7.6899999999999995, -7.689, -7.688000000000001, -7.686999999999999, -7.686, -7.6850000000000005, -7.684, -7.683, -7.682, -7.681, -7.68, -7.679, -7.678, -7.677, -7.676, -7.675, -7.6739999999999995, -7.673, -7.672000000000001, -7.670999999999999, -7.67, -7.6690000000000005, -7.668, -7.667, -7.666, -7.665, -7.664, -7.663, -7.662, -7.661, -7.66, -7.659, -7.6579999999999995, -7.657, -7.656000000000001, -7.654999999999999, -7.654, -7.6530000000000005, -7.652, -7.651, -7.65, -7.649, -7.648, -7.647, -7.646, -7.645, -7.644, -7.643, -7.6419999999999995, -7.641, -7.640000000000001, -7.638999999999999, -7.638, -7.6370000000000005, -7.636, -7.635, -7.634, -7.633, -7.632, -7.631, -7.63, -7.629, -7.628, -7.627, -7.6259999999999994, -7.625, -7.6240000000000006, -7.623, -7.622, -7.621, -7.62, -7.619, -7.618, -7.617, -7.616, -7.615, -7.614, -7.6129999999999995, -7.612, -7.611000000000001, -7.609999999999999, -7.609, -7.6080000000000005, -7.607, -7.606, -7.605, -7.604, -7.603, -7.602, -7.601, -7.6, -7.599, -7.598, -7.5969999999999995, -7.596, -7.595000000000001, -7.593999999999999, -7.593, -7.5920000000000005, -7.591, -7.59, -7.589, -7.588, -7.587, -7.586, -7.585, -7.584, -7.583, -7.582, -7.5809999999999995, -7.58, -7.579000000000001, -7.577999999999999, -7.577, -7.5760000000000005, -7.575, -7.574, -7.573, -7.572, -7.571, -7.57, -7.569, -7.568, -7.567, -7.566, -7.5649999999999995, -7.564, -7.563000000000001, -7.561999999999999, -7.561, -7.5600000000000005, -7.559, -7.558, -7.557, -7.556, -7.555, -7.554, -7.553, -7.552, -7.551, -7.55, -7.5489999999999995, -7.548, -7.547000000000001, -7.545999999999999, -7.545, -7.5440000000000005, -7.543, -7.542, -7.541, -7.54, -7.539, -7.538, -7.537, -7.536, -7.535, -7.534, -7.5329999999999995, -7.532, -7.531000000000001, -7.529999999999999, -7.529, -7.5280000000000005, -7.527, -7.526, -7.525, -7.524, -7.523, -7.522, -7.521, -7.52, -7.519, -7.518, -7.5169999999999995, -7.516, -7.515000000000001, -7.513999999999999, -7.513, -7.5120000000000005, -7.511, -7.51, -7.509, -7.508, -7.507, -7.506, -7.505, -7.504, -7.503, -7.502, -7.5009999999999994, -7.5, -7.4990000000000006, -7.498, -7.497, -7.496, -7.495, -7.494, -7.493, -7.492, -7.491, -7.49, -7.489, -7.4879999999999995, -7.487, -7.486000000000001, -7.484999999999999, -7.484, -7.4830000000000005, -7.482, -7.481, -7.48, -7.479, -7.478, -7.477, -7.476, -7.475, -7.474, -7.473, -7.4719999999999995, -7.471, -7.470000000000001, -7.468999999999999, -7.468, -7.4670000000000005, -7.466, -7.465, -7.464, -7.463, -7.462, -7.461, -7.46, -7.459, -7.458, -7.457, -7.4559999999999995, -7.455, -7.454000000000001, -7.452999999999999, -7.452, -7.4510000000000005, -7.45, -7.449, -7.448, -7.447, -7.446, -7.445, -7.444, -7.443, -7.442, -7.441, -7.4399999999999995, -7.439, -7.438000000000001, -7.436999999999999, -7.436, -7.4350000000000005, -7.434, -7.433, -7.432, -7.431, -7.43, -7.429, -7.428, -7.427, -7.426, -7.425, -7.4239999999999995, -7.423, -7.422000000000001, -7.420999999999999, -7.42, -7.4190000000000005, -7.418, -7.417, -7.416, -7.415, -7.414, -7.413, -7.412, -7.411, -7.41, -7.409, -7.4079999999999995, -7.407, -7.406000000000001, -7.404999999999999, -7.404, -7.4030000000000005, -7.402, -7.401, -7.4, -7.399, -7.398, -7.397, -7.396, -7.395, -7.394, -7.393, -7.3919999999999995, -7.391, -7.390000000000001, -7.388999999999999, -7.388, -7.3870000000000005, -7.386, -7.385, -7.384, -7.383, -7.382, -7.381, -7.38, -7.379, -7.378, -7.377, -7.3759999999999994, -7.375, -7.3740000000000006, -7.373, -7.372, -7.371, -7.37, -7.369, -7.368, -7.367, -7.366, -7.365, -7.364, -7.3629999999999995, -7.362, -7.361000000000001, -7.359999999999999, -7.359, -7.3580000000000005, -7.357, -7.356, -7.355, -7.354, -7.353, -7.352, -7.351, -7.35, -7.349, -7.348, -7.3469999999999995, -7.346, -7.345000000000001, -7.343999999999999, -7.343, -7.3420000000000005, -7.341, -7.34, -7.339, -7.338, -7.337, -7.336, -7.335, -7.334, -7.333, -7.332, -7.3309999999999995, -7.33, -7.329000000000001, -7.327999999999999, -7.327, -7.3260000000000005, -7.325, -7.324, -7.323, -7.322, -7.321, -7.32, -7.319, -7.318, -7.317, -7.316, -7.3149999999999995, -7.314, -7.313000000000001, -7.311999999999999, -7.311, -7.3100000000000005, -7.309, -7.308, -7.307, -7.306, -7.305, -7.304, -7.303, -7.302, -7.301, -7.3, -7.2989999999999995, -7.298, -7.297000000000001, -7.295999999999999, -7.295, -7.2940000000000005, -7.293, -7.292, -7.291, -7.29, -7.289, -7.288, -7.287, -7.286, -7.285, -7.284, -7.2829999999999995, -7.282, -7.281000000000001, -7.279999999999999, -7.279, -7.2780000000000005, -7.277, -7.276, -7.275, -7.274, -7.273, -7.272, -7.271, -7.27, -7.269, -7.268, -7.2669999999999995, -7.266, -7.265000000000001, -7.263999999999999, -7.263, -7.2620000000000005, -7.261, -7.26, -7.259, -7.258, -7.257, -7.256, -7.255, -7.254, -7.253, -7.252, -7.2509999999999994, -7.25, -7.2490000000000006, -7.248, -7.247, -7.246, -7.245, -7.244, -7.243, -7.242, -7.241, -7.24, -7.239, -7.2379999999999995, -7.237, -7.236000000000001, -7.234999999999999, -7.234, -7.2330000000000005, -7.232, -7.231, -7.23, -7.229, -7.228, -7.227, -7.226, -7.225, -7.224, -7.223, -7.2219999999999995, -7.221, -7.220000000000001, -7.218999999999999, -7.218, -7.2170000000000005, -7.216, -7.215, -7.214, -7.213, -7.212, -7.211, -7.21, -7.209, -7.208, -7.207, -7.2059999999999995, -7.205, -7.204000000000001, -7.202999999999999, -7.202, -7.2010000000000005, -7.2, -7.199, -7.198, -7.197, -7.196, -7.195, -7.194, -7.193, -7.192, -7.191, -7.1899999999999995, -7.189, -7.188000000000001, -7.186999999999999, -7.186, -7.1850000000000005, -7.184, -7.183, -7.182, -7.181, -7.18, -7.179, -7.178, -7.177, -7.176, -7.175, -7.1739999999999995, -7.173, -7.172000000000001, -7.170999999999999, -7.17, -7.1690000000000005, -7.168, -7.167, -7.166, -7.165, -7.164, -7.163, -7.162, -7.161, -7.16, -7.159, -7.1579999999999995, -7.157, -7.156000000000001, -7.154999999999999, -7.154, -7.1530000000000005, -7.152, -7.151, -7.15, -7.149, -7.148, -7.147, -7.146, -7.145, -7.144, -7.143, -7.1419999999999995, -7.141, -7.140000000000001, -7.138999999999999, -7.138, -7.1370000000000005, -7.136, -7.135, -7.134, -7.133, -7.132, -7.131, -7.13, -7.129, -7.128, -7.127, -7.1259999999999994, -7.125, -7.1240000000000006, -7.123, -7.122, -7.121, -7.12, -7.119, -7.118, -7.117, -7.116, -7.115, -7.114, -7.1129999999999995, -7.112, -7.111000000000001, -7.109999999999999, -7.109, -7.1080000000000005, -7.107, -7.106, -7.105, -7.104, -7.103, -7.102, -7.101, -7.1, -7.099, -7.098, -7.0969999999999995, -7.096, -7.095000000000001, -7.093999999999999, -7.093, -7.0920000000000005, -7.091, -7.09, -7.089, -7.088, -7.087, -7.086, -7.085, -7.084, -7.083, -7.082, -7.0809999999999995, -7.08, -7.079000000000001, -7.077999999999999, -7.077, -7.0760000000000005, -7.075, -7.074, -7.073, -7.072, -7.071, -7.07, -7.069, -7.068, -7.067, -7.066, -7.0649999999999995, -7.064, -7.063000000000001, -7.061999999999999, -7.061, -7.0600000000000005, -7.059, -7.058, -7.057, -7.056, -7.055, -7.054, -7.053, -7.052, -7.051, -7.05, -7.0489999999999995, -7.048, -7.047000000000001, -7.045999999999999, -7.045, -7.0440000000000005, -7.043, -7.042, -7.041, -7.04, -7.039, -7.038, -7.037, -7.036, -7.035, -7.034, -7.0329999999999995, -7.032, -7.031000000000001, -7.029999999999999, -7.029, -7.0280000000000005, -7.027, -7.026, -7.025, -7.024, -7.023, -7.022, -7.021, -7.02, -7.019, -7.018, -7.0169999999999995, -7.016, -7.015000000000001, -7.013999999999999, -7.013, -7.0120000000000005, -7.011, -7.01, -7.009, -7.008, -7.007, -7.006, -7.005, -7.004, -7.003, -7.002, -7.0009999999999994, -7.0, -6.9990000000000006, -6.998, -6.997, -6.996, -6.995, -6.994, -6.993, -6.992, -6.991, -6.99, -6.989, -6.9879999999999995, -6.987, -6.986000000000001, -6.984999999999999, -6.984, -6.9830000000000005, -6.982, -6.981, -6.98, -6.979, -6.978, -6.977, -6.976, -6.975, -6.974, -6.973, -6.9719999999999995, -6.971, -6.970000000000001, -6.968999999999999, -6.968, -6.9670000000000005, -6.966, -6.965, -6.964, -6.963, -6.962, -6.961, -6.96, -6.959, -6.958, -6.957, -6.9559999999999995, -6.955, -6.954000000000001, -6.952999999999999, -6.952, -6.9510000000000005, -6.95, -6.949, -6.948, -6.947, -6.946, -6.945, -6.944, -6.943, -6.942, -6.941, -6.9399999999999995, -6.939, -6.938000000000001, -6.936999999999999, -6.936, -6.9350000000000005, -6.934, -6.933, -6.932, -6.931, -6.93, -6.929, -6.928, -6.927, -6.926, -6.925, -6.9239999999999995, -6.923, -6.922000000000001, -6.920999999999999, -6.92, -6.9190000000000005, -6.918, -6.917, -6.916, -6.915, -6.914, -6.913, -6.912, -6.911, -6.91, -6.909, -6.9079999999999995, -6.907, -6.906000000000001, -6.904999999999999, -6.904, -6.9030000000000005, -6.902, -6.901, -6.9, -6.899, -6.898, -6.897, -6.896, -6.895, -6.894, -6.893, -6.8919999999999995, -6.891, -6.890000000000001, -6.888999999999999, -6.888, -6.8870000000000005, -6.886, -6.885, -6.884, -6.883, -6.882, -6.881, -6.88, -6.879, -6.878, -6.877, -6.8759999999999994, -6.875, -6.8740000000000006, -6.873, -6.872, -6.871, -6.87, -6.869, -6.868, -6.867, -6.866, -6.865, -6.864, -6.8629999999999995, -6.862, -6.861000000000001, -6.859999999999999, -6.859, -6.8580000000000005, -6.857, -6.856, -6.855, -6.854, -6.853, -6.852, -6.851, -6.85, -6.849, -6.848, -6.8469999999999995, -6.846, -6.845000000000001, -6.843999999999999, -6.843, -6.8420000000000005, -6.841, -6.84, -6.839, -6.838, -6.837, -6.836, -6.835, -6.834, -6.833, -6.832, -6.8309999999999995, -6.83, -6.829000000000001, -6.827999999999999, -6.827, -6.8260000000000005, -6.825, -6.824, -6.823, -6.822, -6.821, -6.82, -6.819, -6.818, -6.817, -6.816, -6.8149999999999995, -6.814, -6.813000000000001, -6.811999999999999, -6.811, -6.8100000000000005, -6.809, -6.808, -6.807, -6.806, -6.805, -6.804, -6.803, -6.802, -6.801, -6.8, -6.7989999999999995, -6.798, -6.797000000000001, -6.795999999999999, -6.795, -6.7940000000000005, -6.793, -6.792, -6.791, -6.79, -6.789, -6.788, -6.787, -6.786, -6.785, -6.784, -6.7829999999999995, -6.782, -6.781000000000001, -6.779999999999999, -6.779, -6.7780000000000005, -6.777, -6.776, -6.775, -6.774, -6.773, -6.772, -6.771, -6.77, -6.769, -6.768, -6.7669999999999995, -6.766, -6.765000000000001, -6.763999999999999, -6.763, -6.7620000000000005, -6.761, -6.76, -6.759, -6.758, -6.757, -6.756, -6.755, -6.754, -6.753, -6.752, -6.7509999999999994, -6.75, -6.7490000000000006, -6.748, -6.747, -6.746, -6.745, -6.744, -6.743, -6.742, -6.741, -6.74, -6.739, -6.7379999999999995, -6.737, -6.736000000000001, -6.734999999999999, -6.734, -6.7330000000000005, -6.732, -6.731, -6.73, -6.729, -6.728, -6.727, -6.726, -6.725, -6.724, -6.723, -6.7219999999999995, -6.721, -6.720000000000001, -6.718999999999999, -6.718, -6.7170000000000005, -6.716, -6.715, -6.714, -6.713, -6.712, -6.711, -6.71, -6.709, -6.708, -6.707, -6.7059999999999995, -6.705, -6.704000000000001, -6.702999999999999, -6.702, -6.7010000000000005, -6.7, -6.699, -6.698, -6.697, -6.696, -6.695, -6.694, -6.693, -6.692, -6.691, -6.6899999999999995, -6.689, -6.688000000000001, -6.686999999999999, -6.686, -6.6850000000000005, -6.684, -6.683, -6.682, -6.681, -6.68, -6.679, -6.678, -6.677, -6.676, -6.675, -6.6739999999999995, -6.673, -6.672000000000001, -6.670999999999999, -6.67, -6.6690000000000005, -6.668, -6.667, -6.666, -6.665, -6.664, -6.663, -6.662, -6.661, -6.66, -6.659, -6.6579999999999995, -6.657, -6.656000000000001, -6.654999999999999, -6.654, -6.6530000000000005, -6.652, -6.651, -6.65, -6.649, -6.648, -6.647, -6.646, -6.645, -6.644, -6.643, -6.6419999999999995, -6.641, -6.640000000000001, -6.638999999999999, -6.638, -6.6370000000000005, -6.636, -6.635, -6.634, -6.633, -6.632, -6.631, -6.63, -6.629, -6.628, -6.627, -6.6259999999999994, -6.625, -6.6240000000000006, -6.623, -6.622, -6.621, -6.62, -6.619, -6.618, -6.617, -6.616, -6.615, -6.614, -6.6129999999999995, -6.612, -6.611000000000001, -6.609999999999999, -6.609, -6.6080000000000005, -6.607, -6.606, -6.605, -6.604, -6.603, -6.602, -6.601, -6.6, -6.599, -6.598, -6.5969999999999995, -6.596, -6.595000000000001, -6.593999999999999, -6.593, -6.5920000000000005, -6.591, -6.59, -6.589, -6.588, -6.587, -6.586, -6.585, -6.584, -6.583, -6.582, -6.5809999999999995, -6.58, -6.579000000000001, -6.577999999999999, -6.577, -6.5760000000000005, -6.575, -6.574, -6.573, -6.572, -6.571, -6.57, -6.569, -6.568, -6.567, -6.566, -6.5649999999999995, -6.564, -6.563000000000001, -6.561999999999999, -6.561, -6.5600000000000005, -6.559, -6.558, -6.557, -6.556, -6.555, -6.554, -6.553, -6.552, -6.551, -6.55, -6.5489999999999995, -6.548, -6.547000000000001, -6.545999999999999, -6.545, -6.5440000000000005, -6.543, -6.542, -6.541, -6.54, -6.539, -6.538, -6.537, -6.536, -6.535, -6.534, -6.5329999999999995, -6.532, -6.531000000000001, -6.529999999999999, -6.529, -6.5280000000000005, -6.527, -6.526, -6.525, -6.524, -6.523, -6.522, -6.521, -6.52, -6.519, -6.518, -6.5169999999999995, -6.516, -6.515000000000001, -6.513999999999999, -6.513, -6.5120000000000005, -6.511, -6.51, -6.509, -6.508, -6.507, -6.506, -6.505, -6.504, -6.503, -6.502, -6.5009999999999994, -6.5, -6.4990000000000006, -6.498, -6.497, -6.496, -6.495, -6.494, -6.493, -6.492, -6.491, -6.49, -6.489, -6.4879999999999995, -6.487, -6.486000000000001, -6.484999999999999, -6.484, -6.4830000000000005, -6.482, -6.481, -6.48, -6.479, -6.478, -6.477, -6.476, -6.475, -6.474, -6.473, -6.4719999999999995, -6.471, -6.470000000000001, -6.468999999999999, -6.468, -6.4670000000000005, -6.466, -6.465, -6.464, -6.463, -6.462, -6.461, -6.46, -6.459, -6.458, -6.457, -6.4559999999999995, -6.455, -6.454000000000001, -6.452999999999999, -6.452, -6.4510000000000005, -6.45, -6.449, -6.448, -6.447, -6.446, -6.445, -6.444, -6.443, -6.442, -6.441, -6.4399999999999995, -6.439, -6.438000000000001, -6.436999999999999, -6.436, -6.4350000000000005, -6.434, -6.433, -6.432, -6.431, -6.43, -6.429, -6.428, -6.427, -6.426, -6.425, -6.4239999999999995, -6.423, -6.422000000000001, -6.420999999999999, -6.42, -6.4190000000000005, -6.418, -6.417, -6.416, -6.415, -6.414, -6.413, -6.412, -6.411, -6.41, -6.409, -6.4079999999999995, -6.407, -6.406000000000001, -6.404999999999999, -6.404, -6.4030000000000005, -6.402, -6.401, -6.4, -6.399, -6.398, -6.397, -6.396, -6.395, -6.394, -6.393, -6.3919999999999995, -6.391, -6.390000000000001, -6.388999999999999, -6.388, -6.3870000000000005, -6.386, -6.385, -6.384, -6.383, -6.382, -6.381, -6.38, -6.379, -6.378, -6.377, -6.3759999999999994, -6.375, -6.3740000000000006, -6.373, -6.372, -6.371, -6.37, -6.369, -6.368, -6.367, -6.366, -6.365, -6.364, -6.3629999999999995, -6.362, -6.361000000000001, -6.359999999999999, -6.359, -6.3580000000000005, -6.357, -6.356, -6.355, -6.354, -6.353, -6.352, -6.351, -6.35, -6.349, -6.348, -6.3469999999999995, -6.346, -6.345000000000001, -6.343999999999999, -6.343, -6.3420000000000005, -6.341, -6.34, -6.339, -6.338, -6.337, -6.336, -6.335, -6.334, -6.333, -6.332, -6.3309999999999995, -6.33, -6.329000000000001, -6.327999999999999, -6.327, -6.3260000000000005, -6.325, -6.324, -6.323, -6.322, -6.321, -6.32, -6.319, -6.318, -6.317, -6.316, -6.3149999999999995, -6.314, -6.313000000000001, -6.311999999999999, -6.311, -6.3100000000000005, -6.309, -6.308, -6.307, -6.306, -6.305, -6.304, -6.303, -6.302, -6.301, -6.3, -6.2989999999999995, -6.298, -6.297000000000001, -6.295999999999999, -6.295, -6.2940000000000005, -6.293, -6.292, -6.291, -6.29, -6.289, -6.288, -6.287, -6.286, -6.285, -6.284, -6.2829999999999995, -6.282, -6.281000000000001, -6.279999999999999, -6.279, -6.2780000000000005, -6.277, -6.276, -6.275, -6.274, -6.273, -6.272, -6.271, -6.27, -6.269, -6.268, -6.2669999999999995, -6.266, -6.265000000000001, -6.263999999999999, -6.263, -6.2620000000000005, -6.261, -6.26, -6.259, -6.258, -6.257, -6.256, -6.255, -6.254, -6.253, -6.252, -6.2509999999999994, -6.25, -6.2490000000000006, -6.248, -6.247, -6.246, -6.245, -6.244, -6.243, -6.242, -6.241, -6.24, -6.239, -6.2379999999999995, -6.237, -6.236000000000001, -6.234999999999999, -6.234, -6.2330000000000005, -6.232, -6.231, -6.23, -6.229, -6.228, -6.227, -6.226, -6.225, -6.224, -6.223, -6.2219999999999995, -6.221, -6.220000000000001, -6.218999999999999, -6.218, -6.2170000000000005, -6.216, -6.215, -6.214, -6.213, -6.212, -6.211, -6.21, -6.209, -6.208, -6.207, -6.2059999999999995, -6.205, -6.204000000000001, -6.202999999999999, -6.202, -6.2010000000000005, -6.2, -6.199, -6.198, -6.197, -6.196, -6.195, -6.194, -6.193, -6.192, -6.191, -6.1899999999999995, -6.189, -6.188000000000001, -6.186999999999999, -6.186, -6.1850000000000005, -6.184, -6.183, -6.182, -6.181, -6.18, -6.179, -6.178, -6.177, -6.176, -6.175, -6.1739999999999995, -6.173, -6.172000000000001, -6.170999999999999, -6.17, -6.1690000000000005, -6.168, -6.167, -6.166, -6.165, -6.164, -6.163, -6.162, -6.161, -6.16, -6.159, -6.1579999999999995, -6.157, -6.156000000000001, -6.154999999999999, -6.154, -6.1530000000000005, -6.152, -6.151, -6.15, -6.149, -6.148, -6.147, -6.146, -6.145, -6.144, -6.143, -6.1419999999999995, -6.141, -6.140000000000001, -6.138999999999999, -6.138, -6.1370000000000005, -6.136, -6.135, -6.134, -6.133, -6.132, -6.131, -6.13, -6.129, -6.128, -6.127, -6.1259999999999994, -6.125, -6.1240000000000006, -6.123, -6.122, -6.121, -6.12, -6.119, -6.118, -6.117, -6.116, -6.115, -6.114, -6.1129999999999995, -6.112, -6.111000000000001, -6.109999999999999, -6.109, -6.1080000000000005, -6.107, -6.106, -6.105, -6.104, -6.103, -6.102, -6.101, -6.1, -6.099, -6.098, -6.0969999999999995, -6.096, -6.095000000000001, -6.093999999999999, -6.093, -6.0920000000000005, -6.091, -6.09, -6.089, -6.088, -6.087, -6.086, -6.085, -6.084, -6.083, -6.082, -6.0809999999999995, -6.08, -6.079000000000001, -6.077999999999999, -6.077, -6.0760000000000005, -6.075, -6.074, -6.073, -6.072, -6.071, -6.07, -6.069, -6.068, -6.067, -6.066, -6.0649999999999995, -6.064, -6.063000000000001, -6.061999999999999, -6.061, -6.0600000000000005, -6.059, -6.058, -6.057, -6.056, -6.055, -6.054, -6.053, -6.052, -6.051, -6.05, -6.0489999999999995, -6.048, -6.047000000000001, -6.045999999999999, -6.045, -6.0440000000000005, -6.043, -6.042, -6.041, -6.04, -6.039, -6.038, -6.037, -6.036, -6.035, -6.034, -6.0329999999999995, -6.032, -6.031000000000001, -6.029999999999999, -6.029, -6.0280000000000005, -6.027, -6.026, -6.025, -6.024, -6.023, -6.022, -6.021, -6.02, -6.019, -6.018, -6.0169999999999995, -6.016, -6.015000000000001, -6.013999999999999, -6.013, -6.0120000000000005, -6.011, -6.01, -6.009, -6.008, -6.007, -6.006, -6.005, -6.004, -6.003, -6.002, -6.0009999999999994, -6.0, -5.999, -5.998, -5.997, -5.996, -5.995, -5.994, -5.993, -5.992, -5.991, -5.99, -5.989, -5.988, -5.987, -5.986, -5.985, -5.984, -5.983, -5.982, -5.981, -5.98, -5.979, -5.978, -5.977, -5.976, -5.975, -5.974, -5.973, -5.972, -5.971, -5.97, -5.969, -5.968, -5.967, -5.966, -5.965, -5.964, -5.963, -5.962, -5.961, -5.96, -5.959, -5.958, -5.957, -5.956, -5.955, -5.954, -5.953, -5.952, -5.951, -5.95, -5.949, -5.948, -5.947, -5.946, -5.945, -5.944, -5.943, -5.942, -5.941, -5.94, -5.939, -5.938, -5.937, -5.936, -5.935, -5.934, -5.933, -5.932, -5.931, -5.93, -5.929, -5.928, -5.927, -5.926, -5.925, -5.924, -5.923, -5.922, -5.921, -5.92, -5.919, -5.918, -5.917, -5.916, -5.915, -5.914, -5.913, -5.912, -5.911, -5.91, -5.909, -5.908, -5.907, -5.906, -5.905, -5.904, -5.903, -5.902, -5.901, -5.9, -5.899, -5.898, -5.897, -5.896, -5.895, -5.894, -5.893, -5.892, -5.891, -5.89, -5.889, -5.888, -5.887, -5.886, -5.885, -5.884, -5.883, -5.882, -5.881, -5.88, -5.879, -5.878, -5.877, -5.876, -5.875, -5.874, -5.873, -5.872, -5.871, -5.87, -5.869, -5.868, -5.867, -5.866, -5.865, -5.864, -5.863, -5.862, -5.861, -5.86, -5.859, -5.858, -5.857, -5.856, -5.855, -5.854, -5.853, -5.852, -5.851, -5.85, -5.849, -5.848, -5.847, -5.846, -5.845, -5.844, -5.843, -5.842, -5.841, -5.84, -5.839, -5.838, -5.837, -5.836, -5.835, -5.834, -5.833, -5.832, -5.831, -5.83, -5.829, -5.828, -5.827, -5.826, -5.825, -5.824, -5.823, -5.822, -5.821, -5.82, -5.819, -5.818, -5.817, -5.816, -5.815, -5.814, -5.813, -5.812, -5.811, -5.81, -5.809, -5.808, -5.807, -5.806, -5.805, -5.804, -5.803, -5.802, -5.801, -5.8, -5.799, -5.798, -5.797, -5.796, -5.795, -5.794, -5.793, -5.792, -5.791, -5.79, -5.789, -5.788, -5.787, -5.786, -5.785, -5.784, -5.783, -5.782, -5.781, -5.78, -5.779, -5.778, -5.777, -5.776, -5.775, -5.774, -5.773, -5.772, -5.771, -5.77, -5.769, -5.768, -5.767, -5.766, -5.765, -5.764, -5.763, -5.762, -5.761, -5.76, -5.759, -5.758, -5.757, -5.756, -5.755, -5.754, -5.753, -5.752, -5.751, -5.75, -5.749, -5.748, -5.747, -5.746, -5.745, -5.744, -5.743, -5.742, -5.741, -5.74, -5.739, -5.738, -5.737, -5.736, -5.735, -5.734, -5.733, -5.732, -5.731, -5.73, -5.729, -5.728, -5.727, -5.726, -5.725, -5.724, -5.723, -5.722, -5.721, -5.72, -5.719, -5.718, -5.717, -5.716, -5.715, -5.714, -5.713, -5.712, -5.711, -5.71, -5.709, -5.708, -5.707, -5.706, -5.705, -5.704, -5.703, -5.702, -5.701, -5.7, -5.699, -5.698, -5.697, -5.696, -5.695, -5.694, -5.693, -5.692, -5.691, -5.69, -5.689, -5.688, -5.687, -5.686, -5.685, -5.684, -5.683, -5.682, -5.681, -5.68, -5.679, -5.678, -5.677, -5.676, -5.675, -5.674, -5.673, -5.672, -5.671, -5.67, -5.669, -5.668, -5.667, -5.666, -5.665, -5.664, -5.663, -5.662, -5.661, -5.66, -5.659, -5.658, -5.657, -5.656, -5.655, -5.654, -5.653, -5.652, -5.651, -5.65, -5.649, -5.648, -5.647, -5.646, -5.645, -5.644, -5.643, -5.642, -5.641, -5.64, -5.639, -5.638, -5.637, -5.636, -5.635, -5.634, -5.633, -5.632, -5.631, -5.63, -5.629, -5.628, -5.627, -5.626, -5.625, -5.624, -5.623, -5.622, -5.621, -5.62, -5.619, -5.618, -5.617, -5.616, -5.615, -5.614, -5.613, -5.612, -5.611, -5.61, -5.609, -5.608, -5.607, -5.606, -5.605, -5.604, -5.603, -5.602, -5.601, -5.6, -5.599, -5.598, -5.597, -5.596, -5.595, -5.594, -5.593, -5.592, -5.591, -5.59, -5.589, -5.588, -5.587, -5.586, -5.585, -5.584, -5.583, -5.582, -5.581, -5.58, -5.579, -5.578, -5.577, -5.576, -5.575, -5.574, -5.573, -5.572, -5.571, -5.57, -5.569, -5.568, -5.567, -5.566, -5.565, -5.564, -5.563, -5.562, -5.561, -5.56, -5.559, -5.558, -5.557, -5.556, -5.555, -5.554, -5.553, -5.552, -5.551, -5.55, -5.549, -5.548, -5.547, -5.546, -5.545, -5.544, -5.543, -5.542, -5.541, -5.54, -5.539, -5.538, -5.537, -5.536, -5.535, -5.534, -5.533, -5.532, -5.531, -5.53, -5.529, -5.528, -5.527, -5.526, -5.525, -5.524, -5.523, -5.522, -5.521, -5.52, -5.519, -5.518, -5.517, -5.516, -5.515, -5.514, -5.513, -5.512, -5.511, -5.51, -5.509, -5.508, -5.507, -5.506, -5.505, -5.504, -5.503, -5.502, -5.501, -5.5, -5.499, -5.498, -5.497, -5.496, -5.495, -5.494, -5.493, -5.492, -5.491, -5.49, -5.489, -5.488, -5.487, -5.486, -5.485, -5.484, -5.483, -5.482, -5.481, -5.48, -5.479, -5.478, -5.477, -5.476, -5.475, -5.474, -5.473, -5.472, -5.471, -5.47, -5.469, -5.468, -5.467, -5.466, -5.465, -5.464, -5.463, -5.462, -5.461, -5.46, -5.459, -5.458, -5.457, -5.456, -5.455, -5.454, -5.453, -5.452, -5.451, -5.45, -5.449, -5.448, -5.447, -5.446, -5.445, -5.444, -5.443, -5.442, -5.441, -5.44, -5.439, -5.438, -5.437, -5.436, -5.435, -5.434, -5.433, -5.432, -5.431, -5.43, -5.429, -5.428, -5.427, -5.426, -5.425, -5.424, -5.423, -5.422, -5.421, -5.42, -5.419, -5.418, -5.417, -5.416, -5.415, -5.414, -5.413, -5.412, -5.411, -5.41, -5.409, -5.408, -5.407, -5.406, -5.405, -5.404, -5.403, -5.402, -5.401, -5.4, -5.399, -5.398, -5.397, -5.396, -5.395, -5.394, -5.393, -5.392, -5.391, -5.39, -5.389, -5.388, -5.387, -5.386, -5.385, -5.384, -5.383, -5.382, -5.381, -5.38, -5.379, -5.378, -5.377, -5.376, -5.375, -5.374, -5.373, -5.372, -5.371, -5.37, -5.369, -5.368, -5.367, -5.366, -5.365, -5.364, -5.363, -5.362, -5.361, -5.36, -5.359, -5.358, -5.357, -5.356, -5.355, -5.354, -5.353, -5.352, -5.351, -5.35, -5.349, -5.348, -5.347, -5.346, -5.345, -5.344, -5.343, -5.342, -5.341, -5.34, -5.339, -5.338, -5.337, -5.336, -5.335, -5.334, -5.333, -5.332, -5.331, -5.33, -5.329, -5.328, -5.327, -5.326, -5.325, -5.324, -5.323, -5.322, -5.321, -5.32, -5.319, -5.318, -5.317, -5.316, -5.315, -5.314, -5.313, -5.312, -5.311, -5.31, -5.309, -5.308, -5.307, -5.306, -5.305, -5.304, -5.303, -5.302, -5.301, -5.3, -5.299, -5.298, -5.297, -5.296, -5.295, -5.294, -5.293, -5.292, -5.291, -5.29, -5.289, -5.288, -5.287, -5.286, -5.285, -5.284, -5.283, -5.282, -5.281, -5.28, -5.279, -5.278, -5.277, -5.276, -5.275, -5.274, -5.273, -5.272, -5.271, -5.27, -5.269, -5.268, -5.267, -5.266, -5.265, -5.264, -5.263, -5.262, -5.261, -5.26, -5.259, -5.258, -5.257, -5.256, -5.255, -5.254, -5.253, -5.252, -5.251, -5.25, -5.249, -5.248, -5.247, -5.246, -5.245, -5.244, -5.243, -5.242, -5.241, -5.24, -5.239, -5.238, -5.237, -5.236, -5.235, -5.234, -5.233, -5.232, -5.231, -5.23, -5.229, -5.228, -5.227, -5.226, -5.225, -5.224, -5.223, -5.222, -5.221, -5.22, -5.219, -5.218, -5.217, -5.216, -5.215, -5.214, -5.213, -5.212, -5.211, -5.21, -5.209, -5.208, -5.207, -5.206, -5.205, -5.204, -5.203, -5.202, -5.201, -5.2, -5.199, -5.198, -5.197, -5.196, -5.195, -5.194, -5.193, -5.192, -5.191, -5.19, -5.189, -5.188, -5.187, -5.186, -5.185, -5.184, -5.183, -5.182, -5.181, -5.18, -5.179, -5.178, -5.177, -5.176, -5.175, -5.174, -5.173, -5.172, -5.171, -5.17, -5.169, -5.168, -5.167, -5.166, -5.165, -5.164, -5.163, -5.162, -5.161, -5.16, -5.159, -5.158, -5.157, -5.156, -5.155, -5.154, -5.153, -5.152, -5.151, -5.15, -5.149, -5.148, -5.147, -5.146, -5.145, -5.144, -5.143, -5.142, -5.141, -5.14, -5.139, -5.138, -5.137, -5.136, -5.135, -5.134, -5.133, -5.132, -5.131, -5.13, -5.129, -5.128, -5.127, -5.126, -5.125, -5.124, -5.123, -5.122, -5.121, -5.12, -5.119, -5.118, -5.117, -5.116, -5.115, -5.114, -5.113, -5.112, -5.111, -5.11, -5.109, -5.108, -5.107, -5.106, -5.105, -5.104, -5.103, -5.102, -5.101, -5.1, -5.099, -5.098, -5.097, -5.096, -5.095, -5.094, -5.093, -5.092, -5.091, -5.09, -5.089, -5.088, -5.087, -5.086, -5.085, -5.084, -5.083, -5.082, -5.081, -5.08, -5.079, -5.078, -5.077, -5.076, -5.075, -5.074, -5.073, -5.072, -5.071, -5.07, -5.069, -5.068, -5.067, -5.066, -5.065, -5.064, -5.063, -5.062, -5.061, -5.06, -5.059, -5.058, -5.057, -5.056, -5.055, -5.054, -5.053, -5.052, -5.051, -5.05, -5.049, -5.048, -5.047, -5.046, -5.045, -5.044, -5.043, -5.042, -5.041, -5.04, -5.039, -5.038, -5.037, -5.036, -5.035, -5.034, -5.033, -5.032, -5.031, -5.03, -5.029, -5.028, -5.027, -5.026, -5.025, -5.024, -5.023, -5.022, -5.021, -5.02, -5.019, -5.018, -5.017, -5.016, -5.015, -5.014, -5.013, -5.012, -5.011, -5.01, -5.009, -5.008, -5.007, -5.006, -5.005, -5.004, -5.003, -5.002, -5.001, -5.0, -4.999, -4.998, -4.997, -4.996, -4.995, -4.994, -4.993, -4.992, -4.991, -4.99, -4.989, -4.988, -4.987, -4.986, -4.985, -4.984, -4.983, -4.982, -4.981, -4.98, -4.979, -4.978, -4.977, -4.976, -4.975, -4.974, -4.973, -4.972, -4.971, -4.97, -4.969, -4.968, -4.967, -4.966, -4.965, -4.964, -4.963, -4.962, -4.961, -4.96, -4.959, -4.958, -4.957, -4.956, -4.955, -4.954, -4.953, -4.952, -4.951, -4.95, -4.949, -4.948, -4.947, -4.946, -4.945, -4.944, -4.943, -4.942, -4.941, -4.94, -4.939, -4.938, -4.937, -4.936, -4.935, -4.934, -4.933, -4.932, -4.931, -4.93, -4.929, -4.928, -4.927, -4.926, -4.925, -4.924, -4.923, -4.922, -4.921, -4.92, -4.919, -4.918, -4.917, -4.916, -4.915, -4.914, -4.913, -4.912, -4.911, -4.91, -4.909, -4.908, -4.907, -4.906, -4.905, -4.904, -4.903, -4.902, -4.901, -4.9, -4.899, -4.898, -4.897, -4.896, -4.895, -4.894, -4.893, -4.892, -4.891, -4.89, -4.889, -4.888, -4.887, -4.886, -4.885, -4.884, -4.883, -4.882, -4.881, -4.88, -4.879, -4.878, -4.877, -4.876, -4.875, -4.874, -4.873, -4.872, -4.871, -4.87, -4.869, -4.868, -4.867, -4.866, -4.865, -4.864, -4.863, -4.862, -4.861, -4.86, -4.859, -4.858, -4.857, -4.856, -4.855, -4.854, -4.853, -4.852, -4.851, -4.85, -4.849, -4.848, -4.847, -4.846, -4.845, -4.844, -4.843, -4.842, -4.841, -4.84, -4.839, -4.838, -4.837, -4.836, -4.835, -4.834, -4.833, -4.832, -4.831, -4.83, -4.829, -4.828, -4.827, -4.826, -4.825, -4.824, -4.823, -4.822, -4.821, -4.82, -4.819, -4.818, -4.817, -4.816, -4.815, -4.814, -4.813, -4.812, -4.811, -4.81, -4.809, -4.808, -4.807, -4.806, -4.805, -4.804, -4.803, -4.802, -4.801, -4.8, -4.799, -4.798, -4.797, -4.796, -4.795, -4.794, -4.793, -4.792, -4.791, -4.79, -4.789, -4.788, -4.787, -4.786, -4.785, -4.784, -4.783, -4.782, -4.781, -4.78, -4.779, -4.778, -4.777, -4.776, -4.775, -4.774, -4.773, -4.772, -4.771, -4.77, -4.769, -4.768, -4.767, -4.766, -4.765, -4.764, -4.763, -4.762, -4.761, -4.76, -4.759, -4.758, -4.757, -4.756, -4.755, -4.754, -4.753, -4.752, -4.751, -4.75, -4.749, -4.748, -4.747, -4.746, -4.745, -4.744, -4.743, -4.742, -4.741, -4.74, -4.739, -4.738, -4.737, -4.736, -4.735, -4.734, -4.733, -4.732, -4.731, -4.73, -4.729, -4.728, -4.727, -4.726, -4.725, -4.724, -4.723, -4.722, -4.721, -4.72, -4.719, -4.718, -4.717, -4.716, -4.715, -4.714, -4.713, -4.712, -4.711, -4.71, -4.709, -4.708, -4.707, -4.706, -4.705, -4.704, -4.703, -4.702, -4.701, -4.7, -4.699, -4.698, -4.697, -4.696, -4.695, -4.694, -4.693, -4.692, -4.691, -4.69, -4.689, -4.688, -4.687, -4.686, -4.685, -4.684, -4.683, -4.682, -4.681, -4.68, -4.679, -4.678, -4.677, -4.676, -4.675, -4.674, -4.673, -4.672, -4.671, -4.67, -4.669, -4.668, -4.667, -4.666, -4.665, -4.664, -4.663, -4.662, -4.661, -4.66, -4.659, -4.658, -4.657, -4.656, -4.655, -4.654, -4.653, -4.652, -4.651, -4.65, -4.649, -4.648, -4.647, -4.646, -4.645, -4.644, -4.643, -4.642, -4.641, -4.64, -4.639, -4.638, -4.637, -4.636, -4.635, -4.634, -4.633, -4.632, -4.631, -4.63, -4.629, -4.628, -4.627, -4.626, -4.625, -4.624, -4.623, -4.622, -4.621, -4.62, -4.619, -4.618, -4.617, -4.616, -4.615, -4.614, -4.613, -4.612, -4.611, -4.61, -4.609, -4.608, -4.607, -4.606, -4.605, -4.604, -4.603, -4.602, -4.601, -4.6, -4.599, -4.598, -4.597, -4.596, -4.595, -4.594, -4.593, -4.592, -4.591, -4.59, -4.589, -4.588, -4.587, -4.586, -4.585, -4.584, -4.583, -4.582, -4.581, -4.58, -4.579, -4.578, -4.577, -4.576, -4.575, -4.574, -4.573, -4.572, -4.571, -4.57, -4.569, -4.568, -4.567, -4.566, -4.565, -4.564, -4.563, -4.562, -4.561, -4.56, -4.559, -4.558, -4.557, -4.556, -4.555, -4.554, -4.553, -4.552, -4.551, -4.55, -4.549, -4.548, -4.547, -4.546, -4.545, -4.544, -4.543, -4.542, -4.541, -4.54, -4.539, -4.538, -4.537, -4.536, -4.535, -4.534, -4.533, -4.532, -4.531, -4.53, -4.529, -4.528, -4.527, -4.526, -4.525, -4.524, -4.523, -4.522, -4.521, -4.52, -4.519, -4.518, -4.517, -4.516, -4.515, -4.514, -4.513, -4.512, -4.511, -4.51, -4.509, -4.508, -4.507, -4.506, -4.505, -4.504, -4.503, -4.502, -4.501, -4.5, -4.499, -4.498, -4.497, -4.496, -4.495, -4.494, -4.493, -4.492, -4.491, -4.49, -4.489, -4.488, -4.487, -4.486, -4.485, -4.484, -4.483, -4.482, -4.481, -4.48, -4.479, -4.478, -4.477, -4.476, -4.475, -4.474, -4.473, -4.472, -4.471, -4.47, -4.469, -4.468, -4.467, -4.466, -4.465, -4.464, -4.463, -4.462, -4.461, -4.46, -4.459, -4.458, -4.457, -4.456, -4.455, -4.454, -4.453, -4.452, -4.451, -4.45, -4.449, -4.448, -4.447, -4.446, -4.445, -4.444, -4.443, -4.442, -4.441, -4.44, -4.439, -4.438, -4.437, -4.436, -4.435, -4.434, -4.433, -4.432, -4.431, -4.43, -4.429, -4.428, -4.427, -4.426, -4.425, -4.424, -4.423, -4.422, -4.421, -4.42, -4.419, -4.418, -4.417, -4.416, -4.415, -4.414, -4.413, -4.412, -4.411, -4.41, -4.409, -4.408, -4.407, -4.406, -4.405, -4.404, -4.403, -4.402, -4.401, -4.4, -4.399, -4.398, -4.397, -4.396, -4.395, -4.394, -4.393, -4.392, -4.391, -4.39, -4.389, -4.388, -4.387, -4.386, -4.385, -4.384, -4.383, -4.382, -4.381, -4.38, -4.379, -4.378, -4.377, -4.376, -4.375, -4.374, -4.373, -4.372, -4.371, -4.37, -4.369, -4.368, -4.367, -4.366, -4.365, -4.364, -4.363, -4.362, -4.361, -4.36, -4.359, -4.358, -4.357, -4.356, -4.355, -4.354, -4.353, -4.352, -4.351, -4.35, -4.349, -4.348, -4.347, -4.346, -4.345, -4.344, -4.343, -4.342, -4.341, -4.34, -4.339, -4.338, -4.337, -4.336, -4.335, -4.334, -4.333, -4.332, -4.331, -4.33, -4.329, -4.328, -4.327, -4.326, -4.325, -4.324, -4.323, -4.322, -4.321, -4.32, -4.319, -4.318, -4.317, -4.316, -4.315, -4.314, -4.313, -4.312, -4.311, -4.31, -4.309, -4.308, -4.307, -4.306, -4.305, -4.304, -4.303, -4.302, -4.301, -4.3, -4.299, -4.298, -4.297, -4.296, -4.295, -4.294, -4.293, -4.292, -4.291, -4.29, -4.289, -4.288, -4.287, -4.286, -4.285, -4.284, -4.283, -4.282, -4.281, -4.28, -4.279, -4.278, -4.277, -4.276, -4.275, -4.274, -4.273, -4.272, -4.271, -4.27, -4.269, -4.268, -4.267, -4.266, -4.265, -4.264, -4.263, -4.262, -4.261, -4.26, -4.259, -4.258, -4.257, -4.256, -4.255, -4.254, -4.253, -4.252, -4.251, -4.25, -4.249, -4.248, -4.247, -4.246, -4.245, -4.244, -4.243, -4.242, -4.241, -4.24, -4.239, -4.238, -4.237, -4.236, -4.235, -4.234, -4.233, -4.232, -4.231, -4.23, -4.229, -4.228, -4.227, -4.226, -4.225, -4.224, -4.223, -4.222, -4.221, -4.22, -4.219, -4.218, -4.217, -4.216, -4.215, -4.214, -4.213, -4.212, -4.211, -4.21, -4.209, -4.208, -4.207, -4.206, -4.205, -4.204, -4.203, -4.202, -4.201, -4.2, -4.199, -4.198, -4.197, -4.196, -4.195, -4.194, -4.193, -4.192, -4.191, -4.19, -4.189, -4.188, -4.187, -4.186, -4.185, -4.184, -4.183, -4.182, -4.181, -4.18, -4.179, -4.178, -4.177, -4.176, -4.175, -4.174, -4.173, -4.172, -4.171, -4.17, -4.169, -4.168, -4.167, -4.166, -4.165, -4.164, -4.163, -4.162, -4.161, -4.16, -4.159, -4.158, -4.157, -4.156, -4.155, -4.154, -4.153, -4.152, -4.151, -4.15, -4.149, -4.148, -4.147, -4.146, -4.145, -4.144, -4.143, -4.142, -4.141, -4.14, -4.139, -4.138, -4.137, -4.136, -4.135, -4.134, -4.133, -4.132, -4.131, -4.13, -4.129, -4.128, -4.127, -4.126, -4.125, -4.124, -4.123, -4.122, -4.121, -4.12, -4.119, -4.118, -4.117, -4.116, -4.115, -4.114, -4.113, -4.112, -4.111, -4.11, -4.109, -4.108, -4.107, -4.106, -4.105, -4.104, -4.103, -4.102, -4.101, -4.1, -4.099, -4.098, -4.097, -4.096, -4.095, -4.094, -4.093, -4.092, -4.091, -4.09, -4.089, -4.088, -4.087, -4.086, -4.085, -4.084, -4.083, -4.082, -4.081, -4.08, -4.079, -4.078, -4.077, -4.076, -4.075, -4.074, -4.073, -4.072, -4.071, -4.07, -4.069, -4.068, -4.067, -4.066, -4.065, -4.064, -4.063, -4.062, -4.061, -4.06, -4.059, -4.058, -4.057, -4.056, -4.055, -4.054, -4.053, -4.052, -4.051, -4.05, -4.049, -4.048, -4.047, -4.046, -4.045, -4.044, -4.043, -4.042, -4.041, -4.04, -4.039, -4.038, -4.037, -4.036, -4.035, -4.034, -4.033, -4.032, -4.031, -4.03, -4.029, -4.028, -4.027, -4.026, -4.025, -4.024, -4.023, -4.022, -4.021, -4.02, -4.019, -4.018, -4.017, -4.016, -4.015, -4.014, -4.013, -4.012, -4.011, -4.01, -4.009, -4.008, -4.007, -4.006, -4.005, -4.004, -4.003, -4.002, -4.001, -4.0, -3.9989999999999997, -3.998, -3.997, -3.9960000000000004, -3.995, -3.9939999999999998, -3.9930000000000003, -3.992, -3.9909999999999997, -3.99, -3.989, -3.9880000000000004, -3.987, -3.9859999999999998, -3.9850000000000003, -3.984, -3.9829999999999997, -3.982, -3.981, -3.9800000000000004, -3.979, -3.9779999999999998, -3.9770000000000003, -3.976, -3.9749999999999996, -3.974, -3.973, -3.9720000000000004, -3.971, -3.9699999999999998, -3.9690000000000003, -3.968, -3.9669999999999996, -3.966, -3.965, -3.9640000000000004, -3.963, -3.9619999999999997, -3.9610000000000003, -3.96, -3.9589999999999996, -3.958, -3.957, -3.9560000000000004, -3.955, -3.9539999999999997, -3.9530000000000003, -3.952, -3.9509999999999996, -3.95, -3.949, -3.9480000000000004, -3.947, -3.9459999999999997, -3.9450000000000003, -3.944, -3.9429999999999996, -3.942, -3.941, -3.9400000000000004, -3.939, -3.9379999999999997, -3.9370000000000003, -3.936, -3.9349999999999996, -3.934, -3.933, -3.9320000000000004, -3.931, -3.9299999999999997, -3.9290000000000003, -3.928, -3.9269999999999996, -3.926, -3.925, -3.9240000000000004, -3.923, -3.9219999999999997, -3.9210000000000003, -3.92, -3.9189999999999996, -3.918, -3.917, -3.9160000000000004, -3.915, -3.9139999999999997, -3.9130000000000003, -3.912, -3.9109999999999996, -3.91, -3.909, -3.9080000000000004, -3.907, -3.9059999999999997, -3.9050000000000002, -3.904, -3.9029999999999996, -3.902, -3.901, -3.9000000000000004, -3.899, -3.8979999999999997, -3.8970000000000002, -3.896, -3.8949999999999996, -3.894, -3.893, -3.8920000000000003, -3.891, -3.8899999999999997, -3.8890000000000002, -3.888, -3.8869999999999996, -3.886, -3.885, -3.8840000000000003, -3.883, -3.8819999999999997, -3.8810000000000002, -3.88, -3.8789999999999996, -3.878, -3.877, -3.8760000000000003, -3.875, -3.8739999999999997, -3.873, -3.872, -3.8710000000000004, -3.87, -3.8689999999999998, -3.8680000000000003, -3.867, -3.8659999999999997, -3.865, -3.864, -3.8630000000000004, -3.862, -3.8609999999999998, -3.8600000000000003, -3.859, -3.8579999999999997, -3.857, -3.856, -3.8550000000000004, -3.854, -3.8529999999999998, -3.8520000000000003, -3.851, -3.8499999999999996, -3.849, -3.848, -3.8470000000000004, -3.846, -3.8449999999999998, -3.8440000000000003, -3.843, -3.8419999999999996, -3.841, -3.84, -3.8390000000000004, -3.838, -3.8369999999999997, -3.8360000000000003, -3.835, -3.8339999999999996, -3.833, -3.832, -3.8310000000000004, -3.83, -3.8289999999999997, -3.8280000000000003, -3.827, -3.8259999999999996, -3.825, -3.824, -3.8230000000000004, -3.822, -3.8209999999999997, -3.8200000000000003, -3.819, -3.8179999999999996, -3.817, -3.816, -3.8150000000000004, -3.814, -3.8129999999999997, -3.8120000000000003, -3.811, -3.8099999999999996, -3.809, -3.808, -3.8070000000000004, -3.806, -3.8049999999999997, -3.8040000000000003, -3.803, -3.8019999999999996, -3.801, -3.8, -3.7990000000000004, -3.798, -3.7969999999999997, -3.7960000000000003, -3.795, -3.7939999999999996, -3.793, -3.792, -3.7910000000000004, -3.79, -3.7889999999999997, -3.7880000000000003, -3.787, -3.7859999999999996, -3.785, -3.784, -3.7830000000000004, -3.782, -3.7809999999999997, -3.7800000000000002, -3.779, -3.7779999999999996, -3.777, -3.776, -3.7750000000000004, -3.774, -3.7729999999999997, -3.7720000000000002, -3.771, -3.7699999999999996, -3.769, -3.768, -3.7670000000000003, -3.766, -3.7649999999999997, -3.7640000000000002, -3.763, -3.7619999999999996, -3.761, -3.76, -3.7590000000000003, -3.758, -3.7569999999999997, -3.7560000000000002, -3.755, -3.7539999999999996, -3.753, -3.752, -3.7510000000000003, -3.75, -3.7489999999999997, -3.748, -3.747, -3.7460000000000004, -3.745, -3.7439999999999998, -3.7430000000000003, -3.742, -3.7409999999999997, -3.74, -3.739, -3.7380000000000004, -3.737, -3.7359999999999998, -3.7350000000000003, -3.734, -3.7329999999999997, -3.732, -3.731, -3.7300000000000004, -3.729, -3.7279999999999998, -3.7270000000000003, -3.726, -3.7249999999999996, -3.724, -3.723, -3.7220000000000004, -3.721, -3.7199999999999998, -3.7190000000000003, -3.718, -3.7169999999999996, -3.716, -3.715, -3.7140000000000004, -3.713, -3.7119999999999997, -3.7110000000000003, -3.71, -3.7089999999999996, -3.708, -3.707, -3.7060000000000004, -3.705, -3.7039999999999997, -3.7030000000000003, -3.702, -3.7009999999999996, -3.7, -3.699, -3.6980000000000004, -3.697, -3.6959999999999997, -3.6950000000000003, -3.694, -3.6929999999999996, -3.692, -3.691, -3.6900000000000004, -3.689, -3.6879999999999997, -3.6870000000000003, -3.686, -3.6849999999999996, -3.684, -3.683, -3.6820000000000004, -3.681, -3.6799999999999997, -3.6790000000000003, -3.678, -3.6769999999999996, -3.676, -3.675, -3.6740000000000004, -3.673, -3.6719999999999997, -3.6710000000000003, -3.67, -3.6689999999999996, -3.668, -3.667, -3.6660000000000004, -3.665, -3.6639999999999997, -3.6630000000000003, -3.662, -3.6609999999999996, -3.66, -3.659, -3.6580000000000004, -3.657, -3.6559999999999997, -3.6550000000000002, -3.654, -3.6529999999999996, -3.652, -3.651, -3.6500000000000004, -3.649, -3.6479999999999997, -3.6470000000000002, -3.646, -3.6449999999999996, -3.644, -3.643, -3.6420000000000003, -3.641, -3.6399999999999997, -3.6390000000000002, -3.638, -3.6369999999999996, -3.636, -3.635, -3.6340000000000003, -3.633, -3.6319999999999997, -3.6310000000000002, -3.63, -3.6289999999999996, -3.628, -3.627, -3.6260000000000003, -3.625, -3.6239999999999997, -3.623, -3.622, -3.6210000000000004, -3.62, -3.6189999999999998, -3.6180000000000003, -3.617, -3.6159999999999997, -3.615, -3.614, -3.6130000000000004, -3.612, -3.6109999999999998, -3.6100000000000003, -3.609, -3.6079999999999997, -3.607, -3.606, -3.6050000000000004, -3.604, -3.6029999999999998, -3.6020000000000003, -3.601, -3.5999999999999996, -3.599, -3.598, -3.5970000000000004, -3.596, -3.5949999999999998, -3.5940000000000003, -3.593, -3.5919999999999996, -3.591, -3.59, -3.5890000000000004, -3.588, -3.5869999999999997, -3.5860000000000003, -3.585, -3.5839999999999996, -3.583, -3.582, -3.5810000000000004, -3.58, -3.5789999999999997, -3.5780000000000003, -3.577, -3.5759999999999996, -3.575, -3.574, -3.5730000000000004, -3.572, -3.5709999999999997, -3.5700000000000003, -3.569, -3.5679999999999996, -3.567, -3.566, -3.5650000000000004, -3.564, -3.5629999999999997, -3.5620000000000003, -3.561, -3.5599999999999996, -3.559, -3.558, -3.5570000000000004, -3.556, -3.5549999999999997, -3.5540000000000003, -3.553, -3.5519999999999996, -3.551, -3.55, -3.5490000000000004, -3.548, -3.5469999999999997, -3.5460000000000003, -3.545, -3.5439999999999996, -3.543, -3.542, -3.5410000000000004, -3.54, -3.5389999999999997, -3.5380000000000003, -3.537, -3.5359999999999996, -3.535, -3.534, -3.5330000000000004, -3.532, -3.5309999999999997, -3.5300000000000002, -3.529, -3.5279999999999996, -3.527, -3.526, -3.5250000000000004, -3.524, -3.5229999999999997, -3.5220000000000002, -3.521, -3.5199999999999996, -3.519, -3.518, -3.5170000000000003, -3.516, -3.5149999999999997, -3.5140000000000002, -3.513, -3.5119999999999996, -3.511, -3.51, -3.5090000000000003, -3.508, -3.5069999999999997, -3.5060000000000002, -3.505, -3.5039999999999996, -3.503, -3.502, -3.5010000000000003, -3.5, -3.4989999999999997, -3.498, -3.497, -3.4960000000000004, -3.495, -3.4939999999999998, -3.4930000000000003, -3.492, -3.4909999999999997, -3.49, -3.489, -3.4880000000000004, -3.487, -3.4859999999999998, -3.4850000000000003, -3.484, -3.4829999999999997, -3.482, -3.481, -3.4800000000000004, -3.479, -3.4779999999999998, -3.4770000000000003, -3.476, -3.4749999999999996, -3.474, -3.473, -3.4720000000000004, -3.471, -3.4699999999999998, -3.4690000000000003, -3.468, -3.4669999999999996, -3.466, -3.465, -3.4640000000000004, -3.463, -3.4619999999999997, -3.4610000000000003, -3.46, -3.4589999999999996, -3.458, -3.457, -3.4560000000000004, -3.455, -3.4539999999999997, -3.4530000000000003, -3.452, -3.4509999999999996, -3.45, -3.449, -3.4480000000000004, -3.447, -3.4459999999999997, -3.4450000000000003, -3.444, -3.4429999999999996, -3.442, -3.441, -3.4400000000000004, -3.439, -3.4379999999999997, -3.4370000000000003, -3.436, -3.4349999999999996, -3.434, -3.433, -3.4320000000000004, -3.431, -3.4299999999999997, -3.4290000000000003, -3.428, -3.4269999999999996, -3.426, -3.425, -3.4240000000000004, -3.423, -3.4219999999999997, -3.4210000000000003, -3.42, -3.4189999999999996, -3.418, -3.417, -3.4160000000000004, -3.415, -3.4139999999999997, -3.4130000000000003, -3.412, -3.4109999999999996, -3.41, -3.409, -3.4080000000000004, -3.407, -3.4059999999999997, -3.4050000000000002, -3.404, -3.4029999999999996, -3.402, -3.401, -3.4000000000000004, -3.399, -3.3979999999999997, -3.3970000000000002, -3.396, -3.3949999999999996, -3.394, -3.393, -3.3920000000000003, -3.391, -3.3899999999999997, -3.3890000000000002, -3.388, -3.3869999999999996, -3.386, -3.385, -3.3840000000000003, -3.383, -3.3819999999999997, -3.3810000000000002, -3.38, -3.3789999999999996, -3.378, -3.377, -3.3760000000000003, -3.375, -3.3739999999999997, -3.373, -3.372, -3.3710000000000004, -3.37, -3.3689999999999998, -3.3680000000000003, -3.367, -3.3659999999999997, -3.365, -3.364, -3.3630000000000004, -3.362, -3.3609999999999998, -3.3600000000000003, -3.359, -3.3579999999999997, -3.357, -3.356, -3.3550000000000004, -3.354, -3.3529999999999998, -3.3520000000000003, -3.351, -3.3499999999999996, -3.349, -3.348, -3.3470000000000004, -3.346, -3.3449999999999998, -3.3440000000000003, -3.343, -3.3419999999999996, -3.341, -3.34, -3.3390000000000004, -3.338, -3.3369999999999997, -3.3360000000000003, -3.335, -3.3339999999999996, -3.333, -3.332, -3.3310000000000004, -3.33, -3.3289999999999997, -3.3280000000000003, -3.327, -3.3259999999999996, -3.325, -3.324, -3.3230000000000004, -3.322, -3.3209999999999997, -3.3200000000000003, -3.319, -3.3179999999999996, -3.317, -3.316, -3.3150000000000004, -3.314, -3.3129999999999997, -3.3120000000000003, -3.311, -3.3099999999999996, -3.309, -3.308, -3.3070000000000004, -3.306, -3.3049999999999997, -3.3040000000000003, -3.303, -3.3019999999999996, -3.301, -3.3, -3.2990000000000004, -3.298, -3.2969999999999997, -3.2960000000000003, -3.295, -3.2939999999999996, -3.293, -3.292, -3.2910000000000004, -3.29, -3.2889999999999997, -3.2880000000000003, -3.287, -3.2859999999999996, -3.285, -3.284, -3.2830000000000004, -3.282, -3.2809999999999997, -3.2800000000000002, -3.279, -3.2779999999999996, -3.277, -3.276, -3.2750000000000004, -3.274, -3.2729999999999997, -3.2720000000000002, -3.271, -3.2699999999999996, -3.269, -3.268, -3.2670000000000003, -3.266, -3.2649999999999997, -3.2640000000000002, -3.263, -3.2619999999999996, -3.261, -3.26, -3.2590000000000003, -3.258, -3.2569999999999997, -3.2560000000000002, -3.255, -3.2539999999999996, -3.253, -3.252, -3.2510000000000003, -3.25, -3.2489999999999997, -3.248, -3.247, -3.2460000000000004, -3.245, -3.2439999999999998, -3.2430000000000003, -3.242, -3.2409999999999997, -3.24, -3.239, -3.2380000000000004, -3.237, -3.2359999999999998, -3.2350000000000003, -3.234, -3.2329999999999997, -3.232, -3.231, -3.2300000000000004, -3.229, -3.2279999999999998, -3.2270000000000003, -3.226, -3.2249999999999996, -3.224, -3.223, -3.2220000000000004, -3.221, -3.2199999999999998, -3.2190000000000003, -3.218, -3.2169999999999996, -3.216, -3.215, -3.2140000000000004, -3.213, -3.2119999999999997, -3.2110000000000003, -3.21, -3.2089999999999996, -3.208, -3.207, -3.2060000000000004, -3.205, -3.2039999999999997, -3.2030000000000003, -3.202, -3.2009999999999996, -3.2, -3.199, -3.1980000000000004, -3.197, -3.1959999999999997, -3.1950000000000003, -3.194, -3.1929999999999996, -3.192, -3.191, -3.1900000000000004, -3.189, -3.1879999999999997, -3.1870000000000003, -3.186, -3.1849999999999996, -3.184, -3.183, -3.1820000000000004, -3.181, -3.1799999999999997, -3.1790000000000003, -3.178, -3.1769999999999996, -3.176, -3.175, -3.1740000000000004, -3.173, -3.1719999999999997, -3.1710000000000003, -3.17, -3.1689999999999996, -3.168, -3.167, -3.1660000000000004, -3.165, -3.1639999999999997, -3.1630000000000003, -3.162, -3.1609999999999996, -3.16, -3.159, -3.1580000000000004, -3.157, -3.1559999999999997, -3.1550000000000002, -3.154, -3.1529999999999996, -3.152, -3.151, -3.1500000000000004, -3.149, -3.1479999999999997, -3.1470000000000002, -3.146, -3.1449999999999996, -3.144, -3.143, -3.1420000000000003, -3.141, -3.1399999999999997, -3.1390000000000002, -3.138, -3.1369999999999996, -3.136, -3.135, -3.1340000000000003, -3.133, -3.1319999999999997, -3.1310000000000002, -3.13, -3.1289999999999996, -3.128, -3.127, -3.1260000000000003, -3.125, -3.1239999999999997, -3.123, -3.122, -3.1210000000000004, -3.12, -3.1189999999999998, -3.1180000000000003, -3.117, -3.1159999999999997, -3.115, -3.114, -3.1130000000000004, -3.112, -3.1109999999999998, -3.1100000000000003, -3.109, -3.1079999999999997, -3.107, -3.106, -3.1050000000000004, -3.104, -3.1029999999999998, -3.1020000000000003, -3.101, -3.0999999999999996, -3.099, -3.098, -3.0970000000000004, -3.096, -3.0949999999999998, -3.0940000000000003, -3.093, -3.0919999999999996, -3.091, -3.09, -3.0890000000000004, -3.088, -3.0869999999999997, -3.0860000000000003, -3.085, -3.0839999999999996, -3.083, -3.082, -3.0810000000000004, -3.08, -3.0789999999999997, -3.0780000000000003, -3.077, -3.0759999999999996, -3.075, -3.074, -3.0730000000000004, -3.072, -3.0709999999999997, -3.0700000000000003, -3.069, -3.0679999999999996, -3.067, -3.066, -3.0650000000000004, -3.064, -3.0629999999999997, -3.0620000000000003, -3.061, -3.0599999999999996, -3.059, -3.058, -3.0570000000000004, -3.056, -3.0549999999999997, -3.0540000000000003, -3.053, -3.0519999999999996, -3.051, -3.05, -3.0490000000000004, -3.048, -3.0469999999999997, -3.0460000000000003, -3.045, -3.0439999999999996, -3.043, -3.042, -3.0410000000000004, -3.04, -3.0389999999999997, -3.0380000000000003, -3.037, -3.0359999999999996, -3.035, -3.034, -3.0330000000000004, -3.032, -3.0309999999999997, -3.0300000000000002, -3.029, -3.0279999999999996, -3.027, -3.026, -3.0250000000000004, -3.024, -3.0229999999999997, -3.0220000000000002, -3.021, -3.0199999999999996, -3.019, -3.018, -3.0170000000000003, -3.016, -3.0149999999999997, -3.0140000000000002, -3.013, -3.0119999999999996, -3.011, -3.01, -3.0090000000000003, -3.008, -3.0069999999999997, -3.0060000000000002, -3.005, -3.0039999999999996, -3.003, -3.002, -3.0010000000000003, -3.0, -2.9989999999999997, -2.998, -2.997, -2.9960000000000004, -2.995, -2.9939999999999998, -2.9930000000000003, -2.992, -2.9909999999999997, -2.99, -2.989, -2.9880000000000004, -2.987, -2.9859999999999998, -2.9850000000000003, -2.984, -2.9829999999999997, -2.982, -2.981, -2.9800000000000004, -2.979, -2.9779999999999998, -2.9770000000000003, -2.976, -2.9749999999999996, -2.974, -2.973, -2.9720000000000004, -2.971, -2.9699999999999998, -2.9690000000000003, -2.968, -2.9669999999999996, -2.966, -2.965, -2.9640000000000004, -2.963, -2.9619999999999997, -2.9610000000000003, -2.96, -2.9589999999999996, -2.958, -2.957, -2.9560000000000004, -2.955, -2.9539999999999997, -2.9530000000000003, -2.952, -2.9509999999999996, -2.95, -2.949, -2.9480000000000004, -2.947, -2.9459999999999997, -2.9450000000000003, -2.944, -2.9429999999999996, -2.942, -2.941, -2.9400000000000004, -2.939, -2.9379999999999997, -2.9370000000000003, -2.936, -2.9349999999999996, -2.934, -2.933, -2.9320000000000004, -2.931, -2.9299999999999997, -2.9290000000000003, -2.928, -2.9269999999999996, -2.926, -2.925, -2.9240000000000004, -2.923, -2.9219999999999997, -2.9210000000000003, -2.92, -2.9189999999999996, -2.918, -2.917, -2.9160000000000004, -2.915, -2.9139999999999997, -2.9130000000000003, -2.912, -2.9109999999999996, -2.91, -2.909, -2.9080000000000004, -2.907, -2.9059999999999997, -2.9050000000000002, -2.904, -2.9029999999999996, -2.902, -2.901, -2.9000000000000004, -2.899, -2.8979999999999997, -2.8970000000000002, -2.896, -2.8949999999999996, -2.894, -2.893, -2.8920000000000003, -2.891, -2.8899999999999997, -2.8890000000000002, -2.888, -2.8869999999999996, -2.886, -2.885, -2.8840000000000003, -2.883, -2.8819999999999997, -2.8810000000000002, -2.88, -2.8789999999999996, -2.878, -2.877, -2.8760000000000003, -2.875, -2.8739999999999997, -2.873, -2.872, -2.8710000000000004, -2.87, -2.8689999999999998, -2.8680000000000003, -2.867, -2.8659999999999997, -2.865, -2.864, -2.8630000000000004, -2.862, -2.8609999999999998, -2.8600000000000003, -2.859, -2.8579999999999997, -2.857, -2.856, -2.8550000000000004, -2.854, -2.8529999999999998, -2.8520000000000003, -2.851, -2.8499999999999996, -2.849, -2.848, -2.8470000000000004, -2.846, -2.8449999999999998, -2.8440000000000003, -2.843, -2.8419999999999996, -2.841, -2.84, -2.8390000000000004, -2.838, -2.8369999999999997, -2.8360000000000003, -2.835, -2.8339999999999996, -2.833, -2.832, -2.8310000000000004, -2.83, -2.8289999999999997, -2.8280000000000003, -2.827, -2.8259999999999996, -2.825, -2.824, -2.8230000000000004, -2.822, -2.8209999999999997, -2.8200000000000003, -2.819, -2.8179999999999996, -2.817, -2.816, -2.8150000000000004, -2.814, -2.8129999999999997, -2.8120000000000003, -2.811, -2.8099999999999996, -2.809, -2.808, -2.8070000000000004, -2.806, -2.8049999999999997, -2.8040000000000003, -2.803, -2.8019999999999996, -2.801, -2.8, -2.7990000000000004, -2.798, -2.7969999999999997, -2.7960000000000003, -2.795, -2.7939999999999996, -2.793, -2.792, -2.7910000000000004, -2.79, -2.7889999999999997, -2.7880000000000003, -2.787, -2.7859999999999996, -2.785, -2.784, -2.7830000000000004, -2.782, -2.7809999999999997, -2.7800000000000002, -2.779, -2.7779999999999996, -2.777, -2.776, -2.7750000000000004, -2.774, -2.7729999999999997, -2.7720000000000002, -2.771, -2.7699999999999996, -2.769, -2.768, -2.7670000000000003, -2.766, -2.7649999999999997, -2.7640000000000002, -2.763, -2.7619999999999996, -2.761, -2.76, -2.7590000000000003, -2.758, -2.7569999999999997, -2.7560000000000002, -2.755, -2.7539999999999996, -2.753, -2.752, -2.7510000000000003, -2.75, -2.7489999999999997, -2.748, -2.747, -2.7460000000000004, -2.745, -2.7439999999999998, -2.7430000000000003, -2.742, -2.7409999999999997, -2.74, -2.739, -2.7380000000000004, -2.737, -2.7359999999999998, -2.7350000000000003, -2.734, -2.7329999999999997, -2.732, -2.731, -2.7300000000000004, -2.729, -2.7279999999999998, -2.7270000000000003, -2.726, -2.7249999999999996, -2.724, -2.723, -2.7220000000000004, -2.721, -2.7199999999999998, -2.7190000000000003, -2.718, -2.7169999999999996, -2.716, -2.715, -2.7140000000000004, -2.713, -2.7119999999999997, -2.7110000000000003, -2.71, -2.7089999999999996, -2.708, -2.707, -2.7060000000000004, -2.705, -2.7039999999999997, -2.7030000000000003, -2.702, -2.7009999999999996, -2.7, -2.699, -2.6980000000000004, -2.697, -2.6959999999999997, -2.6950000000000003, -2.694, -2.6929999999999996, -2.692, -2.691, -2.6900000000000004, -2.689, -2.6879999999999997, -2.6870000000000003, -2.686, -2.6849999999999996, -2.684, -2.683, -2.6820000000000004, -2.681, -2.6799999999999997, -2.6790000000000003, -2.678, -2.6769999999999996, -2.676, -2.675, -2.6740000000000004, -2.673, -2.6719999999999997, -2.6710000000000003, -2.67, -2.6689999999999996, -2.668, -2.667, -2.6660000000000004, -2.665, -2.6639999999999997, -2.6630000000000003, -2.662, -2.6609999999999996, -2.66, -2.659, -2.6580000000000004, -2.657, -2.6559999999999997, -2.6550000000000002, -2.654, -2.6529999999999996, -2.652, -2.651, -2.6500000000000004, -2.649, -2.6479999999999997, -2.6470000000000002, -2.646, -2.6449999999999996, -2.644, -2.643, -2.6420000000000003, -2.641, -2.6399999999999997, -2.6390000000000002, -2.638, -2.6369999999999996, -2.636, -2.635, -2.6340000000000003, -2.633, -2.6319999999999997, -2.6310000000000002, -2.63, -2.6289999999999996, -2.628, -2.627, -2.6260000000000003, -2.625, -2.6239999999999997, -2.623, -2.622, -2.6210000000000004, -2.62, -2.6189999999999998, -2.6180000000000003, -2.617, -2.6159999999999997, -2.615, -2.614, -2.6130000000000004, -2.612, -2.6109999999999998, -2.6100000000000003, -2.609, -2.6079999999999997, -2.607, -2.606, -2.6050000000000004, -2.604, -2.6029999999999998, -2.6020000000000003, -2.601, -2.5999999999999996, -2.599, -2.598, -2.5970000000000004, -2.596, -2.5949999999999998, -2.5940000000000003, -2.593, -2.5919999999999996, -2.591, -2.59, -2.5890000000000004, -2.588, -2.5869999999999997, -2.5860000000000003, -2.585, -2.5839999999999996, -2.583, -2.582, -2.5810000000000004, -2.58, -2.5789999999999997, -2.5780000000000003, -2.577, -2.5759999999999996, -2.575, -2.574, -2.5730000000000004, -2.572, -2.5709999999999997, -2.5700000000000003, -2.569, -2.5679999999999996, -2.567, -2.566, -2.5650000000000004, -2.564, -2.5629999999999997, -2.5620000000000003, -2.561, -2.5599999999999996, -2.559, -2.558, -2.5570000000000004, -2.556, -2.5549999999999997, -2.5540000000000003, -2.553, -2.5519999999999996, -2.551, -2.55, -2.5490000000000004, -2.548, -2.5469999999999997, -2.5460000000000003, -2.545, -2.5439999999999996, -2.543, -2.542, -2.5410000000000004, -2.54, -2.5389999999999997, -2.5380000000000003, -2.537, -2.5359999999999996, -2.535, -2.534, -2.5330000000000004, -2.532, -2.5309999999999997, -2.5300000000000002, -2.529, -2.5279999999999996, -2.527, -2.526, -2.5250000000000004, -2.524, -2.5229999999999997, -2.5220000000000002, -2.521, -2.5199999999999996, -2.519, -2.518, -2.5170000000000003, -2.516, -2.5149999999999997, -2.5140000000000002, -2.513, -2.5119999999999996, -2.511, -2.51, -2.5090000000000003, -2.508, -2.5069999999999997, -2.5060000000000002, -2.505, -2.5039999999999996, -2.503, -2.502, -2.5010000000000003, -2.5, -2.4989999999999997, -2.498, -2.497, -2.4960000000000004, -2.495, -2.4939999999999998, -2.4930000000000003, -2.492, -2.4909999999999997, -2.49, -2.489, -2.4880000000000004, -2.487, -2.4859999999999998, -2.4850000000000003, -2.484, -2.4829999999999997, -2.482, -2.481, -2.4800000000000004, -2.479, -2.4779999999999998, -2.4770000000000003, -2.476, -2.4749999999999996, -2.474, -2.473, -2.4720000000000004, -2.471, -2.4699999999999998, -2.4690000000000003, -2.468, -2.4669999999999996, -2.466, -2.465, -2.4640000000000004, -2.463, -2.4619999999999997, -2.4610000000000003, -2.46, -2.4589999999999996, -2.458, -2.457, -2.4560000000000004, -2.455, -2.4539999999999997, -2.4530000000000003, -2.452, -2.4509999999999996, -2.45, -2.449, -2.4480000000000004, -2.447, -2.4459999999999997, -2.4450000000000003, -2.444, -2.4429999999999996, -2.442, -2.441, -2.4400000000000004, -2.439, -2.4379999999999997, -2.4370000000000003, -2.436, -2.4349999999999996, -2.434, -2.433, -2.4320000000000004, -2.431, -2.4299999999999997, -2.4290000000000003, -2.428, -2.4269999999999996, -2.426, -2.425, -2.4240000000000004, -2.423, -2.4219999999999997, -2.4210000000000003, -2.42, -2.4189999999999996, -2.418, -2.417, -2.4160000000000004, -2.415, -2.4139999999999997, -2.4130000000000003, -2.412, -2.4109999999999996, -2.41, -2.409, -2.4080000000000004, -2.407, -2.4059999999999997, -2.4050000000000002, -2.404, -2.4029999999999996, -2.402, -2.401, -2.4000000000000004, -2.399, -2.3979999999999997, -2.3970000000000002, -2.396, -2.3949999999999996, -2.394, -2.393, -2.3920000000000003, -2.391, -2.3899999999999997, -2.3890000000000002, -2.388, -2.3869999999999996, -2.386, -2.385, -2.3840000000000003, -2.383, -2.3819999999999997, -2.3810000000000002, -2.38, -2.3789999999999996, -2.378, -2.377, -2.3760000000000003, -2.375, -2.3739999999999997, -2.373, -2.372, -2.3710000000000004, -2.37, -2.3689999999999998, -2.3680000000000003, -2.367, -2.3659999999999997, -2.365, -2.364, -2.3630000000000004, -2.362, -2.3609999999999998, -2.3600000000000003, -2.359, -2.3579999999999997, -2.357, -2.356, -2.3550000000000004, -2.354, -2.3529999999999998, -2.3520000000000003, -2.351, -2.3499999999999996, -2.349, -2.348, -2.3470000000000004, -2.346, -2.3449999999999998, -2.3440000000000003, -2.343, -2.3419999999999996, -2.341, -2.34, -2.3390000000000004, -2.338, -2.3369999999999997, -2.3360000000000003, -2.335, -2.3339999999999996, -2.333, -2.332, -2.3310000000000004, -2.33, -2.3289999999999997, -2.3280000000000003, -2.327, -2.3259999999999996, -2.325, -2.324, -2.3230000000000004, -2.322, -2.3209999999999997, -2.3200000000000003, -2.319, -2.3179999999999996, -2.317, -2.316, -2.3150000000000004, -2.314, -2.3129999999999997, -2.3120000000000003, -2.311, -2.3099999999999996, -2.309, -2.308, -2.3070000000000004, -2.306, -2.3049999999999997, -2.3040000000000003, -2.303, -2.3019999999999996, -2.301, -2.3, -2.2990000000000004, -2.298, -2.2969999999999997, -2.2960000000000003, -2.295, -2.2939999999999996, -2.293, -2.292, -2.2910000000000004, -2.29, -2.2889999999999997, -2.2880000000000003, -2.287, -2.2859999999999996, -2.285, -2.284, -2.2830000000000004, -2.282, -2.2809999999999997, -2.2800000000000002, -2.279, -2.2779999999999996, -2.277, -2.276, -2.2750000000000004, -2.274, -2.2729999999999997, -2.2720000000000002, -2.271, -2.2699999999999996, -2.269, -2.268, -2.2670000000000003, -2.266, -2.2649999999999997, -2.2640000000000002, -2.263, -2.2619999999999996, -2.261, -2.26, -2.2590000000000003, -2.258, -2.2569999999999997, -2.2560000000000002, -2.255, -2.2539999999999996, -2.253, -2.252, -2.2510000000000003, -2.25, -2.2489999999999997, -2.248, -2.247, -2.2460000000000004, -2.245, -2.2439999999999998, -2.2430000000000003, -2.242, -2.2409999999999997, -2.24, -2.239, -2.2380000000000004, -2.237, -2.2359999999999998, -2.2350000000000003, -2.234, -2.2329999999999997, -2.232, -2.231, -2.2300000000000004, -2.229, -2.2279999999999998, -2.2270000000000003, -2.226, -2.2249999999999996, -2.224, -2.223, -2.2220000000000004, -2.221, -2.2199999999999998, -2.2190000000000003, -2.218, -2.2169999999999996, -2.216, -2.215, -2.2140000000000004, -2.213, -2.2119999999999997, -2.2110000000000003, -2.21, -2.2089999999999996, -2.208, -2.207, -2.2060000000000004, -2.205, -2.2039999999999997, -2.2030000000000003, -2.202, -2.2009999999999996, -2.2, -2.199, -2.1980000000000004, -2.197, -2.1959999999999997, -2.1950000000000003, -2.194, -2.1929999999999996, -2.192, -2.191, -2.1900000000000004, -2.189, -2.1879999999999997, -2.1870000000000003, -2.186, -2.1849999999999996, -2.184, -2.183, -2.1820000000000004, -2.181, -2.1799999999999997, -2.1790000000000003, -2.178, -2.1769999999999996, -2.176, -2.175, -2.1740000000000004, -2.173, -2.1719999999999997, -2.1710000000000003, -2.17, -2.1689999999999996, -2.168, -2.167, -2.1660000000000004, -2.165, -2.1639999999999997, -2.1630000000000003, -2.162, -2.1609999999999996, -2.16, -2.159, -2.1580000000000004, -2.157, -2.1559999999999997, -2.1550000000000002, -2.154, -2.1529999999999996, -2.152, -2.151, -2.1500000000000004, -2.149, -2.1479999999999997, -2.1470000000000002, -2.146, -2.1449999999999996, -2.144, -2.143, -2.1420000000000003, -2.141, -2.1399999999999997, -2.1390000000000002, -2.138, -2.1369999999999996, -2.136, -2.135, -2.1340000000000003, -2.133, -2.1319999999999997, -2.1310000000000002, -2.13, -2.1289999999999996, -2.128, -2.127, -2.1260000000000003, -2.125, -2.1239999999999997, -2.123, -2.122, -2.1210000000000004, -2.12, -2.1189999999999998, -2.1180000000000003, -2.117, -2.1159999999999997, -2.115, -2.114, -2.1130000000000004, -2.112, -2.1109999999999998, -2.1100000000000003, -2.109, -2.1079999999999997, -2.107, -2.106, -2.1050000000000004, -2.104, -2.1029999999999998, -2.1020000000000003, -2.101, -2.0999999999999996, -2.099, -2.098, -2.0970000000000004, -2.096, -2.0949999999999998, -2.0940000000000003, -2.093, -2.0919999999999996, -2.091, -2.09, -2.0890000000000004, -2.088, -2.0869999999999997, -2.0860000000000003, -2.085, -2.0839999999999996, -2.083, -2.082, -2.0810000000000004, -2.08, -2.0789999999999997, -2.0780000000000003, -2.077, -2.0759999999999996, -2.075, -2.074, -2.0730000000000004, -2.072, -2.0709999999999997, -2.0700000000000003, -2.069, -2.0679999999999996, -2.067, -2.066, -2.0650000000000004, -2.064, -2.0629999999999997, -2.0620000000000003, -2.061, -2.0599999999999996, -2.059, -2.058, -2.0570000000000004, -2.056, -2.0549999999999997, -2.0540000000000003, -2.053, -2.0519999999999996, -2.051, -2.05, -2.0490000000000004, -2.048, -2.0469999999999997, -2.0460000000000003, -2.045, -2.0439999999999996, -2.043, -2.042, -2.0410000000000004, -2.04, -2.0389999999999997, -2.0380000000000003, -2.037, -2.0359999999999996, -2.035, -2.034, -2.0330000000000004, -2.032, -2.0309999999999997, -2.0300000000000002, -2.029, -2.0279999999999996, -2.027, -2.026, -2.0250000000000004, -2.024, -2.0229999999999997, -2.0220000000000002, -2.021, -2.0199999999999996, -2.019, -2.018, -2.0170000000000003, -2.016, -2.0149999999999997, -2.0140000000000002, -2.013, -2.0119999999999996, -2.011, -2.01, -2.0090000000000003, -2.008, -2.0069999999999997, -2.0060000000000002, -2.005, -2.0039999999999996, -2.003, -2.002, -2.0010000000000003, -2.0, -1.9990000000000006, -1.9979999999999993, -1.9969999999999999, -1.9960000000000004, -1.9949999999999992, -1.9939999999999998, -1.9930000000000003, -1.9920000000000009, -1.9909999999999997, -1.9900000000000002, -1.9890000000000008, -1.9879999999999995, -1.987, -1.9860000000000007, -1.9849999999999994, -1.984, -1.9830000000000005, -1.9819999999999993, -1.9809999999999999, -1.9800000000000004, -1.9789999999999992, -1.9779999999999998, -1.9770000000000003, -1.9760000000000009, -1.9749999999999996, -1.9740000000000002, -1.9730000000000008, -1.9719999999999995, -1.971, -1.9700000000000006, -1.9689999999999994, -1.968, -1.9670000000000005, -1.9659999999999993, -1.9649999999999999, -1.9640000000000004, -1.9629999999999992, -1.9619999999999997, -1.9610000000000003, -1.9600000000000009, -1.9589999999999996, -1.9580000000000002, -1.9570000000000007, -1.9559999999999995, -1.955, -1.9540000000000006, -1.9529999999999994, -1.952, -1.9510000000000005, -1.9499999999999993, -1.9489999999999998, -1.9480000000000004, -1.9469999999999992, -1.9459999999999997, -1.9450000000000003, -1.9440000000000008, -1.9429999999999996, -1.9420000000000002, -1.9410000000000007, -1.9399999999999995, -1.939, -1.9380000000000006, -1.9369999999999994, -1.936, -1.9350000000000005, -1.9339999999999993, -1.9329999999999998, -1.9320000000000004, -1.9309999999999992, -1.9299999999999997, -1.9290000000000003, -1.9280000000000008, -1.9269999999999996, -1.9260000000000002, -1.9250000000000007, -1.9239999999999995, -1.923, -1.9220000000000006, -1.9209999999999994, -1.92, -1.9190000000000005, -1.9179999999999993, -1.9169999999999998, -1.9160000000000004, -1.9149999999999991, -1.9139999999999997, -1.9130000000000003, -1.9120000000000008, -1.9109999999999996, -1.9100000000000001, -1.9090000000000007, -1.9079999999999995, -1.907, -1.9060000000000006, -1.9049999999999994, -1.904, -1.9030000000000005, -1.9019999999999992, -1.9009999999999998, -1.9000000000000004, -1.8989999999999991, -1.8979999999999997, -1.8970000000000002, -1.8960000000000008, -1.8949999999999996, -1.8940000000000001, -1.8930000000000007, -1.8919999999999995, -1.891, -1.8900000000000006, -1.8889999999999993, -1.888, -1.8870000000000005, -1.8859999999999992, -1.8849999999999998, -1.8840000000000003, -1.8829999999999991, -1.8819999999999997, -1.8810000000000002, -1.8800000000000008, -1.8789999999999996, -1.8780000000000001, -1.8770000000000007, -1.8759999999999994, -1.875, -1.8740000000000006, -1.8729999999999993, -1.8719999999999999, -1.8710000000000004, -1.8699999999999992, -1.8689999999999998, -1.8680000000000003, -1.8670000000000009, -1.8659999999999997, -1.8650000000000002, -1.8640000000000008, -1.8629999999999995, -1.862, -1.8610000000000007, -1.8599999999999994, -1.859, -1.8580000000000005, -1.8569999999999993, -1.8559999999999999, -1.8550000000000004, -1.8539999999999992, -1.8529999999999998, -1.8520000000000003, -1.8510000000000009, -1.8499999999999996, -1.8490000000000002, -1.8480000000000008, -1.8469999999999995, -1.846, -1.8450000000000006, -1.8439999999999994, -1.843, -1.8420000000000005, -1.8409999999999993, -1.8399999999999999, -1.8390000000000004, -1.8379999999999992, -1.8369999999999997, -1.8360000000000003, -1.8350000000000009, -1.8339999999999996, -1.8330000000000002, -1.8320000000000007, -1.8309999999999995, -1.83, -1.8290000000000006, -1.8279999999999994, -1.827, -1.8260000000000005, -1.8249999999999993, -1.8239999999999998, -1.8230000000000004, -1.8219999999999992, -1.8209999999999997, -1.8200000000000003, -1.8190000000000008, -1.8179999999999996, -1.8170000000000002, -1.8160000000000007, -1.8149999999999995, -1.814, -1.8130000000000006, -1.8119999999999994, -1.811, -1.8100000000000005, -1.8089999999999993, -1.8079999999999998, -1.8070000000000004, -1.8059999999999992, -1.8049999999999997, -1.8040000000000003, -1.8030000000000008, -1.8019999999999996, -1.8010000000000002, -1.8000000000000007, -1.7989999999999995, -1.798, -1.7970000000000006, -1.7959999999999994, -1.795, -1.7940000000000005, -1.7929999999999993, -1.7919999999999998, -1.7910000000000004, -1.7899999999999991, -1.7889999999999997, -1.7880000000000003, -1.7870000000000008, -1.7859999999999996, -1.7850000000000001, -1.7840000000000007, -1.7829999999999995, -1.782, -1.7810000000000006, -1.7799999999999994, -1.779, -1.7780000000000005, -1.7769999999999992, -1.7759999999999998, -1.7750000000000004, -1.7739999999999991, -1.7729999999999997, -1.7720000000000002, -1.7710000000000008, -1.7699999999999996, -1.7690000000000001, -1.7680000000000007, -1.7669999999999995, -1.766, -1.7650000000000006, -1.7639999999999993, -1.763, -1.7620000000000005, -1.7609999999999992, -1.7599999999999998, -1.7590000000000003, -1.7579999999999991, -1.7569999999999997, -1.7560000000000002, -1.7550000000000008, -1.7539999999999996, -1.7530000000000001, -1.7520000000000007, -1.7509999999999994, -1.75, -1.7490000000000006, -1.7479999999999993, -1.7469999999999999, -1.7460000000000004, -1.7449999999999992, -1.7439999999999998, -1.7430000000000003, -1.7420000000000009, -1.7409999999999997, -1.7400000000000002, -1.7390000000000008, -1.7379999999999995, -1.737, -1.7360000000000007, -1.7349999999999994, -1.734, -1.7330000000000005, -1.7319999999999993, -1.7309999999999999, -1.7300000000000004, -1.7289999999999992, -1.7279999999999998, -1.7270000000000003, -1.7260000000000009, -1.7249999999999996, -1.7240000000000002, -1.7230000000000008, -1.7219999999999995, -1.721, -1.7200000000000006, -1.7189999999999994, -1.718, -1.7170000000000005, -1.7159999999999993, -1.7149999999999999, -1.7140000000000004, -1.7129999999999992, -1.7119999999999997, -1.7110000000000003, -1.7100000000000009, -1.7089999999999996, -1.7080000000000002, -1.7070000000000007, -1.7059999999999995, -1.705, -1.7040000000000006, -1.7029999999999994, -1.702, -1.7010000000000005, -1.6999999999999993, -1.6989999999999998, -1.6980000000000004, -1.6969999999999992, -1.6959999999999997, -1.6950000000000003, -1.6940000000000008, -1.6929999999999996, -1.6920000000000002, -1.6910000000000007, -1.6899999999999995, -1.689, -1.6880000000000006, -1.6869999999999994, -1.686, -1.6850000000000005, -1.6839999999999993, -1.6829999999999998, -1.6820000000000004, -1.6809999999999992, -1.6799999999999997, -1.6790000000000003, -1.6780000000000008, -1.6769999999999996, -1.6760000000000002, -1.6750000000000007, -1.6739999999999995, -1.673, -1.6720000000000006, -1.6709999999999994, -1.67, -1.6690000000000005, -1.6679999999999993, -1.6669999999999998, -1.6660000000000004, -1.6649999999999991, -1.6639999999999997, -1.6630000000000003, -1.6620000000000008, -1.6609999999999996, -1.6600000000000001, -1.6590000000000007, -1.6579999999999995, -1.657, -1.6560000000000006, -1.6549999999999994, -1.654, -1.6530000000000005, -1.6519999999999992, -1.6509999999999998, -1.6500000000000004, -1.6489999999999991, -1.6479999999999997, -1.6470000000000002, -1.6460000000000008, -1.6449999999999996, -1.6440000000000001, -1.6430000000000007, -1.6419999999999995, -1.641, -1.6400000000000006, -1.6389999999999993, -1.638, -1.6370000000000005, -1.6359999999999992, -1.6349999999999998, -1.6340000000000003, -1.6329999999999991, -1.6319999999999997, -1.6310000000000002, -1.6300000000000008, -1.6289999999999996, -1.6280000000000001, -1.6270000000000007, -1.6259999999999994, -1.625, -1.6240000000000006, -1.6229999999999993, -1.6219999999999999, -1.6210000000000004, -1.6199999999999992, -1.6189999999999998, -1.6180000000000003, -1.6170000000000009, -1.6159999999999997, -1.6150000000000002, -1.6140000000000008, -1.6129999999999995, -1.612, -1.6110000000000007, -1.6099999999999994, -1.609, -1.6080000000000005, -1.6069999999999993, -1.6059999999999999, -1.6050000000000004, -1.6039999999999992, -1.6029999999999998, -1.6020000000000003, -1.6010000000000009, -1.5999999999999996, -1.5990000000000002, -1.5980000000000008, -1.5969999999999995, -1.596, -1.5950000000000006, -1.5939999999999994, -1.593, -1.5920000000000005, -1.5909999999999993, -1.5899999999999999, -1.5890000000000004, -1.5879999999999992, -1.5869999999999997, -1.5860000000000003, -1.5850000000000009, -1.5839999999999996, -1.5830000000000002, -1.5820000000000007, -1.5809999999999995, -1.58, -1.5790000000000006, -1.5779999999999994, -1.577, -1.5760000000000005, -1.5749999999999993, -1.5739999999999998, -1.5730000000000004, -1.5719999999999992, -1.5709999999999997, -1.5700000000000003, -1.5690000000000008, -1.5679999999999996, -1.5670000000000002, -1.5660000000000007, -1.5649999999999995, -1.564, -1.5630000000000006, -1.5619999999999994, -1.561, -1.5600000000000005, -1.5589999999999993, -1.5579999999999998, -1.5570000000000004, -1.5559999999999992, -1.5549999999999997, -1.5540000000000003, -1.5530000000000008, -1.5519999999999996, -1.5510000000000002, -1.5500000000000007, -1.5489999999999995, -1.548, -1.5470000000000006, -1.5459999999999994, -1.545, -1.5440000000000005, -1.5429999999999993, -1.5419999999999998, -1.5410000000000004, -1.5399999999999991, -1.5389999999999997, -1.5380000000000003, -1.5370000000000008, -1.5359999999999996, -1.5350000000000001, -1.5340000000000007, -1.5329999999999995, -1.532, -1.5310000000000006, -1.5299999999999994, -1.529, -1.5280000000000005, -1.5269999999999992, -1.5259999999999998, -1.5250000000000004, -1.5239999999999991, -1.5229999999999997, -1.5220000000000002, -1.5210000000000008, -1.5199999999999996, -1.5190000000000001, -1.5180000000000007, -1.5169999999999995, -1.516, -1.5150000000000006, -1.5139999999999993, -1.513, -1.5120000000000005, -1.5109999999999992, -1.5099999999999998, -1.5090000000000003, -1.5079999999999991, -1.5069999999999997, -1.5060000000000002, -1.5050000000000008, -1.5039999999999996, -1.5030000000000001, -1.5020000000000007, -1.5009999999999994, -1.5, -1.4990000000000006, -1.4979999999999993, -1.4969999999999999, -1.4960000000000004, -1.4949999999999992, -1.4939999999999998, -1.4930000000000003, -1.4920000000000009, -1.4909999999999997, -1.4900000000000002, -1.4890000000000008, -1.4879999999999995, -1.487, -1.4860000000000007, -1.4849999999999994, -1.484, -1.4830000000000005, -1.4819999999999993, -1.4809999999999999, -1.4800000000000004, -1.4789999999999992, -1.4779999999999998, -1.4770000000000003, -1.4760000000000009, -1.4749999999999996, -1.4740000000000002, -1.4730000000000008, -1.4719999999999995, -1.471, -1.4700000000000006, -1.4689999999999994, -1.468, -1.4670000000000005, -1.4659999999999993, -1.4649999999999999, -1.4640000000000004, -1.4629999999999992, -1.4619999999999997, -1.4610000000000003, -1.4600000000000009, -1.4589999999999996, -1.4580000000000002, -1.4570000000000007, -1.4559999999999995, -1.455, -1.4540000000000006, -1.4529999999999994, -1.452, -1.4510000000000005, -1.4499999999999993, -1.4489999999999998, -1.4480000000000004, -1.4469999999999992, -1.4459999999999997, -1.4450000000000003, -1.4440000000000008, -1.4429999999999996, -1.4420000000000002, -1.4410000000000007, -1.4399999999999995, -1.439, -1.4380000000000006, -1.4369999999999994, -1.436, -1.4350000000000005, -1.4339999999999993, -1.4329999999999998, -1.4320000000000004, -1.4309999999999992, -1.4299999999999997, -1.4290000000000003, -1.4280000000000008, -1.4269999999999996, -1.4260000000000002, -1.4250000000000007, -1.4239999999999995, -1.423, -1.4220000000000006, -1.4209999999999994, -1.42, -1.4190000000000005, -1.4179999999999993, -1.4169999999999998, -1.4160000000000004, -1.4149999999999991, -1.4139999999999997, -1.4130000000000003, -1.4120000000000008, -1.4109999999999996, -1.4100000000000001, -1.4090000000000007, -1.4079999999999995, -1.407, -1.4060000000000006, -1.4049999999999994, -1.404, -1.4030000000000005, -1.4019999999999992, -1.4009999999999998, -1.4000000000000004, -1.3989999999999991, -1.3979999999999997, -1.3970000000000002, -1.3960000000000008, -1.3949999999999996, -1.3940000000000001, -1.3930000000000007, -1.3919999999999995, -1.391, -1.3900000000000006, -1.3889999999999993, -1.388, -1.3870000000000005, -1.3859999999999992, -1.3849999999999998, -1.3840000000000003, -1.3829999999999991, -1.3819999999999997, -1.3810000000000002, -1.3800000000000008, -1.3789999999999996, -1.3780000000000001, -1.3770000000000007, -1.3759999999999994, -1.375, -1.3740000000000006, -1.3729999999999993, -1.3719999999999999, -1.3710000000000004, -1.3699999999999992, -1.3689999999999998, -1.3680000000000003, -1.3670000000000009, -1.3659999999999997, -1.3650000000000002, -1.3640000000000008, -1.3629999999999995, -1.362, -1.3610000000000007, -1.3599999999999994, -1.359, -1.3580000000000005, -1.3569999999999993, -1.3559999999999999, -1.3550000000000004, -1.3539999999999992, -1.3529999999999998, -1.3520000000000003, -1.3510000000000009, -1.3499999999999996, -1.3490000000000002, -1.3480000000000008, -1.3469999999999995, -1.346, -1.3450000000000006, -1.3439999999999994, -1.343, -1.3420000000000005, -1.3409999999999993, -1.3399999999999999, -1.3390000000000004, -1.3379999999999992, -1.3369999999999997, -1.3360000000000003, -1.3350000000000009, -1.3339999999999996, -1.3330000000000002, -1.3320000000000007, -1.3309999999999995, -1.33, -1.3290000000000006, -1.3279999999999994, -1.327, -1.3260000000000005, -1.3249999999999993, -1.3239999999999998, -1.3230000000000004, -1.3219999999999992, -1.3209999999999997, -1.3200000000000003, -1.3190000000000008, -1.3179999999999996, -1.3170000000000002, -1.3160000000000007, -1.3149999999999995, -1.314, -1.3130000000000006, -1.3119999999999994, -1.311, -1.3100000000000005, -1.3089999999999993, -1.3079999999999998, -1.3070000000000004, -1.3059999999999992, -1.3049999999999997, -1.3040000000000003, -1.3030000000000008, -1.3019999999999996, -1.3010000000000002, -1.3000000000000007, -1.2989999999999995, -1.298, -1.2970000000000006, -1.2959999999999994, -1.295, -1.2940000000000005, -1.2929999999999993, -1.2919999999999998, -1.2910000000000004, -1.2899999999999991, -1.2889999999999997, -1.2880000000000003, -1.2870000000000008, -1.2859999999999996, -1.2850000000000001, -1.2840000000000007, -1.2829999999999995, -1.282, -1.2810000000000006, -1.2799999999999994, -1.279, -1.2780000000000005, -1.2769999999999992, -1.2759999999999998, -1.2750000000000004, -1.2739999999999991, -1.2729999999999997, -1.2720000000000002, -1.2710000000000008, -1.2699999999999996, -1.2690000000000001, -1.2680000000000007, -1.2669999999999995, -1.266, -1.2650000000000006, -1.2639999999999993, -1.263, -1.2620000000000005, -1.2609999999999992, -1.2599999999999998, -1.2590000000000003, -1.2579999999999991, -1.2569999999999997, -1.2560000000000002, -1.2550000000000008, -1.2539999999999996, -1.2530000000000001, -1.2520000000000007, -1.2509999999999994, -1.25, -1.2490000000000006, -1.2479999999999993, -1.2469999999999999, -1.2460000000000004, -1.2449999999999992, -1.2439999999999998, -1.2430000000000003, -1.2420000000000009, -1.2409999999999997, -1.2400000000000002, -1.2390000000000008, -1.2379999999999995, -1.237, -1.2360000000000007, -1.2349999999999994, -1.234, -1.2330000000000005, -1.2319999999999993, -1.2309999999999999, -1.2300000000000004, -1.2289999999999992, -1.2279999999999998, -1.2270000000000003, -1.2260000000000009, -1.2249999999999996, -1.2240000000000002, -1.2230000000000008, -1.2219999999999995, -1.221, -1.2200000000000006, -1.2189999999999994, -1.218, -1.2170000000000005, -1.2159999999999993, -1.2149999999999999, -1.2140000000000004, -1.2129999999999992, -1.2119999999999997, -1.2110000000000003, -1.2100000000000009, -1.2089999999999996, -1.2080000000000002, -1.2070000000000007, -1.2059999999999995, -1.205, -1.2040000000000006, -1.2029999999999994, -1.202, -1.2010000000000005, -1.1999999999999993, -1.1989999999999998, -1.1980000000000004, -1.1969999999999992, -1.1959999999999997, -1.1950000000000003, -1.1940000000000008, -1.1929999999999996, -1.1920000000000002, -1.1910000000000007, -1.1899999999999995, -1.189, -1.1880000000000006, -1.1869999999999994, -1.186, -1.1850000000000005, -1.1839999999999993, -1.1829999999999998, -1.1820000000000004, -1.1809999999999992, -1.1799999999999997, -1.1790000000000003, -1.1780000000000008, -1.1769999999999996, -1.1760000000000002, -1.1750000000000007, -1.1739999999999995, -1.173, -1.1720000000000006, -1.1709999999999994, -1.17, -1.1690000000000005, -1.1679999999999993, -1.1669999999999998, -1.1660000000000004, -1.1649999999999991, -1.1639999999999997, -1.1630000000000003, -1.1620000000000008, -1.1609999999999996, -1.1600000000000001, -1.1590000000000007, -1.1579999999999995, -1.157, -1.1560000000000006, -1.1549999999999994, -1.154, -1.1530000000000005, -1.1519999999999992, -1.1509999999999998, -1.1500000000000004, -1.1489999999999991, -1.1479999999999997, -1.1470000000000002, -1.1460000000000008, -1.1449999999999996, -1.1440000000000001, -1.1430000000000007, -1.1419999999999995, -1.141, -1.1400000000000006, -1.1389999999999993, -1.138, -1.1370000000000005, -1.1359999999999992, -1.1349999999999998, -1.1340000000000003, -1.1329999999999991, -1.1319999999999997, -1.1310000000000002, -1.1300000000000008, -1.1289999999999996, -1.1280000000000001, -1.1270000000000007, -1.1259999999999994, -1.125, -1.1240000000000006, -1.1229999999999993, -1.1219999999999999, -1.1210000000000004, -1.1199999999999992, -1.1189999999999998, -1.1180000000000003, -1.1170000000000009, -1.1159999999999997, -1.1150000000000002, -1.1140000000000008, -1.1129999999999995, -1.112, -1.1110000000000007, -1.1099999999999994, -1.109, -1.1080000000000005, -1.1069999999999993, -1.1059999999999999, -1.1050000000000004, -1.1039999999999992, -1.1029999999999998, -1.1020000000000003, -1.1010000000000009, -1.0999999999999996, -1.0990000000000002, -1.0980000000000008, -1.0969999999999995, -1.096, -1.0950000000000006, -1.0939999999999994, -1.093, -1.0920000000000005, -1.0909999999999993, -1.0899999999999999, -1.0890000000000004, -1.0879999999999992, -1.0869999999999997, -1.0860000000000003, -1.0850000000000009, -1.0839999999999996, -1.0830000000000002, -1.0820000000000007, -1.0809999999999995, -1.08, -1.0790000000000006, -1.0779999999999994, -1.077, -1.0760000000000005, -1.0749999999999993, -1.0739999999999998, -1.0730000000000004, -1.0719999999999992, -1.0709999999999997, -1.0700000000000003, -1.0690000000000008, -1.0679999999999996, -1.0670000000000002, -1.0660000000000007, -1.0649999999999995, -1.064, -1.0630000000000006, -1.0619999999999994, -1.061, -1.0600000000000005, -1.0589999999999993, -1.0579999999999998, -1.0570000000000004, -1.0559999999999992, -1.0549999999999997, -1.0540000000000003, -1.0530000000000008, -1.0519999999999996, -1.0510000000000002, -1.0500000000000007, -1.0489999999999995, -1.048, -1.0470000000000006, -1.0459999999999994, -1.045, -1.0440000000000005, -1.0429999999999993, -1.0419999999999998, -1.0410000000000004, -1.0399999999999991, -1.0389999999999997, -1.0380000000000003, -1.0370000000000008, -1.0359999999999996, -1.0350000000000001, -1.0340000000000007, -1.0329999999999995, -1.032, -1.0310000000000006, -1.0299999999999994, -1.029, -1.0280000000000005, -1.0269999999999992, -1.0259999999999998, -1.0250000000000004, -1.0239999999999991, -1.0229999999999997, -1.0220000000000002, -1.0210000000000008, -1.0199999999999996, -1.0190000000000001, -1.0180000000000007, -1.0169999999999995, -1.016, -1.0150000000000006, -1.0139999999999993, -1.013, -1.0120000000000005, -1.0109999999999992, -1.0099999999999998, -1.0090000000000003, -1.0079999999999991, -1.0069999999999997, -1.0060000000000002, -1.0050000000000008, -1.0039999999999996, -1.0030000000000001, -1.0020000000000007, -1.0009999999999994, -1.0, -0.9990000000000006, -0.9979999999999993, -0.9969999999999999, -0.9960000000000004, -0.9949999999999992, -0.9939999999999998, -0.9930000000000003, -0.9920000000000009, -0.9909999999999997, -0.9900000000000002, -0.9890000000000008, -0.9879999999999995, -0.9870000000000001, -0.9860000000000007, -0.9849999999999994, -0.984, -0.9830000000000005, -0.9819999999999993, -0.9809999999999999, -0.9800000000000004, -0.9789999999999992, -0.9779999999999998, -0.9770000000000003, -0.9760000000000009, -0.9749999999999996, -0.9740000000000002, -0.9730000000000008, -0.9719999999999995, -0.9710000000000001, -0.9700000000000006, -0.9689999999999994, -0.968, -0.9670000000000005, -0.9659999999999993, -0.9649999999999999, -0.9640000000000004, -0.9629999999999992, -0.9619999999999997, -0.9610000000000003, -0.9600000000000009, -0.9589999999999996, -0.9580000000000002, -0.9570000000000007, -0.9559999999999995, -0.9550000000000001, -0.9540000000000006, -0.9529999999999994, -0.952, -0.9510000000000005, -0.9499999999999993, -0.9489999999999998, -0.9480000000000004, -0.9469999999999992, -0.9459999999999997, -0.9450000000000003, -0.9440000000000008, -0.9429999999999996, -0.9420000000000002, -0.9410000000000007, -0.9399999999999995, -0.9390000000000001, -0.9380000000000006, -0.9369999999999994, -0.9359999999999999, -0.9350000000000005, -0.9339999999999993, -0.9329999999999998, -0.9320000000000004, -0.9309999999999992, -0.9299999999999997, -0.9290000000000003, -0.9280000000000008, -0.9269999999999996, -0.9260000000000002, -0.9250000000000007, -0.9239999999999995, -0.923, -0.9220000000000006, -0.9209999999999994, -0.9199999999999999, -0.9190000000000005, -0.9179999999999993, -0.9169999999999998, -0.9160000000000004, -0.9149999999999991, -0.9139999999999997, -0.9130000000000003, -0.9120000000000008, -0.9109999999999996, -0.9100000000000001, -0.9090000000000007, -0.9079999999999995, -0.907, -0.9060000000000006, -0.9049999999999994, -0.9039999999999999, -0.9030000000000005, -0.9019999999999992, -0.9009999999999998, -0.9000000000000004, -0.8989999999999991, -0.8979999999999997, -0.8970000000000002, -0.8960000000000008, -0.8949999999999996, -0.8940000000000001, -0.8930000000000007, -0.8919999999999995, -0.891, -0.8900000000000006, -0.8889999999999993, -0.8879999999999999, -0.8870000000000005, -0.8859999999999992, -0.8849999999999998, -0.8840000000000003, -0.8829999999999991, -0.8819999999999997, -0.8810000000000002, -0.8800000000000008, -0.8789999999999996, -0.8780000000000001, -0.8770000000000007, -0.8759999999999994, -0.875, -0.8740000000000006, -0.8729999999999993, -0.8719999999999999, -0.8710000000000004, -0.8699999999999992, -0.8689999999999998, -0.8680000000000003, -0.8670000000000009, -0.8659999999999997, -0.8650000000000002, -0.8640000000000008, -0.8629999999999995, -0.8620000000000001, -0.8610000000000007, -0.8599999999999994, -0.859, -0.8580000000000005, -0.8569999999999993, -0.8559999999999999, -0.8550000000000004, -0.8539999999999992, -0.8529999999999998, -0.8520000000000003, -0.8510000000000009, -0.8499999999999996, -0.8490000000000002, -0.8480000000000008, -0.8469999999999995, -0.8460000000000001, -0.8450000000000006, -0.8439999999999994, -0.843, -0.8420000000000005, -0.8409999999999993, -0.8399999999999999, -0.8390000000000004, -0.8379999999999992, -0.8369999999999997, -0.8360000000000003, -0.8350000000000009, -0.8339999999999996, -0.8330000000000002, -0.8320000000000007, -0.8309999999999995, -0.8300000000000001, -0.8290000000000006, -0.8279999999999994, -0.827, -0.8260000000000005, -0.8249999999999993, -0.8239999999999998, -0.8230000000000004, -0.8219999999999992, -0.8209999999999997, -0.8200000000000003, -0.8190000000000008, -0.8179999999999996, -0.8170000000000002, -0.8160000000000007, -0.8149999999999995, -0.8140000000000001, -0.8130000000000006, -0.8119999999999994, -0.8109999999999999, -0.8100000000000005, -0.8089999999999993, -0.8079999999999998, -0.8070000000000004, -0.8059999999999992, -0.8049999999999997, -0.8040000000000003, -0.8030000000000008, -0.8019999999999996, -0.8010000000000002, -0.8000000000000007, -0.7989999999999995, -0.798, -0.7970000000000006, -0.7959999999999994, -0.7949999999999999, -0.7940000000000005, -0.7929999999999993, -0.7919999999999998, -0.7910000000000004, -0.7899999999999991, -0.7889999999999997, -0.7880000000000003, -0.7870000000000008, -0.7859999999999996, -0.7850000000000001, -0.7840000000000007, -0.7829999999999995, -0.782, -0.7810000000000006, -0.7799999999999994, -0.7789999999999999, -0.7780000000000005, -0.7769999999999992, -0.7759999999999998, -0.7750000000000004, -0.7739999999999991, -0.7729999999999997, -0.7720000000000002, -0.7710000000000008, -0.7699999999999996, -0.7690000000000001, -0.7680000000000007, -0.7669999999999995, -0.766, -0.7650000000000006, -0.7639999999999993, -0.7629999999999999, -0.7620000000000005, -0.7609999999999992, -0.7599999999999998, -0.7590000000000003, -0.7579999999999991, -0.7569999999999997, -0.7560000000000002, -0.7550000000000008, -0.7539999999999996, -0.7530000000000001, -0.7520000000000007, -0.7509999999999994, -0.75, -0.7490000000000006, -0.7479999999999993, -0.7469999999999999, -0.7460000000000004, -0.7449999999999992, -0.7439999999999998, -0.7430000000000003, -0.7420000000000009, -0.7409999999999997, -0.7400000000000002, -0.7390000000000008, -0.7379999999999995, -0.7370000000000001, -0.7360000000000007, -0.7349999999999994, -0.734, -0.7330000000000005, -0.7319999999999993, -0.7309999999999999, -0.7300000000000004, -0.7289999999999992, -0.7279999999999998, -0.7270000000000003, -0.7260000000000009, -0.7249999999999996, -0.7240000000000002, -0.7230000000000008, -0.7219999999999995, -0.7210000000000001, -0.7200000000000006, -0.7189999999999994, -0.718, -0.7170000000000005, -0.7159999999999993, -0.7149999999999999, -0.7140000000000004, -0.7129999999999992, -0.7119999999999997, -0.7110000000000003, -0.7100000000000009, -0.7089999999999996, -0.7080000000000002, -0.7070000000000007, -0.7059999999999995, -0.7050000000000001, -0.7040000000000006, -0.7029999999999994, -0.702, -0.7010000000000005, -0.6999999999999993, -0.6989999999999998, -0.6980000000000004, -0.6969999999999992, -0.6959999999999997, -0.6950000000000003, -0.6940000000000008, -0.6929999999999996, -0.6920000000000002, -0.6910000000000007, -0.6899999999999995, -0.6890000000000001, -0.6880000000000006, -0.6869999999999994, -0.6859999999999999, -0.6850000000000005, -0.6839999999999993, -0.6829999999999998, -0.6820000000000004, -0.6809999999999992, -0.6799999999999997, -0.6790000000000003, -0.6780000000000008, -0.6769999999999996, -0.6760000000000002, -0.6750000000000007, -0.6739999999999995, -0.673, -0.6720000000000006, -0.6709999999999994, -0.6699999999999999, -0.6690000000000005, -0.6679999999999993, -0.6669999999999998, -0.6660000000000004, -0.6649999999999991, -0.6639999999999997, -0.6630000000000003, -0.6620000000000008, -0.6609999999999996, -0.6600000000000001, -0.6590000000000007, -0.6579999999999995, -0.657, -0.6560000000000006, -0.6549999999999994, -0.6539999999999999, -0.6530000000000005, -0.6519999999999992, -0.6509999999999998, -0.6500000000000004, -0.6489999999999991, -0.6479999999999997, -0.6470000000000002, -0.6460000000000008, -0.6449999999999996, -0.6440000000000001, -0.6430000000000007, -0.6419999999999995, -0.641, -0.6400000000000006, -0.6389999999999993, -0.6379999999999999, -0.6370000000000005, -0.6359999999999992, -0.6349999999999998, -0.6340000000000003, -0.6329999999999991, -0.6319999999999997, -0.6310000000000002, -0.6300000000000008, -0.6289999999999996, -0.6280000000000001, -0.6270000000000007, -0.6259999999999994, -0.625, -0.6240000000000006, -0.6229999999999993, -0.6219999999999999, -0.6210000000000004, -0.6199999999999992, -0.6189999999999998, -0.6180000000000003, -0.6170000000000009, -0.6159999999999997, -0.6150000000000002, -0.6140000000000008, -0.6129999999999995, -0.6120000000000001, -0.6110000000000007, -0.6099999999999994, -0.609, -0.6080000000000005, -0.6069999999999993, -0.6059999999999999, -0.6050000000000004, -0.6039999999999992, -0.6029999999999998, -0.6020000000000003, -0.6010000000000009, -0.5999999999999996, -0.5990000000000002, -0.5980000000000008, -0.5969999999999995, -0.5960000000000001, -0.5950000000000006, -0.5939999999999994, -0.593, -0.5920000000000005, -0.5909999999999993, -0.5899999999999999, -0.5890000000000004, -0.5879999999999992, -0.5869999999999997, -0.5860000000000003, -0.5850000000000009, -0.5839999999999996, -0.5830000000000002, -0.5820000000000007, -0.5809999999999995, -0.5800000000000001, -0.5790000000000006, -0.5779999999999994, -0.577, -0.5760000000000005, -0.5749999999999993, -0.5739999999999998, -0.5730000000000004, -0.5719999999999992, -0.5709999999999997, -0.5700000000000003, -0.5690000000000008, -0.5679999999999996, -0.5670000000000002, -0.5660000000000007, -0.5649999999999995, -0.5640000000000001, -0.5630000000000006, -0.5619999999999994, -0.5609999999999999, -0.5600000000000005, -0.5589999999999993, -0.5579999999999998, -0.5570000000000004, -0.5559999999999992, -0.5549999999999997, -0.5540000000000003, -0.5530000000000008, -0.5519999999999996, -0.5510000000000002, -0.5500000000000007, -0.5489999999999995, -0.548, -0.5470000000000006, -0.5459999999999994, -0.5449999999999999, -0.5440000000000005, -0.5429999999999993, -0.5419999999999998, -0.5410000000000004, -0.5399999999999991, -0.5389999999999997, -0.5380000000000003, -0.5370000000000008, -0.5359999999999996, -0.5350000000000001, -0.5340000000000007, -0.5329999999999995, -0.532, -0.5310000000000006, -0.5299999999999994, -0.5289999999999999, -0.5280000000000005, -0.5269999999999992, -0.5259999999999998, -0.5250000000000004, -0.5239999999999991, -0.5229999999999997, -0.5220000000000002, -0.5210000000000008, -0.5199999999999996, -0.5190000000000001, -0.5180000000000007, -0.5169999999999995, -0.516, -0.5150000000000006, -0.5139999999999993, -0.5129999999999999, -0.5120000000000005, -0.5109999999999992, -0.5099999999999998, -0.5090000000000003, -0.5079999999999991, -0.5069999999999997, -0.5060000000000002, -0.5050000000000008, -0.5039999999999996, -0.5030000000000001, -0.5020000000000007, -0.5009999999999994, -0.5, -0.49900000000000055, -0.49799999999999933, -0.4969999999999999, -0.49600000000000044, -0.4949999999999992, -0.4939999999999998, -0.4930000000000003, -0.4920000000000009, -0.49099999999999966, -0.4900000000000002, -0.48900000000000077, -0.48799999999999955, -0.4870000000000001, -0.48600000000000065, -0.48499999999999943, -0.484, -0.48300000000000054, -0.4819999999999993, -0.48099999999999987, -0.4800000000000004, -0.4789999999999992, -0.47799999999999976, -0.4770000000000003, -0.47600000000000087, -0.47499999999999964, -0.4740000000000002, -0.47300000000000075, -0.47199999999999953, -0.4710000000000001, -0.47000000000000064, -0.4689999999999994, -0.46799999999999997, -0.4670000000000005, -0.4659999999999993, -0.46499999999999986, -0.4640000000000004, -0.4629999999999992, -0.46199999999999974, -0.4610000000000003, -0.46000000000000085, -0.45899999999999963, -0.4580000000000002, -0.45700000000000074, -0.4559999999999995, -0.45500000000000007, -0.4540000000000006, -0.4529999999999994, -0.45199999999999996, -0.4510000000000005, -0.4499999999999993, -0.44899999999999984, -0.4480000000000004, -0.4469999999999992, -0.44599999999999973, -0.4450000000000003, -0.44400000000000084, -0.4429999999999996, -0.44200000000000017, -0.4410000000000007, -0.4399999999999995, -0.43900000000000006, -0.4380000000000006, -0.4369999999999994, -0.43599999999999994, -0.4350000000000005, -0.4339999999999993, -0.43299999999999983, -0.4320000000000004, -0.43099999999999916, -0.4299999999999997, -0.42900000000000027, -0.4280000000000008, -0.4269999999999996, -0.42600000000000016, -0.4250000000000007, -0.4239999999999995, -0.42300000000000004, -0.4220000000000006, -0.4209999999999994, -0.41999999999999993, -0.4190000000000005, -0.41799999999999926, -0.4169999999999998, -0.41600000000000037, -0.41499999999999915, -0.4139999999999997, -0.41300000000000026, -0.4120000000000008, -0.4109999999999996, -0.41000000000000014, -0.4090000000000007, -0.4079999999999995, -0.40700000000000003, -0.4060000000000006, -0.40499999999999936, -0.4039999999999999, -0.40300000000000047, -0.40199999999999925, -0.4009999999999998, -0.40000000000000036, -0.39899999999999913, -0.3979999999999997, -0.39700000000000024, -0.3960000000000008, -0.3949999999999996, -0.39400000000000013, -0.3930000000000007, -0.39199999999999946, -0.391, -0.39000000000000057, -0.38899999999999935, -0.3879999999999999, -0.38700000000000045, -0.38599999999999923, -0.3849999999999998, -0.38400000000000034, -0.3829999999999991, -0.3819999999999997, -0.3810000000000002, -0.3800000000000008, -0.37899999999999956, -0.3780000000000001, -0.37700000000000067, -0.37599999999999945, -0.375, -0.37400000000000055, -0.37299999999999933, -0.3719999999999999, -0.37100000000000044, -0.3699999999999992, -0.3689999999999998, -0.3680000000000003, -0.3670000000000009, -0.36599999999999966, -0.3650000000000002, -0.36400000000000077, -0.36299999999999955, -0.3620000000000001, -0.36100000000000065, -0.35999999999999943, -0.359, -0.35800000000000054, -0.3569999999999993, -0.35599999999999987, -0.3550000000000004, -0.3539999999999992, -0.35299999999999976, -0.3520000000000003, -0.35100000000000087, -0.34999999999999964, -0.3490000000000002, -0.34800000000000075, -0.34699999999999953, -0.3460000000000001, -0.34500000000000064, -0.3439999999999994, -0.34299999999999997, -0.3420000000000005, -0.3409999999999993, -0.33999999999999986, -0.3390000000000004, -0.3379999999999992, -0.33699999999999974, -0.3360000000000003, -0.33500000000000085, -0.33399999999999963, -0.3330000000000002, -0.33200000000000074, -0.3309999999999995, -0.33000000000000007, -0.3290000000000006, -0.3279999999999994, -0.32699999999999996, -0.3260000000000005, -0.3249999999999993, -0.32399999999999984, -0.3230000000000004, -0.3219999999999992, -0.32099999999999973, -0.3200000000000003, -0.31900000000000084, -0.3179999999999996, -0.31700000000000017, -0.3160000000000007, -0.3149999999999995, -0.31400000000000006, -0.3130000000000006, -0.3119999999999994, -0.31099999999999994, -0.3100000000000005, -0.3089999999999993, -0.30799999999999983, -0.3070000000000004, -0.30599999999999916, -0.3049999999999997, -0.30400000000000027, -0.3030000000000008, -0.3019999999999996, -0.30100000000000016, -0.3000000000000007, -0.2989999999999995, -0.29800000000000004, -0.2970000000000006, -0.2959999999999994, -0.29499999999999993, -0.2940000000000005, -0.29299999999999926, -0.2919999999999998, -0.29100000000000037, -0.28999999999999915, -0.2889999999999997, -0.28800000000000026, -0.2870000000000008, -0.2859999999999996, -0.28500000000000014, -0.2840000000000007, -0.2829999999999995, -0.28200000000000003, -0.2810000000000006, -0.27999999999999936, -0.2789999999999999, -0.27800000000000047, -0.27699999999999925, -0.2759999999999998, -0.27500000000000036, -0.27399999999999913, -0.2729999999999997, -0.27200000000000024, -0.2710000000000008, -0.2699999999999996, -0.26900000000000013, -0.2680000000000007, -0.26699999999999946, -0.266, -0.26500000000000057, -0.26399999999999935, -0.2629999999999999, -0.26200000000000045, -0.26099999999999923, -0.2599999999999998, -0.25900000000000034, -0.2579999999999991, -0.2569999999999997, -0.2560000000000002, -0.2550000000000008, -0.25399999999999956, -0.2530000000000001, -0.25200000000000067, -0.25099999999999945, -0.25, -0.24900000000000055, -0.24799999999999933, -0.2469999999999999, -0.24600000000000044, -0.24499999999999922, -0.24399999999999977, -0.24300000000000033, -0.24200000000000088, -0.24099999999999966, -0.2400000000000002, -0.23900000000000077, -0.23799999999999955, -0.2370000000000001, -0.23600000000000065, -0.23499999999999943, -0.23399999999999999, -0.23300000000000054, -0.23199999999999932, -0.23099999999999987, -0.23000000000000043, -0.2289999999999992, -0.22799999999999976, -0.2270000000000003, -0.22600000000000087, -0.22499999999999964, -0.2240000000000002, -0.22300000000000075, -0.22199999999999953, -0.22100000000000009, -0.22000000000000064, -0.21899999999999942, -0.21799999999999997, -0.21700000000000053, -0.2159999999999993, -0.21499999999999986, -0.2140000000000004, -0.2129999999999992, -0.21199999999999974, -0.2110000000000003, -0.21000000000000085, -0.20899999999999963, -0.20800000000000018, -0.20700000000000074, -0.20599999999999952, -0.20500000000000007, -0.20400000000000063, -0.2029999999999994, -0.20199999999999996, -0.2010000000000005, -0.1999999999999993, -0.19899999999999984, -0.1980000000000004, -0.19699999999999918, -0.19599999999999973, -0.19500000000000028, -0.19400000000000084, -0.19299999999999962, -0.19200000000000017, -0.19100000000000072, -0.1899999999999995, -0.18900000000000006, -0.1880000000000006, -0.1869999999999994, -0.18599999999999994, -0.1850000000000005, -0.18399999999999928, -0.18299999999999983, -0.18200000000000038, -0.18099999999999916, -0.17999999999999972, -0.17900000000000027, -0.17800000000000082, -0.1769999999999996, -0.17600000000000016, -0.1750000000000007, -0.1739999999999995, -0.17300000000000004, -0.1720000000000006, -0.17099999999999937, -0.16999999999999993, -0.16900000000000048, -0.16799999999999926, -0.16699999999999982, -0.16600000000000037, -0.16499999999999915, -0.1639999999999997, -0.16300000000000026, -0.1620000000000008, -0.1609999999999996, -0.16000000000000014, -0.1590000000000007, -0.15799999999999947, -0.15700000000000003, -0.15600000000000058, -0.15499999999999936, -0.15399999999999991, -0.15300000000000047, -0.15199999999999925, -0.1509999999999998, -0.15000000000000036, -0.14899999999999913, -0.1479999999999997, -0.14700000000000024, -0.1460000000000008, -0.14499999999999957, -0.14400000000000013, -0.14300000000000068, -0.14199999999999946, -0.14100000000000001, -0.14000000000000057, -0.13899999999999935, -0.1379999999999999, -0.13700000000000045, -0.13599999999999923, -0.1349999999999998, -0.13400000000000034, -0.13299999999999912, -0.13199999999999967, -0.13100000000000023, -0.13000000000000078, -0.12899999999999956, -0.1280000000000001, -0.12700000000000067, -0.12599999999999945, -0.125, -0.12400000000000055, -0.12299999999999933, -0.12199999999999989, -0.12100000000000044, -0.11999999999999922, -0.11899999999999977, -0.11800000000000033, -0.11700000000000088, -0.11599999999999966, -0.11500000000000021, -0.11400000000000077, -0.11299999999999955, -0.1120000000000001, -0.11100000000000065, -0.10999999999999943, -0.10899999999999999, -0.10800000000000054, -0.10699999999999932, -0.10599999999999987, -0.10500000000000043, -0.1039999999999992, -0.10299999999999976, -0.10200000000000031, -0.10100000000000087, -0.09999999999999964, -0.0990000000000002, -0.09800000000000075, -0.09699999999999953, -0.09600000000000009, -0.09500000000000064, -0.09399999999999942, -0.09299999999999997, -0.09200000000000053, -0.0909999999999993, -0.08999999999999986, -0.08900000000000041, -0.08799999999999919, -0.08699999999999974, -0.0860000000000003, -0.08500000000000085, -0.08399999999999963, -0.08300000000000018, -0.08200000000000074, -0.08099999999999952, -0.08000000000000007, -0.07900000000000063, -0.0779999999999994, -0.07699999999999996, -0.07600000000000051, -0.07499999999999929, -0.07399999999999984, -0.0730000000000004, -0.07199999999999918, -0.07099999999999973, -0.07000000000000028, -0.06900000000000084, -0.06799999999999962, -0.06700000000000017, -0.06600000000000072, -0.0649999999999995, -0.06400000000000006, -0.06300000000000061, -0.06199999999999939, -0.06099999999999994, -0.0600000000000005, -0.058999999999999275, -0.05799999999999983, -0.057000000000000384, -0.05599999999999916, -0.054999999999999716, -0.05400000000000027, -0.053000000000000824, -0.0519999999999996, -0.051000000000000156, -0.05000000000000071, -0.04899999999999949, -0.04800000000000004, -0.0470000000000006, -0.045999999999999375, -0.04499999999999993, -0.04400000000000048, -0.04299999999999926, -0.041999999999999815, -0.04100000000000037, -0.03999999999999915, -0.0389999999999997, -0.038000000000000256, -0.03700000000000081, -0.03599999999999959, -0.03500000000000014, -0.034000000000000696, -0.032999999999999474, -0.03200000000000003, -0.031000000000000583, -0.02999999999999936, -0.028999999999999915, -0.02800000000000047, -0.026999999999999247, -0.0259999999999998, -0.025000000000000355, -0.023999999999999133, -0.022999999999999687, -0.02200000000000024, -0.021000000000000796, -0.019999999999999574, -0.019000000000000128, -0.018000000000000682, -0.01699999999999946, -0.016000000000000014, -0.015000000000000568, -0.013999999999999346, -0.0129999999999999, -0.012000000000000455, -0.010999999999999233, -0.009999999999999787, -0.009000000000000341, -0.007999999999999119, -0.006999999999999673, -0.006000000000000227, -0.005000000000000782, -0.0039999999999995595, -0.0030000000000001137, -0.002000000000000668, -0.0009999999999994458, 0.0, 0.0009999999999994458, 0.002000000000000668, 0.0030000000000001137, 0.0039999999999995595, 0.005000000000000782, 0.006000000000000227, 0.006999999999999673, 0.007999999999999119, 0.009000000000000341, 0.009999999999999787, 0.010999999999999233, 0.012000000000000455, 0.0129999999999999, 0.013999999999999346, 0.015000000000000568, 0.016000000000000014, 0.01699999999999946, 0.018000000000000682, 0.019000000000000128, 0.019999999999999574, 0.021000000000000796, 0.02200000000000024, 0.022999999999999687, 0.023999999999999133, 0.025000000000000355, 0.0259999999999998, 0.026999999999999247, 0.02800000000000047, 0.028999999999999915, 0.02999999999999936, 0.031000000000000583, 0.03200000000000003, 0.032999999999999474, 0.034000000000000696, 0.03500000000000014, 0.03599999999999959, 0.03700000000000081, 0.038000000000000256, 0.0389999999999997, 0.03999999999999915, 0.04100000000000037, 0.041999999999999815, 0.04299999999999926, 0.04400000000000048, 0.04499999999999993, 0.045999999999999375, 0.0470000000000006, 0.04800000000000004, 0.04899999999999949, 0.05000000000000071, 0.051000000000000156, 0.0519999999999996, 0.053000000000000824, 0.05400000000000027, 0.054999999999999716, 0.05599999999999916, 0.057000000000000384, 0.05799999999999983, 0.058999999999999275, 0.0600000000000005, 0.06099999999999994, 0.06199999999999939, 0.06300000000000061, 0.06400000000000006, 0.0649999999999995, 0.06600000000000072, 0.06700000000000017, 0.06799999999999962, 0.06900000000000084, 0.07000000000000028, 0.07099999999999973, 0.07199999999999918, 0.0730000000000004, 0.07399999999999984, 0.07499999999999929, 0.07600000000000051, 0.07699999999999996, 0.0779999999999994, 0.07900000000000063, 0.08000000000000007, 0.08099999999999952, 0.08200000000000074, 0.08300000000000018, 0.08399999999999963, 0.08500000000000085, 0.0860000000000003, 0.08699999999999974, 0.08799999999999919, 0.08900000000000041, 0.08999999999999986, 0.0909999999999993, 0.09200000000000053, 0.09299999999999997, 0.09399999999999942, 0.09500000000000064, 0.09600000000000009, 0.09699999999999953, 0.09800000000000075, 0.0990000000000002, 0.09999999999999964, 0.10100000000000087, 0.10200000000000031, 0.10299999999999976, 0.1039999999999992, 0.10500000000000043, 0.10599999999999987, 0.10699999999999932, 0.10800000000000054, 0.10899999999999999, 0.10999999999999943, 0.11100000000000065, 0.1120000000000001, 0.11299999999999955, 0.11400000000000077, 0.11500000000000021, 0.11599999999999966, 0.11700000000000088, 0.11800000000000033, 0.11899999999999977, 0.11999999999999922, 0.12100000000000044, 0.12199999999999989, 0.12299999999999933, 0.12400000000000055, 0.125, 0.12599999999999945, 0.12700000000000067, 0.1280000000000001, 0.12899999999999956, 0.13000000000000078, 0.13100000000000023, 0.13199999999999967, 0.13299999999999912, 0.13400000000000034, 0.1349999999999998, 0.13599999999999923, 0.13700000000000045, 0.1379999999999999, 0.13899999999999935, 0.14000000000000057, 0.14100000000000001, 0.14199999999999946, 0.14300000000000068, 0.14400000000000013, 0.14499999999999957, 0.1460000000000008, 0.14700000000000024, 0.1479999999999997, 0.14899999999999913, 0.15000000000000036, 0.1509999999999998, 0.15199999999999925, 0.15300000000000047, 0.15399999999999991, 0.15499999999999936, 0.15600000000000058, 0.15700000000000003, 0.15799999999999947, 0.1590000000000007, 0.16000000000000014, 0.1609999999999996, 0.1620000000000008, 0.16300000000000026, 0.1639999999999997, 0.16499999999999915, 0.16600000000000037, 0.16699999999999982, 0.16799999999999926, 0.16900000000000048, 0.16999999999999993, 0.17099999999999937, 0.1720000000000006, 0.17300000000000004, 0.1739999999999995, 0.1750000000000007, 0.17600000000000016, 0.1769999999999996, 0.17800000000000082, 0.17900000000000027, 0.17999999999999972, 0.18099999999999916, 0.18200000000000038, 0.18299999999999983, 0.18399999999999928, 0.1850000000000005, 0.18599999999999994, 0.1869999999999994, 0.1880000000000006, 0.18900000000000006, 0.1899999999999995, 0.19100000000000072, 0.19200000000000017, 0.19299999999999962, 0.19400000000000084, 0.19500000000000028, 0.19599999999999973, 0.19699999999999918, 0.1980000000000004, 0.19899999999999984, 0.1999999999999993, 0.2010000000000005, 0.20199999999999996, 0.2029999999999994, 0.20400000000000063, 0.20500000000000007, 0.20599999999999952, 0.20700000000000074, 0.20800000000000018, 0.20899999999999963, 0.21000000000000085, 0.2110000000000003, 0.21199999999999974, 0.2129999999999992, 0.2140000000000004, 0.21499999999999986, 0.2159999999999993, 0.21700000000000053, 0.21799999999999997, 0.21899999999999942, 0.22000000000000064, 0.22100000000000009, 0.22199999999999953, 0.22300000000000075, 0.2240000000000002, 0.22499999999999964, 0.22600000000000087, 0.2270000000000003, 0.22799999999999976, 0.2289999999999992, 0.23000000000000043, 0.23099999999999987, 0.23199999999999932, 0.23300000000000054, 0.23399999999999999, 0.23499999999999943, 0.23600000000000065, 0.2370000000000001, 0.23799999999999955, 0.23900000000000077, 0.2400000000000002, 0.24099999999999966, 0.24200000000000088, 0.24300000000000033, 0.24399999999999977, 0.24499999999999922, 0.24600000000000044, 0.2469999999999999, 0.24799999999999933, 0.24900000000000055, 0.25, 0.25099999999999945, 0.25200000000000067, 0.2530000000000001, 0.25399999999999956, 0.2550000000000008, 0.2560000000000002, 0.2569999999999997, 0.2579999999999991, 0.25900000000000034, 0.2599999999999998, 0.26099999999999923, 0.26200000000000045, 0.2629999999999999, 0.26399999999999935, 0.26500000000000057, 0.266, 0.26699999999999946, 0.2680000000000007, 0.26900000000000013, 0.2699999999999996, 0.2710000000000008, 0.27200000000000024, 0.2729999999999997, 0.27399999999999913, 0.27500000000000036, 0.2759999999999998, 0.27699999999999925, 0.27800000000000047, 0.2789999999999999, 0.27999999999999936, 0.2810000000000006, 0.28200000000000003, 0.2829999999999995, 0.2840000000000007, 0.28500000000000014, 0.2859999999999996, 0.2870000000000008, 0.28800000000000026, 0.2889999999999997, 0.28999999999999915, 0.29100000000000037, 0.2919999999999998, 0.29299999999999926, 0.2940000000000005, 0.29499999999999993, 0.2959999999999994, 0.2970000000000006, 0.29800000000000004, 0.2989999999999995, 0.3000000000000007, 0.30100000000000016, 0.3019999999999996, 0.3030000000000008, 0.30400000000000027, 0.3049999999999997, 0.30599999999999916, 0.3070000000000004, 0.30799999999999983, 0.3089999999999993, 0.3100000000000005, 0.31099999999999994, 0.3119999999999994, 0.3130000000000006, 0.31400000000000006, 0.3149999999999995, 0.3160000000000007, 0.31700000000000017, 0.3179999999999996, 0.31900000000000084, 0.3200000000000003, 0.32099999999999973, 0.3219999999999992, 0.3230000000000004, 0.32399999999999984, 0.3249999999999993, 0.3260000000000005, 0.32699999999999996, 0.3279999999999994, 0.3290000000000006, 0.33000000000000007, 0.3309999999999995, 0.33200000000000074, 0.3330000000000002, 0.33399999999999963, 0.33500000000000085, 0.3360000000000003, 0.33699999999999974, 0.3379999999999992, 0.3390000000000004, 0.33999999999999986, 0.3409999999999993, 0.3420000000000005, 0.34299999999999997, 0.3439999999999994, 0.34500000000000064, 0.3460000000000001, 0.34699999999999953, 0.34800000000000075, 0.3490000000000002, 0.34999999999999964, 0.35100000000000087, 0.3520000000000003, 0.35299999999999976, 0.3539999999999992, 0.3550000000000004, 0.35599999999999987, 0.3569999999999993, 0.35800000000000054, 0.359, 0.35999999999999943, 0.36100000000000065, 0.3620000000000001, 0.36299999999999955, 0.36400000000000077, 0.3650000000000002, 0.36599999999999966, 0.3670000000000009, 0.3680000000000003, 0.3689999999999998, 0.3699999999999992, 0.37100000000000044, 0.3719999999999999, 0.37299999999999933, 0.37400000000000055, 0.375, 0.37599999999999945, 0.37700000000000067, 0.3780000000000001, 0.37899999999999956, 0.3800000000000008, 0.3810000000000002, 0.3819999999999997, 0.3829999999999991, 0.38400000000000034, 0.3849999999999998, 0.38599999999999923, 0.38700000000000045, 0.3879999999999999, 0.38899999999999935, 0.39000000000000057, 0.391, 0.39199999999999946, 0.3930000000000007, 0.39400000000000013, 0.3949999999999996, 0.3960000000000008, 0.39700000000000024, 0.3979999999999997, 0.39899999999999913, 0.40000000000000036, 0.4009999999999998, 0.40199999999999925, 0.40300000000000047, 0.4039999999999999, 0.40499999999999936, 0.4060000000000006, 0.40700000000000003, 0.4079999999999995, 0.4090000000000007, 0.41000000000000014, 0.4109999999999996, 0.4120000000000008, 0.41300000000000026, 0.4139999999999997, 0.41499999999999915, 0.41600000000000037, 0.4169999999999998, 0.41799999999999926, 0.4190000000000005, 0.41999999999999993, 0.4209999999999994, 0.4220000000000006, 0.42300000000000004, 0.4239999999999995, 0.4250000000000007, 0.42600000000000016, 0.4269999999999996, 0.4280000000000008, 0.42900000000000027, 0.4299999999999997, 0.43099999999999916, 0.4320000000000004, 0.43299999999999983, 0.4339999999999993, 0.4350000000000005, 0.43599999999999994, 0.4369999999999994, 0.4380000000000006, 0.43900000000000006, 0.4399999999999995, 0.4410000000000007, 0.44200000000000017, 0.4429999999999996, 0.44400000000000084, 0.4450000000000003, 0.44599999999999973, 0.4469999999999992, 0.4480000000000004, 0.44899999999999984, 0.4499999999999993, 0.4510000000000005, 0.45199999999999996, 0.4529999999999994, 0.4540000000000006, 0.45500000000000007, 0.4559999999999995, 0.45700000000000074, 0.4580000000000002, 0.45899999999999963, 0.46000000000000085, 0.4610000000000003, 0.46199999999999974, 0.4629999999999992, 0.4640000000000004, 0.46499999999999986, 0.4659999999999993, 0.4670000000000005, 0.46799999999999997, 0.4689999999999994, 0.47000000000000064, 0.4710000000000001, 0.47199999999999953, 0.47300000000000075, 0.4740000000000002, 0.47499999999999964, 0.47600000000000087, 0.4770000000000003, 0.47799999999999976, 0.4789999999999992, 0.4800000000000004, 0.48099999999999987, 0.4819999999999993, 0.48300000000000054, 0.484, 0.48499999999999943, 0.48600000000000065, 0.4870000000000001, 0.48799999999999955, 0.48900000000000077, 0.4900000000000002, 0.49099999999999966, 0.4920000000000009, 0.4930000000000003, 0.4939999999999998, 0.4949999999999992, 0.49600000000000044, 0.4969999999999999, 0.49799999999999933, 0.49900000000000055, 0.5, 0.5009999999999994, 0.5020000000000007, 0.5030000000000001, 0.5039999999999996, 0.5050000000000008, 0.5060000000000002, 0.5069999999999997, 0.5079999999999991, 0.5090000000000003, 0.5099999999999998, 0.5109999999999992, 0.5120000000000005, 0.5129999999999999, 0.5139999999999993, 0.5150000000000006, 0.516, 0.5169999999999995, 0.5180000000000007, 0.5190000000000001, 0.5199999999999996, 0.5210000000000008, 0.5220000000000002, 0.5229999999999997, 0.5239999999999991, 0.5250000000000004, 0.5259999999999998, 0.5269999999999992, 0.5280000000000005, 0.5289999999999999, 0.5299999999999994, 0.5310000000000006, 0.532, 0.5329999999999995, 0.5340000000000007, 0.5350000000000001, 0.5359999999999996, 0.5370000000000008, 0.5380000000000003, 0.5389999999999997, 0.5399999999999991, 0.5410000000000004, 0.5419999999999998, 0.5429999999999993, 0.5440000000000005, 0.5449999999999999, 0.5459999999999994, 0.5470000000000006, 0.548, 0.5489999999999995, 0.5500000000000007, 0.5510000000000002, 0.5519999999999996, 0.5530000000000008, 0.5540000000000003, 0.5549999999999997, 0.5559999999999992, 0.5570000000000004, 0.5579999999999998, 0.5589999999999993, 0.5600000000000005, 0.5609999999999999, 0.5619999999999994, 0.5630000000000006, 0.5640000000000001, 0.5649999999999995, 0.5660000000000007, 0.5670000000000002, 0.5679999999999996, 0.5690000000000008, 0.5700000000000003, 0.5709999999999997, 0.5719999999999992, 0.5730000000000004, 0.5739999999999998, 0.5749999999999993, 0.5760000000000005, 0.577, 0.5779999999999994, 0.5790000000000006, 0.5800000000000001, 0.5809999999999995, 0.5820000000000007, 0.5830000000000002, 0.5839999999999996, 0.5850000000000009, 0.5860000000000003, 0.5869999999999997, 0.5879999999999992, 0.5890000000000004, 0.5899999999999999, 0.5909999999999993, 0.5920000000000005, 0.593, 0.5939999999999994, 0.5950000000000006, 0.5960000000000001, 0.5969999999999995, 0.5980000000000008, 0.5990000000000002, 0.5999999999999996, 0.6010000000000009, 0.6020000000000003, 0.6029999999999998, 0.6039999999999992, 0.6050000000000004, 0.6059999999999999, 0.6069999999999993, 0.6080000000000005, 0.609, 0.6099999999999994, 0.6110000000000007, 0.6120000000000001, 0.6129999999999995, 0.6140000000000008, 0.6150000000000002, 0.6159999999999997, 0.6170000000000009, 0.6180000000000003, 0.6189999999999998, 0.6199999999999992, 0.6210000000000004, 0.6219999999999999, 0.6229999999999993, 0.6240000000000006, 0.625, 0.6259999999999994, 0.6270000000000007, 0.6280000000000001, 0.6289999999999996, 0.6300000000000008, 0.6310000000000002, 0.6319999999999997, 0.6329999999999991, 0.6340000000000003, 0.6349999999999998, 0.6359999999999992, 0.6370000000000005, 0.6379999999999999, 0.6389999999999993, 0.6400000000000006, 0.641, 0.6419999999999995, 0.6430000000000007, 0.6440000000000001, 0.6449999999999996, 0.6460000000000008, 0.6470000000000002, 0.6479999999999997, 0.6489999999999991, 0.6500000000000004, 0.6509999999999998, 0.6519999999999992, 0.6530000000000005, 0.6539999999999999, 0.6549999999999994, 0.6560000000000006, 0.657, 0.6579999999999995, 0.6590000000000007, 0.6600000000000001, 0.6609999999999996, 0.6620000000000008, 0.6630000000000003, 0.6639999999999997, 0.6649999999999991, 0.6660000000000004, 0.6669999999999998, 0.6679999999999993, 0.6690000000000005, 0.6699999999999999, 0.6709999999999994, 0.6720000000000006, 0.673, 0.6739999999999995, 0.6750000000000007, 0.6760000000000002, 0.6769999999999996, 0.6780000000000008, 0.6790000000000003, 0.6799999999999997, 0.6809999999999992, 0.6820000000000004, 0.6829999999999998, 0.6839999999999993, 0.6850000000000005, 0.6859999999999999, 0.6869999999999994, 0.6880000000000006, 0.6890000000000001, 0.6899999999999995, 0.6910000000000007, 0.6920000000000002, 0.6929999999999996, 0.6940000000000008, 0.6950000000000003, 0.6959999999999997, 0.6969999999999992, 0.6980000000000004, 0.6989999999999998, 0.6999999999999993, 0.7010000000000005, 0.702, 0.7029999999999994, 0.7040000000000006, 0.7050000000000001, 0.7059999999999995, 0.7070000000000007, 0.7080000000000002, 0.7089999999999996, 0.7100000000000009, 0.7110000000000003, 0.7119999999999997, 0.7129999999999992, 0.7140000000000004, 0.7149999999999999, 0.7159999999999993, 0.7170000000000005, 0.718, 0.7189999999999994, 0.7200000000000006, 0.7210000000000001, 0.7219999999999995, 0.7230000000000008, 0.7240000000000002, 0.7249999999999996, 0.7260000000000009, 0.7270000000000003, 0.7279999999999998, 0.7289999999999992, 0.7300000000000004, 0.7309999999999999, 0.7319999999999993, 0.7330000000000005, 0.734, 0.7349999999999994, 0.7360000000000007, 0.7370000000000001, 0.7379999999999995, 0.7390000000000008, 0.7400000000000002, 0.7409999999999997, 0.7420000000000009, 0.7430000000000003, 0.7439999999999998, 0.7449999999999992, 0.7460000000000004, 0.7469999999999999, 0.7479999999999993, 0.7490000000000006, 0.75, 0.7509999999999994, 0.7520000000000007, 0.7530000000000001, 0.7539999999999996, 0.7550000000000008, 0.7560000000000002, 0.7569999999999997, 0.7579999999999991, 0.7590000000000003, 0.7599999999999998, 0.7609999999999992, 0.7620000000000005, 0.7629999999999999, 0.7639999999999993, 0.7650000000000006, 0.766, 0.7669999999999995, 0.7680000000000007, 0.7690000000000001, 0.7699999999999996, 0.7710000000000008, 0.7720000000000002, 0.7729999999999997, 0.7739999999999991, 0.7750000000000004, 0.7759999999999998, 0.7769999999999992, 0.7780000000000005, 0.7789999999999999, 0.7799999999999994, 0.7810000000000006, 0.782, 0.7829999999999995, 0.7840000000000007, 0.7850000000000001, 0.7859999999999996, 0.7870000000000008, 0.7880000000000003, 0.7889999999999997, 0.7899999999999991, 0.7910000000000004, 0.7919999999999998, 0.7929999999999993, 0.7940000000000005, 0.7949999999999999, 0.7959999999999994, 0.7970000000000006, 0.798, 0.7989999999999995, 0.8000000000000007, 0.8010000000000002, 0.8019999999999996, 0.8030000000000008, 0.8040000000000003, 0.8049999999999997, 0.8059999999999992, 0.8070000000000004, 0.8079999999999998, 0.8089999999999993, 0.8100000000000005, 0.8109999999999999, 0.8119999999999994, 0.8130000000000006, 0.8140000000000001, 0.8149999999999995, 0.8160000000000007, 0.8170000000000002, 0.8179999999999996, 0.8190000000000008, 0.8200000000000003, 0.8209999999999997, 0.8219999999999992, 0.8230000000000004, 0.8239999999999998, 0.8249999999999993, 0.8260000000000005, 0.827, 0.8279999999999994, 0.8290000000000006, 0.8300000000000001, 0.8309999999999995, 0.8320000000000007, 0.8330000000000002, 0.8339999999999996, 0.8350000000000009, 0.8360000000000003, 0.8369999999999997, 0.8379999999999992, 0.8390000000000004, 0.8399999999999999, 0.8409999999999993, 0.8420000000000005, 0.843, 0.8439999999999994, 0.8450000000000006, 0.8460000000000001, 0.8469999999999995, 0.8480000000000008, 0.8490000000000002, 0.8499999999999996, 0.8510000000000009, 0.8520000000000003, 0.8529999999999998, 0.8539999999999992, 0.8550000000000004, 0.8559999999999999, 0.8569999999999993, 0.8580000000000005, 0.859, 0.8599999999999994, 0.8610000000000007, 0.8620000000000001, 0.8629999999999995, 0.8640000000000008, 0.8650000000000002, 0.8659999999999997, 0.8670000000000009, 0.8680000000000003, 0.8689999999999998, 0.8699999999999992, 0.8710000000000004, 0.8719999999999999, 0.8729999999999993, 0.8740000000000006, 0.875, 0.8759999999999994, 0.8770000000000007, 0.8780000000000001, 0.8789999999999996, 0.8800000000000008, 0.8810000000000002, 0.8819999999999997, 0.8829999999999991, 0.8840000000000003, 0.8849999999999998, 0.8859999999999992, 0.8870000000000005, 0.8879999999999999, 0.8889999999999993, 0.8900000000000006, 0.891, 0.8919999999999995, 0.8930000000000007, 0.8940000000000001, 0.8949999999999996, 0.8960000000000008, 0.8970000000000002, 0.8979999999999997, 0.8989999999999991, 0.9000000000000004, 0.9009999999999998, 0.9019999999999992, 0.9030000000000005, 0.9039999999999999, 0.9049999999999994, 0.9060000000000006, 0.907, 0.9079999999999995, 0.9090000000000007, 0.9100000000000001, 0.9109999999999996, 0.9120000000000008, 0.9130000000000003, 0.9139999999999997, 0.9149999999999991, 0.9160000000000004, 0.9169999999999998, 0.9179999999999993, 0.9190000000000005, 0.9199999999999999, 0.9209999999999994, 0.9220000000000006, 0.923, 0.9239999999999995, 0.9250000000000007, 0.9260000000000002, 0.9269999999999996, 0.9280000000000008, 0.9290000000000003, 0.9299999999999997, 0.9309999999999992, 0.9320000000000004, 0.9329999999999998, 0.9339999999999993, 0.9350000000000005, 0.9359999999999999, 0.9369999999999994, 0.9380000000000006, 0.9390000000000001, 0.9399999999999995, 0.9410000000000007, 0.9420000000000002, 0.9429999999999996, 0.9440000000000008, 0.9450000000000003, 0.9459999999999997, 0.9469999999999992, 0.9480000000000004, 0.9489999999999998, 0.9499999999999993, 0.9510000000000005, 0.952, 0.9529999999999994, 0.9540000000000006, 0.9550000000000001, 0.9559999999999995, 0.9570000000000007, 0.9580000000000002, 0.9589999999999996, 0.9600000000000009, 0.9610000000000003, 0.9619999999999997, 0.9629999999999992, 0.9640000000000004, 0.9649999999999999, 0.9659999999999993, 0.9670000000000005, 0.968, 0.9689999999999994, 0.9700000000000006, 0.9710000000000001, 0.9719999999999995, 0.9730000000000008, 0.9740000000000002, 0.9749999999999996, 0.9760000000000009, 0.9770000000000003, 0.9779999999999998, 0.9789999999999992, 0.9800000000000004, 0.9809999999999999, 0.9819999999999993, 0.9830000000000005, 0.984, 0.9849999999999994, 0.9860000000000007, 0.9870000000000001, 0.9879999999999995, 0.9890000000000008, 0.9900000000000002, 0.9909999999999997, 0.9920000000000009, 0.9930000000000003, 0.9939999999999998, 0.9949999999999992, 0.9960000000000004, 0.9969999999999999, 0.9979999999999993, 0.9990000000000006, 1.0, 1.0009999999999994, 1.0020000000000007, 1.0030000000000001, 1.0039999999999996, 1.0050000000000008, 1.0060000000000002, 1.0069999999999997, 1.0079999999999991, 1.0090000000000003, 1.0099999999999998, 1.0109999999999992, 1.0120000000000005, 1.013, 1.0139999999999993, 1.0150000000000006, 1.016, 1.0169999999999995, 1.0180000000000007, 1.0190000000000001, 1.0199999999999996, 1.0210000000000008, 1.0220000000000002, 1.0229999999999997, 1.0239999999999991, 1.0250000000000004, 1.0259999999999998, 1.0269999999999992, 1.0280000000000005, 1.029, 1.0299999999999994, 1.0310000000000006, 1.032, 1.0329999999999995, 1.0340000000000007, 1.0350000000000001, 1.0359999999999996, 1.0370000000000008, 1.0380000000000003, 1.0389999999999997, 1.0399999999999991, 1.0410000000000004, 1.0419999999999998, 1.0429999999999993, 1.0440000000000005, 1.045, 1.0459999999999994, 1.0470000000000006, 1.048, 1.0489999999999995, 1.0500000000000007, 1.0510000000000002, 1.0519999999999996, 1.0530000000000008, 1.0540000000000003, 1.0549999999999997, 1.0559999999999992, 1.0570000000000004, 1.0579999999999998, 1.0589999999999993, 1.0600000000000005, 1.061, 1.0619999999999994, 1.0630000000000006, 1.064, 1.0649999999999995, 1.0660000000000007, 1.0670000000000002, 1.0679999999999996, 1.0690000000000008, 1.0700000000000003, 1.0709999999999997, 1.0719999999999992, 1.0730000000000004, 1.0739999999999998, 1.0749999999999993, 1.0760000000000005, 1.077, 1.0779999999999994, 1.0790000000000006, 1.08, 1.0809999999999995, 1.0820000000000007, 1.0830000000000002, 1.0839999999999996, 1.0850000000000009, 1.0860000000000003, 1.0869999999999997, 1.0879999999999992, 1.0890000000000004, 1.0899999999999999, 1.0909999999999993, 1.0920000000000005, 1.093, 1.0939999999999994, 1.0950000000000006, 1.096, 1.0969999999999995, 1.0980000000000008, 1.0990000000000002, 1.0999999999999996, 1.1010000000000009, 1.1020000000000003, 1.1029999999999998, 1.1039999999999992, 1.1050000000000004, 1.1059999999999999, 1.1069999999999993, 1.1080000000000005, 1.109, 1.1099999999999994, 1.1110000000000007, 1.112, 1.1129999999999995, 1.1140000000000008, 1.1150000000000002, 1.1159999999999997, 1.1170000000000009, 1.1180000000000003, 1.1189999999999998, 1.1199999999999992, 1.1210000000000004, 1.1219999999999999, 1.1229999999999993, 1.1240000000000006, 1.125, 1.1259999999999994, 1.1270000000000007, 1.1280000000000001, 1.1289999999999996, 1.1300000000000008, 1.1310000000000002, 1.1319999999999997, 1.1329999999999991, 1.1340000000000003, 1.1349999999999998, 1.1359999999999992, 1.1370000000000005, 1.138, 1.1389999999999993, 1.1400000000000006, 1.141, 1.1419999999999995, 1.1430000000000007, 1.1440000000000001, 1.1449999999999996, 1.1460000000000008, 1.1470000000000002, 1.1479999999999997, 1.1489999999999991, 1.1500000000000004, 1.1509999999999998, 1.1519999999999992, 1.1530000000000005, 1.154, 1.1549999999999994, 1.1560000000000006, 1.157, 1.1579999999999995, 1.1590000000000007, 1.1600000000000001, 1.1609999999999996, 1.1620000000000008, 1.1630000000000003, 1.1639999999999997, 1.1649999999999991, 1.1660000000000004, 1.1669999999999998, 1.1679999999999993, 1.1690000000000005, 1.17, 1.1709999999999994, 1.1720000000000006, 1.173, 1.1739999999999995, 1.1750000000000007, 1.1760000000000002, 1.1769999999999996, 1.1780000000000008, 1.1790000000000003, 1.1799999999999997, 1.1809999999999992, 1.1820000000000004, 1.1829999999999998, 1.1839999999999993, 1.1850000000000005, 1.186, 1.1869999999999994, 1.1880000000000006, 1.189, 1.1899999999999995, 1.1910000000000007, 1.1920000000000002, 1.1929999999999996, 1.1940000000000008, 1.1950000000000003, 1.1959999999999997, 1.1969999999999992, 1.1980000000000004, 1.1989999999999998, 1.1999999999999993, 1.2010000000000005, 1.202, 1.2029999999999994, 1.2040000000000006, 1.205, 1.2059999999999995, 1.2070000000000007, 1.2080000000000002, 1.2089999999999996, 1.2100000000000009, 1.2110000000000003, 1.2119999999999997, 1.2129999999999992, 1.2140000000000004, 1.2149999999999999, 1.2159999999999993, 1.2170000000000005, 1.218, 1.2189999999999994, 1.2200000000000006, 1.221, 1.2219999999999995, 1.2230000000000008, 1.2240000000000002, 1.2249999999999996, 1.2260000000000009, 1.2270000000000003, 1.2279999999999998, 1.2289999999999992, 1.2300000000000004, 1.2309999999999999, 1.2319999999999993, 1.2330000000000005, 1.234, 1.2349999999999994, 1.2360000000000007, 1.237, 1.2379999999999995, 1.2390000000000008, 1.2400000000000002, 1.2409999999999997, 1.2420000000000009, 1.2430000000000003, 1.2439999999999998, 1.2449999999999992, 1.2460000000000004, 1.2469999999999999, 1.2479999999999993, 1.2490000000000006, 1.25, 1.2509999999999994, 1.2520000000000007, 1.2530000000000001, 1.2539999999999996, 1.2550000000000008, 1.2560000000000002, 1.2569999999999997, 1.2579999999999991, 1.2590000000000003, 1.2599999999999998, 1.2609999999999992, 1.2620000000000005, 1.263, 1.2639999999999993, 1.2650000000000006, 1.266, 1.2669999999999995, 1.2680000000000007, 1.2690000000000001, 1.2699999999999996, 1.2710000000000008, 1.2720000000000002, 1.2729999999999997, 1.2739999999999991, 1.2750000000000004, 1.2759999999999998, 1.2769999999999992, 1.2780000000000005, 1.279, 1.2799999999999994, 1.2810000000000006, 1.282, 1.2829999999999995, 1.2840000000000007, 1.2850000000000001, 1.2859999999999996, 1.2870000000000008, 1.2880000000000003, 1.2889999999999997, 1.2899999999999991, 1.2910000000000004, 1.2919999999999998, 1.2929999999999993, 1.2940000000000005, 1.295, 1.2959999999999994, 1.2970000000000006, 1.298, 1.2989999999999995, 1.3000000000000007, 1.3010000000000002, 1.3019999999999996, 1.3030000000000008, 1.3040000000000003, 1.3049999999999997, 1.3059999999999992, 1.3070000000000004, 1.3079999999999998, 1.3089999999999993, 1.3100000000000005, 1.311, 1.3119999999999994, 1.3130000000000006, 1.314, 1.3149999999999995, 1.3160000000000007, 1.3170000000000002, 1.3179999999999996, 1.3190000000000008, 1.3200000000000003, 1.3209999999999997, 1.3219999999999992, 1.3230000000000004, 1.3239999999999998, 1.3249999999999993, 1.3260000000000005, 1.327, 1.3279999999999994, 1.3290000000000006, 1.33, 1.3309999999999995, 1.3320000000000007, 1.3330000000000002, 1.3339999999999996, 1.3350000000000009, 1.3360000000000003, 1.3369999999999997, 1.3379999999999992, 1.3390000000000004, 1.3399999999999999, 1.3409999999999993, 1.3420000000000005, 1.343, 1.3439999999999994, 1.3450000000000006, 1.346, 1.3469999999999995, 1.3480000000000008, 1.3490000000000002, 1.3499999999999996, 1.3510000000000009, 1.3520000000000003, 1.3529999999999998, 1.3539999999999992, 1.3550000000000004, 1.3559999999999999, 1.3569999999999993, 1.3580000000000005, 1.359, 1.3599999999999994, 1.3610000000000007, 1.362, 1.3629999999999995, 1.3640000000000008, 1.3650000000000002, 1.3659999999999997, 1.3670000000000009, 1.3680000000000003, 1.3689999999999998, 1.3699999999999992, 1.3710000000000004, 1.3719999999999999, 1.3729999999999993, 1.3740000000000006, 1.375, 1.3759999999999994, 1.3770000000000007, 1.3780000000000001, 1.3789999999999996, 1.3800000000000008, 1.3810000000000002, 1.3819999999999997, 1.3829999999999991, 1.3840000000000003, 1.3849999999999998, 1.3859999999999992, 1.3870000000000005, 1.388, 1.3889999999999993, 1.3900000000000006, 1.391, 1.3919999999999995, 1.3930000000000007, 1.3940000000000001, 1.3949999999999996, 1.3960000000000008, 1.3970000000000002, 1.3979999999999997, 1.3989999999999991, 1.4000000000000004, 1.4009999999999998, 1.4019999999999992, 1.4030000000000005, 1.404, 1.4049999999999994, 1.4060000000000006, 1.407, 1.4079999999999995, 1.4090000000000007, 1.4100000000000001, 1.4109999999999996, 1.4120000000000008, 1.4130000000000003, 1.4139999999999997, 1.4149999999999991, 1.4160000000000004, 1.4169999999999998, 1.4179999999999993, 1.4190000000000005, 1.42, 1.4209999999999994, 1.4220000000000006, 1.423, 1.4239999999999995, 1.4250000000000007, 1.4260000000000002, 1.4269999999999996, 1.4280000000000008, 1.4290000000000003, 1.4299999999999997, 1.4309999999999992, 1.4320000000000004, 1.4329999999999998, 1.4339999999999993, 1.4350000000000005, 1.436, 1.4369999999999994, 1.4380000000000006, 1.439, 1.4399999999999995, 1.4410000000000007, 1.4420000000000002, 1.4429999999999996, 1.4440000000000008, 1.4450000000000003, 1.4459999999999997, 1.4469999999999992, 1.4480000000000004, 1.4489999999999998, 1.4499999999999993, 1.4510000000000005, 1.452, 1.4529999999999994, 1.4540000000000006, 1.455, 1.4559999999999995, 1.4570000000000007, 1.4580000000000002, 1.4589999999999996, 1.4600000000000009, 1.4610000000000003, 1.4619999999999997, 1.4629999999999992, 1.4640000000000004, 1.4649999999999999, 1.4659999999999993, 1.4670000000000005, 1.468, 1.4689999999999994, 1.4700000000000006, 1.471, 1.4719999999999995, 1.4730000000000008, 1.4740000000000002, 1.4749999999999996, 1.4760000000000009, 1.4770000000000003, 1.4779999999999998, 1.4789999999999992, 1.4800000000000004, 1.4809999999999999, 1.4819999999999993, 1.4830000000000005, 1.484, 1.4849999999999994, 1.4860000000000007, 1.487, 1.4879999999999995, 1.4890000000000008, 1.4900000000000002, 1.4909999999999997, 1.4920000000000009, 1.4930000000000003, 1.4939999999999998, 1.4949999999999992, 1.4960000000000004, 1.4969999999999999, 1.4979999999999993, 1.4990000000000006, 1.5, 1.5009999999999994, 1.5020000000000007, 1.5030000000000001, 1.5039999999999996, 1.5050000000000008, 1.5060000000000002, 1.5069999999999997, 1.5079999999999991, 1.5090000000000003, 1.5099999999999998, 1.5109999999999992, 1.5120000000000005, 1.513, 1.5139999999999993, 1.5150000000000006, 1.516, 1.5169999999999995, 1.5180000000000007, 1.5190000000000001, 1.5199999999999996, 1.5210000000000008, 1.5220000000000002, 1.5229999999999997, 1.5239999999999991, 1.5250000000000004, 1.5259999999999998, 1.5269999999999992, 1.5280000000000005, 1.529, 1.5299999999999994, 1.5310000000000006, 1.532, 1.5329999999999995, 1.5340000000000007, 1.5350000000000001, 1.5359999999999996, 1.5370000000000008, 1.5380000000000003, 1.5389999999999997, 1.5399999999999991, 1.5410000000000004, 1.5419999999999998, 1.5429999999999993, 1.5440000000000005, 1.545, 1.5459999999999994, 1.5470000000000006, 1.548, 1.5489999999999995, 1.5500000000000007, 1.5510000000000002, 1.5519999999999996, 1.5530000000000008, 1.5540000000000003, 1.5549999999999997, 1.5559999999999992, 1.5570000000000004, 1.5579999999999998, 1.5589999999999993, 1.5600000000000005, 1.561, 1.5619999999999994, 1.5630000000000006, 1.564, 1.5649999999999995, 1.5660000000000007, 1.5670000000000002, 1.5679999999999996, 1.5690000000000008, 1.5700000000000003, 1.5709999999999997, 1.5719999999999992, 1.5730000000000004, 1.5739999999999998, 1.5749999999999993, 1.5760000000000005, 1.577, 1.5779999999999994, 1.5790000000000006, 1.58, 1.5809999999999995, 1.5820000000000007, 1.5830000000000002, 1.5839999999999996, 1.5850000000000009, 1.5860000000000003, 1.5869999999999997, 1.5879999999999992, 1.5890000000000004, 1.5899999999999999, 1.5909999999999993, 1.5920000000000005, 1.593, 1.5939999999999994, 1.5950000000000006, 1.596, 1.5969999999999995, 1.5980000000000008, 1.5990000000000002, 1.5999999999999996, 1.6010000000000009, 1.6020000000000003, 1.6029999999999998, 1.6039999999999992, 1.6050000000000004, 1.6059999999999999, 1.6069999999999993, 1.6080000000000005, 1.609, 1.6099999999999994, 1.6110000000000007, 1.612, 1.6129999999999995, 1.6140000000000008, 1.6150000000000002, 1.6159999999999997, 1.6170000000000009, 1.6180000000000003, 1.6189999999999998, 1.6199999999999992, 1.6210000000000004, 1.6219999999999999, 1.6229999999999993, 1.6240000000000006, 1.625, 1.6259999999999994, 1.6270000000000007, 1.6280000000000001, 1.6289999999999996, 1.6300000000000008, 1.6310000000000002, 1.6319999999999997, 1.6329999999999991, 1.6340000000000003, 1.6349999999999998, 1.6359999999999992, 1.6370000000000005, 1.638, 1.6389999999999993, 1.6400000000000006, 1.641, 1.6419999999999995, 1.6430000000000007, 1.6440000000000001, 1.6449999999999996, 1.6460000000000008, 1.6470000000000002, 1.6479999999999997, 1.6489999999999991, 1.6500000000000004, 1.6509999999999998, 1.6519999999999992, 1.6530000000000005, 1.654, 1.6549999999999994, 1.6560000000000006, 1.657, 1.6579999999999995, 1.6590000000000007, 1.6600000000000001, 1.6609999999999996, 1.6620000000000008, 1.6630000000000003, 1.6639999999999997, 1.6649999999999991, 1.6660000000000004, 1.6669999999999998, 1.6679999999999993, 1.6690000000000005, 1.67, 1.6709999999999994, 1.6720000000000006, 1.673, 1.6739999999999995, 1.6750000000000007, 1.6760000000000002, 1.6769999999999996, 1.6780000000000008, 1.6790000000000003, 1.6799999999999997, 1.6809999999999992, 1.6820000000000004, 1.6829999999999998, 1.6839999999999993, 1.6850000000000005, 1.686, 1.6869999999999994, 1.6880000000000006, 1.689, 1.6899999999999995, 1.6910000000000007, 1.6920000000000002, 1.6929999999999996, 1.6940000000000008, 1.6950000000000003, 1.6959999999999997, 1.6969999999999992, 1.6980000000000004, 1.6989999999999998, 1.6999999999999993, 1.7010000000000005, 1.702, 1.7029999999999994, 1.7040000000000006, 1.705, 1.7059999999999995, 1.7070000000000007, 1.7080000000000002, 1.7089999999999996, 1.7100000000000009, 1.7110000000000003, 1.7119999999999997, 1.7129999999999992, 1.7140000000000004, 1.7149999999999999, 1.7159999999999993, 1.7170000000000005, 1.718, 1.7189999999999994, 1.7200000000000006, 1.721, 1.7219999999999995, 1.7230000000000008, 1.7240000000000002, 1.7249999999999996, 1.7260000000000009, 1.7270000000000003, 1.7279999999999998, 1.7289999999999992, 1.7300000000000004, 1.7309999999999999, 1.7319999999999993, 1.7330000000000005, 1.734, 1.7349999999999994, 1.7360000000000007, 1.737, 1.7379999999999995, 1.7390000000000008, 1.7400000000000002, 1.7409999999999997, 1.7420000000000009, 1.7430000000000003, 1.7439999999999998, 1.7449999999999992, 1.7460000000000004, 1.7469999999999999, 1.7479999999999993, 1.7490000000000006, 1.75, 1.7509999999999994, 1.7520000000000007, 1.7530000000000001, 1.7539999999999996, 1.7550000000000008, 1.7560000000000002, 1.7569999999999997, 1.7579999999999991, 1.7590000000000003, 1.7599999999999998, 1.7609999999999992, 1.7620000000000005, 1.763, 1.7639999999999993, 1.7650000000000006, 1.766, 1.7669999999999995, 1.7680000000000007, 1.7690000000000001, 1.7699999999999996, 1.7710000000000008, 1.7720000000000002, 1.7729999999999997, 1.7739999999999991, 1.7750000000000004, 1.7759999999999998, 1.7769999999999992, 1.7780000000000005, 1.779, 1.7799999999999994, 1.7810000000000006, 1.782, 1.7829999999999995, 1.7840000000000007, 1.7850000000000001, 1.7859999999999996, 1.7870000000000008, 1.7880000000000003, 1.7889999999999997, 1.7899999999999991, 1.7910000000000004, 1.7919999999999998, 1.7929999999999993, 1.7940000000000005, 1.795, 1.7959999999999994, 1.7970000000000006, 1.798, 1.7989999999999995, 1.8000000000000007, 1.8010000000000002, 1.8019999999999996, 1.8030000000000008, 1.8040000000000003, 1.8049999999999997, 1.8059999999999992, 1.8070000000000004, 1.8079999999999998, 1.8089999999999993, 1.8100000000000005, 1.811, 1.8119999999999994, 1.8130000000000006, 1.814, 1.8149999999999995, 1.8160000000000007, 1.8170000000000002, 1.8179999999999996, 1.8190000000000008, 1.8200000000000003, 1.8209999999999997, 1.8219999999999992, 1.8230000000000004, 1.8239999999999998, 1.8249999999999993, 1.8260000000000005, 1.827, 1.8279999999999994, 1.8290000000000006, 1.83, 1.8309999999999995, 1.8320000000000007, 1.8330000000000002, 1.8339999999999996, 1.8350000000000009, 1.8360000000000003, 1.8369999999999997, 1.8379999999999992, 1.8390000000000004, 1.8399999999999999, 1.8409999999999993, 1.8420000000000005, 1.843, 1.8439999999999994, 1.8450000000000006, 1.846, 1.8469999999999995, 1.8480000000000008, 1.8490000000000002, 1.8499999999999996, 1.8510000000000009, 1.8520000000000003, 1.8529999999999998, 1.8539999999999992, 1.8550000000000004, 1.8559999999999999, 1.8569999999999993, 1.8580000000000005, 1.859, 1.8599999999999994, 1.8610000000000007, 1.862, 1.8629999999999995, 1.8640000000000008, 1.8650000000000002, 1.8659999999999997, 1.8670000000000009, 1.8680000000000003, 1.8689999999999998, 1.8699999999999992, 1.8710000000000004, 1.8719999999999999, 1.8729999999999993, 1.8740000000000006, 1.875, 1.8759999999999994, 1.8770000000000007, 1.8780000000000001, 1.8789999999999996, 1.8800000000000008, 1.8810000000000002, 1.8819999999999997, 1.8829999999999991, 1.8840000000000003, 1.8849999999999998, 1.8859999999999992, 1.8870000000000005, 1.888, 1.8889999999999993, 1.8900000000000006, 1.891, 1.8919999999999995, 1.8930000000000007, 1.8940000000000001, 1.8949999999999996, 1.8960000000000008, 1.8970000000000002, 1.8979999999999997, 1.8989999999999991, 1.9000000000000004, 1.9009999999999998, 1.9019999999999992, 1.9030000000000005, 1.904, 1.9049999999999994, 1.9060000000000006, 1.907, 1.9079999999999995, 1.9090000000000007, 1.9100000000000001, 1.9109999999999996, 1.9120000000000008, 1.9130000000000003, 1.9139999999999997, 1.9149999999999991, 1.9160000000000004, 1.9169999999999998, 1.9179999999999993, 1.9190000000000005, 1.92, 1.9209999999999994, 1.9220000000000006, 1.923, 1.9239999999999995, 1.9250000000000007, 1.9260000000000002, 1.9269999999999996, 1.9280000000000008, 1.9290000000000003, 1.9299999999999997, 1.9309999999999992, 1.9320000000000004, 1.9329999999999998, 1.9339999999999993, 1.9350000000000005, 1.936, 1.9369999999999994, 1.9380000000000006, 1.939, 1.9399999999999995, 1.9410000000000007, 1.9420000000000002, 1.9429999999999996, 1.9440000000000008, 1.9450000000000003, 1.9459999999999997, 1.9469999999999992, 1.9480000000000004, 1.9489999999999998, 1.9499999999999993, 1.9510000000000005, 1.952, 1.9529999999999994, 1.9540000000000006, 1.955, 1.9559999999999995, 1.9570000000000007, 1.9580000000000002, 1.9589999999999996, 1.9600000000000009, 1.9610000000000003, 1.9619999999999997, 1.9629999999999992, 1.9640000000000004, 1.9649999999999999, 1.9659999999999993, 1.9670000000000005, 1.968, 1.9689999999999994, 1.9700000000000006, 1.971, 1.9719999999999995, 1.9730000000000008, 1.9740000000000002, 1.9749999999999996, 1.9760000000000009, 1.9770000000000003, 1.9779999999999998, 1.9789999999999992, 1.9800000000000004, 1.9809999999999999, 1.9819999999999993, 1.9830000000000005, 1.984, 1.9849999999999994, 1.9860000000000007, 1.987, 1.9879999999999995, 1.9890000000000008, 1.9900000000000002, 1.9909999999999997, 1.9920000000000009, 1.9930000000000003, 1.9939999999999998, 1.9949999999999992, 1.9960000000000004, 1.9969999999999999, 1.9979999999999993, 1.9990000000000006, 2.0, 2.0009999999999994, 2.0020000000000007, 2.003, 2.0039999999999996, 2.005000000000001, 2.0060000000000002, 2.0069999999999997, 2.007999999999999, 2.0090000000000003, 2.01, 2.0109999999999992, 2.0120000000000005, 2.013, 2.0139999999999993, 2.0150000000000006, 2.016, 2.0169999999999995, 2.0180000000000007, 2.019, 2.0199999999999996, 2.021000000000001, 2.0220000000000002, 2.0229999999999997, 2.023999999999999, 2.0250000000000004, 2.026, 2.0269999999999992, 2.0280000000000005, 2.029, 2.0299999999999994, 2.0310000000000006, 2.032, 2.0329999999999995, 2.0340000000000007, 2.035, 2.0359999999999996, 2.037000000000001, 2.0380000000000003, 2.0389999999999997, 2.039999999999999, 2.0410000000000004, 2.042, 2.0429999999999993, 2.0440000000000005, 2.045, 2.0459999999999994, 2.0470000000000006, 2.048, 2.0489999999999995, 2.0500000000000007, 2.051, 2.0519999999999996, 2.053000000000001, 2.0540000000000003, 2.0549999999999997, 2.055999999999999, 2.0570000000000004, 2.058, 2.0589999999999993, 2.0600000000000005, 2.061, 2.0619999999999994, 2.0630000000000006, 2.064, 2.0649999999999995, 2.0660000000000007, 2.067, 2.0679999999999996, 2.069000000000001, 2.0700000000000003, 2.0709999999999997, 2.071999999999999, 2.0730000000000004, 2.074, 2.0749999999999993, 2.0760000000000005, 2.077, 2.0779999999999994, 2.0790000000000006, 2.08, 2.0809999999999995, 2.0820000000000007, 2.083, 2.0839999999999996, 2.085000000000001, 2.0860000000000003, 2.0869999999999997, 2.087999999999999, 2.0890000000000004, 2.09, 2.0909999999999993, 2.0920000000000005, 2.093, 2.0939999999999994, 2.0950000000000006, 2.096, 2.0969999999999995, 2.0980000000000008, 2.099, 2.0999999999999996, 2.101000000000001, 2.1020000000000003, 2.1029999999999998, 2.103999999999999, 2.1050000000000004, 2.106, 2.1069999999999993, 2.1080000000000005, 2.109, 2.1099999999999994, 2.1110000000000007, 2.112, 2.1129999999999995, 2.1140000000000008, 2.115, 2.1159999999999997, 2.117000000000001, 2.1180000000000003, 2.1189999999999998, 2.119999999999999, 2.1210000000000004, 2.122, 2.1229999999999993, 2.1240000000000006, 2.125, 2.1259999999999994, 2.1270000000000007, 2.128, 2.1289999999999996, 2.130000000000001, 2.1310000000000002, 2.1319999999999997, 2.132999999999999, 2.1340000000000003, 2.135, 2.1359999999999992, 2.1370000000000005, 2.138, 2.1389999999999993, 2.1400000000000006, 2.141, 2.1419999999999995, 2.1430000000000007, 2.144, 2.1449999999999996, 2.146000000000001, 2.1470000000000002, 2.1479999999999997, 2.148999999999999, 2.1500000000000004, 2.151, 2.1519999999999992, 2.1530000000000005, 2.154, 2.1549999999999994, 2.1560000000000006, 2.157, 2.1579999999999995, 2.1590000000000007, 2.16, 2.1609999999999996, 2.162000000000001, 2.1630000000000003, 2.1639999999999997, 2.164999999999999, 2.1660000000000004, 2.167, 2.1679999999999993, 2.1690000000000005, 2.17, 2.1709999999999994, 2.1720000000000006, 2.173, 2.1739999999999995, 2.1750000000000007, 2.176, 2.1769999999999996, 2.178000000000001, 2.1790000000000003, 2.1799999999999997, 2.180999999999999, 2.1820000000000004, 2.183, 2.1839999999999993, 2.1850000000000005, 2.186, 2.1869999999999994, 2.1880000000000006, 2.189, 2.1899999999999995, 2.1910000000000007, 2.192, 2.1929999999999996, 2.194000000000001, 2.1950000000000003, 2.1959999999999997, 2.196999999999999, 2.1980000000000004, 2.199, 2.1999999999999993, 2.2010000000000005, 2.202, 2.2029999999999994, 2.2040000000000006, 2.205, 2.2059999999999995, 2.2070000000000007, 2.208, 2.2089999999999996, 2.210000000000001, 2.2110000000000003, 2.2119999999999997, 2.212999999999999, 2.2140000000000004, 2.215, 2.2159999999999993, 2.2170000000000005, 2.218, 2.2189999999999994, 2.2200000000000006, 2.221, 2.2219999999999995, 2.2230000000000008, 2.224, 2.2249999999999996, 2.226000000000001, 2.2270000000000003, 2.2279999999999998, 2.228999999999999, 2.2300000000000004, 2.231, 2.2319999999999993, 2.2330000000000005, 2.234, 2.2349999999999994, 2.2360000000000007, 2.237, 2.2379999999999995, 2.2390000000000008, 2.24, 2.2409999999999997, 2.242000000000001, 2.2430000000000003, 2.2439999999999998, 2.244999999999999, 2.2460000000000004, 2.247, 2.2479999999999993, 2.2490000000000006, 2.25, 2.2509999999999994, 2.2520000000000007, 2.253, 2.2539999999999996, 2.255000000000001, 2.2560000000000002, 2.2569999999999997, 2.257999999999999, 2.2590000000000003, 2.26, 2.2609999999999992, 2.2620000000000005, 2.263, 2.2639999999999993, 2.2650000000000006, 2.266, 2.2669999999999995, 2.2680000000000007, 2.269, 2.2699999999999996, 2.271000000000001, 2.2720000000000002, 2.2729999999999997, 2.273999999999999, 2.2750000000000004, 2.276, 2.2769999999999992, 2.2780000000000005, 2.279, 2.2799999999999994, 2.2810000000000006, 2.282, 2.2829999999999995, 2.2840000000000007, 2.285, 2.2859999999999996, 2.287000000000001, 2.2880000000000003, 2.2889999999999997, 2.289999999999999, 2.2910000000000004, 2.292, 2.2929999999999993, 2.2940000000000005, 2.295, 2.2959999999999994, 2.2970000000000006, 2.298, 2.2989999999999995, 2.3000000000000007, 2.301, 2.3019999999999996, 2.303000000000001, 2.3040000000000003, 2.3049999999999997, 2.305999999999999, 2.3070000000000004, 2.308, 2.3089999999999993, 2.3100000000000005, 2.311, 2.3119999999999994, 2.3130000000000006, 2.314, 2.3149999999999995, 2.3160000000000007, 2.317, 2.3179999999999996, 2.319000000000001, 2.3200000000000003, 2.3209999999999997, 2.321999999999999, 2.3230000000000004, 2.324, 2.3249999999999993, 2.3260000000000005, 2.327, 2.3279999999999994, 2.3290000000000006, 2.33, 2.3309999999999995, 2.3320000000000007, 2.333, 2.3339999999999996, 2.335000000000001, 2.3360000000000003, 2.3369999999999997, 2.337999999999999, 2.3390000000000004, 2.34, 2.3409999999999993, 2.3420000000000005, 2.343, 2.3439999999999994, 2.3450000000000006, 2.346, 2.3469999999999995, 2.3480000000000008, 2.349, 2.3499999999999996, 2.351000000000001, 2.3520000000000003, 2.3529999999999998, 2.353999999999999, 2.3550000000000004, 2.356, 2.3569999999999993, 2.3580000000000005, 2.359, 2.3599999999999994, 2.3610000000000007, 2.362, 2.3629999999999995, 2.3640000000000008, 2.365, 2.3659999999999997, 2.367000000000001, 2.3680000000000003, 2.3689999999999998, 2.369999999999999, 2.3710000000000004, 2.372, 2.3729999999999993, 2.3740000000000006, 2.375, 2.3759999999999994, 2.3770000000000007, 2.378, 2.3789999999999996, 2.380000000000001, 2.3810000000000002, 2.3819999999999997, 2.382999999999999, 2.3840000000000003, 2.385, 2.3859999999999992, 2.3870000000000005, 2.388, 2.3889999999999993, 2.3900000000000006, 2.391, 2.3919999999999995, 2.3930000000000007, 2.394, 2.3949999999999996, 2.396000000000001, 2.3970000000000002, 2.3979999999999997, 2.398999999999999, 2.4000000000000004, 2.401, 2.4019999999999992, 2.4030000000000005, 2.404, 2.4049999999999994, 2.4060000000000006, 2.407, 2.4079999999999995, 2.4090000000000007, 2.41, 2.4109999999999996, 2.412000000000001, 2.4130000000000003, 2.4139999999999997, 2.414999999999999, 2.4160000000000004, 2.417, 2.4179999999999993, 2.4190000000000005, 2.42, 2.4209999999999994, 2.4220000000000006, 2.423, 2.4239999999999995, 2.4250000000000007, 2.426, 2.4269999999999996, 2.428000000000001, 2.4290000000000003, 2.4299999999999997, 2.430999999999999, 2.4320000000000004, 2.433, 2.4339999999999993, 2.4350000000000005, 2.436, 2.4369999999999994, 2.4380000000000006, 2.439, 2.4399999999999995, 2.4410000000000007, 2.442, 2.4429999999999996, 2.444000000000001, 2.4450000000000003, 2.4459999999999997, 2.446999999999999, 2.4480000000000004, 2.449, 2.4499999999999993, 2.4510000000000005, 2.452, 2.4529999999999994, 2.4540000000000006, 2.455, 2.4559999999999995, 2.4570000000000007, 2.458, 2.4589999999999996, 2.460000000000001, 2.4610000000000003, 2.4619999999999997, 2.462999999999999, 2.4640000000000004, 2.465, 2.4659999999999993, 2.4670000000000005, 2.468, 2.4689999999999994, 2.4700000000000006, 2.471, 2.4719999999999995, 2.4730000000000008, 2.474, 2.4749999999999996, 2.476000000000001, 2.4770000000000003, 2.4779999999999998, 2.478999999999999, 2.4800000000000004, 2.481, 2.4819999999999993, 2.4830000000000005, 2.484, 2.4849999999999994, 2.4860000000000007, 2.487, 2.4879999999999995, 2.4890000000000008, 2.49, 2.4909999999999997, 2.492000000000001, 2.4930000000000003, 2.4939999999999998, 2.494999999999999, 2.4960000000000004, 2.497, 2.4979999999999993, 2.4990000000000006, 2.5, 2.5009999999999994, 2.5020000000000007, 2.503, 2.5039999999999996, 2.505000000000001, 2.5060000000000002, 2.5069999999999997, 2.507999999999999, 2.5090000000000003, 2.51, 2.5109999999999992, 2.5120000000000005, 2.513, 2.5139999999999993, 2.5150000000000006, 2.516, 2.5169999999999995, 2.5180000000000007, 2.519, 2.5199999999999996, 2.521000000000001, 2.5220000000000002, 2.5229999999999997, 2.523999999999999, 2.5250000000000004, 2.526, 2.5269999999999992, 2.5280000000000005, 2.529, 2.5299999999999994, 2.5310000000000006, 2.532, 2.5329999999999995, 2.5340000000000007, 2.535, 2.5359999999999996, 2.537000000000001, 2.5380000000000003, 2.5389999999999997, 2.539999999999999, 2.5410000000000004, 2.542, 2.5429999999999993, 2.5440000000000005, 2.545, 2.5459999999999994, 2.5470000000000006, 2.548, 2.5489999999999995, 2.5500000000000007, 2.551, 2.5519999999999996, 2.553000000000001, 2.5540000000000003, 2.5549999999999997, 2.555999999999999, 2.5570000000000004, 2.558, 2.5589999999999993, 2.5600000000000005, 2.561, 2.5619999999999994, 2.5630000000000006, 2.564, 2.5649999999999995, 2.5660000000000007, 2.567, 2.5679999999999996, 2.569000000000001, 2.5700000000000003, 2.5709999999999997, 2.571999999999999, 2.5730000000000004, 2.574, 2.5749999999999993, 2.5760000000000005, 2.577, 2.5779999999999994, 2.5790000000000006, 2.58, 2.5809999999999995, 2.5820000000000007, 2.583, 2.5839999999999996, 2.585000000000001, 2.5860000000000003, 2.5869999999999997, 2.587999999999999, 2.5890000000000004, 2.59, 2.5909999999999993, 2.5920000000000005, 2.593, 2.5939999999999994, 2.5950000000000006, 2.596, 2.5969999999999995, 2.5980000000000008, 2.599, 2.5999999999999996, 2.601000000000001, 2.6020000000000003, 2.6029999999999998, 2.603999999999999, 2.6050000000000004, 2.606, 2.6069999999999993, 2.6080000000000005, 2.609, 2.6099999999999994, 2.6110000000000007, 2.612, 2.6129999999999995, 2.6140000000000008, 2.615, 2.6159999999999997, 2.617000000000001, 2.6180000000000003, 2.6189999999999998, 2.619999999999999, 2.6210000000000004, 2.622, 2.6229999999999993, 2.6240000000000006, 2.625, 2.6259999999999994, 2.6270000000000007, 2.628, 2.6289999999999996, 2.630000000000001, 2.6310000000000002, 2.6319999999999997, 2.632999999999999, 2.6340000000000003, 2.635, 2.6359999999999992, 2.6370000000000005, 2.638, 2.6389999999999993, 2.6400000000000006, 2.641, 2.6419999999999995, 2.6430000000000007, 2.644, 2.6449999999999996, 2.646000000000001, 2.6470000000000002, 2.6479999999999997, 2.648999999999999, 2.6500000000000004, 2.651, 2.6519999999999992, 2.6530000000000005, 2.654, 2.6549999999999994, 2.6560000000000006, 2.657, 2.6579999999999995, 2.6590000000000007, 2.66, 2.6609999999999996, 2.662000000000001, 2.6630000000000003, 2.6639999999999997, 2.664999999999999, 2.6660000000000004, 2.667, 2.6679999999999993, 2.6690000000000005, 2.67, 2.6709999999999994, 2.6720000000000006, 2.673, 2.6739999999999995, 2.6750000000000007, 2.676, 2.6769999999999996, 2.678000000000001, 2.6790000000000003, 2.6799999999999997, 2.680999999999999, 2.6820000000000004, 2.683, 2.6839999999999993, 2.6850000000000005, 2.686, 2.6869999999999994, 2.6880000000000006, 2.689, 2.6899999999999995, 2.6910000000000007, 2.692, 2.6929999999999996, 2.694000000000001, 2.6950000000000003, 2.6959999999999997, 2.696999999999999, 2.6980000000000004, 2.699, 2.6999999999999993, 2.7010000000000005, 2.702, 2.7029999999999994, 2.7040000000000006, 2.705, 2.7059999999999995, 2.7070000000000007, 2.708, 2.7089999999999996, 2.710000000000001, 2.7110000000000003, 2.7119999999999997, 2.712999999999999, 2.7140000000000004, 2.715, 2.7159999999999993, 2.7170000000000005, 2.718, 2.7189999999999994, 2.7200000000000006, 2.721, 2.7219999999999995, 2.7230000000000008, 2.724, 2.7249999999999996, 2.726000000000001, 2.7270000000000003, 2.7279999999999998, 2.728999999999999, 2.7300000000000004, 2.731, 2.7319999999999993, 2.7330000000000005, 2.734, 2.7349999999999994, 2.7360000000000007, 2.737, 2.7379999999999995, 2.7390000000000008, 2.74, 2.7409999999999997, 2.742000000000001, 2.7430000000000003, 2.7439999999999998, 2.744999999999999, 2.7460000000000004, 2.747, 2.7479999999999993, 2.7490000000000006, 2.75, 2.7509999999999994, 2.7520000000000007, 2.753, 2.7539999999999996, 2.755000000000001, 2.7560000000000002, 2.7569999999999997, 2.757999999999999, 2.7590000000000003, 2.76, 2.7609999999999992, 2.7620000000000005, 2.763, 2.7639999999999993, 2.7650000000000006, 2.766, 2.7669999999999995, 2.7680000000000007, 2.769, 2.7699999999999996, 2.771000000000001, 2.7720000000000002, 2.7729999999999997, 2.773999999999999, 2.7750000000000004, 2.776, 2.7769999999999992, 2.7780000000000005, 2.779, 2.7799999999999994, 2.7810000000000006, 2.782, 2.7829999999999995, 2.7840000000000007, 2.785, 2.7859999999999996, 2.787000000000001, 2.7880000000000003, 2.7889999999999997, 2.789999999999999, 2.7910000000000004, 2.792, 2.7929999999999993, 2.7940000000000005, 2.795, 2.7959999999999994, 2.7970000000000006, 2.798, 2.7989999999999995, 2.8000000000000007, 2.801, 2.8019999999999996, 2.803000000000001, 2.8040000000000003, 2.8049999999999997, 2.805999999999999, 2.8070000000000004, 2.808, 2.8089999999999993, 2.8100000000000005, 2.811, 2.8119999999999994, 2.8130000000000006, 2.814, 2.8149999999999995, 2.8160000000000007, 2.817, 2.8179999999999996, 2.819000000000001, 2.8200000000000003, 2.8209999999999997, 2.821999999999999, 2.8230000000000004, 2.824, 2.8249999999999993, 2.8260000000000005, 2.827, 2.8279999999999994, 2.8290000000000006, 2.83, 2.8309999999999995, 2.8320000000000007, 2.833, 2.8339999999999996, 2.835000000000001, 2.8360000000000003, 2.8369999999999997, 2.837999999999999, 2.8390000000000004, 2.84, 2.8409999999999993, 2.8420000000000005, 2.843, 2.8439999999999994, 2.8450000000000006, 2.846, 2.8469999999999995, 2.8480000000000008, 2.849, 2.8499999999999996, 2.851000000000001, 2.8520000000000003, 2.8529999999999998, 2.853999999999999, 2.8550000000000004, 2.856, 2.8569999999999993, 2.8580000000000005, 2.859, 2.8599999999999994, 2.8610000000000007, 2.862, 2.8629999999999995, 2.8640000000000008, 2.865, 2.8659999999999997, 2.867000000000001, 2.8680000000000003, 2.8689999999999998, 2.869999999999999, 2.8710000000000004, 2.872, 2.8729999999999993, 2.8740000000000006, 2.875, 2.8759999999999994, 2.8770000000000007, 2.878, 2.8789999999999996, 2.880000000000001, 2.8810000000000002, 2.8819999999999997, 2.882999999999999, 2.8840000000000003, 2.885, 2.8859999999999992, 2.8870000000000005, 2.888, 2.8889999999999993, 2.8900000000000006, 2.891, 2.8919999999999995, 2.8930000000000007, 2.894, 2.8949999999999996, 2.896000000000001, 2.8970000000000002, 2.8979999999999997, 2.898999999999999, 2.9000000000000004, 2.901, 2.9019999999999992, 2.9030000000000005, 2.904, 2.9049999999999994, 2.9060000000000006, 2.907, 2.9079999999999995, 2.9090000000000007, 2.91, 2.9109999999999996, 2.912000000000001, 2.9130000000000003, 2.9139999999999997, 2.914999999999999, 2.9160000000000004, 2.917, 2.9179999999999993, 2.9190000000000005, 2.92, 2.9209999999999994, 2.9220000000000006, 2.923, 2.9239999999999995, 2.9250000000000007, 2.926, 2.9269999999999996, 2.928000000000001, 2.9290000000000003, 2.9299999999999997, 2.930999999999999, 2.9320000000000004, 2.933, 2.9339999999999993, 2.9350000000000005, 2.936, 2.9369999999999994, 2.9380000000000006, 2.939, 2.9399999999999995, 2.9410000000000007, 2.942, 2.9429999999999996, 2.944000000000001, 2.9450000000000003, 2.9459999999999997, 2.946999999999999, 2.9480000000000004, 2.949, 2.9499999999999993, 2.9510000000000005, 2.952, 2.9529999999999994, 2.9540000000000006, 2.955, 2.9559999999999995, 2.9570000000000007, 2.958, 2.9589999999999996, 2.960000000000001, 2.9610000000000003, 2.9619999999999997, 2.962999999999999, 2.9640000000000004, 2.965, 2.9659999999999993, 2.9670000000000005, 2.968, 2.9689999999999994, 2.9700000000000006, 2.971, 2.9719999999999995, 2.9730000000000008, 2.974, 2.9749999999999996, 2.976000000000001, 2.9770000000000003, 2.9779999999999998, 2.978999999999999, 2.9800000000000004, 2.981, 2.9819999999999993, 2.9830000000000005, 2.984, 2.9849999999999994, 2.9860000000000007, 2.987, 2.9879999999999995, 2.9890000000000008, 2.99, 2.9909999999999997, 2.992000000000001, 2.9930000000000003, 2.9939999999999998, 2.994999999999999, 2.9960000000000004, 2.997, 2.9979999999999993, 2.9990000000000006, 3.0, 3.0009999999999994, 3.0020000000000007, 3.003, 3.0039999999999996, 3.005000000000001, 3.0060000000000002, 3.0069999999999997, 3.007999999999999, 3.0090000000000003, 3.01, 3.0109999999999992, 3.0120000000000005, 3.013, 3.0139999999999993, 3.0150000000000006, 3.016, 3.0169999999999995, 3.0180000000000007, 3.019, 3.0199999999999996, 3.021000000000001, 3.0220000000000002, 3.0229999999999997, 3.023999999999999, 3.0250000000000004, 3.026, 3.0269999999999992, 3.0280000000000005, 3.029, 3.0299999999999994, 3.0310000000000006, 3.032, 3.0329999999999995, 3.0340000000000007, 3.035, 3.0359999999999996, 3.037000000000001, 3.0380000000000003, 3.0389999999999997, 3.039999999999999, 3.0410000000000004, 3.042, 3.0429999999999993, 3.0440000000000005, 3.045, 3.0459999999999994, 3.0470000000000006, 3.048, 3.0489999999999995, 3.0500000000000007, 3.051, 3.0519999999999996, 3.053000000000001, 3.0540000000000003, 3.0549999999999997, 3.055999999999999, 3.0570000000000004, 3.058, 3.0589999999999993, 3.0600000000000005, 3.061, 3.0619999999999994, 3.0630000000000006, 3.064, 3.0649999999999995, 3.0660000000000007, 3.067, 3.0679999999999996, 3.069000000000001, 3.0700000000000003, 3.0709999999999997, 3.071999999999999, 3.0730000000000004, 3.074, 3.0749999999999993, 3.0760000000000005, 3.077, 3.0779999999999994, 3.0790000000000006, 3.08, 3.0809999999999995, 3.0820000000000007, 3.083, 3.0839999999999996, 3.085000000000001, 3.0860000000000003, 3.0869999999999997, 3.087999999999999, 3.0890000000000004, 3.09, 3.0909999999999993, 3.0920000000000005, 3.093, 3.0939999999999994, 3.0950000000000006, 3.096, 3.0969999999999995, 3.0980000000000008, 3.099, 3.0999999999999996, 3.101000000000001, 3.1020000000000003, 3.1029999999999998, 3.103999999999999, 3.1050000000000004, 3.106, 3.1069999999999993, 3.1080000000000005, 3.109, 3.1099999999999994, 3.1110000000000007, 3.112, 3.1129999999999995, 3.1140000000000008, 3.115, 3.1159999999999997, 3.117000000000001, 3.1180000000000003, 3.1189999999999998, 3.119999999999999, 3.1210000000000004, 3.122, 3.1229999999999993, 3.1240000000000006, 3.125, 3.1259999999999994, 3.1270000000000007, 3.128, 3.1289999999999996, 3.130000000000001, 3.1310000000000002, 3.1319999999999997, 3.132999999999999, 3.1340000000000003, 3.135, 3.1359999999999992, 3.1370000000000005, 3.138, 3.1389999999999993, 3.1400000000000006, 3.141, 3.1419999999999995, 3.1430000000000007, 3.144, 3.1449999999999996, 3.146000000000001, 3.1470000000000002, 3.1479999999999997, 3.148999999999999, 3.1500000000000004, 3.151, 3.1519999999999992, 3.1530000000000005, 3.154, 3.1549999999999994, 3.1560000000000006, 3.157, 3.1579999999999995, 3.1590000000000007, 3.16, 3.1609999999999996, 3.162000000000001, 3.1630000000000003, 3.1639999999999997, 3.164999999999999, 3.1660000000000004, 3.167, 3.1679999999999993, 3.1690000000000005, 3.17, 3.1709999999999994, 3.1720000000000006, 3.173, 3.1739999999999995, 3.1750000000000007, 3.176, 3.1769999999999996, 3.178000000000001, 3.1790000000000003, 3.1799999999999997, 3.180999999999999, 3.1820000000000004, 3.183, 3.1839999999999993, 3.1850000000000005, 3.186, 3.1869999999999994, 3.1880000000000006, 3.189, 3.1899999999999995, 3.1910000000000007, 3.192, 3.1929999999999996, 3.194000000000001, 3.1950000000000003, 3.1959999999999997, 3.196999999999999, 3.1980000000000004, 3.199, 3.1999999999999993, 3.2010000000000005, 3.202, 3.2029999999999994, 3.2040000000000006, 3.205, 3.2059999999999995, 3.2070000000000007, 3.208, 3.2089999999999996, 3.210000000000001, 3.2110000000000003, 3.2119999999999997, 3.212999999999999, 3.2140000000000004, 3.215, 3.2159999999999993, 3.2170000000000005, 3.218, 3.2189999999999994, 3.2200000000000006, 3.221, 3.2219999999999995, 3.2230000000000008, 3.224, 3.2249999999999996, 3.226000000000001, 3.2270000000000003, 3.2279999999999998, 3.228999999999999, 3.2300000000000004, 3.231, 3.2319999999999993, 3.2330000000000005, 3.234, 3.2349999999999994, 3.2360000000000007, 3.237, 3.2379999999999995, 3.2390000000000008, 3.24, 3.2409999999999997, 3.242000000000001, 3.2430000000000003, 3.2439999999999998, 3.244999999999999, 3.2460000000000004, 3.247, 3.2479999999999993, 3.2490000000000006, 3.25, 3.2509999999999994, 3.2520000000000007, 3.253, 3.2539999999999996, 3.255000000000001, 3.2560000000000002, 3.2569999999999997, 3.257999999999999, 3.2590000000000003, 3.26, 3.2609999999999992, 3.2620000000000005, 3.263, 3.2639999999999993, 3.2650000000000006, 3.266, 3.2669999999999995, 3.2680000000000007, 3.269, 3.2699999999999996, 3.271000000000001, 3.2720000000000002, 3.2729999999999997, 3.273999999999999, 3.2750000000000004, 3.276, 3.2769999999999992, 3.2780000000000005, 3.279, 3.2799999999999994, 3.2810000000000006, 3.282, 3.2829999999999995, 3.2840000000000007, 3.285, 3.2859999999999996, 3.287000000000001, 3.2880000000000003, 3.2889999999999997, 3.289999999999999, 3.2910000000000004, 3.292, 3.2929999999999993, 3.2940000000000005, 3.295, 3.2959999999999994, 3.2970000000000006, 3.298, 3.2989999999999995, 3.3000000000000007, 3.301, 3.3019999999999996, 3.303000000000001, 3.3040000000000003, 3.3049999999999997, 3.305999999999999, 3.3070000000000004, 3.308, 3.3089999999999993, 3.3100000000000005, 3.311, 3.3119999999999994, 3.3130000000000006, 3.314, 3.3149999999999995, 3.3160000000000007, 3.317, 3.3179999999999996, 3.319000000000001, 3.3200000000000003, 3.3209999999999997, 3.321999999999999, 3.3230000000000004, 3.324, 3.3249999999999993, 3.3260000000000005, 3.327, 3.3279999999999994, 3.3290000000000006, 3.33, 3.3309999999999995, 3.3320000000000007, 3.333, 3.3339999999999996, 3.335000000000001, 3.3360000000000003, 3.3369999999999997, 3.337999999999999, 3.3390000000000004, 3.34, 3.3409999999999993, 3.3420000000000005, 3.343, 3.3439999999999994, 3.3450000000000006, 3.346, 3.3469999999999995, 3.3480000000000008, 3.349, 3.3499999999999996, 3.351000000000001, 3.3520000000000003, 3.3529999999999998, 3.353999999999999, 3.3550000000000004, 3.356, 3.3569999999999993, 3.3580000000000005, 3.359, 3.3599999999999994, 3.3610000000000007, 3.362, 3.3629999999999995, 3.3640000000000008, 3.365, 3.3659999999999997, 3.367000000000001, 3.3680000000000003, 3.3689999999999998, 3.369999999999999, 3.3710000000000004, 3.372, 3.3729999999999993, 3.3740000000000006, 3.375, 3.3759999999999994, 3.3770000000000007, 3.378, 3.3789999999999996, 3.380000000000001, 3.3810000000000002, 3.3819999999999997, 3.382999999999999, 3.3840000000000003, 3.385, 3.3859999999999992, 3.3870000000000005, 3.388, 3.3889999999999993, 3.3900000000000006, 3.391, 3.3919999999999995, 3.3930000000000007, 3.394, 3.3949999999999996, 3.396000000000001, 3.3970000000000002, 3.3979999999999997, 3.398999999999999, 3.4000000000000004, 3.401, 3.4019999999999992, 3.4030000000000005, 3.404, 3.4049999999999994, 3.4060000000000006, 3.407, 3.4079999999999995, 3.4090000000000007, 3.41, 3.4109999999999996, 3.412000000000001, 3.4130000000000003, 3.4139999999999997, 3.414999999999999, 3.4160000000000004, 3.417, 3.4179999999999993, 3.4190000000000005, 3.42, 3.4209999999999994, 3.4220000000000006, 3.423, 3.4239999999999995, 3.4250000000000007, 3.426, 3.4269999999999996, 3.428000000000001, 3.4290000000000003, 3.4299999999999997, 3.430999999999999, 3.4320000000000004, 3.433, 3.4339999999999993, 3.4350000000000005, 3.436, 3.4369999999999994, 3.4380000000000006, 3.439, 3.4399999999999995, 3.4410000000000007, 3.442, 3.4429999999999996, 3.444000000000001, 3.4450000000000003, 3.4459999999999997, 3.446999999999999, 3.4480000000000004, 3.449, 3.4499999999999993, 3.4510000000000005, 3.452, 3.4529999999999994, 3.4540000000000006, 3.455, 3.4559999999999995, 3.4570000000000007, 3.458, 3.4589999999999996, 3.460000000000001, 3.4610000000000003, 3.4619999999999997, 3.462999999999999, 3.4640000000000004, 3.465, 3.4659999999999993, 3.4670000000000005, 3.468, 3.4689999999999994, 3.4700000000000006, 3.471, 3.4719999999999995, 3.4730000000000008, 3.474, 3.4749999999999996, 3.476000000000001, 3.4770000000000003, 3.4779999999999998, 3.478999999999999, 3.4800000000000004, 3.481, 3.4819999999999993, 3.4830000000000005, 3.484, 3.4849999999999994, 3.4860000000000007, 3.487, 3.4879999999999995, 3.4890000000000008, 3.49, 3.4909999999999997, 3.492000000000001, 3.4930000000000003, 3.4939999999999998, 3.494999999999999, 3.4960000000000004, 3.497, 3.4979999999999993, 3.4990000000000006, 3.5, 3.5009999999999994, 3.5020000000000007, 3.503, 3.5039999999999996, 3.505000000000001, 3.5060000000000002, 3.5069999999999997, 3.507999999999999, 3.5090000000000003, 3.51, 3.5109999999999992, 3.5120000000000005, 3.513, 3.5139999999999993, 3.5150000000000006, 3.516, 3.5169999999999995, 3.5180000000000007, 3.519, 3.5199999999999996, 3.521000000000001, 3.5220000000000002, 3.5229999999999997, 3.523999999999999, 3.5250000000000004, 3.526, 3.5269999999999992, 3.5280000000000005, 3.529, 3.5299999999999994, 3.5310000000000006, 3.532, 3.5329999999999995, 3.5340000000000007, 3.535, 3.5359999999999996, 3.537000000000001, 3.5380000000000003, 3.5389999999999997, 3.539999999999999, 3.5410000000000004, 3.542, 3.5429999999999993, 3.5440000000000005, 3.545, 3.5459999999999994, 3.5470000000000006, 3.548, 3.5489999999999995, 3.5500000000000007, 3.551, 3.5519999999999996, 3.553000000000001, 3.5540000000000003, 3.5549999999999997, 3.555999999999999, 3.5570000000000004, 3.558, 3.5589999999999993, 3.5600000000000005, 3.561, 3.5619999999999994, 3.5630000000000006, 3.564, 3.5649999999999995, 3.5660000000000007, 3.567, 3.5679999999999996, 3.569000000000001, 3.5700000000000003, 3.5709999999999997, 3.571999999999999, 3.5730000000000004, 3.574, 3.5749999999999993, 3.5760000000000005, 3.577, 3.5779999999999994, 3.5790000000000006, 3.58, 3.5809999999999995, 3.5820000000000007, 3.583, 3.5839999999999996, 3.585000000000001, 3.5860000000000003, 3.5869999999999997, 3.587999999999999, 3.5890000000000004, 3.59, 3.5909999999999993, 3.5920000000000005, 3.593, 3.5939999999999994, 3.5950000000000006, 3.596, 3.5969999999999995, 3.5980000000000008, 3.599, 3.5999999999999996, 3.601000000000001, 3.6020000000000003, 3.6029999999999998, 3.603999999999999, 3.6050000000000004, 3.606, 3.6069999999999993, 3.6080000000000005, 3.609, 3.6099999999999994, 3.6110000000000007, 3.612, 3.6129999999999995, 3.6140000000000008, 3.615, 3.6159999999999997, 3.617000000000001, 3.6180000000000003, 3.6189999999999998, 3.619999999999999, 3.6210000000000004, 3.622, 3.6229999999999993, 3.6240000000000006, 3.625, 3.6259999999999994, 3.6270000000000007, 3.628, 3.6289999999999996, 3.630000000000001, 3.6310000000000002, 3.6319999999999997, 3.632999999999999, 3.6340000000000003, 3.635, 3.6359999999999992, 3.6370000000000005, 3.638, 3.6389999999999993, 3.6400000000000006, 3.641, 3.6419999999999995, 3.6430000000000007, 3.644, 3.6449999999999996, 3.646000000000001, 3.6470000000000002, 3.6479999999999997, 3.648999999999999, 3.6500000000000004, 3.651, 3.6519999999999992, 3.6530000000000005, 3.654, 3.6549999999999994, 3.6560000000000006, 3.657, 3.6579999999999995, 3.6590000000000007, 3.66, 3.6609999999999996, 3.662000000000001, 3.6630000000000003, 3.6639999999999997, 3.664999999999999, 3.6660000000000004, 3.667, 3.6679999999999993, 3.6690000000000005, 3.67, 3.6709999999999994, 3.6720000000000006, 3.673, 3.6739999999999995, 3.6750000000000007, 3.676, 3.6769999999999996, 3.678000000000001, 3.6790000000000003, 3.6799999999999997, 3.680999999999999, 3.6820000000000004, 3.683, 3.6839999999999993, 3.6850000000000005, 3.686, 3.6869999999999994, 3.6880000000000006, 3.689, 3.6899999999999995, 3.6910000000000007, 3.692, 3.6929999999999996, 3.694000000000001, 3.6950000000000003, 3.6959999999999997, 3.696999999999999, 3.6980000000000004, 3.699, 3.6999999999999993, 3.7010000000000005, 3.702, 3.7029999999999994, 3.7040000000000006, 3.705, 3.7059999999999995, 3.7070000000000007, 3.708, 3.7089999999999996, 3.710000000000001, 3.7110000000000003, 3.7119999999999997, 3.712999999999999, 3.7140000000000004, 3.715, 3.7159999999999993, 3.7170000000000005, 3.718, 3.7189999999999994, 3.7200000000000006, 3.721, 3.7219999999999995, 3.7230000000000008, 3.724, 3.7249999999999996, 3.726000000000001, 3.7270000000000003, 3.7279999999999998, 3.728999999999999, 3.7300000000000004, 3.731, 3.7319999999999993, 3.7330000000000005, 3.734, 3.7349999999999994, 3.7360000000000007, 3.737, 3.7379999999999995, 3.7390000000000008, 3.74, 3.7409999999999997, 3.742000000000001, 3.7430000000000003, 3.7439999999999998, 3.744999999999999, 3.7460000000000004, 3.747, 3.7479999999999993, 3.7490000000000006, 3.75, 3.7509999999999994, 3.7520000000000007, 3.753, 3.7539999999999996, 3.755000000000001, 3.7560000000000002, 3.7569999999999997, 3.757999999999999, 3.7590000000000003, 3.76, 3.7609999999999992, 3.7620000000000005, 3.763, 3.7639999999999993, 3.7650000000000006, 3.766, 3.7669999999999995, 3.7680000000000007, 3.769, 3.7699999999999996, 3.771000000000001, 3.7720000000000002, 3.7729999999999997, 3.773999999999999, 3.7750000000000004, 3.776, 3.7769999999999992, 3.7780000000000005, 3.779, 3.7799999999999994, 3.7810000000000006, 3.782, 3.7829999999999995, 3.7840000000000007, 3.785, 3.7859999999999996, 3.787000000000001, 3.7880000000000003, 3.7889999999999997, 3.789999999999999, 3.7910000000000004, 3.792, 3.7929999999999993, 3.7940000000000005, 3.795, 3.7959999999999994, 3.7970000000000006, 3.798, 3.7989999999999995, 3.8000000000000007, 3.801, 3.8019999999999996, 3.803000000000001, 3.8040000000000003, 3.8049999999999997, 3.805999999999999, 3.8070000000000004, 3.808, 3.8089999999999993, 3.8100000000000005, 3.811, 3.8119999999999994, 3.8130000000000006, 3.814, 3.8149999999999995, 3.8160000000000007, 3.817, 3.8179999999999996, 3.819000000000001, 3.8200000000000003, 3.8209999999999997, 3.821999999999999, 3.8230000000000004, 3.824, 3.8249999999999993, 3.8260000000000005, 3.827, 3.8279999999999994, 3.8290000000000006, 3.83, 3.8309999999999995, 3.8320000000000007, 3.833, 3.8339999999999996, 3.835000000000001, 3.8360000000000003, 3.8369999999999997, 3.837999999999999, 3.8390000000000004, 3.84, 3.8409999999999993, 3.8420000000000005, 3.843, 3.8439999999999994, 3.8450000000000006, 3.846, 3.8469999999999995, 3.8480000000000008, 3.849, 3.8499999999999996, 3.851000000000001, 3.8520000000000003, 3.8529999999999998, 3.853999999999999, 3.8550000000000004, 3.856, 3.8569999999999993, 3.8580000000000005, 3.859, 3.8599999999999994, 3.8610000000000007, 3.862, 3.8629999999999995, 3.8640000000000008, 3.865, 3.8659999999999997, 3.867000000000001, 3.8680000000000003, 3.8689999999999998, 3.869999999999999, 3.8710000000000004, 3.872, 3.8729999999999993, 3.8740000000000006, 3.875, 3.8759999999999994, 3.8770000000000007, 3.878, 3.8789999999999996, 3.880000000000001, 3.8810000000000002, 3.8819999999999997, 3.882999999999999, 3.8840000000000003, 3.885, 3.8859999999999992, 3.8870000000000005, 3.888, 3.8889999999999993, 3.8900000000000006, 3.891, 3.8919999999999995, 3.8930000000000007, 3.894, 3.8949999999999996, 3.896000000000001, 3.8970000000000002, 3.8979999999999997, 3.898999999999999, 3.9000000000000004, 3.901, 3.9019999999999992, 3.9030000000000005, 3.904, 3.9049999999999994, 3.9060000000000006, 3.907, 3.9079999999999995, 3.9090000000000007, 3.91, 3.9109999999999996, 3.912000000000001, 3.9130000000000003, 3.9139999999999997, 3.914999999999999, 3.9160000000000004, 3.917, 3.9179999999999993, 3.9190000000000005, 3.92, 3.9209999999999994, 3.9220000000000006, 3.923, 3.9239999999999995, 3.9250000000000007, 3.926, 3.9269999999999996, 3.928000000000001, 3.9290000000000003, 3.9299999999999997, 3.930999999999999, 3.9320000000000004, 3.933, 3.9339999999999993, 3.9350000000000005, 3.936, 3.9369999999999994, 3.9380000000000006, 3.939, 3.9399999999999995, 3.9410000000000007, 3.942, 3.9429999999999996, 3.944000000000001, 3.9450000000000003, 3.9459999999999997, 3.946999999999999, 3.9480000000000004, 3.949, 3.9499999999999993, 3.9510000000000005, 3.952, 3.9529999999999994, 3.9540000000000006, 3.955, 3.9559999999999995, 3.9570000000000007, 3.958, 3.9589999999999996, 3.960000000000001, 3.9610000000000003, 3.9619999999999997, 3.962999999999999, 3.9640000000000004, 3.965, 3.9659999999999993, 3.9670000000000005, 3.968, 3.9689999999999994, 3.9700000000000006, 3.971, 3.9719999999999995, 3.9730000000000008, 3.974, 3.9749999999999996, 3.976000000000001, 3.9770000000000003, 3.9779999999999998, 3.978999999999999, 3.9800000000000004, 3.981, 3.9819999999999993, 3.9830000000000005, 3.984, 3.9849999999999994, 3.9860000000000007, 3.987, 3.9879999999999995, 3.9890000000000008, 3.99, 3.9909999999999997, 3.992000000000001, 3.9930000000000003, 3.9939999999999998, 3.994999999999999, 3.9960000000000004, 3.997, 3.9979999999999993, 3.9990000000000006, 4.0, 4.0009999999999994, 4.002000000000001, 4.003, 4.004, 4.005000000000001, 4.006, 4.007, 4.007999999999999, 4.009, 4.01, 4.010999999999999, 4.0120000000000005, 4.013, 4.013999999999999, 4.015000000000001, 4.016, 4.0169999999999995, 4.018000000000001, 4.019, 4.02, 4.021000000000001, 4.022, 4.023, 4.023999999999999, 4.025, 4.026, 4.026999999999999, 4.0280000000000005, 4.029, 4.029999999999999, 4.031000000000001, 4.032, 4.0329999999999995, 4.034000000000001, 4.035, 4.036, 4.037000000000001, 4.038, 4.039, 4.039999999999999, 4.041, 4.042, 4.042999999999999, 4.0440000000000005, 4.045, 4.045999999999999, 4.047000000000001, 4.048, 4.0489999999999995, 4.050000000000001, 4.051, 4.052, 4.053000000000001, 4.054, 4.055, 4.055999999999999, 4.057, 4.058, 4.058999999999999, 4.0600000000000005, 4.061, 4.061999999999999, 4.063000000000001, 4.064, 4.0649999999999995, 4.066000000000001, 4.067, 4.068, 4.069000000000001, 4.07, 4.071, 4.071999999999999, 4.073, 4.074, 4.074999999999999, 4.0760000000000005, 4.077, 4.077999999999999, 4.079000000000001, 4.08, 4.0809999999999995, 4.082000000000001, 4.083, 4.084, 4.085000000000001, 4.086, 4.087, 4.087999999999999, 4.089, 4.09, 4.090999999999999, 4.0920000000000005, 4.093, 4.093999999999999, 4.095000000000001, 4.096, 4.0969999999999995, 4.098000000000001, 4.099, 4.1, 4.101000000000001, 4.102, 4.103, 4.103999999999999, 4.105, 4.106, 4.106999999999999, 4.1080000000000005, 4.109, 4.109999999999999, 4.111000000000001, 4.112, 4.1129999999999995, 4.114000000000001, 4.115, 4.116, 4.117000000000001, 4.118, 4.119, 4.119999999999999, 4.121, 4.122, 4.122999999999999, 4.1240000000000006, 4.125, 4.1259999999999994, 4.127000000000001, 4.128, 4.129, 4.130000000000001, 4.131, 4.132, 4.132999999999999, 4.134, 4.135, 4.135999999999999, 4.1370000000000005, 4.138, 4.138999999999999, 4.140000000000001, 4.141, 4.1419999999999995, 4.143000000000001, 4.144, 4.145, 4.146000000000001, 4.147, 4.148, 4.148999999999999, 4.15, 4.151, 4.151999999999999, 4.1530000000000005, 4.154, 4.154999999999999, 4.156000000000001, 4.157, 4.1579999999999995, 4.159000000000001, 4.16, 4.161, 4.162000000000001, 4.163, 4.164, 4.164999999999999, 4.166, 4.167, 4.167999999999999, 4.1690000000000005, 4.17, 4.170999999999999, 4.172000000000001, 4.173, 4.1739999999999995, 4.175000000000001, 4.176, 4.177, 4.178000000000001, 4.179, 4.18, 4.180999999999999, 4.182, 4.183, 4.183999999999999, 4.1850000000000005, 4.186, 4.186999999999999, 4.188000000000001, 4.189, 4.1899999999999995, 4.191000000000001, 4.192, 4.193, 4.194000000000001, 4.195, 4.196, 4.196999999999999, 4.198, 4.199, 4.199999999999999, 4.2010000000000005, 4.202, 4.202999999999999, 4.204000000000001, 4.205, 4.2059999999999995, 4.207000000000001, 4.208, 4.209, 4.210000000000001, 4.211, 4.212, 4.212999999999999, 4.214, 4.215, 4.215999999999999, 4.2170000000000005, 4.218, 4.218999999999999, 4.220000000000001, 4.221, 4.2219999999999995, 4.223000000000001, 4.224, 4.225, 4.226000000000001, 4.227, 4.228, 4.228999999999999, 4.23, 4.231, 4.231999999999999, 4.2330000000000005, 4.234, 4.234999999999999, 4.236000000000001, 4.237, 4.2379999999999995, 4.239000000000001, 4.24, 4.241, 4.242000000000001, 4.243, 4.244, 4.244999999999999, 4.246, 4.247, 4.247999999999999, 4.2490000000000006, 4.25, 4.2509999999999994, 4.252000000000001, 4.253, 4.254, 4.255000000000001, 4.256, 4.257, 4.257999999999999, 4.259, 4.26, 4.260999999999999, 4.2620000000000005, 4.263, 4.263999999999999, 4.265000000000001, 4.266, 4.2669999999999995, 4.268000000000001, 4.269, 4.27, 4.271000000000001, 4.272, 4.273, 4.273999999999999, 4.275, 4.276, 4.276999999999999, 4.2780000000000005, 4.279, 4.279999999999999, 4.281000000000001, 4.282, 4.2829999999999995, 4.284000000000001, 4.285, 4.286, 4.287000000000001, 4.288, 4.289, 4.289999999999999, 4.291, 4.292, 4.292999999999999, 4.2940000000000005, 4.295, 4.295999999999999, 4.297000000000001, 4.298, 4.2989999999999995, 4.300000000000001, 4.301, 4.302, 4.303000000000001, 4.304, 4.305, 4.305999999999999, 4.307, 4.308, 4.308999999999999, 4.3100000000000005, 4.311, 4.311999999999999, 4.313000000000001, 4.314, 4.3149999999999995, 4.316000000000001, 4.317, 4.318, 4.319000000000001, 4.32, 4.321, 4.321999999999999, 4.323, 4.324, 4.324999999999999, 4.3260000000000005, 4.327, 4.327999999999999, 4.329000000000001, 4.33, 4.3309999999999995, 4.332000000000001, 4.333, 4.334, 4.335000000000001, 4.336, 4.337, 4.337999999999999, 4.339, 4.34, 4.340999999999999, 4.3420000000000005, 4.343, 4.343999999999999, 4.345000000000001, 4.346, 4.3469999999999995, 4.348000000000001, 4.349, 4.35, 4.351000000000001, 4.352, 4.353, 4.353999999999999, 4.355, 4.356, 4.356999999999999, 4.3580000000000005, 4.359, 4.359999999999999, 4.361000000000001, 4.362, 4.3629999999999995, 4.364000000000001, 4.365, 4.366, 4.367000000000001, 4.368, 4.369, 4.369999999999999, 4.371, 4.372, 4.372999999999999, 4.3740000000000006, 4.375, 4.3759999999999994, 4.377000000000001, 4.378, 4.379, 4.380000000000001, 4.381, 4.382, 4.382999999999999, 4.384, 4.385, 4.385999999999999, 4.3870000000000005, 4.388, 4.388999999999999, 4.390000000000001, 4.391, 4.3919999999999995, 4.393000000000001, 4.394, 4.395, 4.396000000000001, 4.397, 4.398, 4.398999999999999, 4.4, 4.401, 4.401999999999999, 4.4030000000000005, 4.404, 4.404999999999999, 4.406000000000001, 4.407, 4.4079999999999995, 4.409000000000001, 4.41, 4.411, 4.412000000000001, 4.413, 4.414, 4.414999999999999, 4.416, 4.417, 4.417999999999999, 4.4190000000000005, 4.42, 4.420999999999999, 4.422000000000001, 4.423, 4.4239999999999995, 4.425000000000001, 4.426, 4.427, 4.428000000000001, 4.429, 4.43, 4.430999999999999, 4.432, 4.433, 4.433999999999999, 4.4350000000000005, 4.436, 4.436999999999999, 4.438000000000001, 4.439, 4.4399999999999995, 4.441000000000001, 4.442, 4.443, 4.444000000000001, 4.445, 4.446, 4.446999999999999, 4.448, 4.449, 4.449999999999999, 4.4510000000000005, 4.452, 4.452999999999999, 4.454000000000001, 4.455, 4.4559999999999995, 4.457000000000001, 4.458, 4.459, 4.460000000000001, 4.461, 4.462, 4.462999999999999, 4.464, 4.465, 4.465999999999999, 4.4670000000000005, 4.468, 4.468999999999999, 4.470000000000001, 4.471, 4.4719999999999995, 4.473000000000001, 4.474, 4.475, 4.476000000000001, 4.477, 4.478, 4.478999999999999, 4.48, 4.481, 4.481999999999999, 4.4830000000000005, 4.484, 4.484999999999999, 4.486000000000001, 4.487, 4.4879999999999995, 4.489000000000001, 4.49, 4.491, 4.492000000000001, 4.493, 4.494, 4.494999999999999, 4.496, 4.497, 4.497999999999999, 4.4990000000000006, 4.5, 4.5009999999999994, 4.502000000000001, 4.503, 4.504, 4.505000000000001, 4.506, 4.507, 4.507999999999999, 4.509, 4.51, 4.510999999999999, 4.5120000000000005, 4.513, 4.513999999999999, 4.515000000000001, 4.516, 4.5169999999999995, 4.518000000000001, 4.519, 4.52, 4.521000000000001, 4.522, 4.523, 4.523999999999999, 4.525, 4.526, 4.526999999999999, 4.5280000000000005, 4.529, 4.529999999999999, 4.531000000000001, 4.532, 4.5329999999999995, 4.534000000000001, 4.535, 4.536, 4.537000000000001, 4.538, 4.539, 4.539999999999999, 4.541, 4.542, 4.542999999999999, 4.5440000000000005, 4.545, 4.545999999999999, 4.547000000000001, 4.548, 4.5489999999999995, 4.550000000000001, 4.551, 4.552, 4.553000000000001, 4.554, 4.555, 4.555999999999999, 4.557, 4.558, 4.558999999999999, 4.5600000000000005, 4.561, 4.561999999999999, 4.563000000000001, 4.564, 4.5649999999999995, 4.566000000000001, 4.567, 4.568, 4.569000000000001, 4.57, 4.571, 4.571999999999999, 4.573, 4.574, 4.574999999999999, 4.5760000000000005, 4.577, 4.577999999999999, 4.579000000000001, 4.58, 4.5809999999999995, 4.582000000000001, 4.583, 4.584, 4.585000000000001, 4.586, 4.587, 4.587999999999999, 4.589, 4.59, 4.590999999999999, 4.5920000000000005, 4.593, 4.593999999999999, 4.595000000000001, 4.596, 4.5969999999999995, 4.598000000000001, 4.599, 4.6, 4.601000000000001, 4.602, 4.603, 4.603999999999999, 4.605, 4.606, 4.606999999999999, 4.6080000000000005, 4.609, 4.609999999999999, 4.611000000000001, 4.612, 4.6129999999999995, 4.614000000000001, 4.615, 4.616, 4.617000000000001, 4.618, 4.619, 4.619999999999999, 4.621, 4.622, 4.622999999999999, 4.6240000000000006, 4.625, 4.6259999999999994, 4.627000000000001, 4.628, 4.629, 4.630000000000001, 4.631, 4.632, 4.632999999999999, 4.634, 4.635, 4.635999999999999, 4.6370000000000005, 4.638, 4.638999999999999, 4.640000000000001, 4.641, 4.6419999999999995, 4.643000000000001, 4.644, 4.645, 4.646000000000001, 4.647, 4.648, 4.648999999999999, 4.65, 4.651, 4.651999999999999, 4.6530000000000005, 4.654, 4.654999999999999, 4.656000000000001, 4.657, 4.6579999999999995, 4.659000000000001, 4.66, 4.661, 4.662000000000001, 4.663, 4.664, 4.664999999999999, 4.666, 4.667, 4.667999999999999, 4.6690000000000005, 4.67, 4.670999999999999, 4.672000000000001, 4.673, 4.6739999999999995, 4.675000000000001, 4.676, 4.677, 4.678000000000001, 4.679, 4.68, 4.680999999999999, 4.682, 4.683, 4.683999999999999, 4.6850000000000005, 4.686, 4.686999999999999, 4.688000000000001, 4.689, 4.6899999999999995, 4.691000000000001, 4.692, 4.693, 4.694000000000001, 4.695, 4.696, 4.696999999999999, 4.698, 4.699, 4.699999999999999, 4.7010000000000005, 4.702, 4.702999999999999, 4.704000000000001, 4.705, 4.7059999999999995, 4.707000000000001, 4.708, 4.709, 4.710000000000001, 4.711, 4.712, 4.712999999999999, 4.714, 4.715, 4.715999999999999, 4.7170000000000005, 4.718, 4.718999999999999, 4.720000000000001, 4.721, 4.7219999999999995, 4.723000000000001, 4.724, 4.725, 4.726000000000001, 4.727, 4.728, 4.728999999999999, 4.73, 4.731, 4.731999999999999, 4.7330000000000005, 4.734, 4.734999999999999, 4.736000000000001, 4.737, 4.7379999999999995, 4.739000000000001, 4.74, 4.741, 4.742000000000001, 4.743, 4.744, 4.744999999999999, 4.746, 4.747, 4.747999999999999, 4.7490000000000006, 4.75, 4.7509999999999994, 4.752000000000001, 4.753, 4.754, 4.755000000000001, 4.756, 4.757, 4.757999999999999, 4.759, 4.76, 4.760999999999999, 4.7620000000000005, 4.763, 4.763999999999999, 4.765000000000001, 4.766, 4.7669999999999995, 4.768000000000001, 4.769, 4.77, 4.771000000000001, 4.772, 4.773, 4.773999999999999, 4.775, 4.776, 4.776999999999999, 4.7780000000000005, 4.779, 4.779999999999999, 4.781000000000001, 4.782, 4.7829999999999995, 4.784000000000001, 4.785, 4.786, 4.787000000000001, 4.788, 4.789, 4.789999999999999, 4.791, 4.792, 4.792999999999999, 4.7940000000000005, 4.795, 4.795999999999999, 4.797000000000001, 4.798, 4.7989999999999995, 4.800000000000001, 4.801, 4.802, 4.803000000000001, 4.804, 4.805, 4.805999999999999, 4.807, 4.808, 4.808999999999999, 4.8100000000000005, 4.811, 4.811999999999999, 4.813000000000001, 4.814, 4.8149999999999995, 4.816000000000001, 4.817, 4.818, 4.819000000000001, 4.82, 4.821, 4.821999999999999, 4.823, 4.824, 4.824999999999999, 4.8260000000000005, 4.827, 4.827999999999999, 4.829000000000001, 4.83, 4.8309999999999995, 4.832000000000001, 4.833, 4.834, 4.835000000000001, 4.836, 4.837, 4.837999999999999, 4.839, 4.84, 4.840999999999999, 4.8420000000000005, 4.843, 4.843999999999999, 4.845000000000001, 4.846, 4.8469999999999995, 4.848000000000001, 4.849, 4.85, 4.851000000000001, 4.852, 4.853, 4.853999999999999, 4.855, 4.856, 4.856999999999999, 4.8580000000000005, 4.859, 4.859999999999999, 4.861000000000001, 4.862, 4.8629999999999995, 4.864000000000001, 4.865, 4.866, 4.867000000000001, 4.868, 4.869, 4.869999999999999, 4.871, 4.872, 4.872999999999999, 4.8740000000000006, 4.875, 4.8759999999999994, 4.877000000000001, 4.878, 4.879, 4.880000000000001, 4.881, 4.882, 4.882999999999999, 4.884, 4.885, 4.885999999999999, 4.8870000000000005, 4.888, 4.888999999999999, 4.890000000000001, 4.891, 4.8919999999999995, 4.893000000000001, 4.894, 4.895, 4.896000000000001, 4.897, 4.898, 4.898999999999999, 4.9, 4.901, 4.901999999999999, 4.9030000000000005, 4.904, 4.904999999999999, 4.906000000000001, 4.907, 4.9079999999999995, 4.909000000000001, 4.91, 4.911, 4.912000000000001, 4.913, 4.914, 4.914999999999999, 4.916, 4.917, 4.917999999999999, 4.9190000000000005, 4.92, 4.920999999999999, 4.922000000000001, 4.923, 4.9239999999999995, 4.925000000000001, 4.926, 4.927, 4.928000000000001, 4.929, 4.93, 4.930999999999999, 4.932, 4.933, 4.933999999999999, 4.9350000000000005, 4.936, 4.936999999999999, 4.938000000000001, 4.939, 4.9399999999999995, 4.941000000000001, 4.942, 4.943, 4.944000000000001, 4.945, 4.946, 4.946999999999999, 4.948, 4.949, 4.949999999999999, 4.9510000000000005, 4.952, 4.952999999999999, 4.954000000000001, 4.955, 4.9559999999999995, 4.957000000000001, 4.958, 4.959, 4.960000000000001, 4.961, 4.962, 4.962999999999999, 4.964, 4.965, 4.965999999999999, 4.9670000000000005, 4.968, 4.968999999999999, 4.970000000000001, 4.971, 4.9719999999999995, 4.973000000000001, 4.974, 4.975, 4.976000000000001, 4.977, 4.978, 4.978999999999999, 4.98, 4.981, 4.981999999999999, 4.9830000000000005, 4.984, 4.984999999999999, 4.986000000000001, 4.987, 4.9879999999999995, 4.989000000000001, 4.99, 4.991, 4.992000000000001, 4.993, 4.994, 4.994999999999999, 4.996, 4.997, 4.997999999999999, 4.9990000000000006, 5.0, 5.0009999999999994, 5.002000000000001, 5.003, 5.004, 5.005000000000001, 5.006, 5.007, 5.007999999999999, 5.009, 5.01, 5.010999999999999, 5.0120000000000005, 5.013, 5.013999999999999, 5.015000000000001, 5.016, 5.0169999999999995, 5.018000000000001, 5.019, 5.02, 5.021000000000001, 5.022, 5.023, 5.023999999999999, 5.025, 5.026, 5.026999999999999, 5.0280000000000005, 5.029, 5.029999999999999, 5.031000000000001, 5.032, 5.0329999999999995, 5.034000000000001, 5.035, 5.036, 5.037000000000001, 5.038, 5.039, 5.039999999999999, 5.041, 5.042, 5.042999999999999, 5.0440000000000005, 5.045, 5.045999999999999, 5.047000000000001, 5.048, 5.0489999999999995, 5.050000000000001, 5.051, 5.052, 5.053000000000001, 5.054, 5.055, 5.055999999999999, 5.057, 5.058, 5.058999999999999, 5.0600000000000005, 5.061, 5.061999999999999, 5.063000000000001, 5.064, 5.0649999999999995, 5.066000000000001, 5.067, 5.068, 5.069000000000001, 5.07, 5.071, 5.071999999999999, 5.073, 5.074, 5.074999999999999, 5.0760000000000005, 5.077, 5.077999999999999, 5.079000000000001, 5.08, 5.0809999999999995, 5.082000000000001, 5.083, 5.084, 5.085000000000001, 5.086, 5.087, 5.087999999999999, 5.089, 5.09, 5.090999999999999, 5.0920000000000005, 5.093, 5.093999999999999, 5.095000000000001, 5.096, 5.0969999999999995, 5.098000000000001, 5.099, 5.1, 5.101000000000001, 5.102, 5.103, 5.103999999999999, 5.105, 5.106, 5.106999999999999, 5.1080000000000005, 5.109, 5.109999999999999, 5.111000000000001, 5.112, 5.1129999999999995, 5.114000000000001, 5.115, 5.116, 5.117000000000001, 5.118, 5.119, 5.119999999999999, 5.121, 5.122, 5.122999999999999, 5.1240000000000006, 5.125, 5.1259999999999994, 5.127000000000001, 5.128, 5.129, 5.130000000000001, 5.131, 5.132, 5.132999999999999, 5.134, 5.135, 5.135999999999999, 5.1370000000000005, 5.138, 5.138999999999999, 5.140000000000001, 5.141, 5.1419999999999995, 5.143000000000001, 5.144, 5.145, 5.146000000000001, 5.147, 5.148, 5.148999999999999, 5.15, 5.151, 5.151999999999999, 5.1530000000000005, 5.154, 5.154999999999999, 5.156000000000001, 5.157, 5.1579999999999995, 5.159000000000001, 5.16, 5.161, 5.162000000000001, 5.163, 5.164, 5.164999999999999, 5.166, 5.167, 5.167999999999999, 5.1690000000000005, 5.17, 5.170999999999999, 5.172000000000001, 5.173, 5.1739999999999995, 5.175000000000001, 5.176, 5.177, 5.178000000000001, 5.179, 5.18, 5.180999999999999, 5.182, 5.183, 5.183999999999999, 5.1850000000000005, 5.186, 5.186999999999999, 5.188000000000001, 5.189, 5.1899999999999995, 5.191000000000001, 5.192, 5.193, 5.194000000000001, 5.195, 5.196, 5.196999999999999, 5.198, 5.199, 5.199999999999999, 5.2010000000000005, 5.202, 5.202999999999999, 5.204000000000001, 5.205, 5.2059999999999995, 5.207000000000001, 5.208, 5.209, 5.210000000000001, 5.211, 5.212, 5.212999999999999, 5.214, 5.215, 5.215999999999999, 5.2170000000000005, 5.218, 5.218999999999999, 5.220000000000001, 5.221, 5.2219999999999995, 5.223000000000001, 5.224, 5.225, 5.226000000000001, 5.227, 5.228, 5.228999999999999, 5.23, 5.231, 5.231999999999999, 5.2330000000000005, 5.234, 5.234999999999999, 5.236000000000001, 5.237, 5.2379999999999995, 5.239000000000001, 5.24, 5.241, 5.242000000000001, 5.243, 5.244, 5.244999999999999, 5.246, 5.247, 5.247999999999999, 5.2490000000000006, 5.25, 5.2509999999999994, 5.252000000000001, 5.253, 5.254, 5.255000000000001, 5.256, 5.257, 5.257999999999999, 5.259, 5.26, 5.260999999999999, 5.2620000000000005, 5.263, 5.263999999999999, 5.265000000000001, 5.266, 5.2669999999999995, 5.268000000000001, 5.269, 5.27, 5.271000000000001, 5.272, 5.273, 5.273999999999999, 5.275, 5.276, 5.276999999999999, 5.2780000000000005, 5.279, 5.279999999999999, 5.281000000000001, 5.282, 5.2829999999999995, 5.284000000000001, 5.285, 5.286, 5.287000000000001, 5.288, 5.289, 5.289999999999999, 5.291, 5.292, 5.292999999999999, 5.2940000000000005, 5.295, 5.295999999999999, 5.297000000000001, 5.298, 5.2989999999999995, 5.300000000000001, 5.301, 5.302, 5.303000000000001, 5.304, 5.305, 5.305999999999999, 5.307, 5.308, 5.308999999999999, 5.3100000000000005, 5.311, 5.311999999999999, 5.313000000000001, 5.314, 5.3149999999999995, 5.316000000000001, 5.317, 5.318, 5.319000000000001, 5.32, 5.321, 5.321999999999999, 5.323, 5.324, 5.324999999999999, 5.3260000000000005, 5.327, 5.327999999999999, 5.329000000000001, 5.33, 5.3309999999999995, 5.332000000000001, 5.333, 5.334, 5.335000000000001, 5.336, 5.337, 5.337999999999999, 5.339, 5.34, 5.340999999999999, 5.3420000000000005, 5.343, 5.343999999999999, 5.345000000000001, 5.346, 5.3469999999999995, 5.348000000000001, 5.349, 5.35, 5.351000000000001, 5.352, 5.353, 5.353999999999999, 5.355, 5.356, 5.356999999999999, 5.3580000000000005, 5.359, 5.359999999999999, 5.361000000000001, 5.362, 5.3629999999999995, 5.364000000000001, 5.365, 5.366, 5.367000000000001, 5.368, 5.369, 5.369999999999999, 5.371, 5.372, 5.372999999999999, 5.3740000000000006, 5.375, 5.3759999999999994, 5.377000000000001, 5.378, 5.379, 5.380000000000001, 5.381, 5.382, 5.382999999999999, 5.384, 5.385, 5.385999999999999, 5.3870000000000005, 5.388, 5.388999999999999, 5.390000000000001, 5.391, 5.3919999999999995, 5.393000000000001, 5.394, 5.395, 5.396000000000001, 5.397, 5.398, 5.398999999999999, 5.4, 5.401, 5.401999999999999, 5.4030000000000005, 5.404, 5.404999999999999, 5.406000000000001, 5.407, 5.4079999999999995, 5.409000000000001, 5.41, 5.411, 5.412000000000001, 5.413, 5.414, 5.414999999999999, 5.416, 5.417, 5.417999999999999, 5.4190000000000005, 5.42, 5.420999999999999, 5.422000000000001, 5.423, 5.4239999999999995, 5.425000000000001, 5.426, 5.427, 5.428000000000001, 5.429, 5.43, 5.430999999999999, 5.432, 5.433, 5.433999999999999, 5.4350000000000005, 5.436, 5.436999999999999, 5.438000000000001, 5.439, 5.4399999999999995, 5.441000000000001, 5.442, 5.443, 5.444000000000001, 5.445, 5.446, 5.446999999999999, 5.448, 5.449, 5.449999999999999, 5.4510000000000005, 5.452, 5.452999999999999, 5.454000000000001, 5.455, 5.4559999999999995, 5.457000000000001, 5.458, 5.459, 5.460000000000001, 5.461, 5.462, 5.462999999999999, 5.464, 5.465, 5.465999999999999, 5.4670000000000005, 5.468, 5.468999999999999, 5.470000000000001, 5.471, 5.4719999999999995, 5.473000000000001, 5.474, 5.475, 5.476000000000001, 5.477, 5.478, 5.478999999999999, 5.48, 5.481, 5.481999999999999, 5.4830000000000005, 5.484, 5.484999999999999, 5.486000000000001, 5.487, 5.4879999999999995, 5.489000000000001, 5.49, 5.491, 5.492000000000001, 5.493, 5.494, 5.494999999999999, 5.496, 5.497, 5.497999999999999, 5.4990000000000006, 5.5, 5.5009999999999994, 5.502000000000001, 5.503, 5.504, 5.505000000000001, 5.506, 5.507, 5.507999999999999, 5.509, 5.51, 5.510999999999999, 5.5120000000000005, 5.513, 5.513999999999999, 5.515000000000001, 5.516, 5.5169999999999995, 5.518000000000001, 5.519, 5.52, 5.521000000000001, 5.522, 5.523, 5.523999999999999, 5.525, 5.526, 5.526999999999999, 5.5280000000000005, 5.529, 5.529999999999999, 5.531000000000001, 5.532, 5.5329999999999995, 5.534000000000001, 5.535, 5.536, 5.537000000000001, 5.538, 5.539, 5.539999999999999, 5.541, 5.542, 5.542999999999999, 5.5440000000000005, 5.545, 5.545999999999999, 5.547000000000001, 5.548, 5.5489999999999995, 5.550000000000001, 5.551, 5.552, 5.553000000000001, 5.554, 5.555, 5.555999999999999, 5.557, 5.558, 5.558999999999999, 5.5600000000000005, 5.561, 5.561999999999999, 5.563000000000001, 5.564, 5.5649999999999995, 5.566000000000001, 5.567, 5.568, 5.569000000000001, 5.57, 5.571, 5.571999999999999, 5.573, 5.574, 5.574999999999999, 5.5760000000000005, 5.577, 5.577999999999999, 5.579000000000001, 5.58, 5.5809999999999995, 5.582000000000001, 5.583, 5.584, 5.585000000000001, 5.586, 5.587, 5.587999999999999, 5.589, 5.59, 5.590999999999999, 5.5920000000000005, 5.593, 5.593999999999999, 5.595000000000001, 5.596, 5.5969999999999995, 5.598000000000001, 5.599, 5.6, 5.601000000000001, 5.602, 5.603, 5.603999999999999, 5.605, 5.606, 5.606999999999999, 5.6080000000000005, 5.609, 5.609999999999999, 5.611000000000001, 5.612, 5.6129999999999995, 5.614000000000001, 5.615, 5.616, 5.617000000000001, 5.618, 5.619, 5.619999999999999, 5.621, 5.622, 5.622999999999999, 5.6240000000000006, 5.625, 5.6259999999999994, 5.627000000000001, 5.628, 5.629, 5.630000000000001, 5.631, 5.632, 5.632999999999999, 5.634, 5.635, 5.635999999999999, 5.6370000000000005, 5.638, 5.638999999999999, 5.640000000000001, 5.641, 5.6419999999999995, 5.643000000000001, 5.644, 5.645, 5.646000000000001, 5.647, 5.648, 5.648999999999999, 5.65, 5.651, 5.651999999999999, 5.6530000000000005, 5.654, 5.654999999999999, 5.656000000000001, 5.657, 5.6579999999999995, 5.659000000000001, 5.66, 5.661, 5.662000000000001, 5.663, 5.664, 5.664999999999999, 5.666, 5.667, 5.667999999999999, 5.6690000000000005, 5.67, 5.670999999999999, 5.672000000000001, 5.673, 5.6739999999999995, 5.675000000000001, 5.676, 5.677, 5.678000000000001, 5.679, 5.68, 5.680999999999999, 5.682, 5.683, 5.683999999999999, 5.6850000000000005, 5.686, 5.686999999999999, 5.688000000000001, 5.689, 5.6899999999999995, 5.691000000000001, 5.692, 5.693, 5.694000000000001, 5.695, 5.696, 5.696999999999999, 5.698, 5.699, 5.699999999999999, 5.7010000000000005, 5.702, 5.702999999999999, 5.704000000000001, 5.705, 5.7059999999999995, 5.707000000000001, 5.708, 5.709, 5.710000000000001, 5.711, 5.712, 5.712999999999999, 5.714, 5.715, 5.715999999999999, 5.7170000000000005, 5.718, 5.718999999999999, 5.720000000000001, 5.721, 5.7219999999999995, 5.723000000000001, 5.724, 5.725, 5.726000000000001, 5.727, 5.728, 5.728999999999999, 5.73, 5.731, 5.731999999999999, 5.7330000000000005, 5.734, 5.734999999999999, 5.736000000000001, 5.737, 5.7379999999999995, 5.739000000000001, 5.74, 5.741, 5.742000000000001, 5.743, 5.744, 5.744999999999999, 5.746, 5.747, 5.747999999999999, 5.7490000000000006, 5.75, 5.7509999999999994, 5.752000000000001, 5.753, 5.754, 5.755000000000001, 5.756, 5.757, 5.757999999999999, 5.759, 5.76, 5.760999999999999, 5.7620000000000005, 5.763, 5.763999999999999, 5.765000000000001, 5.766, 5.7669999999999995, 5.768000000000001, 5.769, 5.77, 5.771000000000001, 5.772, 5.773, 5.773999999999999, 5.775, 5.776, 5.776999999999999, 5.7780000000000005, 5.779, 5.779999999999999, 5.781000000000001, 5.782, 5.7829999999999995, 5.784000000000001, 5.785, 5.786, 5.787000000000001, 5.788, 5.789, 5.789999999999999, 5.791, 5.792, 5.792999999999999, 5.7940000000000005, 5.795, 5.795999999999999, 5.797000000000001, 5.798, 5.7989999999999995, 5.800000000000001, 5.801, 5.802, 5.803000000000001, 5.804, 5.805, 5.805999999999999, 5.807, 5.808, 5.808999999999999, 5.8100000000000005, 5.811, 5.811999999999999, 5.813000000000001, 5.814, 5.8149999999999995, 5.816000000000001, 5.817, 5.818, 5.819000000000001, 5.82, 5.821, 5.821999999999999, 5.823, 5.824, 5.824999999999999, 5.8260000000000005, 5.827, 5.827999999999999, 5.829000000000001, 5.83, 5.8309999999999995, 5.832000000000001, 5.833, 5.834, 5.835000000000001, 5.836, 5.837, 5.837999999999999, 5.839, 5.84, 5.840999999999999, 5.8420000000000005, 5.843, 5.843999999999999, 5.845000000000001, 5.846, 5.8469999999999995, 5.848000000000001, 5.849, 5.85, 5.851000000000001, 5.852, 5.853, 5.853999999999999, 5.855, 5.856, 5.856999999999999, 5.8580000000000005, 5.859, 5.859999999999999, 5.861000000000001, 5.862, 5.8629999999999995, 5.864000000000001, 5.865, 5.866, 5.867000000000001, 5.868, 5.869, 5.869999999999999, 5.871, 5.872, 5.872999999999999, 5.8740000000000006, 5.875, 5.8759999999999994, 5.877000000000001, 5.878, 5.879, 5.880000000000001, 5.881, 5.882, 5.882999999999999, 5.884, 5.885, 5.885999999999999, 5.8870000000000005, 5.888, 5.888999999999999, 5.890000000000001, 5.891, 5.8919999999999995, 5.893000000000001, 5.894, 5.895, 5.896000000000001, 5.897, 5.898, 5.898999999999999, 5.9, 5.901, 5.901999999999999, 5.9030000000000005, 5.904, 5.904999999999999, 5.906000000000001, 5.907, 5.9079999999999995, 5.909000000000001, 5.91, 5.911, 5.912000000000001, 5.913, 5.914, 5.914999999999999, 5.916, 5.917, 5.917999999999999, 5.9190000000000005, 5.92, 5.920999999999999, 5.922000000000001, 5.923, 5.9239999999999995, 5.925000000000001, 5.926, 5.927, 5.928000000000001, 5.929, 5.93, 5.930999999999999, 5.932, 5.933, 5.933999999999999, 5.9350000000000005, 5.936, 5.936999999999999, 5.938000000000001, 5.939, 5.9399999999999995, 5.941000000000001, 5.942, 5.943, 5.944000000000001, 5.945, 5.946, 5.946999999999999, 5.948, 5.949, 5.949999999999999, 5.9510000000000005, 5.952, 5.952999999999999, 5.954000000000001, 5.955, 5.9559999999999995, 5.957000000000001, 5.958, 5.959, 5.960000000000001, 5.961, 5.962, 5.962999999999999, 5.964, 5.965, 5.965999999999999, 5.9670000000000005, 5.968, 5.968999999999999, 5.970000000000001, 5.971, 5.9719999999999995, 5.973000000000001, 5.974, 5.975, 5.976000000000001, 5.977, 5.978, 5.978999999999999, 5.98, 5.981, 5.981999999999999, 5.9830000000000005, 5.984, 5.984999999999999, 5.986000000000001, 5.987, 5.9879999999999995, 5.989000000000001, 5.99, 5.991, 5.992000000000001, 5.993, 5.994, 5.994999999999999, 5.996, 5.997, 5.997999999999999, 5.9990000000000006, 6.0, 6.001000000000001, 6.001999999999999, 6.003, 6.004000000000001, 6.004999999999999, 6.006, 6.0070000000000014, 6.007999999999999, 6.009, 6.010000000000002, 6.010999999999999, 6.0120000000000005, 6.013000000000002, 6.013999999999999, 6.015000000000001, 6.015999999999998, 6.0169999999999995, 6.018000000000001, 6.018999999999998, 6.02, 6.021000000000001, 6.0219999999999985, 6.023, 6.024000000000001, 6.024999999999999, 6.026, 6.027000000000001, 6.027999999999999, 6.029, 6.030000000000001, 6.030999999999999, 6.032, 6.033000000000001, 6.033999999999999, 6.035, 6.036000000000001, 6.036999999999999, 6.038, 6.0390000000000015, 6.039999999999999, 6.041, 6.042000000000002, 6.042999999999999, 6.0440000000000005, 6.045000000000002, 6.045999999999999, 6.047000000000001, 6.047999999999998, 6.0489999999999995, 6.050000000000001, 6.050999999999998, 6.052, 6.053000000000001, 6.0539999999999985, 6.055, 6.056000000000001, 6.056999999999999, 6.058, 6.059000000000001, 6.059999999999999, 6.061, 6.062000000000001, 6.062999999999999, 6.064, 6.065000000000001, 6.065999999999999, 6.067, 6.068000000000001, 6.068999999999999, 6.07, 6.0710000000000015, 6.071999999999999, 6.073, 6.074000000000002, 6.074999999999999, 6.0760000000000005, 6.077000000000002, 6.077999999999999, 6.079000000000001, 6.079999999999998, 6.0809999999999995, 6.082000000000001, 6.082999999999998, 6.084, 6.085000000000001, 6.0859999999999985, 6.087, 6.088000000000001, 6.088999999999999, 6.09, 6.091000000000001, 6.091999999999999, 6.093, 6.094000000000001, 6.094999999999999, 6.096, 6.097000000000001, 6.097999999999999, 6.099, 6.100000000000001, 6.100999999999999, 6.102, 6.1030000000000015, 6.103999999999999, 6.105, 6.106000000000002, 6.106999999999999, 6.1080000000000005, 6.109000000000002, 6.109999999999999, 6.111000000000001, 6.111999999999998, 6.1129999999999995, 6.114000000000001, 6.114999999999998, 6.116, 6.117000000000001, 6.1179999999999986, 6.119, 6.120000000000001, 6.120999999999999, 6.122, 6.123000000000001, 6.123999999999999, 6.125, 6.126000000000001, 6.126999999999999, 6.128, 6.129000000000001, 6.129999999999999, 6.131, 6.1320000000000014, 6.132999999999999, 6.134, 6.135000000000002, 6.135999999999999, 6.1370000000000005, 6.138000000000002, 6.138999999999999, 6.140000000000001, 6.140999999999998, 6.1419999999999995, 6.143000000000001, 6.143999999999998, 6.145, 6.146000000000001, 6.1469999999999985, 6.148, 6.149000000000001, 6.149999999999999, 6.151, 6.152000000000001, 6.152999999999999, 6.154, 6.155000000000001, 6.155999999999999, 6.157, 6.158000000000001, 6.158999999999999, 6.16, 6.161000000000001, 6.161999999999999, 6.163, 6.1640000000000015, 6.164999999999999, 6.166, 6.167000000000002, 6.167999999999999, 6.1690000000000005, 6.170000000000002, 6.170999999999999, 6.172000000000001, 6.172999999999998, 6.1739999999999995, 6.175000000000001, 6.175999999999998, 6.177, 6.178000000000001, 6.1789999999999985, 6.18, 6.181000000000001, 6.181999999999999, 6.183, 6.184000000000001, 6.184999999999999, 6.186, 6.187000000000001, 6.187999999999999, 6.189, 6.190000000000001, 6.190999999999999, 6.192, 6.193000000000001, 6.193999999999999, 6.195, 6.1960000000000015, 6.196999999999999, 6.198, 6.199000000000002, 6.199999999999999, 6.2010000000000005, 6.202000000000002, 6.202999999999999, 6.204000000000001, 6.204999999999998, 6.2059999999999995, 6.207000000000001, 6.207999999999998, 6.209, 6.210000000000001, 6.2109999999999985, 6.212, 6.213000000000001, 6.213999999999999, 6.215, 6.216000000000001, 6.216999999999999, 6.218, 6.219000000000001, 6.219999999999999, 6.221, 6.222000000000001, 6.222999999999999, 6.224, 6.225000000000001, 6.225999999999999, 6.227, 6.2280000000000015, 6.228999999999999, 6.23, 6.231000000000002, 6.231999999999999, 6.2330000000000005, 6.234000000000002, 6.234999999999999, 6.236000000000001, 6.236999999999998, 6.2379999999999995, 6.239000000000001, 6.239999999999998, 6.241, 6.242000000000001, 6.2429999999999986, 6.244, 6.245000000000001, 6.245999999999999, 6.247, 6.248000000000001, 6.248999999999999, 6.25, 6.251000000000001, 6.251999999999999, 6.253, 6.254000000000001, 6.254999999999999, 6.256, 6.2570000000000014, 6.257999999999999, 6.259, 6.260000000000002, 6.260999999999999, 6.2620000000000005, 6.263000000000002, 6.263999999999999, 6.265000000000001, 6.265999999999998, 6.2669999999999995, 6.268000000000001, 6.268999999999998, 6.27, 6.271000000000001, 6.2719999999999985, 6.273, 6.274000000000001, 6.274999999999999, 6.276, 6.277000000000001, 6.277999999999999, 6.279, 6.280000000000001, 6.280999999999999, 6.282, 6.283000000000001, 6.283999999999999, 6.285, 6.286000000000001, 6.286999999999999, 6.288, 6.2890000000000015, 6.289999999999999, 6.291, 6.292000000000002, 6.292999999999999, 6.2940000000000005, 6.295000000000002, 6.295999999999999, 6.297000000000001, 6.297999999999998, 6.2989999999999995, 6.300000000000001, 6.300999999999998, 6.302, 6.303000000000001, 6.3039999999999985, 6.305, 6.306000000000001, 6.306999999999999, 6.308, 6.309000000000001, 6.309999999999999, 6.311, 6.312000000000001, 6.312999999999999, 6.314, 6.315000000000001, 6.315999999999999, 6.317, 6.318000000000001, 6.318999999999999, 6.32, 6.3210000000000015, 6.321999999999999, 6.323, 6.324000000000002, 6.324999999999999, 6.3260000000000005, 6.327000000000002, 6.327999999999999, 6.329000000000001, 6.329999999999998, 6.3309999999999995, 6.332000000000001, 6.332999999999998, 6.334, 6.335000000000001, 6.3359999999999985, 6.337, 6.338000000000001, 6.338999999999999, 6.34, 6.341000000000001, 6.341999999999999, 6.343, 6.344000000000001, 6.344999999999999, 6.346, 6.347000000000001, 6.347999999999999, 6.349, 6.350000000000001, 6.350999999999999, 6.352, 6.3530000000000015, 6.353999999999999, 6.355, 6.356000000000002, 6.356999999999999, 6.3580000000000005, 6.359000000000002, 6.359999999999999, 6.361000000000001, 6.361999999999998, 6.3629999999999995, 6.364000000000001, 6.364999999999998, 6.366, 6.367000000000001, 6.3679999999999986, 6.369, 6.370000000000001, 6.370999999999999, 6.372, 6.373000000000001, 6.373999999999999, 6.375, 6.376000000000001, 6.376999999999999, 6.378, 6.379000000000001, 6.379999999999999, 6.381, 6.3820000000000014, 6.382999999999999, 6.384, 6.385000000000002, 6.385999999999999, 6.3870000000000005, 6.388000000000002, 6.388999999999999, 6.390000000000001, 6.390999999999998, 6.3919999999999995, 6.393000000000001, 6.393999999999998, 6.395, 6.396000000000001, 6.3969999999999985, 6.398, 6.399000000000001, 6.399999999999999, 6.401, 6.402000000000001, 6.402999999999999, 6.404, 6.405000000000001, 6.405999999999999, 6.407, 6.408000000000001, 6.408999999999999, 6.41, 6.411000000000001, 6.411999999999999, 6.413, 6.4140000000000015, 6.414999999999999, 6.416, 6.417000000000002, 6.417999999999999, 6.4190000000000005, 6.420000000000002, 6.420999999999999, 6.422000000000001, 6.422999999999998, 6.4239999999999995, 6.425000000000001, 6.425999999999998, 6.427, 6.428000000000001, 6.4289999999999985, 6.43, 6.431000000000001, 6.431999999999999, 6.433, 6.434000000000001, 6.434999999999999, 6.436, 6.437000000000001, 6.437999999999999, 6.439, 6.440000000000001, 6.440999999999999, 6.442, 6.443000000000001, 6.443999999999999, 6.445, 6.4460000000000015, 6.446999999999999, 6.448, 6.449000000000002, 6.449999999999999, 6.4510000000000005, 6.452000000000002, 6.452999999999999, 6.454000000000001, 6.454999999999998, 6.4559999999999995, 6.457000000000001, 6.457999999999998, 6.459, 6.460000000000001, 6.4609999999999985, 6.462, 6.463000000000001, 6.463999999999999, 6.465, 6.466000000000001, 6.466999999999999, 6.468, 6.469000000000001, 6.469999999999999, 6.471, 6.472000000000001, 6.472999999999999, 6.474, 6.475000000000001, 6.475999999999999, 6.477, 6.4780000000000015, 6.478999999999999, 6.48, 6.481000000000002, 6.481999999999999, 6.4830000000000005, 6.484000000000002, 6.484999999999999, 6.486000000000001, 6.486999999999998, 6.4879999999999995, 6.489000000000001, 6.489999999999998, 6.491, 6.492000000000001, 6.4929999999999986, 6.494, 6.495000000000001, 6.495999999999999, 6.497, 6.498000000000001, 6.498999999999999, 6.5, 6.501000000000001, 6.501999999999999, 6.503, 6.504000000000001, 6.504999999999999, 6.506, 6.5070000000000014, 6.507999999999999, 6.509, 6.510000000000002, 6.510999999999999, 6.5120000000000005, 6.513000000000002, 6.513999999999999, 6.515000000000001, 6.515999999999998, 6.5169999999999995, 6.518000000000001, 6.518999999999998, 6.52, 6.521000000000001, 6.5219999999999985, 6.523, 6.524000000000001, 6.524999999999999, 6.526, 6.527000000000001, 6.527999999999999, 6.529, 6.530000000000001, 6.530999999999999, 6.532, 6.533000000000001, 6.533999999999999, 6.535, 6.536000000000001, 6.536999999999999, 6.538, 6.5390000000000015, 6.539999999999999, 6.541, 6.542000000000002, 6.542999999999999, 6.5440000000000005, 6.545000000000002, 6.545999999999999, 6.547000000000001, 6.547999999999998, 6.5489999999999995, 6.550000000000001, 6.550999999999998, 6.552, 6.553000000000001, 6.5539999999999985, 6.555, 6.556000000000001, 6.556999999999999, 6.558, 6.559000000000001, 6.559999999999999, 6.561, 6.562000000000001, 6.562999999999999, 6.564, 6.565000000000001, 6.565999999999999, 6.567, 6.568000000000001, 6.568999999999999, 6.57, 6.5710000000000015, 6.571999999999999, 6.573, 6.574000000000002, 6.574999999999999, 6.5760000000000005, 6.577000000000002, 6.577999999999999, 6.579000000000001, 6.579999999999998, 6.5809999999999995, 6.582000000000001, 6.582999999999998, 6.584, 6.585000000000001, 6.5859999999999985, 6.587, 6.588000000000001, 6.588999999999999, 6.59, 6.591000000000001, 6.591999999999999, 6.593, 6.594000000000001, 6.594999999999999, 6.596, 6.597000000000001, 6.597999999999999, 6.599, 6.600000000000001, 6.600999999999999, 6.602, 6.6030000000000015, 6.603999999999999, 6.605, 6.606000000000002, 6.606999999999999, 6.6080000000000005, 6.609000000000002, 6.609999999999999, 6.611000000000001, 6.611999999999998, 6.6129999999999995, 6.614000000000001, 6.614999999999998, 6.616, 6.617000000000001, 6.6179999999999986, 6.619, 6.620000000000001, 6.620999999999999, 6.622, 6.623000000000001, 6.623999999999999, 6.625, 6.626000000000001, 6.626999999999999, 6.628, 6.629000000000001, 6.629999999999999, 6.631, 6.6320000000000014, 6.632999999999999, 6.634, 6.635000000000002, 6.635999999999999, 6.6370000000000005, 6.638000000000002, 6.638999999999999, 6.640000000000001, 6.640999999999998, 6.6419999999999995, 6.643000000000001, 6.643999999999998, 6.645, 6.646000000000001, 6.6469999999999985, 6.648, 6.649000000000001, 6.649999999999999, 6.651, 6.652000000000001, 6.652999999999999, 6.654, 6.655000000000001, 6.655999999999999, 6.657, 6.658000000000001, 6.658999999999999, 6.66, 6.661000000000001, 6.661999999999999, 6.663, 6.6640000000000015, 6.664999999999999, 6.666, 6.667000000000002, 6.667999999999999, 6.6690000000000005, 6.670000000000002, 6.670999999999999, 6.672000000000001, 6.672999999999998, 6.6739999999999995, 6.675000000000001, 6.675999999999998, 6.677, 6.678000000000001, 6.6789999999999985, 6.68, 6.681000000000001, 6.681999999999999, 6.683, 6.684000000000001, 6.684999999999999, 6.686, 6.687000000000001, 6.687999999999999, 6.689, 6.690000000000001, 6.690999999999999, 6.692, 6.693000000000001, 6.693999999999999, 6.695, 6.6960000000000015, 6.696999999999999, 6.698, 6.699000000000002, 6.699999999999999, 6.7010000000000005, 6.702000000000002, 6.702999999999999, 6.704000000000001, 6.704999999999998, 6.7059999999999995, 6.707000000000001, 6.707999999999998, 6.709, 6.710000000000001, 6.7109999999999985, 6.712, 6.713000000000001, 6.713999999999999, 6.715, 6.716000000000001, 6.716999999999999, 6.718, 6.719000000000001, 6.719999999999999, 6.721, 6.722000000000001, 6.722999999999999, 6.724, 6.725000000000001, 6.725999999999999, 6.727, 6.7280000000000015, 6.728999999999999, 6.73, 6.731000000000002, 6.731999999999999, 6.7330000000000005, 6.734000000000002, 6.734999999999999, 6.736000000000001, 6.736999999999998, 6.7379999999999995, 6.739000000000001, 6.739999999999998, 6.741, 6.742000000000001, 6.7429999999999986, 6.744, 6.745000000000001, 6.745999999999999, 6.747, 6.748000000000001, 6.748999999999999, 6.75, 6.751000000000001, 6.751999999999999, 6.753, 6.754000000000001, 6.754999999999999, 6.756, 6.7570000000000014, 6.757999999999999, 6.759, 6.760000000000002, 6.760999999999999, 6.7620000000000005, 6.763000000000002, 6.763999999999999, 6.765000000000001, 6.765999999999998, 6.7669999999999995, 6.768000000000001, 6.768999999999998, 6.77, 6.771000000000001, 6.7719999999999985, 6.773, 6.774000000000001, 6.774999999999999, 6.776, 6.777000000000001, 6.777999999999999, 6.779, 6.780000000000001, 6.780999999999999, 6.782, 6.783000000000001, 6.783999999999999, 6.785, 6.786000000000001, 6.786999999999999, 6.788, 6.7890000000000015, 6.789999999999999, 6.791, 6.792000000000002, 6.792999999999999, 6.7940000000000005, 6.795000000000002, 6.795999999999999, 6.797000000000001, 6.797999999999998, 6.7989999999999995, 6.800000000000001, 6.800999999999998, 6.802, 6.803000000000001, 6.8039999999999985, 6.805, 6.806000000000001, 6.806999999999999, 6.808, 6.809000000000001, 6.809999999999999, 6.811, 6.812000000000001, 6.812999999999999, 6.814, 6.815000000000001, 6.815999999999999, 6.817, 6.818000000000001, 6.818999999999999, 6.82, 6.8210000000000015, 6.821999999999999, 6.823, 6.824000000000002, 6.824999999999999, 6.8260000000000005, 6.827000000000002, 6.827999999999999, 6.829000000000001, 6.829999999999998, 6.8309999999999995, 6.832000000000001, 6.832999999999998, 6.834, 6.835000000000001, 6.8359999999999985, 6.837, 6.838000000000001, 6.838999999999999, 6.84, 6.841000000000001, 6.841999999999999, 6.843, 6.844000000000001, 6.844999999999999, 6.846, 6.847000000000001, 6.847999999999999, 6.849, 6.850000000000001, 6.850999999999999, 6.852, 6.8530000000000015, 6.853999999999999, 6.855, 6.856000000000002, 6.856999999999999, 6.8580000000000005, 6.859000000000002, 6.859999999999999, 6.861000000000001, 6.861999999999998, 6.8629999999999995, 6.864000000000001, 6.864999999999998, 6.866, 6.867000000000001, 6.8679999999999986, 6.869, 6.870000000000001, 6.870999999999999, 6.872, 6.873000000000001, 6.873999999999999, 6.875, 6.876000000000001, 6.876999999999999, 6.878, 6.879000000000001, 6.879999999999999, 6.881, 6.8820000000000014, 6.882999999999999, 6.884, 6.885000000000002, 6.885999999999999, 6.8870000000000005, 6.888000000000002, 6.888999999999999, 6.890000000000001, 6.890999999999998, 6.8919999999999995, 6.893000000000001, 6.893999999999998, 6.895, 6.896000000000001, 6.8969999999999985, 6.898, 6.899000000000001, 6.899999999999999, 6.901, 6.902000000000001, 6.902999999999999, 6.904, 6.905000000000001, 6.905999999999999, 6.907, 6.908000000000001, 6.908999999999999, 6.91, 6.911000000000001, 6.911999999999999, 6.913, 6.9140000000000015, 6.914999999999999, 6.916, 6.917000000000002, 6.917999999999999, 6.9190000000000005, 6.920000000000002, 6.920999999999999, 6.922000000000001, 6.922999999999998, 6.9239999999999995, 6.925000000000001, 6.925999999999998, 6.927, 6.928000000000001, 6.9289999999999985, 6.93, 6.931000000000001, 6.931999999999999, 6.933, 6.934000000000001, 6.934999999999999, 6.936, 6.937000000000001, 6.937999999999999, 6.939, 6.940000000000001, 6.940999999999999, 6.942, 6.943000000000001, 6.943999999999999, 6.945, 6.9460000000000015, 6.946999999999999, 6.948, 6.949000000000002, 6.949999999999999, 6.9510000000000005, 6.952000000000002, 6.952999999999999, 6.954000000000001, 6.954999999999998, 6.9559999999999995, 6.957000000000001, 6.957999999999998, 6.959, 6.960000000000001, 6.9609999999999985, 6.962, 6.963000000000001, 6.963999999999999, 6.965, 6.966000000000001, 6.966999999999999, 6.968, 6.969000000000001, 6.969999999999999, 6.971, 6.972000000000001, 6.972999999999999, 6.974, 6.975000000000001, 6.975999999999999, 6.977, 6.9780000000000015, 6.978999999999999, 6.98, 6.981000000000002, 6.981999999999999, 6.9830000000000005, 6.984000000000002, 6.984999999999999, 6.986000000000001, 6.986999999999998, 6.9879999999999995, 6.989000000000001, 6.989999999999998, 6.991, 6.992000000000001, 6.9929999999999986, 6.994, 6.995000000000001, 6.995999999999999, 6.997, 6.998000000000001, 6.998999999999999, 7.0, 7.001000000000001, 7.001999999999999, 7.003, 7.004000000000001, 7.004999999999999, 7.006, 7.0070000000000014, 7.007999999999999, 7.009, 7.010000000000002, 7.010999999999999, 7.0120000000000005, 7.013000000000002, 7.013999999999999, 7.015000000000001, 7.015999999999998, 7.0169999999999995, 7.018000000000001, 7.018999999999998, 7.02, 7.021000000000001, 7.0219999999999985, 7.023, 7.024000000000001, 7.024999999999999, 7.026, 7.027000000000001, 7.027999999999999, 7.029, 7.030000000000001, 7.030999999999999, 7.032, 7.033000000000001, 7.033999999999999, 7.035, 7.036000000000001, 7.036999999999999, 7.038, 7.0390000000000015, 7.039999999999999, 7.041, 7.042000000000002, 7.042999999999999, 7.0440000000000005, 7.045000000000002, 7.045999999999999, 7.047000000000001, 7.047999999999998, 7.0489999999999995, 7.050000000000001, 7.050999999999998, 7.052, 7.053000000000001, 7.0539999999999985, 7.055, 7.056000000000001, 7.056999999999999, 7.058, 7.059000000000001, 7.059999999999999, 7.061, 7.062000000000001, 7.062999999999999, 7.064, 7.065000000000001, 7.065999999999999, 7.067, 7.068000000000001, 7.068999999999999, 7.07, 7.0710000000000015, 7.071999999999999, 7.073, 7.074000000000002, 7.074999999999999, 7.0760000000000005, 7.077000000000002, 7.077999999999999, 7.079000000000001, 7.079999999999998, 7.0809999999999995, 7.082000000000001, 7.082999999999998, 7.084, 7.085000000000001, 7.0859999999999985, 7.087, 7.088000000000001, 7.088999999999999, 7.09, 7.091000000000001, 7.091999999999999, 7.093, 7.094000000000001, 7.094999999999999, 7.096, 7.097000000000001, 7.097999999999999, 7.099, 7.100000000000001, 7.100999999999999, 7.102, 7.1030000000000015, 7.103999999999999, 7.105, 7.106000000000002, 7.106999999999999, 7.1080000000000005, 7.109000000000002, 7.109999999999999, 7.111000000000001, 7.111999999999998, 7.1129999999999995, 7.114000000000001, 7.114999999999998, 7.116, 7.117000000000001, 7.1179999999999986, 7.119, 7.120000000000001, 7.120999999999999, 7.122, 7.123000000000001, 7.123999999999999, 7.125, 7.126000000000001, 7.126999999999999, 7.128, 7.129000000000001, 7.129999999999999, 7.131, 7.1320000000000014, 7.132999999999999, 7.134, 7.135000000000002, 7.135999999999999, 7.1370000000000005, 7.138000000000002, 7.138999999999999, 7.140000000000001, 7.140999999999998, 7.1419999999999995, 7.143000000000001, 7.143999999999998, 7.145, 7.146000000000001, 7.1469999999999985, 7.148, 7.149000000000001, 7.149999999999999, 7.151, 7.152000000000001, 7.152999999999999, 7.154, 7.155000000000001, 7.155999999999999, 7.157, 7.158000000000001, 7.158999999999999, 7.16, 7.161000000000001, 7.161999999999999, 7.163, 7.1640000000000015, 7.164999999999999, 7.166, 7.167000000000002, 7.167999999999999, 7.1690000000000005, 7.170000000000002, 7.170999999999999, 7.172000000000001, 7.172999999999998, 7.1739999999999995, 7.175000000000001, 7.175999999999998, 7.177, 7.178000000000001, 7.1789999999999985, 7.18, 7.181000000000001, 7.181999999999999, 7.183, 7.184000000000001, 7.184999999999999, 7.186, 7.187000000000001, 7.187999999999999, 7.189, 7.190000000000001, 7.190999999999999, 7.192, 7.193000000000001, 7.193999999999999, 7.195, 7.1960000000000015, 7.196999999999999, 7.198, 7.199000000000002, 7.199999999999999, 7.2010000000000005, 7.202000000000002, 7.202999999999999, 7.204000000000001, 7.204999999999998, 7.2059999999999995, 7.207000000000001, 7.207999999999998, 7.209, 7.210000000000001, 7.2109999999999985, 7.212, 7.213000000000001, 7.213999999999999, 7.215, 7.216000000000001, 7.216999999999999, 7.218, 7.219000000000001, 7.219999999999999, 7.221, 7.222000000000001, 7.222999999999999, 7.224, 7.225000000000001, 7.225999999999999, 7.227, 7.2280000000000015, 7.228999999999999, 7.23, 7.231000000000002, 7.231999999999999, 7.2330000000000005, 7.234000000000002, 7.234999999999999, 7.236000000000001, 7.236999999999998, 7.2379999999999995, 7.239000000000001, 7.239999999999998, 7.241, 7.242000000000001, 7.2429999999999986, 7.244, 7.245000000000001, 7.245999999999999, 7.247, 7.248000000000001, 7.248999999999999, 7.25, 7.251000000000001, 7.251999999999999, 7.253, 7.254000000000001, 7.254999999999999, 7.256, 7.2570000000000014, 7.257999999999999, 7.259, 7.260000000000002, 7.260999999999999, 7.2620000000000005, 7.263000000000002, 7.263999999999999, 7.265000000000001, 7.265999999999998, 7.2669999999999995, 7.268000000000001, 7.268999999999998, 7.27, 7.271000000000001, 7.2719999999999985, 7.273, 7.274000000000001, 7.274999999999999, 7.276, 7.277000000000001, 7.277999999999999, 7.279, 7.280000000000001, 7.280999999999999, 7.282, 7.283000000000001, 7.283999999999999, 7.285, 7.286000000000001, 7.286999999999999, 7.288, 7.2890000000000015, 7.289999999999999, 7.291, 7.292000000000002, 7.292999999999999, 7.2940000000000005, 7.295000000000002, 7.295999999999999, 7.297000000000001, 7.297999999999998, 7.2989999999999995, 7.300000000000001, 7.300999999999998, 7.302, 7.303000000000001, 7.3039999999999985, 7.305, 7.306000000000001, 7.306999999999999, 7.308, 7.309000000000001, 7.309999999999999, 7.311, 7.312000000000001, 7.312999999999999, 7.314, 7.315000000000001, 7.315999999999999, 7.317, 7.318000000000001, 7.318999999999999, 7.32, 7.3210000000000015, 7.321999999999999, 7.323, 7.324000000000002, 7.324999999999999, 7.3260000000000005, 7.327000000000002, 7.327999999999999, 7.329000000000001, 7.329999999999998, 7.3309999999999995, 7.332000000000001, 7.332999999999998, 7.334, 7.335000000000001, 7.3359999999999985, 7.337, 7.338000000000001, 7.338999999999999, 7.34, 7.341000000000001, 7.341999999999999, 7.343, 7.344000000000001, 7.344999999999999, 7.346, 7.347000000000001, 7.347999999999999, 7.349, 7.350000000000001, 7.350999999999999, 7.352, 7.3530000000000015, 7.353999999999999, 7.355, 7.356000000000002, 7.356999999999999, 7.3580000000000005, 7.359000000000002, 7.359999999999999, 7.361000000000001, 7.361999999999998, 7.3629999999999995, 7.364000000000001, 7.364999999999998, 7.366, 7.367000000000001, 7.3679999999999986, 7.369, 7.370000000000001, 7.370999999999999, 7.372, 7.373000000000001, 7.373999999999999, 7.375, 7.376000000000001, 7.376999999999999, 7.378, 7.379000000000001, 7.379999999999999, 7.381, 7.3820000000000014, 7.382999999999999, 7.384, 7.385000000000002, 7.385999999999999, 7.3870000000000005, 7.388000000000002, 7.388999999999999, 7.390000000000001, 7.390999999999998, 7.3919999999999995, 7.393000000000001, 7.393999999999998, 7.395, 7.396000000000001, 7.3969999999999985, 7.398, 7.399000000000001, 7.399999999999999, 7.401, 7.402000000000001, 7.402999999999999, 7.404, 7.405000000000001, 7.405999999999999, 7.407, 7.408000000000001, 7.408999999999999, 7.41, 7.411000000000001, 7.411999999999999, 7.413, 7.4140000000000015, 7.414999999999999, 7.416, 7.417000000000002, 7.417999999999999, 7.4190000000000005, 7.420000000000002, 7.420999999999999, 7.422000000000001, 7.422999999999998, 7.4239999999999995, 7.425000000000001, 7.425999999999998, 7.427, 7.428000000000001, 7.4289999999999985, 7.43, 7.431000000000001, 7.431999999999999, 7.433, 7.434000000000001, 7.434999999999999, 7.436, 7.437000000000001, 7.437999999999999, 7.439, 7.440000000000001, 7.440999999999999, 7.442, 7.443000000000001, 7.443999999999999, 7.445, 7.4460000000000015, 7.446999999999999, 7.448, 7.449000000000002, 7.449999999999999, 7.4510000000000005, 7.452000000000002, 7.452999999999999, 7.454000000000001, 7.454999999999998, 7.4559999999999995, 7.457000000000001, 7.457999999999998, 7.459, 7.460000000000001, 7.4609999999999985, 7.462, 7.463000000000001, 7.463999999999999, 7.465, 7.466000000000001, 7.466999999999999, 7.468, 7.469000000000001, 7.469999999999999, 7.471, 7.472000000000001, 7.472999999999999, 7.474, 7.475000000000001, 7.475999999999999, 7.477, 7.4780000000000015, 7.478999999999999, 7.48, 7.481000000000002, 7.481999999999999, 7.4830000000000005, 7.484000000000002, 7.484999999999999, 7.486000000000001, 7.486999999999998, 7.4879999999999995, 7.489000000000001, 7.489999999999998, 7.491, 7.492000000000001, 7.4929999999999986, 7.494, 7.495000000000001, 7.495999999999999, 7.497, 7.498000000000001, 7.498999999999999, 7.5, 7.501000000000001, 7.501999999999999, 7.503, 7.504000000000001, 7.504999999999999, 7.506, 7.5070000000000014, 7.507999999999999, 7.509, 7.510000000000002, 7.510999999999999, 7.5120000000000005, 7.513000000000002, 7.513999999999999, 7.515000000000001, 7.515999999999998, 7.5169999999999995, 7.518000000000001, 7.518999999999998, 7.52, 7.521000000000001, 7.5219999999999985, 7.523, 7.524000000000001, 7.524999999999999, 7.526, 7.527000000000001, 7.527999999999999, 7.529, 7.530000000000001, 7.530999999999999, 7.532, 7.533000000000001, 7.533999999999999, 7.535, 7.536000000000001, 7.536999999999999, 7.538, 7.5390000000000015, 7.539999999999999, 7.541, 7.542000000000002, 7.542999999999999, 7.5440000000000005, 7.545000000000002, 7.545999999999999, 7.547000000000001, 7.547999999999998, 7.5489999999999995, 7.550000000000001, 7.550999999999998, 7.552, 7.553000000000001, 7.5539999999999985, 7.555, 7.556000000000001, 7.556999999999999, 7.558, 7.559000000000001, 7.559999999999999, 7.561, 7.562000000000001, 7.562999999999999, 7.564, 7.565000000000001, 7.565999999999999, 7.567, 7.568000000000001, 7.568999999999999, 7.57, 7.5710000000000015, 7.571999999999999, 7.573, 7.574000000000002, 7.574999999999999, 7.5760000000000005, 7.577000000000002, 7.577999999999999, 7.579000000000001, 7.579999999999998, 7.5809999999999995, 7.582000000000001, 7.582999999999998, 7.584, 7.585000000000001, 7.5859999999999985, 7.587, 7.588000000000001, 7.588999999999999, 7.59, 7.591000000000001, 7.591999999999999, 7.593, 7.594000000000001, 7.594999999999999, 7.596, 7.597000000000001, 7.597999999999999, 7.599, 7.600000000000001, 7.600999999999999, 7.602, 7.6030000000000015, 7.603999999999999, 7.605, 7.606000000000002, 7.606999999999999, 7.6080000000000005, 7.609000000000002, 7.609999999999999, 7.611000000000001, 7.611999999999998, 7.6129999999999995, 7.614000000000001, 7.614999999999998, 7.616, 7.617000000000001, 7.6179999999999986, 7.619, 7.620000000000001, 7.620999999999999, 7.622, 7.623000000000001, 7.623999999999999, 7.625, 7.626000000000001, 7.626999999999999, 7.628, 7.629000000000001, 7.629999999999999, 7.631, 7.6320000000000014, 7.632999999999999, 7.634, 7.635000000000002, 7.635999999999999, 7.6370000000000005, 7.638000000000002, 7.638999999999999, 7.640000000000001, 7.640999999999998, 7.6419999999999995, 7.643000000000001, 7.643999999999998, 7.645, 7.646000000000001, 7.6469999999999985, 7.648, 7.649000000000001, 7.649999999999999, 7.651, 7.652000000000001, 7.652999999999999, 7.654, 7.655000000000001, 7.655999999999999, 7.657, 7.658000000000001, 7.658999999999999, 7.66, 7.661000000000001, 7.661999999999999, 7.663, 7.6640000000000015, 7.664999999999999, 7.666, 7.667000000000002, 7.667999999999999, 7.6690000000000005, 7.670000000000002, 7.670999999999999, 7.672000000000001, 7.672999999999998, 7.6739999999999995, 7.675000000000001, 7.675999999999998, 7.677, 7.678000000000001, 7.6789999999999985, 7.68, 7.681000000000001, 7.681999999999999, 7.683, 7.684000000000001, 7.684999999999999, 7.686, 7.687000000000001, 7.687999999999999, 7.689, 7.690000000000001, 7.690999999999999, 7.692, 7.693000000000001, 7.693999999999999, 7.695, 7.6960000000000015, 7.696999999999999, 7.698, 7.699000000000002, 7.699999999999999, 7.7010000000000005, 7.702000000000002, 7.702999999999999, 7.704000000000001, 7.704999999999998, 7.7059999999999995, 7.707000000000001, 7.707999999999998, 7.709, 7.710000000000001, 7.7109999999999985, 7.712, 7.713000000000001, 7.713999999999999, 7.715, 7.716000000000001, 7.716999999999999, 7.718, 7.719000000000001, 7.719999999999999, 7.721, 7.722000000000001, 7.722999999999999, 7.724, 7.725000000000001, 7.725999999999999, 7.727, 7.7280000000000015, 7.728999999999999, 7.73, 7.731000000000002, 7.731999999999999, 7.7330000000000005, 7.734000000000002, 7.734999999999999, 7.736000000000001, 7.736999999999998, 7.7379999999999995, 7.739000000000001, 7.739999999999998, 7.741, 7.742000000000001, 7.7429999999999986, 7.744, 7.745000000000001, 7.745999999999999, 7.747, 7.748000000000001, 7.748999999999999, 7.75, 7.751000000000001, 7.751999999999999, 7.753, 7.754000000000001, 7.754999999999999, 7.756, 7.7570000000000014, 7.757999999999999, 7.759, 7.760000000000002, 7.760999999999999, 7.7620000000000005, 7.763000000000002, 7.763999999999999, 7.765000000000001, 7.765999999999998, 7.7669999999999995, 7.768000000000001, 7.768999999999998, 7.77, 7.771000000000001, 7.7719999999999985, 7.773, 7.774000000000001, 7.774999999999999, 7.776, 7.777000000000001, 7.777999999999999, 7.779, 7.780000000000001, 7.780999999999999, 7.782, 7.783000000000001, 7.783999999999999, 7.785, 7.786000000000001, 7.786999999999999, 7.788, 7.7890000000000015, 7.789999999999999, 7.791, 7.792000000000002, 7.792999999999999, 7.7940000000000005, 7.795000000000002, 7.795999999999999, 7.797000000000001, 7.797999999999998, 7.7989999999999995, 7.800000000000001, 7.800999999999998, 7.802, 7.803000000000001, 7.8039999999999985, 7.805, 7.806000000000001, 7.806999999999999, 7.808, 7.809000000000001, 7.809999999999999, 7.811, 7.812000000000001, 7.812999999999999, 7.814, 7.815000000000001, 7.815999999999999, 7.817, 7.818000000000001, 7.818999999999999, 7.82, 7.8210000000000015, 7.821999999999999, 7.823, 7.824000000000002, 7.824999999999999, 7.8260000000000005, 7.827000000000002, 7.827999999999999, 7.829000000000001, 7.829999999999998, 7.8309999999999995, 7.832000000000001, 7.832999999999998, 7.834, 7.835000000000001, 7.8359999999999985, 7.837, 7.838000000000001, 7.838999999999999, 7.84, 7.841000000000001, 7.841999999999999, 7.843, 7.844000000000001, 7.844999999999999, 7.846, 7.847000000000001, 7.847999999999999, 7.849, 7.850000000000001, 7.850999999999999, 7.852, 7.8530000000000015, 7.853999999999999, 7.855, 7.856000000000002, 7.856999999999999, 7.8580000000000005, 7.859000000000002, 7.859999999999999, 7.861000000000001, 7.861999999999998, 7.8629999999999995, 7.864000000000001, 7.864999999999998, 7.866, 7.867000000000001, 7.8679999999999986, 7.869, 7.870000000000001, 7.870999999999999, 7.872, 7.873000000000001, 7.873999999999999, 7.875, 7.876000000000001, 7.876999999999999, 7.878, 7.879000000000001, 7.879999999999999, 7.881, 7.8820000000000014, 7.882999999999999, 7.884, 7.885000000000002, 7.885999999999999, 7.8870000000000005, 7.888000000000002, 7.888999999999999, 7.890000000000001, 7.890999999999998, 7.8919999999999995, 7.893000000000001, 7.893999999999998, 7.895, 7.896000000000001, 7.8969999999999985, 7.898, 7.899000000000001, 7.899999999999999, 7.901, 7.902000000000001, 7.902999999999999, 7.904, 7.905000000000001, 7.905999999999999, 7.907, 7.908000000000001, 7.908999999999999, 7.91, 7.911000000000001, 7.911999999999999, 7.913, 7.9140000000000015, 7.914999999999999, 7.916, 7.917000000000002, 7.917999999999999, 7.9190000000000005, 7.920000000000002, 7.920999999999999, 7.922000000000001, 7.922999999999998, 7.9239999999999995, 7.925000000000001, 7.925999999999998, 7.927, 7.928000000000001, 7.9289999999999985, 7.93, 7.931000000000001, 7.931999999999999, 7.933, 7.934000000000001, 7.934999999999999, 7.936, 7.937000000000001, 7.937999999999999, 7.939, 7.940000000000001, 7.940999999999999, 7.942, 7.943000000000001, 7.943999999999999, 7.945, 7.9460000000000015, 7.946999999999999, 7.948, 7.949000000000002, 7.949999999999999, 7.9510000000000005, 7.952000000000002, 7.952999999999999, 7.954000000000001, 7.954999999999998, 7.9559999999999995, 7.957000000000001, 7.957999999999998, 7.959, 7.960000000000001, 7.9609999999999985, 7.962, 7.963000000000001, 7.963999999999999, 7.965, 7.966000000000001, 7.966999999999999, 7.968, 7.969000000000001, 7.969999999999999, 7.971, 7.972000000000001, 7.972999999999999, 7.974, 7.975000000000001, 7.975999999999999, 7.977, 7.9780000000000015, 7.978999999999999, 7.98, 7.981000000000002, 7.981999999999999, 7.9830000000000005, 7.984000000000002, 7.984999999999999, 7.986000000000001, 7.986999999999998, 7.9879999999999995, 7.989000000000001, 7.989999999999998, 7.991, 7.992000000000001, 7.9929999999999986, 7.994, 7.995000000000001, 7.995999999999999, 7.997, 7.998000000000001, 7.998999999999999, 8.0, 8.001000000000001, 8.001999999999999, 8.003, 8.004000000000001, 8.004999999999999, 8.006, 8.007000000000001, 8.008, 8.009, 8.010000000000002, 8.011, 8.012, 8.013000000000002, 8.014, 8.015, 8.015999999999998, 8.017, 8.018, 8.018999999999998, 8.02, 8.021, 8.021999999999998, 8.023, 8.024000000000001, 8.024999999999999, 8.026, 8.027000000000001, 8.027999999999999, 8.029, 8.030000000000001, 8.030999999999999, 8.032, 8.033000000000001, 8.033999999999999, 8.035, 8.036000000000001, 8.036999999999999, 8.038, 8.039000000000001, 8.04, 8.041, 8.042000000000002, 8.043, 8.044, 8.045000000000002, 8.046, 8.047, 8.047999999999998, 8.049, 8.05, 8.050999999999998, 8.052, 8.053, 8.053999999999998, 8.055, 8.056000000000001, 8.056999999999999, 8.058, 8.059000000000001, 8.059999999999999, 8.061, 8.062000000000001, 8.062999999999999, 8.064, 8.065000000000001, 8.065999999999999, 8.067, 8.068000000000001, 8.068999999999999, 8.07, 8.071000000000002, 8.072, 8.073, 8.074000000000002, 8.075, 8.076, 8.077000000000002, 8.078, 8.079, 8.079999999999998, 8.081, 8.082, 8.082999999999998, 8.084, 8.085, 8.085999999999999, 8.087, 8.088000000000001, 8.088999999999999, 8.09, 8.091000000000001, 8.091999999999999, 8.093, 8.094000000000001, 8.094999999999999, 8.096, 8.097000000000001, 8.097999999999999, 8.099, 8.100000000000001, 8.100999999999999, 8.102, 8.103000000000002, 8.104, 8.105, 8.106000000000002, 8.107, 8.108, 8.109000000000002, 8.11, 8.111, 8.111999999999998, 8.113, 8.114, 8.114999999999998, 8.116, 8.117, 8.117999999999999, 8.119, 8.120000000000001, 8.120999999999999, 8.122, 8.123000000000001, 8.123999999999999, 8.125, 8.126000000000001, 8.126999999999999, 8.128, 8.129000000000001, 8.129999999999999, 8.131, 8.132000000000001, 8.133, 8.134, 8.135000000000002, 8.136, 8.137, 8.138000000000002, 8.139, 8.14, 8.140999999999998, 8.142, 8.143, 8.143999999999998, 8.145, 8.146, 8.146999999999998, 8.148, 8.149000000000001, 8.149999999999999, 8.151, 8.152000000000001, 8.152999999999999, 8.154, 8.155000000000001, 8.155999999999999, 8.157, 8.158000000000001, 8.158999999999999, 8.16, 8.161000000000001, 8.161999999999999, 8.163, 8.164000000000001, 8.165, 8.166, 8.167000000000002, 8.168, 8.169, 8.170000000000002, 8.171, 8.172, 8.172999999999998, 8.174, 8.175, 8.175999999999998, 8.177, 8.178, 8.178999999999998, 8.18, 8.181000000000001, 8.181999999999999, 8.183, 8.184000000000001, 8.184999999999999, 8.186, 8.187000000000001, 8.187999999999999, 8.189, 8.190000000000001, 8.190999999999999, 8.192, 8.193000000000001, 8.193999999999999, 8.195, 8.196000000000002, 8.197, 8.198, 8.199000000000002, 8.2, 8.201, 8.202000000000002, 8.203, 8.204, 8.204999999999998, 8.206, 8.207, 8.207999999999998, 8.209, 8.21, 8.210999999999999, 8.212, 8.213000000000001, 8.213999999999999, 8.215, 8.216000000000001, 8.216999999999999, 8.218, 8.219000000000001, 8.219999999999999, 8.221, 8.222000000000001, 8.222999999999999, 8.224, 8.225000000000001, 8.225999999999999, 8.227, 8.228000000000002, 8.229, 8.23, 8.231000000000002, 8.232, 8.233, 8.234000000000002, 8.235, 8.236, 8.236999999999998, 8.238, 8.239, 8.239999999999998, 8.241, 8.242, 8.242999999999999, 8.244, 8.245000000000001, 8.245999999999999, 8.247, 8.248000000000001, 8.248999999999999, 8.25, 8.251000000000001, 8.251999999999999, 8.253, 8.254000000000001, 8.254999999999999, 8.256, 8.257000000000001, 8.258, 8.259, 8.260000000000002, 8.261, 8.262, 8.263000000000002, 8.264, 8.265, 8.265999999999998, 8.267, 8.268, 8.268999999999998, 8.27, 8.271, 8.271999999999998, 8.273, 8.274000000000001, 8.274999999999999, 8.276, 8.277000000000001, 8.277999999999999, 8.279, 8.280000000000001, 8.280999999999999, 8.282, 8.283000000000001, 8.283999999999999, 8.285, 8.286000000000001, 8.286999999999999, 8.288, 8.289000000000001, 8.29, 8.291, 8.292000000000002, 8.293, 8.294, 8.295000000000002, 8.296, 8.297, 8.297999999999998, 8.299, 8.3, 8.300999999999998, 8.302, 8.303, 8.303999999999998, 8.305, 8.306000000000001, 8.306999999999999, 8.308, 8.309000000000001, 8.309999999999999, 8.311, 8.312000000000001, 8.312999999999999, 8.314, 8.315000000000001, 8.315999999999999, 8.317, 8.318000000000001, 8.318999999999999, 8.32, 8.321000000000002, 8.322, 8.323, 8.324000000000002, 8.325, 8.326, 8.327000000000002, 8.328, 8.329, 8.329999999999998, 8.331, 8.332, 8.332999999999998, 8.334, 8.335, 8.335999999999999, 8.337, 8.338000000000001, 8.338999999999999, 8.34, 8.341000000000001, 8.341999999999999, 8.343, 8.344000000000001, 8.344999999999999, 8.346, 8.347000000000001, 8.347999999999999, 8.349, 8.350000000000001, 8.350999999999999, 8.352, 8.353000000000002, 8.354, 8.355, 8.356000000000002, 8.357, 8.358, 8.359000000000002, 8.36, 8.361, 8.361999999999998, 8.363, 8.364, 8.364999999999998, 8.366, 8.367, 8.367999999999999, 8.369, 8.370000000000001, 8.370999999999999, 8.372, 8.373000000000001, 8.373999999999999, 8.375, 8.376000000000001, 8.376999999999999, 8.378, 8.379000000000001, 8.379999999999999, 8.381, 8.382000000000001, 8.383, 8.384, 8.385000000000002, 8.386, 8.387, 8.388000000000002, 8.389, 8.39, 8.390999999999998, 8.392, 8.393, 8.393999999999998, 8.395, 8.396, 8.396999999999998, 8.398, 8.399000000000001, 8.399999999999999, 8.401, 8.402000000000001, 8.402999999999999, 8.404, 8.405000000000001, 8.405999999999999, 8.407, 8.408000000000001, 8.408999999999999, 8.41, 8.411000000000001, 8.411999999999999, 8.413, 8.414000000000001, 8.415, 8.416, 8.417000000000002, 8.418, 8.419, 8.420000000000002, 8.421, 8.422, 8.422999999999998, 8.424, 8.425, 8.425999999999998, 8.427, 8.428, 8.428999999999998, 8.43, 8.431000000000001, 8.431999999999999, 8.433, 8.434000000000001, 8.434999999999999, 8.436, 8.437000000000001, 8.437999999999999, 8.439, 8.440000000000001, 8.440999999999999, 8.442, 8.443000000000001, 8.443999999999999, 8.445, 8.446000000000002, 8.447, 8.448, 8.449000000000002, 8.45, 8.451, 8.452000000000002, 8.453, 8.454, 8.454999999999998, 8.456, 8.457, 8.457999999999998, 8.459, 8.46, 8.460999999999999, 8.462, 8.463000000000001, 8.463999999999999, 8.465, 8.466000000000001, 8.466999999999999, 8.468, 8.469000000000001, 8.469999999999999, 8.471, 8.472000000000001, 8.472999999999999, 8.474, 8.475000000000001, 8.475999999999999, 8.477, 8.478000000000002, 8.479, 8.48, 8.481000000000002, 8.482, 8.483, 8.484000000000002, 8.485, 8.486, 8.486999999999998, 8.488, 8.489, 8.489999999999998, 8.491, 8.492, 8.492999999999999, 8.494, 8.495000000000001, 8.495999999999999, 8.497, 8.498000000000001, 8.498999999999999, 8.5, 8.501000000000001, 8.501999999999999, 8.503, 8.504000000000001, 8.504999999999999, 8.506, 8.507000000000001, 8.508, 8.509, 8.510000000000002, 8.511, 8.512, 8.513000000000002, 8.514, 8.515, 8.515999999999998, 8.517, 8.518, 8.518999999999998, 8.52, 8.521, 8.521999999999998, 8.523, 8.524000000000001, 8.524999999999999, 8.526, 8.527000000000001, 8.527999999999999, 8.529, 8.530000000000001, 8.530999999999999, 8.532, 8.533000000000001, 8.533999999999999, 8.535, 8.536000000000001, 8.536999999999999, 8.538, 8.539000000000001, 8.54, 8.541, 8.542000000000002, 8.543, 8.544, 8.545000000000002, 8.546, 8.547, 8.547999999999998, 8.549, 8.55, 8.550999999999998, 8.552, 8.553, 8.553999999999998, 8.555, 8.556000000000001, 8.556999999999999, 8.558, 8.559000000000001, 8.559999999999999, 8.561, 8.562000000000001, 8.562999999999999, 8.564, 8.565000000000001, 8.565999999999999, 8.567, 8.568000000000001, 8.568999999999999, 8.57, 8.571000000000002, 8.572, 8.573, 8.574000000000002, 8.575, 8.576, 8.577000000000002, 8.578, 8.579, 8.579999999999998, 8.581, 8.582, 8.582999999999998, 8.584, 8.585, 8.585999999999999, 8.587, 8.588000000000001, 8.588999999999999, 8.59, 8.591000000000001, 8.591999999999999, 8.593, 8.594000000000001, 8.594999999999999, 8.596, 8.597000000000001, 8.597999999999999, 8.599, 8.600000000000001, 8.600999999999999, 8.602, 8.603000000000002, 8.604, 8.605, 8.606000000000002, 8.607, 8.608, 8.609000000000002, 8.61, 8.611, 8.611999999999998, 8.613, 8.614, 8.614999999999998, 8.616, 8.617, 8.617999999999999, 8.619, 8.620000000000001, 8.620999999999999, 8.622, 8.623000000000001, 8.623999999999999, 8.625, 8.626000000000001, 8.626999999999999, 8.628, 8.629000000000001, 8.629999999999999, 8.631, 8.632000000000001, 8.633, 8.634, 8.635000000000002, 8.636, 8.637, 8.638000000000002, 8.639, 8.64, 8.640999999999998, 8.642, 8.643, 8.643999999999998, 8.645, 8.646, 8.646999999999998, 8.648, 8.649000000000001, 8.649999999999999, 8.651, 8.652000000000001, 8.652999999999999, 8.654, 8.655000000000001, 8.655999999999999, 8.657, 8.658000000000001, 8.658999999999999, 8.66, 8.661000000000001, 8.661999999999999, 8.663, 8.664000000000001, 8.665, 8.666, 8.667000000000002, 8.668, 8.669, 8.670000000000002, 8.671, 8.672, 8.672999999999998, 8.674, 8.675, 8.675999999999998, 8.677, 8.678, 8.678999999999998, 8.68, 8.681000000000001, 8.681999999999999, 8.683, 8.684000000000001, 8.684999999999999, 8.686, 8.687000000000001, 8.687999999999999, 8.689, 8.690000000000001, 8.690999999999999, 8.692, 8.693000000000001, 8.693999999999999, 8.695, 8.696000000000002, 8.697, 8.698, 8.699000000000002, 8.7, 8.701, 8.702000000000002, 8.703, 8.704, 8.704999999999998, 8.706, 8.707, 8.707999999999998, 8.709, 8.71, 8.710999999999999, 8.712, 8.713000000000001, 8.713999999999999, 8.715, 8.716000000000001, 8.716999999999999, 8.718, 8.719000000000001, 8.719999999999999, 8.721, 8.722000000000001, 8.722999999999999, 8.724, 8.725000000000001, 8.725999999999999, 8.727, 8.728000000000002, 8.729, 8.73, 8.731000000000002, 8.732, 8.733, 8.734000000000002, 8.735, 8.736, 8.736999999999998, 8.738, 8.739, 8.739999999999998, 8.741, 8.742, 8.742999999999999, 8.744, 8.745000000000001, 8.745999999999999, 8.747, 8.748000000000001, 8.748999999999999, 8.75, 8.751000000000001, 8.751999999999999, 8.753, 8.754000000000001, 8.754999999999999, 8.756, 8.757000000000001, 8.758, 8.759, 8.760000000000002, 8.761, 8.762, 8.763000000000002, 8.764, 8.765, 8.765999999999998, 8.767, 8.768, 8.768999999999998, 8.77, 8.771, 8.771999999999998, 8.773, 8.774000000000001, 8.774999999999999, 8.776, 8.777000000000001, 8.777999999999999, 8.779, 8.780000000000001, 8.780999999999999, 8.782, 8.783000000000001, 8.783999999999999, 8.785, 8.786000000000001, 8.786999999999999, 8.788, 8.789000000000001, 8.79, 8.791, 8.792000000000002, 8.793, 8.794, 8.795000000000002, 8.796, 8.797, 8.797999999999998, 8.799, 8.8, 8.800999999999998, 8.802, 8.803, 8.803999999999998, 8.805, 8.806000000000001, 8.806999999999999, 8.808, 8.809000000000001, 8.809999999999999, 8.811, 8.812000000000001, 8.812999999999999, 8.814, 8.815000000000001, 8.815999999999999, 8.817, 8.818000000000001, 8.818999999999999, 8.82, 8.821000000000002, 8.822, 8.823, 8.824000000000002, 8.825, 8.826, 8.827000000000002, 8.828, 8.829, 8.829999999999998, 8.831, 8.832, 8.832999999999998, 8.834, 8.835, 8.835999999999999, 8.837, 8.838000000000001, 8.838999999999999, 8.84, 8.841000000000001, 8.841999999999999, 8.843, 8.844000000000001, 8.844999999999999, 8.846, 8.847000000000001, 8.847999999999999, 8.849, 8.850000000000001, 8.850999999999999, 8.852, 8.853000000000002, 8.854, 8.855, 8.856000000000002, 8.857, 8.858, 8.859000000000002, 8.86, 8.861, 8.861999999999998, 8.863, 8.864, 8.864999999999998, 8.866, 8.867, 8.867999999999999, 8.869, 8.870000000000001, 8.870999999999999, 8.872, 8.873000000000001, 8.873999999999999, 8.875, 8.876000000000001, 8.876999999999999, 8.878, 8.879000000000001, 8.879999999999999, 8.881, 8.882000000000001, 8.883, 8.884, 8.885000000000002, 8.886, 8.887, 8.888000000000002, 8.889, 8.89, 8.890999999999998, 8.892, 8.893, 8.893999999999998, 8.895, 8.896, 8.896999999999998, 8.898, 8.899000000000001, 8.899999999999999, 8.901, 8.902000000000001, 8.902999999999999, 8.904, 8.905000000000001, 8.905999999999999, 8.907, 8.908000000000001, 8.908999999999999, 8.91, 8.911000000000001, 8.911999999999999, 8.913, 8.914000000000001, 8.915, 8.916, 8.917000000000002, 8.918, 8.919, 8.920000000000002, 8.921, 8.922, 8.922999999999998, 8.924, 8.925, 8.925999999999998, 8.927, 8.928, 8.928999999999998, 8.93, 8.931000000000001, 8.931999999999999, 8.933, 8.934000000000001, 8.934999999999999, 8.936, 8.937000000000001, 8.937999999999999, 8.939, 8.940000000000001, 8.940999999999999, 8.942, 8.943000000000001, 8.943999999999999, 8.945, 8.946000000000002, 8.947, 8.948, 8.949000000000002, 8.95, 8.951, 8.952000000000002, 8.953, 8.954, 8.954999999999998, 8.956, 8.957, 8.957999999999998, 8.959, 8.96, 8.960999999999999, 8.962, 8.963000000000001, 8.963999999999999, 8.965, 8.966000000000001, 8.966999999999999, 8.968, 8.969000000000001, 8.969999999999999, 8.971, 8.972000000000001, 8.972999999999999, 8.974, 8.975000000000001, 8.975999999999999, 8.977, 8.978000000000002, 8.979, 8.98, 8.981000000000002, 8.982, 8.983, 8.984000000000002, 8.985, 8.986, 8.986999999999998, 8.988, 8.989, 8.989999999999998, 8.991, 8.992, 8.992999999999999, 8.994, 8.995000000000001, 8.995999999999999, 8.997, 8.998000000000001, 8.998999999999999, 9.0, 9.001000000000001, 9.001999999999999, 9.003, 9.004000000000001, 9.004999999999999, 9.006, 9.007000000000001, 9.008, 9.009, 9.010000000000002, 9.011, 9.012, 9.013000000000002, 9.014, 9.015, 9.015999999999998, 9.017, 9.018, 9.018999999999998, 9.02, 9.021, 9.021999999999998, 9.023, 9.024000000000001, 9.024999999999999, 9.026, 9.027000000000001, 9.027999999999999, 9.029, 9.030000000000001, 9.030999999999999, 9.032, 9.033000000000001, 9.033999999999999, 9.035, 9.036000000000001, 9.036999999999999, 9.038, 9.039000000000001, 9.04, 9.041, 9.042000000000002, 9.043, 9.044, 9.045000000000002, 9.046, 9.047, 9.047999999999998, 9.049, 9.05, 9.050999999999998, 9.052, 9.053, 9.053999999999998, 9.055, 9.056000000000001, 9.056999999999999, 9.058, 9.059000000000001, 9.059999999999999, 9.061, 9.062000000000001, 9.062999999999999, 9.064, 9.065000000000001, 9.065999999999999, 9.067, 9.068000000000001, 9.068999999999999, 9.07, 9.071000000000002, 9.072, 9.073, 9.074000000000002, 9.075, 9.076, 9.077000000000002, 9.078, 9.079, 9.079999999999998, 9.081, 9.082, 9.082999999999998, 9.084, 9.085, 9.085999999999999, 9.087, 9.088000000000001, 9.088999999999999, 9.09, 9.091000000000001, 9.091999999999999, 9.093, 9.094000000000001, 9.094999999999999, 9.096, 9.097000000000001, 9.097999999999999, 9.099, 9.100000000000001, 9.100999999999999, 9.102, 9.103000000000002, 9.104, 9.105, 9.106000000000002, 9.107, 9.108, 9.109000000000002, 9.11, 9.111, 9.111999999999998, 9.113, 9.114, 9.114999999999998, 9.116, 9.117, 9.117999999999999, 9.119, 9.120000000000001, 9.120999999999999, 9.122, 9.123000000000001, 9.123999999999999, 9.125, 9.126000000000001, 9.126999999999999, 9.128, 9.129000000000001, 9.129999999999999, 9.131, 9.132000000000001, 9.133, 9.134, 9.135000000000002, 9.136, 9.137, 9.138000000000002, 9.139, 9.14, 9.140999999999998, 9.142, 9.143, 9.143999999999998, 9.145, 9.146, 9.146999999999998, 9.148, 9.149000000000001, 9.149999999999999, 9.151, 9.152000000000001, 9.152999999999999, 9.154, 9.155000000000001, 9.155999999999999, 9.157, 9.158000000000001, 9.158999999999999, 9.16, 9.161000000000001, 9.161999999999999, 9.163, 9.164000000000001, 9.165, 9.166, 9.167000000000002, 9.168, 9.169, 9.170000000000002, 9.171, 9.172, 9.172999999999998, 9.174, 9.175, 9.175999999999998, 9.177, 9.178, 9.178999999999998, 9.18, 9.181000000000001, 9.181999999999999, 9.183, 9.184000000000001, 9.184999999999999, 9.186, 9.187000000000001, 9.187999999999999, 9.189, 9.190000000000001, 9.190999999999999, 9.192, 9.193000000000001, 9.193999999999999, 9.195, 9.196000000000002, 9.197, 9.198, 9.199000000000002, 9.2, 9.201, 9.202000000000002, 9.203, 9.204, 9.204999999999998, 9.206, 9.207, 9.207999999999998, 9.209, 9.21, 9.210999999999999, 9.212, 9.213000000000001, 9.213999999999999, 9.215, 9.216000000000001, 9.216999999999999, 9.218, 9.219000000000001, 9.219999999999999, 9.221, 9.222000000000001, 9.222999999999999, 9.224, 9.225000000000001, 9.225999999999999, 9.227, 9.228000000000002, 9.229, 9.23, 9.231000000000002, 9.232, 9.233, 9.234000000000002, 9.235, 9.236, 9.236999999999998, 9.238, 9.239, 9.239999999999998, 9.241, 9.242, 9.242999999999999, 9.244, 9.245000000000001, 9.245999999999999, 9.247, 9.248000000000001, 9.248999999999999, 9.25, 9.251000000000001, 9.251999999999999, 9.253, 9.254000000000001, 9.254999999999999, 9.256, 9.257000000000001, 9.258, 9.259, 9.260000000000002, 9.261, 9.262, 9.263000000000002, 9.264, 9.265, 9.265999999999998, 9.267, 9.268, 9.268999999999998, 9.27, 9.271, 9.271999999999998, 9.273, 9.274000000000001, 9.274999999999999, 9.276, 9.277000000000001, 9.277999999999999, 9.279, 9.280000000000001, 9.280999999999999, 9.282, 9.283000000000001, 9.283999999999999, 9.285, 9.286000000000001, 9.286999999999999, 9.288, 9.289000000000001, 9.29, 9.291, 9.292000000000002, 9.293, 9.294, 9.295000000000002, 9.296, 9.297, 9.297999999999998, 9.299, 9.3, 9.300999999999998, 9.302, 9.303, 9.303999999999998, 9.305, 9.306000000000001, 9.306999999999999, 9.308, 9.309000000000001, 9.309999999999999, 9.311, 9.312000000000001, 9.312999999999999, 9.314, 9.315000000000001, 9.315999999999999, 9.317, 9.318000000000001, 9.318999999999999, 9.32, 9.321000000000002, 9.322, 9.323, 9.324000000000002, 9.325, 9.326, 9.327000000000002, 9.328, 9.329, 9.329999999999998, 9.331, 9.332, 9.332999999999998, 9.334, 9.335, 9.335999999999999, 9.337, 9.338000000000001, 9.338999999999999, 9.34, 9.341000000000001, 9.341999999999999, 9.343, 9.344000000000001, 9.344999999999999, 9.346, 9.347000000000001, 9.347999999999999, 9.349, 9.350000000000001, 9.350999999999999, 9.352, 9.353000000000002, 9.354, 9.355, 9.356000000000002, 9.357, 9.358, 9.359000000000002, 9.36, 9.361, 9.361999999999998, 9.363, 9.364, 9.364999999999998, 9.366, 9.367, 9.367999999999999, 9.369, 9.370000000000001, 9.370999999999999, 9.372, 9.373000000000001, 9.373999999999999, 9.375, 9.376000000000001, 9.376999999999999, 9.378, 9.379000000000001, 9.379999999999999, 9.381, 9.382000000000001, 9.383, 9.384, 9.385000000000002, 9.386, 9.387, 9.388000000000002, 9.389, 9.39, 9.390999999999998, 9.392, 9.393, 9.393999999999998, 9.395, 9.396, 9.396999999999998, 9.398, 9.399000000000001, 9.399999999999999, 9.401, 9.402000000000001, 9.402999999999999, 9.404, 9.405000000000001, 9.405999999999999, 9.407, 9.408000000000001, 9.408999999999999, 9.41, 9.411000000000001, 9.411999999999999, 9.413, 9.414000000000001, 9.415, 9.416, 9.417000000000002, 9.418, 9.419, 9.420000000000002, 9.421, 9.422, 9.422999999999998, 9.424, 9.425, 9.425999999999998, 9.427, 9.428, 9.428999999999998, 9.43, 9.431000000000001, 9.431999999999999, 9.433, 9.434000000000001, 9.434999999999999, 9.436, 9.437000000000001, 9.437999999999999, 9.439, 9.440000000000001, 9.440999999999999, 9.442, 9.443000000000001, 9.443999999999999, 9.445, 9.446000000000002, 9.447, 9.448, 9.449000000000002, 9.45, 9.451, 9.452000000000002, 9.453, 9.454, 9.454999999999998, 9.456, 9.457, 9.457999999999998, 9.459, 9.46, 9.460999999999999, 9.462, 9.463000000000001, 9.463999999999999, 9.465, 9.466000000000001, 9.466999999999999, 9.468, 9.469000000000001, 9.469999999999999, 9.471, 9.472000000000001, 9.472999999999999, 9.474, 9.475000000000001, 9.475999999999999, 9.477, 9.478000000000002, 9.479, 9.48, 9.481000000000002, 9.482, 9.483, 9.484000000000002, 9.485, 9.486, 9.486999999999998, 9.488, 9.489, 9.489999999999998, 9.491, 9.492, 9.492999999999999, 9.494, 9.495000000000001, 9.495999999999999, 9.497, 9.498000000000001, 9.498999999999999, 9.5, 9.501000000000001, 9.501999999999999, 9.503, 9.504000000000001, 9.504999999999999, 9.506, 9.507000000000001, 9.508, 9.509, 9.510000000000002, 9.511, 9.512, 9.513000000000002, 9.514, 9.515, 9.515999999999998, 9.517, 9.518, 9.518999999999998, 9.52, 9.521, 9.521999999999998, 9.523, 9.524000000000001, 9.524999999999999, 9.526, 9.527000000000001, 9.527999999999999, 9.529, 9.530000000000001, 9.530999999999999, 9.532, 9.533000000000001, 9.533999999999999, 9.535, 9.536000000000001, 9.536999999999999, 9.538, 9.539000000000001, 9.54, 9.541, 9.542000000000002, 9.543, 9.544, 9.545000000000002, 9.546, 9.547, 9.547999999999998, 9.549, 9.55, 9.550999999999998, 9.552, 9.553, 9.553999999999998, 9.555, 9.556000000000001, 9.556999999999999, 9.558, 9.559000000000001, 9.559999999999999, 9.561, 9.562000000000001, 9.562999999999999, 9.564, 9.565000000000001, 9.565999999999999, 9.567, 9.568000000000001, 9.568999999999999, 9.57, 9.571000000000002, 9.572, 9.573, 9.574000000000002, 9.575, 9.576, 9.577000000000002, 9.578, 9.579, 9.579999999999998, 9.581, 9.582, 9.582999999999998, 9.584, 9.585, 9.585999999999999, 9.587, 9.588000000000001, 9.588999999999999, 9.59, 9.591000000000001, 9.591999999999999, 9.593, 9.594000000000001, 9.594999999999999, 9.596, 9.597000000000001, 9.597999999999999, 9.599, 9.600000000000001, 9.600999999999999, 9.602, 9.603000000000002, 9.604, 9.605, 9.606000000000002, 9.607, 9.608, 9.609000000000002, 9.61, 9.611, 9.611999999999998, 9.613, 9.614, 9.614999999999998, 9.616, 9.617, 9.617999999999999, 9.619, 9.620000000000001, 9.620999999999999, 9.622, 9.623000000000001, 9.623999999999999, 9.625, 9.626000000000001, 9.626999999999999, 9.628, 9.629000000000001, 9.629999999999999, 9.631, 9.632000000000001, 9.633, 9.634, 9.635000000000002, 9.636, 9.637, 9.638000000000002, 9.639, 9.64, 9.640999999999998, 9.642, 9.643, 9.643999999999998, 9.645, 9.646, 9.646999999999998, 9.648, 9.649000000000001, 9.649999999999999, 9.651, 9.652000000000001, 9.652999999999999, 9.654, 9.655000000000001, 9.655999999999999, 9.657, 9.658000000000001, 9.658999999999999, 9.66, 9.661000000000001, 9.661999999999999, 9.663, 9.664000000000001, 9.665, 9.666, 9.667000000000002, 9.668, 9.669, 9.670000000000002, 9.671, 9.672, 9.672999999999998, 9.674, 9.675, 9.675999999999998, 9.677, 9.678, 9.678999999999998, 9.68, 9.681000000000001, 9.681999999999999, 9.683, 9.684000000000001, 9.684999999999999, 9.686, 9.687000000000001, 9.687999999999999, 9.689, 9.690000000000001, 9.690999999999999, 9.692, 9.693000000000001, 9.693999999999999, 9.695, 9.696000000000002, 9.697, 9.698, 9.699000000000002, 9.7, 9.701, 9.702000000000002, 9.703, 9.704, 9.704999999999998, 9.706, 9.707, 9.707999999999998, 9.709, 9.71, 9.710999999999999, 9.712, 9.713000000000001, 9.713999999999999, 9.715, 9.716000000000001, 9.716999999999999, 9.718, 9.719000000000001, 9.719999999999999, 9.721, 9.722000000000001, 9.722999999999999, 9.724, 9.725000000000001, 9.725999999999999, 9.727, 9.728000000000002, 9.729, 9.73, 9.731000000000002, 9.732, 9.733, 9.734000000000002, 9.735, 9.736, 9.736999999999998, 9.738, 9.739, 9.739999999999998, 9.741, 9.742, 9.742999999999999, 9.744, 9.745000000000001, 9.745999999999999, 9.747, 9.748000000000001, 9.748999999999999, 9.75, 9.751000000000001, 9.751999999999999, 9.753, 9.754000000000001, 9.754999999999999, 9.756, 9.757000000000001, 9.758, 9.759, 9.760000000000002, 9.761, 9.762, 9.763000000000002, 9.764, 9.765, 9.765999999999998, 9.767, 9.768, 9.768999999999998, 9.77, 9.771, 9.771999999999998, 9.773, 9.774000000000001, 9.774999999999999, 9.776, 9.777000000000001, 9.777999999999999, 9.779, 9.780000000000001, 9.780999999999999, 9.782, 9.783000000000001, 9.783999999999999, 9.785, 9.786000000000001, 9.786999999999999, 9.788, 9.789000000000001, 9.79, 9.791, 9.792000000000002, 9.793, 9.794, 9.795000000000002, 9.796, 9.797, 9.797999999999998, 9.799, 9.8, 9.800999999999998, 9.802, 9.803, 9.803999999999998, 9.805, 9.806000000000001, 9.806999999999999, 9.808, 9.809000000000001, 9.809999999999999, 9.811, 9.812000000000001, 9.812999999999999, 9.814, 9.815000000000001, 9.815999999999999, 9.817, 9.818000000000001, 9.818999999999999, 9.82, 9.821000000000002, 9.822, 9.823, 9.824000000000002, 9.825, 9.826, 9.827000000000002, 9.828, 9.829, 9.829999999999998, 9.831, 9.832, 9.832999999999998, 9.834, 9.835, 9.835999999999999, 9.837, 9.838000000000001, 9.838999999999999, 9.84, 9.841000000000001, 9.841999999999999, 9.843, 9.844000000000001, 9.844999999999999, 9.846, 9.847000000000001, 9.847999999999999, 9.849, 9.850000000000001, 9.850999999999999, 9.852, 9.853000000000002, 9.854, 9.855, 9.856000000000002, 9.857, 9.858, 9.859000000000002, 9.86, 9.861, 9.861999999999998, 9.863, 9.864, 9.864999999999998, 9.866, 9.867, 9.867999999999999, 9.869, 9.870000000000001, 9.870999999999999, 9.872, 9.873000000000001, 9.873999999999999, 9.875, 9.876000000000001, 9.876999999999999, 9.878, 9.879000000000001, 9.879999999999999, 9.881, 9.882000000000001, 9.883, 9.884, 9.885000000000002, 9.886, 9.887, 9.888000000000002, 9.889, 9.89, 9.890999999999998, 9.892, 9.893, 9.893999999999998, 9.895, 9.896, 9.896999999999998, 9.898, 9.899000000000001, 9.899999999999999, 9.901, 9.902000000000001, 9.902999999999999, 9.904, 9.905000000000001, 9.905999999999999, 9.907, 9.908000000000001, 9.908999999999999, 9.91, 9.911000000000001, 9.911999999999999, 9.913, 9.914000000000001, 9.915, 9.916, 9.917000000000002, 9.918, 9.919, 9.920000000000002, 9.921, 9.922, 9.922999999999998, 9.924, 9.925, 9.925999999999998, 9.927, 9.928, 9.928999999999998, 9.93, 9.931000000000001, 9.931999999999999, 9.933, 9.934000000000001, 9.934999999999999, 9.936, 9.937000000000001, 9.937999999999999, 9.939, 9.940000000000001, 9.940999999999999, 9.942, 9.943000000000001, 9.943999999999999, 9.945, 9.946000000000002, 9.947, 9.948, 9.949000000000002, 9.95, 9.951, 9.952000000000002, 9.953, 9.954, 9.954999999999998, 9.956, 9.957, 9.957999999999998, 9.959, 9.96, 9.960999999999999, 9.962, 9.963000000000001, 9.963999999999999, 9.965, 9.966000000000001, 9.966999999999999, 9.968, 9.969000000000001, 9.969999999999999, 9.971, 9.972000000000001, 9.972999999999999, 9.974, 9.975000000000001, 9.975999999999999, 9.977, 9.978000000000002, 9.979, 9.98, 9.981000000000002, 9.982, 9.983, 9.984000000000002, 9.985, 9.986, 9.986999999999998, 9.988, 9.989, 9.989999999999998, 9.991, 9.992, 9.992999999999999, 9.994, 9.995000000000001, 9.995999999999999, 9.997, 9.998000000000001, 9.998999999999999];
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::*;
+use super::accuracy;
+
+macro_rules! test_activations {
+    ($t:ident, $x:ident, saturated: $saturated:expr) => {
+        #[test]
+        fn sigmoid_newton_saturates_for_large_magnitudes() {
+            for x in [$saturated, 1e10, $t::INFINITY] {
+                let low = (-x).fast_sigmoid_newton::<2>();
+                assert!((0.0..1e-30).contains(&low), "fast_sigmoid_newton({}) = {}", -x, low);
+                let high = x.fast_sigmoid_newton::<2>();
+                assert!((high - 1.0).abs() < 1e-4, "fast_sigmoid_newton({}) = {}", x, high);
+            }
+        }
+
+        #[test]
+        fn activations_saturate_and_propagate_nan() {
+            // Far enough out that fast_sigmoid and fast_exp on their own stop producing valid bits
+            for x in [-1000.0 as $t, -1e10, $t::NEG_INFINITY] {
+                assert!(x.fast_gelu().abs() < 1e-30, "fast_gelu({}) = {}", x, x.fast_gelu());
+                assert!(x.fast_silu().abs() < 1e-30, "fast_silu({}) = {}", x, x.fast_silu());
+                assert!(x.fast_softplus().abs() < 1e-30, "fast_softplus({}) = {}", x, x.fast_softplus());
+                assert!(x.fast_mish().abs() < 1e-30, "fast_mish({}) = {}", x, x.fast_mish());
+                assert_same_float!(x.fast_elu(0.5), -0.5 as $t);
+            }
+            for x in [1000.0 as $t, 1e10] {
+                assert_same_float!(x.fast_gelu(), x);
+                assert_same_float!(x.fast_silu(), x);
+                assert_same_float!(x.fast_softplus(), x);
+                assert_same_float!(x.fast_mish(), x);
+                assert_same_float!(x.fast_elu(0.5), x);
+            }
+            assert_same_float!($t::INFINITY.fast_softplus(), $t::INFINITY);
+            assert_same_float!($t::INFINITY.fast_mish(), $t::INFINITY);
+            assert_same_float!((0.0 as $t).fast_gelu_erf(), 0.0 as $t);
+            for activation in [$t::fast_gelu, $t::fast_gelu_erf, $t::fast_silu, $t::fast_softplus, $t::fast_mish] {
+                assert_same_float!(activation($t::NAN), $t::NAN);
+            }
+            assert_same_float!($t::NAN.fast_elu(1.0), $t::NAN);
+        }
+
+        #[test]
+        fn activation_slices_and_parallel_match_scalars() {
+            let x = accuracy::$t::$x.to_vec();
+            fn check(scalar: fn($t) -> $t, slice: fn(&[$t]) -> Vec<$t>, parallel: fn(&[$t]) -> Vec<$t>, input: &[$t]) {
+                for batch in [slice(input), parallel(input)] {
+                    assert_eq!(batch.len(), input.len());
+                    for (a, &b) in batch.into_iter().zip(input) {
+                        assert_same_float!(a, scalar(b));
+                    }
+                }
+            }
+            check($t::fast_gelu, <[$t]>::fast_gelu, <[$t]>::par_gelu, &x);
+            check($t::fast_gelu_erf, <[$t]>::fast_gelu_erf, <[$t]>::par_gelu_erf, &x);
+            check($t::fast_silu, <[$t]>::fast_silu, <[$t]>::par_silu, &x);
+            check($t::fast_softplus, <[$t]>::fast_softplus, <[$t]>::par_softplus, &x);
+            check($t::fast_mish, <[$t]>::fast_mish, <[$t]>::par_mish, &x);
+            // A Vec reaches the slice impls through deref
+            for batch in [x.fast_elu(0.5), x.par_elu(0.5)] {
+                assert_eq!(batch.len(), x.len());
+                for (a, &b) in batch.into_iter().zip(&x) {
+                    assert_same_float!(a, b.fast_elu(0.5));
+                }
+            }
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+
+    test_activations!(f64, X_F64, saturated: 1000.0);
+}
+
+mod f32 {
+    use super::*;
+
+    test_activations!(f32, X_F32, saturated: 100.0);
+}
@@ -0,0 +1,100 @@
+use crate::*;
+use super::accuracy;
+use rayon::iter::ParallelIterator;
+
+macro_rules! test_bessel {
+    ($t:ident, $x:ident, j0_zeros: $j0_zeros:expr, j1_zeros: $j1_zeros:expr, envelope_tolerance: $envelope_tolerance:expr, i0_tolerance: $i0_tolerance:expr, i0_max: $i0_max:expr) => {
+        #[test]
+        fn bessel_special_values_and_symmetry() {
+            assert_same_float!((0.0 as $t).fast_y0(), $t::NEG_INFINITY);
+            assert_same_float!((-1.0 as $t).fast_y0(), $t::NAN);
+            assert_same_float!($t::INFINITY.fast_j0(), 0.0 as $t);
+            assert_same_float!($t::NEG_INFINITY.fast_j1(), -0.0 as $t);
+            assert_same_float!($t::INFINITY.fast_y0(), 0.0 as $t);
+            assert_same_float!($t::NEG_INFINITY.fast_i0(), $t::INFINITY);
+            assert_eq!((0.0 as $t).fast_j1(), 0.0);
+            for f in [$t::fast_j0, $t::fast_j1, $t::fast_y0, $t::fast_i0] {
+                assert_same_float!(f($t::NAN), $t::NAN);
+            }
+            for &x in accuracy::$t::$x.iter() {
+                assert_same_float!((-x).fast_j0(), x.fast_j0());
+                assert_same_float!((-x).fast_j1(), -x.fast_j1());
+                assert_same_float!((-x).fast_i0(), x.fast_i0());
+            }
+        }
+
+        #[test]
+        fn bessel_relative_error_near_zeros() {
+            // The small-argument forms factor out the zeros, so the relative error stays small next to them
+            let relative_error = |fast: $t, exact: $t| ((fast - exact) / exact).abs();
+            for zero in $j0_zeros {
+                for x in [zero * (1.0 - 1e-4), zero * (1.0 + 1e-4)] {
+                    let error = relative_error(x.fast_j0(), accuracy::exact::$t::j0(x));
+                    assert!(error < 1e-5, "relative error of fast_j0({}) is {}", x, error);
+                }
+            }
+            for zero in $j1_zeros {
+                for x in [zero * (1.0 - 1e-4), zero * (1.0 + 1e-4)] {
+                    let error = relative_error(x.fast_j1(), accuracy::exact::$t::j1(x));
+                    assert!(error < 1e-5, "relative error of fast_j1({}) is {}", x, error);
+                }
+            }
+        }
+
+        #[test]
+        fn bessel_large_arguments() {
+            // Geometric samples from the asymptotic threshold up to 1e4, where J0, J1 and Y0 oscillate within an
+            // envelope of sqrt(2 / (pi x)), so the error is measured against that
+            let x = (0..=200).map(|i| 8.0 * (1250.0 as $t).powf(i as $t / 200.0)).collect::<Vec<$t>>();
+            for (f, exact) in [
+                ($t::fast_j0 as fn($t) -> $t, accuracy::exact::$t::j0 as fn($t) -> $t),
+                ($t::fast_j1, accuracy::exact::$t::j1),
+                ($t::fast_y0, accuracy::exact::$t::y0),
+            ] {
+                let max_error = x.iter().map(|&x| (f(x) - exact(x)).abs() / (2.0 / (consts::PI * x)).sqrt()).fold(0.0, $t::max);
+                assert!(max_error < $envelope_tolerance, "max error relative to the envelope is {}", max_error);
+            }
+            // I0 keeps the accuracy of its polynomial through the switch to the asymptotic series
+            let max_relative_error = x.iter().map(|&x| ((x.fast_i0() - accuracy::exact::$t::i0(x)) / accuracy::exact::$t::i0(x)).abs())
+                .filter(|error| !error.is_nan())
+                .fold(0.0, $t::max);
+            assert!(max_relative_error < $i0_tolerance, "max relative error of fast_i0 is {}", max_relative_error);
+            assert!(($i0_max as $t).fast_i0().is_finite());
+            assert_same_float!(($i0_max as $t + 1.0).fast_i0(), $t::INFINITY);
+        }
+
+        #[test]
+        fn bessel_lookup_matches_functions() {
+            let j1 = crate::lookup::BesselLookupTable::<$t>::j1(10.0);
+            let x = accuracy::$t::$x.to_vec();
+            let max_error = x.iter().map(|&x| (j1.lookup(x) - accuracy::exact::$t::j1(x)).abs()).fold(0.0, $t::max);
+            // Half a table step times the steepest slope of J1, which is 1/2 at zero
+            assert!(max_error < 3e-3, "max error of BesselLookupTable::j1 is {}", max_error);
+            for &x in &x {
+                assert_same_float!(j1.lookup(-x), -j1.lookup(x));
+            }
+            // Past the table, the lookups fall back to the functions
+            assert_same_float!(j1.lookup(-12.5), (-12.5 as $t).fast_j1());
+            assert_same_float!(j1.lookup($t::NAN), $t::NAN);
+            let i0 = crate::lookup::BesselLookupTable::<$t>::i0(10.0);
+            assert_same_float!(i0.lookup(-20.0), (20.0 as $t).fast_i0());
+            for (a, b) in i0.par_map_lookups(x.clone()).collect::<Vec<$t>>().into_iter().zip(i0.map_lookups(x)) {
+                assert_same_float!(a, b);
+            }
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+    use std::f64::consts;
+
+    test_bessel!(f64, X_F64, j0_zeros: [2.404825557695773, 5.520078110286311], j1_zeros: [3.8317059702075125, 7.015586669815619], envelope_tolerance: 1e-7, i0_tolerance: 1e-7, i0_max: 713.0);
+}
+
+mod f32 {
+    use super::*;
+    use std::f32::consts;
+
+    test_bessel!(f32, X_F32, j0_zeros: [2.4048254, 5.520078], j1_zeros: [3.831706, 7.015587], envelope_tolerance: 3e-5, i0_tolerance: 1e-5, i0_max: 91.0);
+}
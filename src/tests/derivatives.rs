@@ -0,0 +1,111 @@
+use crate::*;
+use super::accuracy;
+use super::accuracy::exact;
+
+mod f64 {
+    use super::*;
+    use accuracy::f64::X_F64;
+
+    // Central differences of the exact functions, checked to within the accuracy of each forward approximation,
+    // which is relative for exp and absolute for the bounded functions
+    fn assert_matches_finite_difference(
+        name: &str, with_grad: fn(f64) -> (f64, f64), forward: fn(f64) -> f64, exact: fn(f64) -> f64, x: &[f64],
+        relative_tolerance: f64, absolute_tolerance: f64,
+    ) {
+        const STEP: f64 = 1e-6;
+        for &x in x {
+            let (y, dy) = with_grad(x);
+            assert!(y == forward(x), "{}_with_grad({}) returned {} as the value, expected {}", name, x, y, forward(x));
+            let finite_difference = (exact(x + STEP) - exact(x - STEP)) / (2.0 * STEP);
+            let tolerance = relative_tolerance * finite_difference.abs() + absolute_tolerance;
+            assert!(
+                (dy - finite_difference).abs() <= tolerance,
+                "{}_with_grad({}) returned {} as the derivative, expected {}", name, x, dy, finite_difference
+            );
+        }
+    }
+
+    #[test]
+    fn exp_with_grad() {
+        assert_matches_finite_difference("fast_exp", f64::fast_exp_with_grad, f64::fast_exp, f64::exp, &X_F64, 0.045, 0.0);
+    }
+
+    #[test]
+    fn sin_with_grad() {
+        assert_matches_finite_difference("fast_sin", f64::fast_sin_with_grad, f64::fast_sin, f64::sin, &X_F64, 0.0, 0.013);
+    }
+
+    #[test]
+    fn cos_with_grad() {
+        assert_matches_finite_difference("fast_cos", f64::fast_cos_with_grad, f64::fast_cos, f64::cos, &X_F64, 0.0, 0.013);
+    }
+
+    #[test]
+    fn tanh_with_grad() {
+        assert_matches_finite_difference("fast_tanh", f64::fast_tanh_with_grad, f64::fast_tanh, f64::tanh, &X_F64, 0.0, 0.02);
+    }
+
+    #[test]
+    fn sigmoid_with_grad() {
+        assert_matches_finite_difference("fast_sigmoid", f64::fast_sigmoid_with_grad, f64::fast_sigmoid, exact::f64::sigmoid, &X_F64, 0.0, 0.005);
+    }
+
+    #[test]
+    fn softplus_with_grad() {
+        assert_matches_finite_difference("fast_softplus", f64::fast_softplus_with_grad, f64::fast_softplus, exact::f64::softplus, &X_F64, 0.0, 0.015);
+    }
+}
+
+mod f32 {
+    use super::*;
+    use accuracy::f32::X_F32;
+
+    // Central differences of the exact functions, checked to within the accuracy of each forward approximation,
+    // which is relative for exp and absolute for the bounded functions
+    fn assert_matches_finite_difference(
+        name: &str, with_grad: fn(f32) -> (f32, f32), forward: fn(f32) -> f32, exact: fn(f32) -> f32, x: &[f32],
+        relative_tolerance: f32, absolute_tolerance: f32,
+    ) {
+        const STEP: f32 = 1e-2;
+        for &x in x {
+            let (y, dy) = with_grad(x);
+            assert!(y == forward(x), "{}_with_grad({}) returned {} as the value, expected {}", name, x, y, forward(x));
+            let finite_difference = (exact(x + STEP) - exact(x - STEP)) / (2.0 * STEP);
+            let tolerance = relative_tolerance * finite_difference.abs() + absolute_tolerance;
+            assert!(
+                (dy - finite_difference).abs() <= tolerance,
+                "{}_with_grad({}) returned {} as the derivative, expected {}", name, x, dy, finite_difference
+            );
+        }
+    }
+
+    #[test]
+    fn exp_with_grad() {
+        assert_matches_finite_difference("fast_exp", f32::fast_exp_with_grad, f32::fast_exp, f32::exp, &X_F32, 0.045, 0.0);
+    }
+
+    #[test]
+    fn sin_with_grad() {
+        assert_matches_finite_difference("fast_sin", f32::fast_sin_with_grad, f32::fast_sin, f32::sin, &X_F32, 0.0, 0.013);
+    }
+
+    #[test]
+    fn cos_with_grad() {
+        assert_matches_finite_difference("fast_cos", f32::fast_cos_with_grad, f32::fast_cos, f32::cos, &X_F32, 0.0, 0.013);
+    }
+
+    #[test]
+    fn tanh_with_grad() {
+        assert_matches_finite_difference("fast_tanh", f32::fast_tanh_with_grad, f32::fast_tanh, f32::tanh, &X_F32, 0.0, 0.02);
+    }
+
+    #[test]
+    fn sigmoid_with_grad() {
+        assert_matches_finite_difference("fast_sigmoid", f32::fast_sigmoid_with_grad, f32::fast_sigmoid, exact::f32::sigmoid, &X_F32, 0.0, 0.005);
+    }
+
+    #[test]
+    fn softplus_with_grad() {
+        assert_matches_finite_difference("fast_softplus", f32::fast_softplus_with_grad, f32::fast_softplus, exact::f32::softplus, &X_F32, 0.0, 0.015);
+    }
+}
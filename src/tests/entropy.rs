@@ -0,0 +1,60 @@
+use crate::*;
+use super::accuracy;
+
+macro_rules! test_entropy {
+    ($t:ident, $x:ident) => {
+        #[test]
+        fn entropy_zero_probabilities() {
+            let inf = $t::INFINITY;
+            // 0 * ln(0) counts as zero, so certain outcomes carry no information
+            assert_eq!([0.0 as $t, 1.0, 0.0].fast_entropy(), 0.0);
+            assert!(([0.25 as $t; 4].fast_entropy() - (4.0 as $t).ln()).abs() < 1e-4);
+            let (p, q) = ([0.5 as $t, 0.5, 0.0], [0.25 as $t, 0.25, 0.5]);
+            assert!((p.fast_cross_entropy(&q) - (4.0 as $t).ln()).abs() < 1e-4);
+            assert!((p.fast_kl_divergence(&q) - (2.0 as $t).ln()).abs() < 1e-4);
+            assert_eq!(p.fast_kl_divergence(&p), 0.0);
+            // Outcomes that q rules out but p doesn't are infinitely surprising
+            assert_same_float!(q.fast_cross_entropy(&p), inf);
+            assert_same_float!(q.fast_kl_divergence(&p), inf);
+            assert_eq!([1.0 as $t, 0.0].fast_binary_cross_entropy(&[1.0, 0.0]), 0.0);
+            assert_same_float!([1.0 as $t, 0.0].fast_binary_cross_entropy(&[0.0, 0.0]), inf);
+            assert!(([1.0 as $t, 0.0].fast_binary_cross_entropy(&[0.5, 0.5]) - (2.0 as $t).ln()).abs() < 1e-4);
+            let empty: [$t; 0] = [];
+            assert_same_float!(empty.fast_binary_cross_entropy(&empty), 0.0 as $t);
+            assert_same_float!(empty.par_binary_cross_entropy(&empty), 0.0 as $t);
+            assert_same_float!([0.5 as $t, $t::NAN].fast_entropy(), $t::NAN);
+            assert_same_float!([0.0 as $t].fast_cross_entropy(&[$t::NAN]), $t::NAN);
+        }
+
+        #[test]
+        fn entropy_parallel_matches_sequential() {
+            let p = accuracy::exact::$t::softmax(&accuracy::$t::$x);
+            let q = p.iter().rev().copied().collect::<Vec<$t>>();
+            let y = accuracy::$t::$x.iter().map(|&x| accuracy::exact::$t::sigmoid(x)).collect::<Vec<$t>>();
+            // Only the order of the summation differs
+            let close = |a: $t, b: $t| (a - b).abs() <= 1e-4 * b.abs();
+            assert!(close(p.par_entropy(), p.fast_entropy()));
+            assert!(close(p.par_cross_entropy(&q), p.fast_cross_entropy(&q)));
+            assert!(close(p.par_kl_divergence(&q), p.fast_kl_divergence(&q)));
+            assert!(close(y.par_binary_cross_entropy(&q), y.fast_binary_cross_entropy(&q)));
+        }
+
+        #[test]
+        #[should_panic]
+        fn kl_divergence_rejects_mismatched_lengths() {
+            [0.5 as $t, 0.5].fast_kl_divergence(&[1.0]);
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+
+    test_entropy!(f64, X_F64);
+}
+
+mod f32 {
+    use super::*;
+
+    test_entropy!(f32, X_F32);
+}
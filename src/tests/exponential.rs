@@ -0,0 +1,117 @@
+use crate::*;
+use super::accuracy;
+
+macro_rules! test_exponential {
+    ($t:ident, $x:ident, expm1_tolerance: $expm1_tolerance:expr, log1p_tolerance: $log1p_tolerance:expr) => {
+        #[test]
+        fn expm1_log1p_relative_error_near_zero() {
+            assert_same_float!((0.0 as $t).fast_expm1(), 0.0 as $t);
+            assert_same_float!((-0.0 as $t).fast_log1p(), -0.0 as $t);
+            assert_same_float!($t::NEG_INFINITY.fast_expm1(), -1.0 as $t);
+            assert_same_float!($t::NAN.fast_expm1(), $t::NAN);
+            assert_same_float!((-1.0 as $t).fast_log1p(), $t::NEG_INFINITY);
+            assert_same_float!((-2.0 as $t).fast_log1p(), $t::NAN);
+            assert_same_float!($t::INFINITY.fast_log1p(), $t::INFINITY);
+            // Unlike fast_exp(x) - 1 and fast_ln(1 + x), neither loses relative accuracy as x -> 0
+            let x = (-1200..=-300).map(|e| (10.0 as $t).powf(e as $t / 100.0)).flat_map(|x| [x, -x]).collect::<Vec<$t>>();
+            let max_relative_error = |f: fn($t) -> $t, exact: fn($t) -> $t| {
+                x.iter().map(|&x| ((f(x) - exact(x)) / exact(x)).abs()).fold(0.0, $t::max)
+            };
+            let expm1_error = max_relative_error($t::fast_expm1, accuracy::exact::$t::expm1);
+            let log1p_error = max_relative_error($t::fast_log1p, accuracy::exact::$t::log1p);
+            assert!(expm1_error < $expm1_tolerance, "max relative error of fast_expm1 near zero is {}", expm1_error);
+            assert!(log1p_error < $log1p_tolerance, "max relative error of fast_log1p near zero is {}", log1p_error);
+        }
+
+        #[test]
+        fn decibel_vec_and_parallel_match_scalars() {
+            let db = accuracy::$t::$x.iter().map(|&x| 10.0 * x).collect::<Vec<$t>>();
+            let linear = accuracy::$t::$x.iter().map(|&x| x.abs()).collect::<Vec<$t>>();
+            fn check(scalar: fn($t) -> $t, vec: fn(Vec<$t>) -> Vec<$t>, parallel: fn(Vec<$t>) -> Vec<$t>, input: &[$t]) {
+                let expected = input.iter().map(|&x| scalar(x)).collect::<Vec<$t>>();
+                for (a, b) in vec(input.to_vec()).into_iter().zip(&expected) {
+                    assert_same_float!(a, *b);
+                }
+                for (a, b) in parallel(input.to_vec()).into_iter().zip(&expected) {
+                    assert_same_float!(a, *b);
+                }
+            }
+            check($t::db_to_gain, Vec::<$t>::db_to_gain, Vec::<$t>::par_db_to_gain, &db);
+            check($t::gain_to_db, Vec::<$t>::gain_to_db, Vec::<$t>::par_gain_to_db, &linear);
+            check($t::db_to_power, Vec::<$t>::db_to_power, Vec::<$t>::par_db_to_power, &db);
+            check($t::power_to_db, Vec::<$t>::power_to_db, Vec::<$t>::par_power_to_db, &linear);
+            assert_same_float!((0.0 as $t).gain_to_db(), $t::NEG_INFINITY);
+            assert_same_float!((-1.0 as $t).power_to_db(), $t::NAN);
+        }
+
+        #[test]
+        fn log_domain_extremes() {
+            let inf = $t::INFINITY;
+            assert_same_float!((-inf).fast_log_add_exp(-inf), -inf);
+            assert_same_float!((3.0 as $t).fast_log_add_exp(-inf), 3.0 as $t);
+            assert_same_float!((-inf).fast_log_add_exp(-3.0), -3.0 as $t);
+            assert_same_float!(inf.fast_log_add_exp(inf), inf);
+            assert_same_float!(inf.fast_log_add_exp(-inf), inf);
+            assert_same_float!($t::NAN.fast_log_add_exp(inf), $t::NAN);
+            // Wildly different magnitudes must neither overflow nor lose the larger term
+            assert_same_float!((80.0 as $t).fast_log_add_exp(-80.0), 80.0 as $t);
+            assert_same_float!((1e30 as $t).fast_log_add_exp(-1e30), 1e30 as $t);
+            assert_same_float!((-1e30 as $t).fast_log_add_exp(-5e29), -5e29 as $t);
+            assert!(((1000.0 as $t).fast_log_add_exp(1000.0) - (1000.0 + consts::LN_2)).abs() < 0.05);
+            // -inf terms add exactly nothing, even next to zero where MIN_POSITIVE would show
+            assert_same_float!((0.0 as $t).fast_log_add_exp(-inf), 0.0 as $t);
+            assert_same_float!((-inf).fast_log_add_exp(0.0), 0.0 as $t);
+            assert_same_float!((0.0 as $t).fast_log_sub_exp(-inf), 0.0 as $t);
+
+            assert_same_float!((3.0 as $t).fast_log_sub_exp(-inf), 3.0 as $t);
+            assert_same_float!((3.0 as $t).fast_log_sub_exp(3.0), -inf);
+            assert_same_float!((-inf).fast_log_sub_exp(-inf), -inf);
+            assert_same_float!(inf.fast_log_sub_exp(3.0), inf);
+            assert_same_float!(inf.fast_log_sub_exp(inf), $t::NAN);
+            assert_same_float!((2.0 as $t).fast_log_sub_exp(3.0), $t::NAN);
+            assert_same_float!((1e30 as $t).fast_log_sub_exp(-1e30), 1e30 as $t);
+            // Nearly equal arguments, where exp(a) - exp(b) cancels completely
+            for d in [1e-3 as $t, 1e-5, 1e-7] {
+                let exact = accuracy::exact::$t::log_sub_exp(1.0, 1.0 - d);
+                let fast = (1.0 as $t).fast_log_sub_exp(1.0 - d);
+                assert!((fast / exact - 1.0).abs() < 1e-3, "fast_log_sub_exp(1, 1 - {}) = {}, expected {}", d, fast, exact);
+            }
+
+            let empty: [$t; 0] = [];
+            assert_same_float!(empty.fast_logsumexp(), -inf);
+            assert_same_float!(empty.par_logsumexp(), -inf);
+            assert_same_float!([-7.5 as $t].fast_logsumexp(), -7.5 as $t);
+            assert_same_float!([-inf, -inf].fast_logsumexp(), -inf);
+            assert_same_float!([1.0, inf, -inf].fast_logsumexp(), inf);
+            assert_same_float!([1.0, inf, $t::NAN].fast_logsumexp(), $t::NAN);
+            assert_same_float!([$t::NAN, inf, 1.0].par_logsumexp(), $t::NAN);
+            assert_same_float!([1e30, -1e30, -inf].fast_logsumexp(), 1e30 as $t);
+            assert_same_float!([0.0, -inf].fast_logsumexp(), 0.0 as $t);
+            assert_same_float!([-inf, 0.0, -inf].par_logsumexp(), 0.0 as $t);
+        }
+
+        #[test]
+        fn logsumexp_vec_and_parallel_agree() {
+            let x = accuracy::$t::$x.iter().map(|&x| 50.0 * x).collect::<Vec<$t>>();
+            let exact = accuracy::exact::$t::logsumexp(&x);
+            let (sequential, parallel) = (x.fast_logsumexp(), x.par_logsumexp());
+            assert!((sequential - exact).abs() < 0.05, "fast_logsumexp = {}, expected {}", sequential, exact);
+            // Only the order of the summation differs
+            assert!((parallel - sequential).abs() <= 1e-3 * sequential.abs(), "par_logsumexp = {}, fast_logsumexp = {}", parallel, sequential);
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+    use std::f64::consts;
+
+    test_exponential!(f64, X_F64, expm1_tolerance: 1e-9, log1p_tolerance: 1e-6);
+}
+
+mod f32 {
+    use super::*;
+    use std::f32::consts;
+
+    test_exponential!(f32, X_F32, expm1_tolerance: 1e-6, log1p_tolerance: 1e-4);
+}
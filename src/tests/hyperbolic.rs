@@ -0,0 +1,58 @@
+use crate::*;
+use super::accuracy;
+
+macro_rules! test_hyperbolic {
+    ($t:ident) => {
+        #[test]
+        fn hyperbolic_zero_and_saturation() {
+            assert_same_float!((0.0 as $t).fast_sinh(), 0.0 as $t);
+            assert_same_float!((-0.0 as $t).fast_tanh(), -0.0 as $t);
+            assert_same_float!((1000.0 as $t).fast_tanh(), 1.0 as $t);
+            assert_same_float!((-1000.0 as $t).fast_tanh(), -1.0 as $t);
+            assert_same_float!($t::NAN.fast_tanh(), $t::NAN);
+        }
+
+        #[test]
+        fn inverse_hyperbolic_relative_error() {
+            assert_same_float!((0.0 as $t).fast_asinh(), 0.0 as $t);
+            assert_same_float!((-0.0 as $t).fast_atanh(), -0.0 as $t);
+            assert_same_float!((1.0 as $t).fast_acosh(), 0.0 as $t);
+            assert_same_float!($t::NEG_INFINITY.fast_asinh(), $t::NEG_INFINITY);
+            assert_same_float!($t::INFINITY.fast_acosh(), $t::INFINITY);
+            assert_same_float!((-1.0 as $t).fast_atanh(), $t::NEG_INFINITY);
+            assert_same_float!((0.5 as $t).fast_acosh(), $t::NAN);
+            assert_same_float!((1.5 as $t).fast_atanh(), $t::NAN);
+            for f in [$t::fast_asinh, $t::fast_acosh, $t::fast_atanh] {
+                assert_same_float!(f($t::NAN), $t::NAN);
+            }
+            // Geometric samples from the smallest normal numbers up to the largest
+            let geometric = (0..=2000).map(|i| $t::MIN_POSITIVE.powf(1.0 - i as $t / 1000.0)).filter(|x| x.is_finite()).collect::<Vec<$t>>();
+            let max_relative_error = |f: fn($t) -> $t, exact: fn($t) -> $t, x: &[$t]| {
+                x.iter().map(|&x| ((f(x) - exact(x)) / exact(x)).abs()).fold(0.0, $t::max)
+            };
+            let asinh_x = geometric.iter().flat_map(|&x| [x, -x]).collect::<Vec<$t>>();
+            // Down to 1 + t with t as small as the format allows, where acosh(1 + t) ~ sqrt(2t)
+            let acosh_x = geometric.iter().map(|&t| 1.0 + t).filter(|&x| x > 1.0).collect::<Vec<$t>>();
+            // Both near zero and up to the poles at +-1
+            let atanh_x = geometric.iter().filter(|&&x| x < 1.0).flat_map(|&x| [x, 1.0 - x, -x, x - 1.0]).filter(|x| x.abs() < 1.0).collect::<Vec<$t>>();
+            let asinh_error = max_relative_error($t::fast_asinh, accuracy::exact::$t::asinh, &asinh_x);
+            let acosh_error = max_relative_error($t::fast_acosh, accuracy::exact::$t::acosh, &acosh_x);
+            let atanh_error = max_relative_error($t::fast_atanh, accuracy::exact::$t::atanh, &atanh_x);
+            assert!(asinh_error < 1e-3, "max relative error of fast_asinh is {}", asinh_error);
+            assert!(acosh_error < 1e-3, "max relative error of fast_acosh is {}", acosh_error);
+            assert!(atanh_error < 1e-3, "max relative error of fast_atanh is {}", atanh_error);
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+
+    test_hyperbolic!(f64);
+}
+
+mod f32 {
+    use super::*;
+
+    test_hyperbolic!(f32);
+}
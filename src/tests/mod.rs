@@ -1,3 +1,27 @@
 #![cfg(test)]
+
+macro_rules! assert_same_float {
+    ($actual:expr, $expected:expr) => {
+        let (actual, expected) = ($actual, $expected);
+        assert!(
+            (actual.is_nan() && expected.is_nan())
+                || (actual == expected && actual.is_sign_negative() == expected.is_sign_negative()),
+            "{} returned {:?}, expected {:?}", stringify!($actual), actual, expected
+        );
+    };
+}
+
 mod accuracy;
-mod tolerance;
\ No newline at end of file
+mod activations;
+mod bessel;
+mod derivatives;
+mod entropy;
+mod exponential;
+mod hyperbolic;
+mod normal;
+mod powers;
+mod rounding;
+mod softmax;
+mod special;
+mod tolerance;
+mod trigonometry;
\ No newline at end of file
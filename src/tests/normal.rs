@@ -0,0 +1,74 @@
+use crate::*;
+use crate::stats::*;
+use super::accuracy;
+
+macro_rules! test_normal {
+    ($t:ident, $x:ident, pdf_zero: $pdf_zero:expr, pdf_last: $pdf_last:expr, softplus_zero: $softplus_zero:expr, softplus_last: $softplus_last:expr) => {
+        #[test]
+        fn normal_probit_relative_error_in_tails() {
+            assert_same_float!((0.0 as $t).normal_probit(), $t::NEG_INFINITY);
+            assert_same_float!((1.0 as $t).normal_probit(), $t::INFINITY);
+            assert_same_float!((0.5 as $t).normal_probit(), 0.0 as $t);
+            for p in [-0.5 as $t, 1.5, $t::NAN] {
+                assert_same_float!(p.normal_probit(), $t::NAN);
+            }
+            // Geometric samples from 0.5 all the way down to MIN_POSITIVE, mirrored into the upper tail
+            // wherever 1 - q is still representable
+            let q = (1..=600).map(|i| 0.5 * $t::MIN_POSITIVE.powf(i as $t / 600.0)).collect::<Vec<$t>>();
+            let mut max_relative_error: $t = 0.0;
+            for p in q.iter().copied().chain(q.iter().map(|&q| 1.0 - q).filter(|&p| p < 1.0)) {
+                let exact = accuracy::exact::$t::normal_probit(p);
+                max_relative_error = max_relative_error.max((p.normal_probit() / exact - 1.0).abs());
+            }
+            assert!(max_relative_error < 1e-4, "max relative error of normal_probit in the tails is {}", max_relative_error);
+        }
+
+        #[test]
+        fn normal_vecs_and_parallel_match_scalars() {
+            let x = accuracy::$t::$x.to_vec();
+            let p = x.iter().map(|&x| 0.5 + x / 20.0).collect::<Vec<$t>>();
+            fn check(scalar: fn($t) -> $t, vec: fn(Vec<$t>) -> Vec<$t>, parallel: fn(Vec<$t>) -> Vec<$t>, input: &[$t]) {
+                for (a, &b) in vec(input.to_vec()).into_iter().zip(input) {
+                    assert_same_float!(a, scalar(b));
+                }
+                for (a, &b) in parallel(input.to_vec()).into_iter().zip(input) {
+                    assert_same_float!(a, scalar(b));
+                }
+            }
+            check($t::normal_pdf, Vec::<$t>::normal_pdf, Vec::<$t>::par_normal_pdf, &x);
+            check($t::normal_log_pdf, Vec::<$t>::normal_log_pdf, Vec::<$t>::par_normal_log_pdf, &x);
+            check($t::normal_cdf, Vec::<$t>::normal_cdf, Vec::<$t>::par_normal_cdf, &x);
+            check($t::normal_probit, Vec::<$t>::normal_probit, Vec::<$t>::par_normal_probit, &p);
+        }
+
+        #[test]
+        fn normal_pdf_and_softplus_decay_to_zero_in_far_tails() {
+            // Past the point where fast_exp clips, both follow exp(x) down through the subnormals to zero
+            for x in [$pdf_zero, $t::INFINITY] {
+                assert_same_float!(x.normal_pdf(), 0.0 as $t);
+                assert_same_float!((-x).normal_pdf(), 0.0 as $t);
+            }
+            for x in [$softplus_zero, $t::NEG_INFINITY] {
+                assert_same_float!(x.fast_softplus(), 0.0 as $t);
+            }
+            let (before, last): ($t, $t) = $pdf_last;
+            let pdf = last.normal_pdf();
+            assert!(pdf > 0.0 && pdf < before.normal_pdf(), "normal_pdf({}) = {}", last, pdf);
+            let last: $t = $softplus_last;
+            let softplus = last.fast_softplus();
+            assert!(softplus > 0.0 && softplus == last.exp(), "fast_softplus({}) = {}", last, softplus);
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+
+    test_normal!(f64, X_F64, pdf_zero: 40.0, pdf_last: (37.5, 38.0), softplus_zero: -800.0, softplus_last: -720.0);
+}
+
+mod f32 {
+    use super::*;
+
+    test_normal!(f32, X_F32, pdf_zero: 15.0, pdf_last: (13.0, 14.0), softplus_zero: -110.0, softplus_last: -100.0);
+}
@@ -0,0 +1,92 @@
+use crate::*;
+
+macro_rules! test_powers {
+    ($t:ident, recip_tolerance: $recip_tolerance:expr) => {
+        #[test]
+        fn pow_zero_negative_and_non_finite() {
+            assert_same_float!((0.0 as $t).fast_pow(2.5), 0.0 as $t);
+            assert_same_float!((0.0 as $t).fast_pow(-2.5), $t::INFINITY);
+            assert_same_float!((0.0 as $t).fast_pow(0.0), 1.0 as $t);
+            assert_same_float!($t::NAN.fast_pow(0.0), 1.0 as $t);
+            assert_same_float!((1.0 as $t).fast_pow($t::NAN), 1.0 as $t);
+            assert_same_float!((-2.0 as $t).fast_pow(0.5), $t::NAN);
+            assert!((-2.0 as $t).fast_pow(3.0) < 0.0);
+            assert!((-2.0 as $t).fast_pow(4.0) > 0.0);
+            assert_same_float!((10.0 as $t).fast_pow(1000.0), $t::INFINITY);
+            assert_same_float!((10.0 as $t).fast_pow(-1000.0), 0.0 as $t);
+            assert_same_float!($t::NAN.fast_pow(2.0), $t::NAN);
+            assert_same_float!((-0.0 as $t).fast_pow(-1.0), $t::NEG_INFINITY);
+            assert_same_float!((-0.0 as $t).fast_pow(3.0), -0.0 as $t);
+            assert_same_float!((-0.0 as $t).fast_pow(2.0), 0.0 as $t);
+            assert_same_float!((-0.0 as $t).fast_pow(0.5), 0.0 as $t);
+            assert_same_float!((-0.0 as $t).fast_pow(-0.5), $t::INFINITY);
+            assert_same_float!((-2.0 as $t).fast_pow($t::INFINITY), $t::INFINITY);
+            assert_same_float!((-0.5 as $t).fast_pow($t::INFINITY), 0.0 as $t);
+            assert_same_float!((-1.0 as $t).fast_pow($t::NEG_INFINITY), 1.0 as $t);
+            assert_same_float!($t::NEG_INFINITY.fast_pow(3.0), $t::NEG_INFINITY);
+            assert_same_float!((0.0 as $t).fast_powi(-1), $t::INFINITY);
+            assert_same_float!((-2.0 as $t).fast_powi(3), -8.0 as $t);
+            assert_same_float!((-2.0 as $t).fast_powi(-2), 0.25 as $t);
+            assert_same_float!((3.0 as $t).fast_powi(0), 1.0 as $t);
+        }
+
+        #[test]
+        fn cbrt_signs_and_non_finite() {
+            assert_same_float!((0.0 as $t).fast_cbrt(), 0.0 as $t);
+            assert_same_float!((-0.0 as $t).fast_cbrt(), -0.0 as $t);
+            assert_same_float!($t::INFINITY.fast_cbrt(), $t::INFINITY);
+            assert_same_float!($t::NEG_INFINITY.fast_cbrt(), $t::NEG_INFINITY);
+            assert_same_float!($t::NAN.fast_cbrt(), $t::NAN);
+            assert!(((-27.0 as $t).fast_cbrt() + 3.0).abs() < 0.01);
+            for x in [$t::MIN_POSITIVE, $t::MAX, -$t::MAX] {
+                assert!((x.fast_cbrt() / x.cbrt() - 1.0).abs() < 0.01, "fast_cbrt({}) = {}", x, x.fast_cbrt());
+            }
+        }
+
+        #[test]
+        fn hypot_extreme_components() {
+            assert_same_float!((0.0 as $t).fast_hypot(-0.0), 0.0 as $t);
+            assert_same_float!($t::INFINITY.fast_hypot($t::NAN), $t::INFINITY);
+            assert_same_float!($t::NAN.fast_hypot($t::NEG_INFINITY), $t::INFINITY);
+            assert_same_float!($t::NAN.fast_hypot(1.0), $t::NAN);
+            assert_same_float!((1.0 as $t).fast_hypot($t::NAN), $t::NAN);
+            // Squaring either component directly would overflow or underflow
+            let cases: [($t, $t); 4] = [($t::MAX / 2.0, $t::MAX / 4.0), (-$t::MAX, 1.0), ($t::MIN_POSITIVE, $t::MIN_POSITIVE), (1e-30, -3e-30)];
+            for (x, y) in cases {
+                assert!((x.fast_hypot(y) / x.hypot(y) - 1.0).abs() < 0.01, "fast_hypot({}, {}) = {}", x, y, x.fast_hypot(y));
+            }
+        }
+
+        #[test]
+        fn recip_and_div_special_values_and_relative_error() {
+            assert_same_float!((0.0 as $t).fast_recip(), $t::INFINITY);
+            assert_same_float!((-0.0 as $t).fast_recip(), $t::NEG_INFINITY);
+            assert_same_float!($t::NEG_INFINITY.fast_recip(), -0.0 as $t);
+            assert_same_float!($t::NAN.fast_recip(), $t::NAN);
+            assert_same_float!($t::MAX.fast_recip(), $t::MAX.recip());
+            assert_same_float!((0.0 as $t).fast_div(0.0), $t::NAN);
+            assert_same_float!((-3.0 as $t).fast_div(0.0), $t::NEG_INFINITY);
+            assert_same_float!((3.0 as $t).fast_div($t::INFINITY), 0.0 as $t);
+            // Geometric samples from the smallest normal numbers up to the largest, of both signs
+            let geometric = (0..=2000).map(|i| $t::MIN_POSITIVE.powf(1.0 - i as $t / 1000.0)).filter(|x| x.is_finite()).collect::<Vec<$t>>();
+            let max_relative_error = geometric.iter().map(|&x| (x * x.fast_recip() - 1.0).abs()).fold(0.0, $t::max);
+            assert!(max_relative_error < $recip_tolerance, "max relative error of fast_recip is {}", max_relative_error);
+            for &x in &geometric {
+                assert_same_float!((-x).fast_recip(), -x.fast_recip());
+                assert_same_float!(x.fast_div(-x), -(x * x.fast_recip()));
+            }
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+
+    test_powers!(f64, recip_tolerance: 1e-10);
+}
+
+mod f32 {
+    use super::*;
+
+    test_powers!(f32, recip_tolerance: 1e-5);
+}
@@ -0,0 +1,76 @@
+use crate::*;
+use super::accuracy;
+
+macro_rules! test_rounding {
+    ($t:ident, edges: $edges:expr, band: $band:expr, huge: $huge:expr) => {
+        #[test]
+        fn round_matches_std() {
+            // Quarter steps hit every tie, along with points a few ulps either side of the ties and near the
+            // edge of the exact range
+            let mut x = (-400..=400).map(|i| i as $t * 0.25).collect::<Vec<$t>>();
+            for base in [0.5 as $t, 1.5, 2.5, 1e6 + 0.5] {
+                for v in [base, -base] {
+                    x.extend([v, v.next_down(), v.next_up(), v.next_down().next_down(), v.next_up().next_up()]);
+                }
+            }
+            x.extend($edges);
+            x.extend([-0.0, $t::MIN_POSITIVE, -$t::MIN_POSITIVE, $t::INFINITY, $t::NEG_INFINITY, $t::NAN]);
+            for &x in &x {
+                assert_same_float!(x.fast_floor(), accuracy::exact::$t::floor(x));
+                assert_same_float!(x.fast_ceil(), x.ceil());
+                assert_same_float!(x.fast_round(), accuracy::exact::$t::round(x));
+                assert_same_float!(x.fast_trunc(), x.trunc());
+                assert_eq!(x.fast_to_i64(), x.round_ties_even() as i64, "fast_to_i64({:?})", x);
+                assert_eq!(x.fast_to_i32(), x.round_ties_even() as i32, "fast_to_i32({:?})", x);
+            }
+            // Half-integers are still rounded in the last octave that has them
+            let (half, floor): ($t, $t) = $band;
+            assert_same_float!(half.fast_floor(), floor);
+            assert_eq!((1e10 as $t).fast_to_i32(), i32::MAX);
+            assert_eq!($t::NAN.fast_to_i64(), 0);
+        }
+
+        #[test]
+        fn fmod_matches_remainder_bit_for_bit() {
+            assert_same_float!((1.0 as $t).fast_fmod(0.0), $t::NAN);
+            assert_same_float!($t::INFINITY.fast_fmod(1.0), $t::NAN);
+            assert_same_float!((-0.0 as $t).fast_fmod(1.5), -0.0 as $t);
+            assert_same_float!((-3.0 as $t).fast_fmod(1.5), -0.0 as $t);
+            assert_same_float!((1.25 as $t).fast_fmod(-1.0), 0.25 as $t);
+            // An infinite divisor leaves the dividend alone, and quotients too large for the fast path defer to std
+            assert_same_float!((3.0 as $t).fast_fmod($t::INFINITY), 3.0 as $t);
+            assert_same_float!((-3.0 as $t).fast_fmod($t::NEG_INFINITY), -3.0 as $t);
+            assert_same_float!(($huge as $t).fast_fmod(3.0), $huge as $t % 3.0);
+            // Multiples of the divisor and their neighbours are where the quotient can come out one off
+            for d in [1.0 as $t, 0.1, 3.0, -7.5, consts::PI, 1e-3, 1e10] {
+                for i in -1000..=1000 {
+                    let x = i as $t * d;
+                    for x in [x, x.next_up(), x.next_down(), x + 0.37 * d] {
+                        // Including where the exact remainder is within rounding of zero or of |d|
+                        assert_same_float!(x.fast_fmod(d), accuracy::exact::$t::fmod(x, d));
+                    }
+                }
+            }
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+    use std::f64::consts;
+
+    test_rounding!(f64, edges: [2251799813685247.5, -2251799813685247.5, 2251799813685246.5, 2251799813685248.5, -2251799813685248.5, 3377699720527871.5, 4503599627370495.5, -4503599627370495.5, 1e-300, -1e-300, 4503599627370497.0, -9.2e18, 1e300], band: (2251799813685248.5, 2251799813685248.0), huge: 1e300);
+
+    #[test]
+    fn to_i32_reaches_both_ends_of_the_range() {
+        assert_eq!(2147483647.0f64.fast_to_i32(), i32::MAX);
+        assert_eq!((-2147483647.0f64).fast_to_i32(), -i32::MAX);
+    }
+}
+
+mod f32 {
+    use super::*;
+    use std::f32::consts;
+
+    test_rounding!(f32, edges: [4194303.5, -4194303.5, 4194302.5, 4194304.5, -4194304.5, 6291455.5, 8388607.5, -8388607.5, 1e-30, -1e-30, 8388609.0, -3e9, 1e30], band: (4194304.5, 4194304.0), huge: 1e30);
+}
@@ -0,0 +1,67 @@
+use crate::*;
+use super::accuracy;
+
+macro_rules! test_softmax {
+    ($t:ident, $x:ident) => {
+        #[test]
+        fn softmax_stability() {
+            let inf = $t::INFINITY;
+            // Logits this large overflow exp without the max subtraction
+            let softmax = [1000.0 as $t, 1000.0, -inf, 990.0].fast_softmax();
+            assert!((softmax.iter().sum::<$t>() - 1.0).abs() < 1e-5, "{:?} doesn't sum to 1", softmax);
+            assert_same_float!(softmax[0], softmax[1]);
+            assert_same_float!(softmax[2], 0.0 as $t);
+            assert!(softmax[3] > 0.0 && softmax[3] < 1e-4, "{:?}", softmax);
+            let log_softmax = [1000.0 as $t, -inf, -1000.0].fast_log_softmax();
+            assert!(log_softmax[0].abs() < 1e-5, "{:?}", log_softmax);
+            assert_same_float!(log_softmax[1], -inf);
+            // ln(softmax) would have underflowed to -inf here
+            assert!((log_softmax[2] + 2000.0).abs() < 1e-2, "{:?}", log_softmax);
+            assert_same_float!([3.5 as $t].fast_softmax()[0], 1.0 as $t);
+            assert_same_float!([3.5 as $t].fast_log_softmax()[0], 0.0 as $t);
+            assert!([-inf, -inf].fast_softmax().iter().all(|x| x.is_nan()));
+            assert!([1.0, $t::NAN].fast_log_softmax().iter().all(|x| x.is_nan()));
+            let mut empty: [$t; 0] = [];
+            empty.fast_softmax_in_place();
+            empty.par_softmax_rows(4);
+            // A high temperature flattens the distribution and a low one sharpens it
+            let x = [1.0 as $t, 2.0, 3.0];
+            assert_eq!(x.fast_softmax_with_temperature(2.0), [0.5 as $t, 1.0, 1.5].fast_softmax());
+            let (hot, cold) = (x.fast_softmax_with_temperature(100.0), x.fast_softmax_with_temperature(0.01));
+            assert!(hot.iter().all(|&p| (p - 1.0 / 3.0).abs() < 0.01), "{:?}", hot);
+            assert!(cold[2] > 0.999, "{:?}", cold);
+        }
+
+        #[test]
+        fn softmax_rows_match_slices() {
+            let matrix = accuracy::$t::$x.iter().map(|&x| 10.0 * x).collect::<Vec<$t>>();
+            for row_len in [1, 16, 1000] {
+                let (mut softmax, mut log_softmax) = (matrix.clone(), matrix.clone());
+                softmax.par_softmax_rows(row_len);
+                log_softmax.par_log_softmax_rows(row_len);
+                for ((x, softmax), log_softmax) in matrix.chunks(row_len).zip(softmax.chunks(row_len)).zip(log_softmax.chunks(row_len)) {
+                    assert_eq!(x.fast_softmax(), softmax);
+                    assert_eq!(x.fast_log_softmax(), log_softmax);
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn softmax_rows_reject_ragged_matrix() {
+            [0.0 as $t; 10].par_softmax_rows(3);
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+
+    test_softmax!(f64, X_F64);
+}
+
+mod f32 {
+    use super::*;
+
+    test_softmax!(f32, X_F32);
+}
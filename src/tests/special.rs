@@ -0,0 +1,113 @@
+use crate::*;
+use super::accuracy;
+use rayon::iter::ParallelIterator;
+
+macro_rules! test_special {
+    ($t:ident, $x:ident, erfc_tail: $erfc_tail:expr, stirling_steps: $stirling_steps:expr, stirling_tolerance: $stirling_tolerance:expr, reflection_steps: $reflection_steps:expr, reflection_step: $reflection_step:expr, reflection_tolerance: $reflection_tolerance:expr) => {
+        #[test]
+        fn erf_limits_and_erfc_tail_relative_error() {
+            assert_same_float!($t::INFINITY.fast_erf(), 1.0 as $t);
+            assert_same_float!($t::NEG_INFINITY.fast_erf(), -1.0 as $t);
+            assert_same_float!($t::INFINITY.fast_erfc(), 0.0 as $t);
+            assert_same_float!($t::NEG_INFINITY.fast_erfc(), 2.0 as $t);
+            assert_same_float!($t::NAN.fast_erf(), $t::NAN);
+            assert_same_float!((1.0 as $t).fast_erfinv(), $t::INFINITY);
+            assert_same_float!((-1.0 as $t).fast_erfinv(), $t::NEG_INFINITY);
+            assert_same_float!((1.5 as $t).fast_erfinv(), $t::NAN);
+            // The relative error of erfc stays bounded (by that of fast_exp) all the way into the tail
+            let max_relative_error = (0..1000)
+                .map(|i| i as $t * ($erfc_tail / 1000.0))
+                .map(|x| ((x.fast_erfc() - accuracy::exact::$t::erfc(x)) / accuracy::exact::$t::erfc(x)).abs())
+                .fold(0.0, $t::max);
+            assert!(max_relative_error < 0.05, "max relative error of fast_erfc is {}", max_relative_error);
+        }
+
+        #[test]
+        fn gamma_poles_and_overflow() {
+            assert_same_float!((0.0 as $t).fast_gamma(), $t::INFINITY);
+            assert_same_float!((-0.0 as $t).fast_gamma(), $t::NEG_INFINITY);
+            assert_same_float!((-1.0 as $t).fast_gamma(), $t::NAN);
+            assert_same_float!((-4.0 as $t).fast_gamma(), $t::NAN);
+            assert_same_float!($t::NEG_INFINITY.fast_gamma(), $t::NAN);
+            assert_same_float!($t::INFINITY.fast_gamma(), $t::INFINITY);
+            assert_same_float!((200.0 as $t).fast_gamma(), $t::INFINITY);
+            assert_same_float!($t::NAN.fast_gamma(), $t::NAN);
+            // The sign alternates between the poles
+            assert!((-0.5 as $t).fast_gamma() < 0.0);
+            assert!((-1.5 as $t).fast_gamma() > 0.0);
+            assert_same_float!((0.0 as $t).fast_lgamma(), $t::INFINITY);
+            assert_same_float!((-3.0 as $t).fast_lgamma(), $t::INFINITY);
+            assert_same_float!($t::INFINITY.fast_lgamma(), $t::INFINITY);
+            assert!((1000.0 as $t).fast_lgamma().is_finite());
+            assert_same_float!((0.0 as $t).fast_digamma(), $t::NAN);
+            assert_same_float!((-2.0 as $t).fast_digamma(), $t::NAN);
+            assert_same_float!($t::NAN.fast_digamma(), $t::NAN);
+        }
+
+        #[test]
+        fn gamma_relative_error_above_asymptotic_threshold() {
+            let max_relative_error = |x: &[$t]| x.iter().map(|&x| (x.fast_gamma() / accuracy::exact::$t::gamma(x) - 1.0).abs()).fold(0.0, $t::max);
+            // Stirling's series takes over at 10 and runs up to where Gamma overflows
+            let x = (0..=$stirling_steps).map(|i| 10.0 + i as $t * 0.1).collect::<Vec<$t>>();
+            let stirling_error = max_relative_error(&x);
+            assert!(stirling_error < $stirling_tolerance, "max relative error of fast_gamma above 10 is {}", stirling_error);
+            // The reflection formula sends x < -9 through the same branch, and adds the error of sin(pi * x)
+            let x = (1..$reflection_steps).map(|i| -9.0 - i as $t * $reflection_step).collect::<Vec<$t>>();
+            let reflection_error = max_relative_error(&x);
+            assert!(reflection_error < $reflection_tolerance, "max relative error of fast_gamma below -9 is {}", reflection_error);
+        }
+
+        #[test]
+        fn sinc_and_lanczos_special_points() {
+            assert_same_float!((0.0 as $t).fast_sinc(), 1.0 as $t);
+            assert!(((1e-20 as $t).fast_sinc() - 1.0).abs() < 1e-6);
+            for n in [1.0 as $t, -2.0, 3.0, 1e6] {
+                assert_eq!(n.fast_sinc(), 0.0);
+            }
+            assert_same_float!($t::INFINITY.fast_sinc(), 0.0 as $t);
+            assert_same_float!($t::NAN.fast_sinc(), $t::NAN);
+            // Both sides of the switch away from the polynomial
+            assert!(((0.5 as $t).fast_sinc() - consts::FRAC_2_PI).abs() < 1e-6);
+            assert!(((-0.5 - $t::EPSILON).fast_sinc() - consts::FRAC_2_PI).abs() < 1e-6);
+
+            assert_same_float!((0.0 as $t).fast_lanczos(3.0), 1.0 as $t);
+            for x in [-3.0 as $t, 3.0, 3.5, $t::NEG_INFINITY] {
+                assert_same_float!(x.fast_lanczos(3.0), 0.0 as $t);
+            }
+            assert_same_float!($t::NAN.fast_lanczos(3.0), $t::NAN);
+            assert_same_float!((1.0 as $t).fast_lanczos($t::NAN), $t::NAN);
+        }
+
+        #[test]
+        fn lanczos_lookup_matches_kernel() {
+            for a in [2.0 as $t, 3.0, 4.5] {
+                let table = crate::lookup::LanczosLookupTable::<$t>::new(a);
+                let x = accuracy::$t::$x.iter().map(|&x| x * a / 8.0).collect::<Vec<$t>>();
+                let max_error = x.iter().map(|&x| (table.lookup(x) - accuracy::exact::$t::lanczos(x, a)).abs()).fold(0.0, $t::max);
+                // Half a table step times the steepest slope of the kernel
+                assert!(max_error < 1e-3 * a, "max error of LanczosLookupTable({}) is {}", a, max_error);
+                assert_same_float!(table.lookup(0.0), 1.0 as $t);
+                assert_same_float!(table.lookup(-a), 0.0 as $t);
+                assert_same_float!(table.lookup($t::INFINITY), 0.0 as $t);
+                assert_same_float!(table.lookup($t::NAN), $t::NAN);
+                for (a, b) in table.par_map_lookups(x.clone()).collect::<Vec<$t>>().into_iter().zip(table.map_lookups(x)) {
+                    assert_same_float!(a, b);
+                }
+            }
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+    use std::f64::consts;
+
+    test_special!(f64, X_F64, erfc_tail: 26.0, stirling_steps: 1610, stirling_tolerance: 1e-11, reflection_steps: 200, reflection_step: 0.0999, reflection_tolerance: 1e-8);
+}
+
+mod f32 {
+    use super::*;
+    use std::f32::consts;
+
+    test_special!(f32, X_F32, erfc_tail: 9.0, stirling_steps: 250, stirling_tolerance: 1e-6, reflection_steps: 150, reflection_step: 0.1666, reflection_tolerance: 2e-5);
+}
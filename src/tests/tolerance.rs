@@ -16,7 +16,7 @@ where
 }
 
 macro_rules! test_within_tolerance {
-    ($function:ident, $t:ty, $test_name:ident) => {
+    ($function:expr, $t:ty, $test_name:ident) => {
         #[test]
         fn $test_name() -> Result<(), Box<dyn std::error::Error>> {
             let tolerance: $t = get_tolerance::<$t>(stringify!($test_name)).unwrap_or_else(|_| panic!("Invalid tolerance for {}", stringify!($test_name)));
@@ -39,6 +39,89 @@ mod f64 {
     test_within_tolerance!(sin_lookup, f64, sin_lk);
     test_within_tolerance!(tan, f64, tan_fast);
     test_within_tolerance!(sigmoid, f64, sigmoid_fast);
+    test_within_tolerance!(ln, f64, ln_fast);
+    test_within_tolerance!(log2, f64, log2_fast);
+    test_within_tolerance!(log10, f64, log10_fast);
+    test_within_tolerance!(sqrt::<0>, f64, sqrt_fast_newton_0);
+    test_within_tolerance!(sqrt::<1>, f64, sqrt_fast_newton_1);
+    test_within_tolerance!(sqrt::<2>, f64, sqrt_fast_newton_2);
+    test_within_tolerance!(inv_sqrt::<0>, f64, inv_sqrt_fast_newton_0);
+    test_within_tolerance!(inv_sqrt::<1>, f64, inv_sqrt_fast_newton_1);
+    test_within_tolerance!(inv_sqrt::<2>, f64, inv_sqrt_fast_newton_2);
+    test_within_tolerance!(atan, f64, atan_fast);
+    test_within_tolerance!(atan2, f64, atan2_fast);
+    test_within_tolerance!(asin, f64, asin_fast);
+    test_within_tolerance!(acos, f64, acos_fast);
+    test_within_tolerance!(sinh, f64, sinh_fast);
+    test_within_tolerance!(cosh, f64, cosh_fast);
+    test_within_tolerance!(tanh, f64, tanh_fast);
+    test_within_tolerance!(pow, f64, pow_fast);
+    test_within_tolerance!(powi, f64, powi_fast);
+    test_within_tolerance!(erf, f64, erf_fast);
+    test_within_tolerance!(erfc, f64, erfc_fast);
+    test_within_tolerance!(erfinv, f64, erfinv_fast);
+    test_within_tolerance!(gamma, f64, gamma_fast);
+    test_within_tolerance!(lgamma, f64, lgamma_fast);
+    test_within_tolerance!(digamma, f64, digamma_fast);
+    test_within_tolerance!(cbrt::<0>, f64, cbrt_fast_newton_0);
+    test_within_tolerance!(cbrt::<1>, f64, cbrt_fast_newton_1);
+    test_within_tolerance!(cbrt::<2>, f64, cbrt_fast_newton_2);
+    test_within_tolerance!(hypot, f64, hypot_fast);
+    test_within_tolerance!(expm1, f64, expm1_fast);
+    test_within_tolerance!(expm1_near_zero, f64, expm1_near_zero_fast);
+    test_within_tolerance!(log1p, f64, log1p_fast);
+    test_within_tolerance!(log1p_near_zero, f64, log1p_near_zero_fast);
+    test_within_tolerance!(exp10, f64, exp10_fast);
+    test_within_tolerance!(db_to_gain, f64, db_to_gain_fast);
+    test_within_tolerance!(gain_to_db, f64, gain_to_db_fast);
+    test_within_tolerance!(db_to_power, f64, db_to_power_fast);
+    test_within_tolerance!(power_to_db, f64, power_to_db_fast);
+    test_within_tolerance!(sincos_sin, f64, sincos_sin_fast);
+    test_within_tolerance!(sincos_cos, f64, sincos_cos_fast);
+    test_within_tolerance!(sincos_lookup_sin, f64, sincos_sin_lk);
+    test_within_tolerance!(sincos_lookup_cos, f64, sincos_cos_lk);
+    test_within_tolerance!(gelu, f64, gelu_fast);
+    test_within_tolerance!(gelu_erf, f64, gelu_erf_fast);
+    test_within_tolerance!(silu, f64, silu_fast);
+    test_within_tolerance!(softplus, f64, softplus_fast);
+    test_within_tolerance!(mish, f64, mish_fast);
+    test_within_tolerance!(elu, f64, elu_fast);
+    test_within_tolerance!(normal_pdf, f64, normal_pdf_fast);
+    test_within_tolerance!(normal_log_pdf, f64, normal_log_pdf_fast);
+    test_within_tolerance!(normal_cdf, f64, normal_cdf_fast);
+    test_within_tolerance!(normal_probit, f64, normal_probit_fast);
+    test_within_tolerance!(log_add_exp, f64, log_add_exp_fast);
+    test_within_tolerance!(log_sub_exp, f64, log_sub_exp_fast);
+    test_within_tolerance!(logsumexp, f64, logsumexp_fast);
+    test_within_tolerance!(softmax, f64, softmax_fast);
+    test_within_tolerance!(log_softmax, f64, log_softmax_fast);
+    test_within_tolerance!(entropy, f64, entropy_fast);
+    test_within_tolerance!(cross_entropy, f64, cross_entropy_fast);
+    test_within_tolerance!(kl_divergence, f64, kl_divergence_fast);
+    test_within_tolerance!(binary_cross_entropy, f64, binary_cross_entropy_fast);
+    test_within_tolerance!(sinc, f64, sinc_fast);
+    test_within_tolerance!(lanczos, f64, lanczos_fast);
+    test_within_tolerance!(lanczos_lookup, f64, lanczos_lk);
+    test_within_tolerance!(asinh, f64, asinh_fast);
+    test_within_tolerance!(asinh_near_zero, f64, asinh_near_zero_fast);
+    test_within_tolerance!(acosh, f64, acosh_fast);
+    test_within_tolerance!(atanh, f64, atanh_fast);
+    test_within_tolerance!(atanh_near_zero, f64, atanh_near_zero_fast);
+    test_within_tolerance!(sec, f64, sec_fast);
+    test_within_tolerance!(csc, f64, csc_fast);
+    test_within_tolerance!(cot, f64, cot_fast);
+    test_within_tolerance!(j0, f64, j0_fast);
+    test_within_tolerance!(j1, f64, j1_fast);
+    test_within_tolerance!(y0, f64, y0_fast);
+    test_within_tolerance!(i0, f64, i0_fast);
+    test_within_tolerance!(j0_lookup, f64, j0_lk);
+    test_within_tolerance!(i0_lookup, f64, i0_lk);
+    test_within_tolerance!(recip::<0>, f64, recip_fast_newton_0);
+    test_within_tolerance!(recip::<1>, f64, recip_fast_newton_1);
+    test_within_tolerance!(recip::<2>, f64, recip_fast_newton_2);
+    test_within_tolerance!(div, f64, div_fast);
+    test_within_tolerance!(sigmoid_newton::<1>, f64, sigmoid_fast_newton_1);
+    test_within_tolerance!(sigmoid_newton::<2>, f64, sigmoid_fast_newton_2);
 }
 
 mod f32 {
@@ -53,4 +136,87 @@ mod f32 {
     test_within_tolerance!(sin_lookup, f32, sin_lk);
     test_within_tolerance!(tan, f32, tan_fast);
     test_within_tolerance!(sigmoid, f32, sigmoid_fast);
+    test_within_tolerance!(ln, f32, ln_fast);
+    test_within_tolerance!(log2, f32, log2_fast);
+    test_within_tolerance!(log10, f32, log10_fast);
+    test_within_tolerance!(sqrt::<0>, f32, sqrt_fast_newton_0);
+    test_within_tolerance!(sqrt::<1>, f32, sqrt_fast_newton_1);
+    test_within_tolerance!(sqrt::<2>, f32, sqrt_fast_newton_2);
+    test_within_tolerance!(inv_sqrt::<0>, f32, inv_sqrt_fast_newton_0);
+    test_within_tolerance!(inv_sqrt::<1>, f32, inv_sqrt_fast_newton_1);
+    test_within_tolerance!(inv_sqrt::<2>, f32, inv_sqrt_fast_newton_2);
+    test_within_tolerance!(atan, f32, atan_fast);
+    test_within_tolerance!(atan2, f32, atan2_fast);
+    test_within_tolerance!(asin, f32, asin_fast);
+    test_within_tolerance!(acos, f32, acos_fast);
+    test_within_tolerance!(sinh, f32, sinh_fast);
+    test_within_tolerance!(cosh, f32, cosh_fast);
+    test_within_tolerance!(tanh, f32, tanh_fast);
+    test_within_tolerance!(pow, f32, pow_fast);
+    test_within_tolerance!(powi, f32, powi_fast);
+    test_within_tolerance!(erf, f32, erf_fast);
+    test_within_tolerance!(erfc, f32, erfc_fast);
+    test_within_tolerance!(erfinv, f32, erfinv_fast);
+    test_within_tolerance!(gamma, f32, gamma_fast);
+    test_within_tolerance!(lgamma, f32, lgamma_fast);
+    test_within_tolerance!(digamma, f32, digamma_fast);
+    test_within_tolerance!(cbrt::<0>, f32, cbrt_fast_newton_0);
+    test_within_tolerance!(cbrt::<1>, f32, cbrt_fast_newton_1);
+    test_within_tolerance!(cbrt::<2>, f32, cbrt_fast_newton_2);
+    test_within_tolerance!(hypot, f32, hypot_fast);
+    test_within_tolerance!(expm1, f32, expm1_fast);
+    test_within_tolerance!(expm1_near_zero, f32, expm1_near_zero_fast);
+    test_within_tolerance!(log1p, f32, log1p_fast);
+    test_within_tolerance!(log1p_near_zero, f32, log1p_near_zero_fast);
+    test_within_tolerance!(exp10, f32, exp10_fast);
+    test_within_tolerance!(db_to_gain, f32, db_to_gain_fast);
+    test_within_tolerance!(gain_to_db, f32, gain_to_db_fast);
+    test_within_tolerance!(db_to_power, f32, db_to_power_fast);
+    test_within_tolerance!(power_to_db, f32, power_to_db_fast);
+    test_within_tolerance!(sincos_sin, f32, sincos_sin_fast);
+    test_within_tolerance!(sincos_cos, f32, sincos_cos_fast);
+    test_within_tolerance!(sincos_lookup_sin, f32, sincos_sin_lk);
+    test_within_tolerance!(sincos_lookup_cos, f32, sincos_cos_lk);
+    test_within_tolerance!(gelu, f32, gelu_fast);
+    test_within_tolerance!(gelu_erf, f32, gelu_erf_fast);
+    test_within_tolerance!(silu, f32, silu_fast);
+    test_within_tolerance!(softplus, f32, softplus_fast);
+    test_within_tolerance!(mish, f32, mish_fast);
+    test_within_tolerance!(elu, f32, elu_fast);
+    test_within_tolerance!(normal_pdf, f32, normal_pdf_fast);
+    test_within_tolerance!(normal_log_pdf, f32, normal_log_pdf_fast);
+    test_within_tolerance!(normal_cdf, f32, normal_cdf_fast);
+    test_within_tolerance!(normal_probit, f32, normal_probit_fast);
+    test_within_tolerance!(log_add_exp, f32, log_add_exp_fast);
+    test_within_tolerance!(log_sub_exp, f32, log_sub_exp_fast);
+    test_within_tolerance!(logsumexp, f32, logsumexp_fast);
+    test_within_tolerance!(softmax, f32, softmax_fast);
+    test_within_tolerance!(log_softmax, f32, log_softmax_fast);
+    test_within_tolerance!(entropy, f32, entropy_fast);
+    test_within_tolerance!(cross_entropy, f32, cross_entropy_fast);
+    test_within_tolerance!(kl_divergence, f32, kl_divergence_fast);
+    test_within_tolerance!(binary_cross_entropy, f32, binary_cross_entropy_fast);
+    test_within_tolerance!(sinc, f32, sinc_fast);
+    test_within_tolerance!(lanczos, f32, lanczos_fast);
+    test_within_tolerance!(lanczos_lookup, f32, lanczos_lk);
+    test_within_tolerance!(asinh, f32, asinh_fast);
+    test_within_tolerance!(asinh_near_zero, f32, asinh_near_zero_fast);
+    test_within_tolerance!(acosh, f32, acosh_fast);
+    test_within_tolerance!(atanh, f32, atanh_fast);
+    test_within_tolerance!(atanh_near_zero, f32, atanh_near_zero_fast);
+    test_within_tolerance!(sec, f32, sec_fast);
+    test_within_tolerance!(csc, f32, csc_fast);
+    test_within_tolerance!(cot, f32, cot_fast);
+    test_within_tolerance!(j0, f32, j0_fast);
+    test_within_tolerance!(j1, f32, j1_fast);
+    test_within_tolerance!(y0, f32, y0_fast);
+    test_within_tolerance!(i0, f32, i0_fast);
+    test_within_tolerance!(j0_lookup, f32, j0_lk);
+    test_within_tolerance!(i0_lookup, f32, i0_lk);
+    test_within_tolerance!(recip::<0>, f32, recip_fast_newton_0);
+    test_within_tolerance!(recip::<1>, f32, recip_fast_newton_1);
+    test_within_tolerance!(recip::<2>, f32, recip_fast_newton_2);
+    test_within_tolerance!(div, f32, div_fast);
+    test_within_tolerance!(sigmoid_newton::<1>, f32, sigmoid_fast_newton_1);
+    test_within_tolerance!(sigmoid_newton::<2>, f32, sigmoid_fast_newton_2);
 }
\ No newline at end of file
@@ -0,0 +1,126 @@
+use crate::*;
+use super::accuracy;
+
+macro_rules! test_trigonometry {
+    ($t:ident, $x:ident, far: $far:expr, steps: $steps:expr, sin_tolerance: $sin_tolerance:expr, exact_range: $exact_range:expr, exact_tolerance: $exact_tolerance:expr) => {
+        #[test]
+        fn atan2_axes_and_signed_zeros() {
+            let cases: [($t, $t); 12] = [(0.0, 0.0), (-0.0, 0.0), (0.0, -0.0), (-0.0, -0.0), (1.0, 0.0), (-1.0, 0.0), (1.0, -0.0), (-1.0, -0.0), (0.0, 1.0), (-0.0, 1.0), (0.0, -1.0), (-0.0, -1.0)];
+            for (y, x) in cases {
+                assert_same_float!(y.fast_atan2(x), y.atan2(x));
+            }
+            assert_same_float!($t::NAN.fast_atan2(1.0), $t::NAN);
+            assert_same_float!((1.0 as $t).fast_atan2($t::NAN), $t::NAN);
+        }
+
+        #[test]
+        fn asin_acos_domain_edges() {
+            assert_same_float!((1.0 as $t).fast_asin(), consts::FRAC_PI_2);
+            assert_same_float!((-1.0 as $t).fast_asin(), -consts::FRAC_PI_2);
+            assert_same_float!((1.0 as $t).fast_acos(), 0.0 as $t);
+            assert_same_float!((-1.0 as $t).fast_acos(), consts::PI);
+            for x in [1.5 as $t, -1.5, $t::INFINITY, $t::NEG_INFINITY, $t::NAN] {
+                assert_same_float!(x.fast_asin(), $t::NAN);
+                assert_same_float!(x.fast_acos(), $t::NAN);
+            }
+            // Relative accuracy must hold as acos(x) -> 0 and asin(x) -> ±pi/2
+            let near_one = 1.0 as $t - 1e-6;
+            assert!(((near_one.fast_acos() - near_one.acos()) / near_one.acos()).abs() < 1e-4);
+            assert!(((near_one.fast_asin() - near_one.asin()) / near_one.asin()).abs() < 1e-4);
+        }
+
+        #[test]
+        fn sincos_matches_separate_calls() {
+            for &x in accuracy::$t::$x.iter() {
+                let (sin, cos) = x.fast_sincos();
+                assert!((sin - x.fast_sin()).abs() < 1e-5 && (cos - x.fast_cos()).abs() < 1e-5, "fast_sincos({}) = {:?}", x, (sin, cos));
+                let (sin, cos) = x.lookup_sincos();
+                assert_same_float!(sin, x.lookup_sin());
+                // lookup_cos shifts the key by pi/2, so it can land on a neighbouring table entry
+                assert!((cos - x.lookup_cos()).abs() < 0.002, "lookup_sincos({}) = {:?}", x, (sin, cos));
+            }
+        }
+
+        #[test]
+        fn tan_family_near_poles() {
+            assert_same_float!((-0.0 as $t).fast_tan(), -0.0 as $t);
+            assert_same_float!((0.0 as $t).fast_csc(), $t::INFINITY);
+            assert_same_float!((-0.0 as $t).fast_cot(), $t::NEG_INFINITY);
+            for f in [$t::fast_tan, $t::fast_sec, $t::fast_csc, $t::fast_cot] {
+                assert_same_float!(f($t::NAN), $t::NAN);
+                assert_same_float!(f($t::INFINITY), $t::NAN);
+            }
+            // The floats on either side of each pole, where the result must have the sign from that side
+            // and a magnitude that matches std
+            let close = |fast: $t, exact: $t| (fast / exact - 1.0).abs() < 1e-4;
+            for k in -20..=20 {
+                let tan_pole = consts::FRAC_PI_2 + k as $t * consts::PI;
+                let cot_pole = k as $t * consts::PI;
+                for x in [tan_pole, tan_pole.next_up(), tan_pole.next_down()] {
+                    assert!(close(x.fast_tan(), x.tan()), "fast_tan({}) = {}, expected {}", x, x.fast_tan(), x.tan());
+                    assert!(close(x.fast_sec(), x.cos().recip()), "fast_sec({}) = {}, expected {}", x, x.fast_sec(), x.cos().recip());
+                }
+                // The neighbours of zero are subnormal, and their reciprocals overflow
+                if k == 0 {
+                    continue;
+                }
+                for x in [cot_pole.next_up(), cot_pole.next_down()] {
+                    assert!(close(x.fast_cot(), x.tan().recip()), "fast_cot({}) = {}, expected {}", x, x.fast_cot(), x.tan().recip());
+                    assert!(close(x.fast_csc(), x.sin().recip()), "fast_csc({}) = {}, expected {}", x, x.fast_csc(), x.sin().recip());
+                }
+            }
+        }
+
+        #[test]
+        fn angle_wrapping_stays_in_range_and_tracks_sin() {
+            assert_same_float!((-0.0 as $t).wrap_to_pi(), -0.0 as $t);
+            assert_same_float!($t::NAN.wrap_to_tau(), $t::NAN);
+            assert_same_float!($t::INFINITY.wrap_to_pi(), $t::NAN);
+            for x in $far {
+                assert!((0.0..consts::TAU).contains(&x.wrap_to_tau()), "wrap_to_tau({:?})", x);
+            }
+            for i in -$steps..=$steps {
+                let x = i as $t * 4.9;
+                for x in [x, (i as $t * consts::FRAC_PI_2).next_up(), (i as $t * consts::FRAC_PI_2).next_down()] {
+                    let (to_pi, to_tau) = (x.wrap_to_pi(), x.wrap_to_tau());
+                    assert!((-consts::PI..consts::PI).contains(&to_pi), "wrap_to_pi({:?}) = {:?}", x, to_pi);
+                    assert!((0.0..consts::TAU).contains(&to_tau), "wrap_to_tau({:?}) = {:?}", x, to_tau);
+                    // Multiples of the true tau come off, so the error stays near an ulp of tau
+                    assert!((to_pi.sin() - x.sin()).abs() < $sin_tolerance && (to_tau.cos() - x.cos()).abs() < $sin_tolerance, "wrapping {:?}", x);
+                    if x.abs() < consts::PI {
+                        assert_same_float!(to_pi, x);
+                    }
+                    if x.abs() < $exact_range {
+                        // Next to multiples of tau, 0 and just below tau are equally close
+                        let error = (to_tau - accuracy::exact::$t::wrap_to_tau(x)).abs();
+                        assert!(error.min((consts::TAU - error).abs()) < $exact_tolerance, "wrap_to_tau({:?}) = {:?}", x, to_tau);
+                    }
+                }
+            }
+        }
+    };
+}
+
+mod f64 {
+    use super::*;
+    use std::f64::consts;
+
+    test_trigonometry!(f64, X_F64, far: [7e6, -7e6, 1e300, -1e300], steps: 20000, sin_tolerance: 1e-14, exact_range: 1e4, exact_tolerance: 1e-11);
+
+    #[test]
+    fn angle_wrapping_defers_to_remainder_past_fast_range() {
+        assert_same_float!(consts::TAU.wrap_to_tau(), 0.0f64);
+        // Past |k| = 2^20 the reduction defers to %
+        for x in [7e6f64, -7e6, 1e300, -1e300] {
+            let r = x % consts::TAU;
+            assert_same_float!(x.wrap_to_tau(), if r < 0.0 { r + consts::TAU } else { r });
+        }
+    }
+}
+
+mod f32 {
+    use super::*;
+    use std::f32::consts;
+
+    test_trigonometry!(f32, X_F32, far: [7e6, -7e6, 1e30, -1e30], steps: 2000, sin_tolerance: 1e-6, exact_range: 1e3, exact_tolerance: 1e-3);
+}